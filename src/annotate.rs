@@ -0,0 +1,349 @@
+// Reverse lookup from Chinese text to pinyin. Text is split into 词语 of a
+// dictionary by greedy longest match, and each 词语 gets the pinyin of its
+// first reading (the one with the lowest HSK level). Text that matches no
+// 词语 passes through unchanged. Readings from a Jyutping dictionary are
+// Jyutping.
+use crate::dictionary::Dictionary;
+use crate::syllable::{self, Scheme};
+use crate::Writer;
+
+// Most characters of text to try matching as one 词语
+const CIYU_CHARS_MAX: usize = 16;
+
+// Vowels that take tone marks, with their marks for tones 1 to 4
+const MARKED: [(u8, [char; 4]); 6] = [
+    (b'a', ['ā', 'á', 'ǎ', 'à']),
+    (b'e', ['ē', 'é', 'ě', 'è']),
+    (b'i', ['ī', 'í', 'ǐ', 'ì']),
+    (b'o', ['ō', 'ó', 'ǒ', 'ò']),
+    (b'u', ['ū', 'ú', 'ǔ', 'ù']),
+    (b'v', ['ǖ', 'ǘ', 'ǚ', 'ǜ']),
+];
+
+// Spelling of pinyin readings.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PinyinStyle {
+    // nǐhǎo, with tone marks and ü
+    ToneMarks,
+    // ni3hao3, with 5 for neutral tone and ü. Jyutping readings always use
+    // tone numbers, like nei5hou2, unless the style is Ascii.
+    ToneNumbers,
+    // nihao, the same as a search key
+    Ascii,
+}
+
+// Layout of annotated text.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Layout {
+    // HTML ruby markup: <ruby>你好<rt>nǐhǎo</rt></ruby>
+    Ruby,
+    // Reading in parentheses after each 词语: 你好(nǐhǎo)
+    Interleaved,
+}
+
+// One piece of annotated text: a 词语 with its reading, or a run of text
+// that matched no 词语.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Word<'a> {
+    pub text: &'a str,
+    // Search key of the reading, or None for text that matched no 词语
+    pub key: Option<&'a str>,
+    // One tone digit per syllable (5 for neutral, 0 for unknown), or "" if
+    // the tones are not known, like for user dictionary entries
+    pub tones: &'a str,
+    // Phonetic scheme of key
+    pub scheme: Scheme,
+}
+impl Word<'_> {
+    // Side-effect: write reading in style to sink (nothing for unmatched text)
+    pub fn write_pinyin(&self, style: PinyinStyle, sink: &mut impl Writer) {
+        let key = match self.key {
+            Some(key) => key,
+            None => return,
+        };
+        let tones = self.tones.as_bytes();
+        let style = match (self.scheme, style) {
+            (Scheme::Jyutping, PinyinStyle::ToneMarks) => PinyinStyle::ToneNumbers,
+            _ => style,
+        };
+        let ends = match syllable::split(self.scheme, key, tones.len()) {
+            Some(ends) if style != PinyinStyle::Ascii && !tones.is_empty() => ends,
+            // Without syllables, the key is the best reading there is
+            _ => return sink.write(key),
+        };
+        let bytes = key.as_bytes();
+        let (mut start, mut k) = (0, 0);
+        for end in 1..=bytes.len() {
+            if ends & 1 << end == 0 {
+                continue;
+            }
+            // Leave out separators like the ` in "bier`gaici"
+            while start < end && !bytes[start].is_ascii_lowercase() {
+                start += 1;
+            }
+            let s = &bytes[start..end];
+            let tone = tones[k];
+            if k > 0 && style == PinyinStyle::ToneMarks && matches!(s[0], b'a' | b'e' | b'o') {
+                sink.write("'");
+            }
+            let mark = match (style, tone) {
+                (PinyinStyle::ToneMarks, b'1'..=b'4') => mark_position(s),
+                _ => None,
+            };
+            for (i, &b) in s.iter().enumerate() {
+                let c = match MARKED.iter().find(|m| m.0 == b) {
+                    Some((_, marks)) if mark == Some(i) => marks[usize::from(tone - b'1')],
+                    _ if b == b'v' => 'ü',
+                    _ => char::from(b),
+                };
+                sink.write(c.encode_utf8(&mut [0; 4]));
+            }
+            if style == PinyinStyle::ToneNumbers && tone != b'0' {
+                sink.write(core::str::from_utf8(&[tone]).unwrap_or_default());
+            }
+            start = end;
+            k += 1;
+        }
+    }
+}
+
+// Return index of the vowel that takes the tone mark in syllable s: a or e
+// if there is one, o of "ou", or else the last vowel.
+fn mark_position(s: &[u8]) -> Option<usize> {
+    let vowel = |b: &u8| MARKED.iter().any(|m| m.0 == *b);
+    s.iter()
+        .position(|&b| b == b'a' || b == b'e')
+        .or_else(|| s.windows(2).position(|w| w == b"ou"))
+        .or_else(|| s.iter().rposition(vowel))
+}
+
+// Iterator over the words of text.
+pub struct Words<'a> {
+    rest: &'a str,
+    dictionary: Dictionary<'a>,
+    // Match found while scanning a run of unmatched text
+    pending: Option<Word<'a>>,
+}
+impl<'a> Words<'a> {
+    // Return longest 词语 at the start of text, checking user entries first.
+    fn match_at(&self, text: &'a str) -> Option<Word<'a>> {
+        let mut ends = [0; CIYU_CHARS_MAX];
+        let mut count = 0;
+        for (end, slot) in text
+            .char_indices()
+            .map(|(i, c)| i + c.len_utf8())
+            .zip(ends.iter_mut())
+        {
+            *slot = end;
+            count += 1;
+        }
+        for &end in ends[..count].iter().rev() {
+            let ciyu = &text[..end];
+            if let Some((key, _)) = self.dictionary.user_entries().find(|&(_, c)| c == ciyu) {
+                let key = Some(key);
+                return Some(Word {
+                    text: ciyu,
+                    key,
+                    tones: "",
+                    scheme: self.dictionary.scheme(),
+                });
+            }
+            if let Some((i, nth)) = self.dictionary.readings_of(ciyu).next() {
+                let tones = self.dictionary.tones(i).split('\t').nth(nth).unwrap_or("");
+                return Some(Word {
+                    text: ciyu,
+                    key: Some(self.dictionary.key(i)),
+                    // First of alternate readings, like 好 hǎo in "3,4"
+                    tones: tones.split(',').next().unwrap_or(""),
+                    scheme: self.dictionary.scheme(),
+                });
+            }
+        }
+        None
+    }
+}
+impl<'a> Iterator for Words<'a> {
+    type Item = Word<'a>;
+
+    fn next(&mut self) -> Option<Word<'a>> {
+        if let Some(word) = self.pending.take() {
+            return Some(word);
+        }
+        let text = self.rest;
+        let mut unmatched = 0;
+        while unmatched < text.len() {
+            if let Some(word) = self.match_at(&text[unmatched..]) {
+                self.rest = &text[unmatched + word.text.len()..];
+                if unmatched == 0 {
+                    return Some(word);
+                }
+                self.pending = Some(word);
+                break;
+            }
+            let c = text[unmatched..].chars().next().unwrap_or_default();
+            unmatched += c.len_utf8();
+        }
+        if unmatched == text.len() {
+            self.rest = "";
+        }
+        match unmatched {
+            0 => None,
+            _ => Some(Word {
+                text: &text[..unmatched],
+                key: None,
+                tones: "",
+                scheme: self.dictionary.scheme(),
+            }),
+        }
+    }
+}
+
+// Split text into 词语 of dictionary and runs of unmatched text.
+pub fn words<'a>(text: &'a str, dictionary: &Dictionary<'a>) -> Words<'a> {
+    Words {
+        rest: text,
+        dictionary: *dictionary,
+        pending: None,
+    }
+}
+
+// Annotate 词语 of text with their pinyin.
+// Side-effect: writes annotated text in layout to sink, with unmatched text
+// unchanged.
+pub fn annotate(
+    text: &str,
+    dictionary: &Dictionary,
+    style: PinyinStyle,
+    layout: Layout,
+    sink: &mut impl Writer,
+) {
+    for word in words(text, dictionary) {
+        if word.key.is_none() {
+            sink.write(word.text);
+            continue;
+        }
+        match layout {
+            Layout::Ruby => {
+                sink.write("<ruby>");
+                sink.write(word.text);
+                sink.write("<rt>");
+                word.write_pinyin(style, sink);
+                sink.write("</rt></ruby>");
+            }
+            Layout::Interleaved => {
+                sink.write(word.text);
+                sink.write("(");
+                word.write_pinyin(style, sink);
+                sink.write(")");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{annotate, words, Layout, PinyinStyle, Scheme, Word};
+    use crate::dictionary::Dictionary;
+    use crate::user_dictionary::UserDictionary;
+    use crate::{autogen_hsk, BufWriter, Writer};
+
+    fn annotated(text: &str, style: PinyinStyle, layout: Layout) -> BufWriter {
+        let mut sink = BufWriter::new();
+        annotate(text, &Dictionary::hsk(), style, layout, &mut sink);
+        sink
+    }
+
+    #[test]
+    fn pinyin_styles() {
+        let text = "我想喝果汁";
+        let sink = annotated(text, PinyinStyle::ToneMarks, Layout::Interleaved);
+        // HSK4 pinyin has no tones, so 果汁 gets none
+        assert_eq!("我(wǒ)想(xiǎng)喝(hē)果汁(guozhi)", sink.to_s());
+        let sink = annotated(text, PinyinStyle::ToneNumbers, Layout::Interleaved);
+        assert_eq!("我(wo3)想(xiang3)喝(he1)果汁(guozhi)", sink.to_s());
+        let sink = annotated(text, PinyinStyle::Ascii, Layout::Interleaved);
+        assert_eq!("我(wo)想(xiang)喝(he)果汁(guozhi)", sink.to_s());
+    }
+
+    #[test]
+    fn ruby_layout() {
+        let sink = annotated("北京朋友!", PinyinStyle::ToneMarks, Layout::Ruby);
+        let ruby = "<ruby>北京<rt>běijīng</rt></ruby><ruby>朋友<rt>péngyou</rt></ruby>!";
+        assert_eq!(ruby, sink.to_s());
+    }
+
+    #[test]
+    fn unmatched_text_passes_through() {
+        let sink = annotated("OK,我们😀", PinyinStyle::ToneNumbers, Layout::Interleaved);
+        assert_eq!("OK,我们(wo3men5)😀", sink.to_s());
+        let mut it = words("abc我", &Dictionary::hsk());
+        let unmatched = Word {
+            text: "abc",
+            key: None,
+            tones: "",
+            scheme: Scheme::Pinyin,
+        };
+        assert_eq!(Some(unmatched), it.next());
+        assert_eq!(Some("我"), it.next().map(|w| w.text));
+        assert_eq!(None, it.next());
+    }
+
+    #[test]
+    fn marks_go_on_the_right_vowel() {
+        let sink = annotated("女儿喜欢旅游", PinyinStyle::ToneMarks, Layout::Interleaved);
+        assert_eq!("女儿(nǚ'ér)喜欢(xǐhuan)旅游(lǚyóu)", sink.to_s());
+        let sink = annotated("比尔·盖茨", PinyinStyle::ToneMarks, Layout::Interleaved);
+        assert_eq!("比尔·盖茨(bǐ'ěrgàicí)", sink.to_s());
+    }
+
+    #[test]
+    fn lowest_level_reading_comes_first() {
+        let sink = annotated("长", PinyinStyle::ToneMarks, Layout::Interleaved);
+        assert_eq!("长(cháng)", sink.to_s());
+        let sink = annotated("长城", PinyinStyle::ToneNumbers, Layout::Interleaved);
+        assert_eq!("长城(changcheng)", sink.to_s());
+    }
+
+    #[test]
+    fn jyutping_readings_use_tone_numbers() {
+        let mut sink = BufWriter::new();
+        let dictionary = Dictionary::jyutping();
+        let (style, layout) = (PinyinStyle::ToneMarks, Layout::Interleaved);
+        annotate("你好，食咗飯未？", &dictionary, style, layout, &mut sink);
+        assert_eq!(
+            "你好(nei5hou2)，食咗飯(sik6zo2faan6)未(mei6)？",
+            sink.to_s()
+        );
+    }
+
+    #[test]
+    fn user_entries_have_ascii_readings() {
+        let mut buf = [0u8; 64];
+        let mut user = UserDictionary::new(&mut buf);
+        user.add("Xīngbākè", "星巴克").unwrap();
+        let dictionary = Dictionary::hsk().with_user(&user);
+        let mut sink = BufWriter::new();
+        let style = PinyinStyle::ToneMarks;
+        annotate(
+            "去星巴克",
+            &dictionary,
+            style,
+            Layout::Interleaved,
+            &mut sink,
+        );
+        assert_eq!("去(qù)星巴克(xingbake)", sink.to_s());
+    }
+
+    // Every vocab 词语 should be found in the reverse index as a whole word.
+    #[test]
+    fn all_vocab_words_have_readings() {
+        let dictionary = Dictionary::hsk();
+        for (pinyin, ciyu) in autogen_hsk::PINYIN_CIYU_TEST_DATA.iter() {
+            // Punctuation is not in the reverse index
+            if pinyin.bytes().any(|b| b.is_ascii_lowercase()) {
+                let word = words(ciyu, &dictionary).next().unwrap();
+                assert_eq!((*ciyu, true), (word.text, word.key.is_some()));
+            }
+        }
+    }
+}
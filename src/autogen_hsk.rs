@@ -1,5132 +1,10 @@
 #![allow(dead_code)]
 // This file is automatically generated. DO NOT MAKE EDITS HERE!
-// To make changes, see ../vocab/autogen-hsk.rb
+// To make changes, see bin/autogen-hsk.rs
 
-// The longest phrase in the list below has PINYIN_SIZE_MAX characters.
-// No need to consider longer slices when checking for match.
-// Note: UTF-8 means both (chars == bytes) or (chars != bytes) possible.
-pub const PINYIN_SIZE_MAX: usize = 25;
-
-// u32 constants are murmur3 hash of pinyin search keys
-pub const MURMUR3_SEED: u32 = 0;
-pub static PINYIN: &[u32] = &[
-    0x000050fb, // ruo
-    0x00155eb9, // beiguan
-    0x002bda55, // shouhuo
-    0x00341b96, // xiangfang
-    0x0058109d, // guimo
-    0x005b3f4f, // yumi
-    0x005beec7, // zheli
-    0x0060c1d6, // laoying
-    0x0064c402, // riji
-    0x0066ab0e, // xiangjiao
-    0x0085149f, // linshi
-    0x00916c68, // sunshi
-    0x00a45150, // hutong
-    0x00a9621b, // zhijie
-    0x00cd84aa, // xingqu
-    0x0102d945, // chengyu
-    0x01195e68, // jiaoshui
-    0x012707d9, // cai
-    0x012cf7ca, // kuazhang
-    0x01443991, // choulou
-    0x01732c9b, // wangsheng
-    0x019117a3, // qiaoqiao
-    0x01ad0cae, // shuaidao
-    0x021f88dd, // laolao
-    0x0221d688, // zaisan
-    0x0224acb9, // dui
-    0x025b4a74, // xingbie
-    0x02723111, // youyou
-    0x02824b69, // huasheng
-    0x028c89c3, // qian
-    0x02a44c92, // shuzi
-    0x02aaf006, // tang
-    0x02c07940, // fenzhong
-    0x02c1b7dc, // chengshou
-    0x02c9e67e, // leixing
-    0x02daa265, // juese
-    0x02e1b450, // juban
-    0x0309744f, // haochu
-    0x037e46cf, // fanrong
-    0x03a09e17, // jiezhe
-    0x03a844f5, // chengzuo
-    0x03cbba4d, // yidan
-    0x03f04cd9, // chongwu
-    0x03fd0e4f, // yuqi
-    0x04053f6c, // fangxin
-    0x040cbc7c, // weibo
-    0x044355b0, // hu
-    0x04452b3d, // riyongpin
-    0x044c787d, // shouming
-    0x04742710, // banshi
-    0x0481772b, // luxun
-    0x04860cf6, // nongye
-    0x04a2598f, // diqiu
-    0x04bacedf, // huai
-    0x051bfb53, // bian
-    0x0522ff8e, // nian
-    0x0566d297, // sheru
-    0x0579cbb1, // juli
-    0x057d203d, // tuijian
-    0x05a1e14c, // zhuangtai
-    0x05cb4683, // lihai
-    0x05d9af9b, // renbuzhu
-    0x05e58d93, // jianzhu
-    0x060e5387, // gonggongqiche
-    0x0612bb05, // shihua
-    0x061bd6a6, // jujue
-    0x062c7c89, // xingren
-    0x0647e54c, // sheji
-    0x066e8cd4, // chufang
-    0x06783105, // shenti
-    0x06dc4e3f, // shoushu
-    0x06e0d479, // ouran
-    0x06f93972, // chaoshi
-    0x071b0a5b, // bujiasisuo
-    0x073273ae, // chuxian
-    0x07472bf3, // ganxiang
-    0x074a8b8a, // hangye
-    0x074eb454, // zui
-    0x0773ada8, // zhuanmen
-    0x07a09663, // chunyou
-    0x07aa9186, // ciyao
-    0x07b145a5, // fannao
-    0x07d3e9e6, // kexue
-    0x07d6f270, // youmei
-    0x080df1c9, // baochi
-    0x081b99ac, // laoban
-    0x0820fd5a, // maibuliqi
-    0x0839255e, // cha
-    0x08720225, // xiandai
-    0x0872a1dd, // jiating
-    0x08802645, // shaowei
-    0x0890a841, // baobei
-    0x0892a038, // mianlin
-    0x089f113f, // zhuangkuang
-    0x08bfb9d3, // meili
-    0x08c074ea, // liqi
-    0x08d131e4, // suoduan
-    0x08e0925a, // zhengqi
-    0x090e5c38, // weikou
-    0x09296e1c, // mangren
-    0x092bfdde, // jihu
-    0x09728f95, // fengfu
-    0x09926663, // queren
-    0x09a99b9a, // julebu
-    0x09c997a0, // wancheng
-    0x0a4dbe5c, // bukeqi
-    0x0a7ee1e2, // jieshu
-    0x0aad6f37, // jianjue
-    0x0ae65de5, // chengxu
-    0x0b10072e, // mei
-    0x0b2d1aa2, // cuxin
-    0x0b2ec54f, // zhanlan
-    0x0b58a15f, // xunsu
-    0x0b5a5eaf, // jiayouzhan
-    0x0b616aef, // zhaoshe
-    0x0b712f1a, // lishi
-    0x0b73a249, // fanwei
-    0x0ba14561, // qiguai
-    0x0bcd1c14, // gandao
-    0x0bd12c3d, // banye
-    0x0be0fdc2, // meitan
-    0x0c3c95a4, // chuantong
-    0x0c41d2ad, // keji
-    0x0c5651d8, // pingfang
-    0x0c6857e2, // lianghao
-    0x0c7bf8b4, // wunai
-    0x0c870953, // shenzhi
-    0x0c8d30ed, // guoqi
-    0x0c8e1484, // jilie
-    0x0c9ce39d, // panzi
-    0x0cb3daf4, // zhongwu
-    0x0cdeae58, // gudai
-    0x0cdf42fc, // aihao
-    0x0cf28653, // kaiwanxiao
-    0x0cf9e928, // jingchengsuozhi
-    0x0d10c1a5, // neike
-    0x0d1fa89d, // yilian
-    0x0db04be3, // laizi
-    0x0dd72a13, // kaimushi
-    0x0e41a62d, // wudao
-    0x0e79e87f, // zaihu
-    0x0e9aead6, // wuyi
-    0x0ea0f6f4, // didian
-    0x0eb37000, // nanshou
-    0x0ed4eb19, // yinqi
-    0x0ee11dd8, // youshi
-    0x0f1b04e5, // ceng
-    0x0f4ec3d1, // jiaozi
-    0x0fcaf412, // zhongshi
-    0x0fd58239, // cuo
-    0x1016fa10, // jilei
-    0x10174403, // yanjiu
-    0x101bc8a2, // leguan
-    0x10217658, // pingheng
-    0x1050669a, // chukou
-    0x10693dab, // gongxi
-    0x109913b0, // huanying
-    0x10ab5f3d, // pangbian
-    0x10c72eed, // chexiang
-    0x10d241f9, // haiyang
-    0x10de96c5, // wuliao
-    0x10efa491, // sheyingshi
-    0x10f96b81, // tuo
-    0x11081212, // jinshen
-    0x110d2925, // ta
-    0x110d3c52, // gongchengshi
-    0x111846f1, // anpai
-    0x112809c2, // beijingshifandaxue
-    0x112c7ac4, // hanyu
-    0x11439568, // xingyun
-    0x114f1b0d, // xiaoyu
-    0x117a0fd9, // youqu
-    0x11eccee8, // wei
-    0x12268b11, // zenme
-    0x1226922f, // zunzhong
-    0x12303385, // didi
-    0x1237f9b2, // zhongjian
-    0x124d6b35, // danyuan
-    0x12518bd2, // zhankai
-    0x126c172f, // chi
-    0x129c533c, // kongtiao
-    0x129f53dd, // ai
-    0x12fba789, // quedian
-    0x13116863, // liyou
-    0x131dec83, // yagao
-    0x134cb624, // daoyan
-    0x134df849, // zhongwen
-    0x13650d77, // renkou
-    0x139ede6a, // youxian
-    0x13a462f5, // yeyu
-    0x13b014e7, // gaosu
-    0x13dd754c, // laohu
-    0x13fdf9c5, // guitai
-    0x140f82cc, // hushuo
-    0x14164094, // peizhansen
-    0x14405de5, // richeng
-    0x14585fed, // dute
-    0x14607da0, // xiaoshun
-    0x1466e660, // darao
-    0x14675df4, // xuyao
-    0x147cf13e, // zhiyao
-    0x14a2319e, // qianbao
-    0x14d92534, // youdianr
-    0x14e1ebf0, // pei`zhansen
-    0x14f9a308, // xushu
-    0x1511a275, // gewai
-    0x152c0443, // zisha
-    0x153bd679, // zhexie
-    0x154a4838, // nanguo
-    0x15575a99, // jingzheng
-    0x1578ec80, // yaobu
-    0x15ca03a8, // shuai
-    0x15e763b0, // fashao
-    0x15ebdc5f, // laji
-    0x1652ffd3, // mahu
-    0x16556cff, // jianli
-    0x1672836b, // `
-    0x16819bdf, // shangdian
-    0x16a3f922, // shao
-    0x16b51708, // xingkui
-    0x16e78217, // heshi
-    0x16f1aa08, // kunchong
-    0x1703bd46, // jiazi
-    0x1709131d, // baoming
-    0x17290ca9, // guannian
-    0x173b6e8e, // chenghu
-    0x174e3279, // weiba
-    0x1752c980, // daoyou
-    0x17582235, // ganjue
-    0x175fcf67, // liaotianr
-    0x17951341, // huoban
-    0x17acbc19, // fenpei
-    0x17cdd84f, // qiong
-    0x17d616ef, // biaozhun
-    0x17d95811, // baoxian
-    0x17df386e, // jiti
-    0x180d1802, // zhiding
-    0x18109247, // zishi
-    0x1816d715, // yinliang
-    0x181cff59, // tian
-    0x1844fbad, // zhengqiu
-    0x1884693e, // zongjie
-    0x188662ea, // zhuyao
-    0x189210cd, // zhiliang
-    0x18b0f9e8, // zhaoxiangji
-    0x18c43239, // yixia
-    0x18d53bb6, // (
-    0x190ac39f, // xiangpi
-    0x192fc038, // yihuir
-    0x1943c14d, // san
-    0x194aa5a5, // shengdiao
-    0x19787c91, // laoshi
-    0x199242e8, // cucao
-    0x1997d8de, // ganjing
-    0x199e0107, // quanmian
-    0x19ad9a30, // daozhi
-    0x1a11bb8b, // huang
-    0x1a1476e3, // ce
-    0x1a4fe4e0, // qiantu
-    0x1a51791f, // zhuyi
-    0x1a6f9273, // zisi
-    0x1a832f81, // tiaozhan
-    0x1ac331b5, // shenghuo
-    0x1ac91569, // shizhong
-    0x1b237bf4, // sixiang
-    0x1b5754de, // nvhair
-    0x1b6436ae, // chenshan
-    0x1b7f32f0, // jingju
-    0x1b8a53b9, // juti
-    0x1b97f1d2, // xiqu
-    0x1b9beb26, // zuoye
-    0x1b9e44cb, // bawo
-    0x1bbbc7be, // shijin
-    0x1bdf71d5, // yi
-    0x1bfaee64, // maojin
-    0x1bfd0067, // bier`gaici
-    0x1c1baf7d, // liuli
-    0x1c224cdd, // qiaomiao
-    0x1c3d6f85, // zhongda
-    0x1c45ce9e, // qunzi
-    0x1caa6f93, // miqie
-    0x1cb913bc, // feihua
-    0x1cc786ef, // sheshi
-    0x1ccc0ccb, // ma
-    0x1cfcd293, // )
-    0x1d10148b, // leng
-    0x1d3240ce, // tudi
-    0x1d49f169, // zhanghu
-    0x1d4bc6c6, // juran
-    0x1d967401, // daodi
-    0x1dae932e, // xiangshan
-    0x1db16adb, // yaoshi
-    0x1dc58a0e, // kou
-    0x1dd1d467, // qingyuanshu
-    0x1df272d0, // la
-    0x1e1e0d19, // tizuqiu
-    0x1e3acbdb, // ruwei
-    0x1e723b18, // enai
-    0x1e97b218, // dai
-    0x1eb4813c, // nimen
-    0x1eb8df51, // yuyan
-    0x1ed18529, // lou
-    0x1f15329b, // deyi
-    0x1f24175c, // jixu
-    0x1f6ea32d, // sichou
-    0x1f7e6bd1, // renming
-    0x1f873924, // zhizuo
-    0x1fabacd3, // shangpin
-    0x1fbe4272, // jianpan
-    0x1fd17ae3, // shushu
-    0x1fe5a589, // jiazhuang
-    0x1ff1ed91, // xiangdui
-    0x2021d1ba, // zongli
-    0x2083e4cc, // zige
-    0x2086fe82, // mutou
-    0x20afc9a8, // liyong
-    0x20c2bec2, // timu
-    0x20c60fd8, // ranhou
-    0x20fbf8a4, // yuanyin
-    0x21294d22, // jiabin
-    0x2131fe1b, // han
-    0x213a4e1d, // heixingxing
-    0x214bddd2, // mianshi
-    0x21628fef, // baoyuan
-    0x21974ab1, // juan
-    0x219d2e9f, // nengli
-    0x21ad3abc, // keneng
-    0x21c04be2, // xin
-    0x21e2cf60, // suowei
-    0x21e528a7, // suishou
-    0x21f1f796, // chuguo
-    0x21fc6f63, // nuobeierjiang
-    0x220cc0f9, // jiujing
-    0x221748da, // wenju
-    0x221a4eca, // donghuapian
-    0x222d0a8c, // zhihao
-    0x2236193c, // bici
-    0x223b026a, // xiangxin
-    0x225bc0c2, // pohuai
-    0x2271e4aa, // hou
-    0x2297f0e8, // beizi
-    0x22afc846, // lvshi
-    0x22bbe504, // lengdan
-    0x22de09b7, // xiuxi
-    0x22f02ba5, // chengshu
-    0x2307b012, // zaiyu
-    0x23082a40, // jiji
-    0x23408edb, // rujin
-    0x234861b6, // quanshenguanzhu
-    0x2389db75, // jiayou
-    0x238b1c79, // langman
-    0x23bab692, // renwei
-    0x23be93dc, // qingshaonian
-    0x23e402c8, // zhixu
-    0x23e49b23, // haohua
-    0x23eb2fec, // jinzhang
-    0x23ee1f9f, // qie
-    0x23f37989, // baodao
-    0x24186879, // luohou
-    0x24416c95, // zhuozi
-    0x245f7f62, // qiju
-    0x2462d82a, // yiwu
-    0x246675db, // qingkuang
-    0x248f783a, // huangzhang
-    0x24a7ce4c, // zhuajin
-    0x24f7c245, // beijing
-    0x25042309, // lvxing
-    0x25143b9f, // pen
-    0x252d2e68, // zuopin
-    0x253d539d, // lingjian
-    0x254f6997, // shihe
-    0x2553c2c7, // tianran
-    0x259327b8, // fanyi
-    0x25965895, // zhangfu
-    0x25b19dfd, // jiaoji
-    0x25e1f29c, // chizao
-    0x25f2705d, // taolun
-    0x2622862f, // dashiguan
-    0x263440d9, // huguosi
-    0x268be89c, // caiqu
-    0x26923417, // biaoming
-    0x26ddeb8d, // gaijin
-    0x26f20d98, // fense
-    0x26f4dd77, // lu
-    0x2717b84c, // kekao
-    0x274945c7, // guangpan
-    0x2788bb71, // bizi
-    0x279b1255, // xiangchang
-    0x27e38a91, // nianling
-    0x27eba344, // wuran
-    0x28380e80, // chuanglian
-    0x286074ac, // zhudong
-    0x2877ca93, // qingdan
-    0x28ba020d, // he
-    0x28bc4af0, // guanzi
-    0x28c3270e, // youyisi
-    0x28da4423, // liushou
-    0x29014e17, // daiyan
-    0x290bc6b0, // guangchang
-    0x292bb811, // yingjian
-    0x2984df09, // pingdeng
-    0x29924e28, // huran
-    0x29c40065, // wangji
-    0x29c64f88, // jingcha
-    0x29d7ffad, // taijiquan
-    0x29db12b4, // juedui
-    0x29f78fc1, // yiyi
-    0x2a192fe8, // xinglixiang
-    0x2a386283, // shouju
-    0x2a6757ee, // yue
-    0x2a76113e, // yuanliao
-    0x2a7a3c16, // haizi
-    0x2a95eee6, // zhaohuo
-    0x2a9c4572, // daying
-    0x2ab626b7, // daifu
-    0x2abc7e4a, // jianzhi
-    0x2ae630b4, // fanchuan
-    0x2ae97113, // jianqing
-    0x2aff66d4, // zidong
-    0x2b038801, // a
-    0x2b1ca8fb, // zhao
-    0x2b336955, // jianju
-    0x2b3b6878, // jinli
-    0x2b53f723, // meimei
-    0x2b8c40aa, // budebu
-    0x2b8e817a, // shougong
-    0x2bc93f00, // jieshao
-    0x2bdeba4a, // nv
-    0x2bf6a428, // geng
-    0x2c1a14a9, // youqi
-    0x2c268174, // yilv
-    0x2c3f1bfb, // sihao
-    0x2c3f4b0a, // fahui
-    0x2c79f9c7, // weixian
-    0x2c9243ae, // <maihuochaidexiaonvhaier>
-    0x2c9b752b, // jizhe
-    0x2caf8201, // jihua
-    0x2cb41244, // dagong
-    0x2cbeea3b, // miao
-    0x2cc63e0d, // shuang
-    0x2cdb24c7, // yisi
-    0x2ce0a73b, // gege
-    0x2ce8d8b4, // youhui
-    0x2cf16df9, // gongli
-    0x2d0b5614, // jianbang
-    0x2d10001f, // goucheng
-    0x2d143d20, // bingqie
-    0x2d1fa9d9, // fengxian
-    0x2d748045, // lianai
-    0x2da03ddc, // suoyi
-    0x2dbe86a3, // jianshao
-    0x2dd6dc98, // lihun
-    0x2de55ed6, // waigong
-    0x2df2ab38, // anwei
-    0x2df4751f, // kanfa
-    0x2df8ca17, // zhongyao
-    0x2e60de8b, // hanyi
-    0x2e7fc924, // anzhao
-    0x2e8fb4eb, // daomei
-    0x2e923ebf, // tizi
-    0x2ed03639, // yachi
-    0x2ed0b0cb, // xinhao
-    0x2f168438, // diantai
-    0x2f1f1d6c, // shenglve
-    0x2f2cc657, // shengbing
-    0x2f3afcee, // shenyuan
-    0x2f4bf29c, // yezi
-    0x2f517f0d, // ceyan
-    0x2f58a6ef, // baba
-    0x2f7709ca, // ganhuor
-    0x2f896fa8, // qishi
-    0x2f9907ff, // guahao
-    0x2fb1da28, // yangguang
-    0x2fcf7ad7, // kuangquanshui
-    0x2fdfc442, // xinxian
-    0x300bea7c, // bunaifan
-    0x3013ac33, // xishoujian
-    0x301a51cb, // qiyu
-    0x302db469, // fanzheng
-    0x305a0bde, // cunzai
-    0x307329ec, // xiamian
-    0x3074029a, // diao
-    0x3075f55a, // jiqi
-    0x30d63a25, // guaiwan
-    0x30f55a3b, // houbei
-    0x30fcf877, // mantou
-    0x3112abbb, // jian
-    0x3127fbb0, // yisheng
-    0x31321647, // shengming
-    0x31379d58, // xiaoshi
-    0x31573d62, // shixi
-    0x315a9372, // guke
-    0x31835d23, // nen
-    0x31aa8a70, // xiangguan
-    0x31b2d1e8, // shuiping
-    0x3205fe47, // xiezuo
-    0x3217d15a, // bingjiling
-    0x32378950, // biaoyan
-    0x3239defa, // jiangjiu
-    0x325bd011, // kuaizi
-    0x32b2ced2, // zhaoguo
-    0x32ccd10f, // zhiye
-    0x32e7501c, // guanxin
-    0x32f18ca5, // xingfu
-    0x32f57404, // weiqu
-    0x32f603d3, // bijiben
-    0x33029bb2, // shangwang
-    0x332895a7, // hebi
-    0x335efa35, // bucuo
-    0x336a7594, // dianchi
-    0x336eb256, // xiexie
-    0x3379c72c, // yongji
-    0x337a6ddc, // xinxin
-    0x3381312d, // baotuquan
-    0x3387e972, // yiyang
-    0x338d840b, // kong
-    0x33921e66, // qiyou
-    0x33b44f76, // xialingying
-    0x33c7caf4, // zhengju
-    0x33f8679c, // shai
-    0x34017b0d, // ganqing
-    0x3414429e, // neibu
-    0x341cf66c, // jiaotong
-    0x342e35e7, // nan
-    0x343026e6, // suan
-    0x343357d6, // wusuowei
-    0x3454f3c9, // caishikou
-    0x34703791, // laoshe
-    0x3478743f, // huaiyun
-    0x3478d8f2, // tuya
-    0x347d94f9, // shousui
-    0x34855b85, // piaoliang
-    0x3486c18e, // xinxi
-    0x34cd1c6d, // shiyan
-    0x34d8c110, // zhuchi
-    0x34dac768, // heiban
-    0x34e43d8c, // dapenti
-    0x34f97a15, // qianbi
-    0x34ff48c4, // hege
-    0x350285e5, // mifan
-    0x3513b272, // shafa
-    0x3520b8db, // tui
-    0x35574bdc, // tiao
-    0x355b2cd8, // nali
-    0x3566cac7, // yongbao
-    0x35a5eb79, // tou
-    0x35a639a0, // kuaiji
-    0x35b11ea8, // guanyu
-    0x35b84211, // xinnian
-    0x35d72b2e, // xuanze
-    0x35dafa4b, // nongcun
-    0x35e0a247, // ruan
-    0x35ff96d7, // chouxiang
-    0x3630e479, // yu
-    0x3653935e, // fangfa
-    0x3655284b, // kao
-    0x367756e3, // shanliang
-    0x36a8b5c0, // guoqu
-    0x36fee8e5, // keen
-    0x370e27c8, // dadao
-    0x372392ad, // shengdong
-    0x375bc7a1, // ziliao
-    0x376d1937, // kunrao
-    0x37709f72, // chuban
-    0x3772a371, // jidao
-    0x377422f2, // tingshuo
-    0x378dc319, // shubiao
-    0x37a76a6f, // shengyin
-    0x37ce320d, // zhiliao
-    0x37d75ffa, // yazhou
-    0x37f45469, // peizhun
-    0x381362dc, // biyao
-    0x3825b691, // ganji
-    0x382737f9, // luqu
-    0x3850c0f5, // shishi
-    0x385607cf, // zhuanbian
-    0x385f8759, // danren
-    0x38ae1fe8, // tu
-    0x38bbcdf1, // yiwen
-    0x38bdbd13, // shaoxu
-    0x38bec11b, // feinimoshu
-    0x38dd51ec, // kelian
-    0x38dec0e6, // chehuo
-    0x38e42cc1, // shen
-    0x38e6cca1, // deng
-    0x390821cc, // guilv
-    0x39473889, // xiaoyuan
-    0x3954f551, // cun
-    0x3998c7bf, // jingguo
-    0x399e4b2d, // yiding
-    0x39b1db68, // laoshu
-    0x39e77e63, // yeye
-    0x3a1be3f6, // meiyou
-    0x3a1daa3f, // touru
-    0x3a211011, // genju
-    0x3a4771e2, // shiqi
-    0x3a5fc5f6, // tuibu
-    0x3a690e94, // ha
-    0x3a86a04b, // gan
-    0x3a91c76e, // suishi
-    0x3acad72a, // yun
-    0x3b1b5da1, // gudian
-    0x3b1c3960, // kuang
-    0x3b5527b6, // dandu
-    0x3b668005, // shengyi
-    0x3b9ad4ac, // yange
-    0x3bb76bba, // qing
-    0x3bbda547, // quanbu
-    0x3bcb505e, // shei
-    0x3bdbef44, // yongqi
-    0x3c220957, // lianhe
-    0x3c258eb1, // baohu
-    0x3c7beae3, // zhishi
-    0x3c867248, // xinren
-    0x3c91dee4, // zhengjian
-    0x3cd868d9, // chuanran
-    0x3ce8a001, // pindao
-    0x3d1e51f0, // jindai
-    0x3d54c1eb, // paidui
-    0x3d5a77fd, // penzi
-    0x3da3954d, // jiazhi
-    0x3dcd8b1f, // yiran
-    0x3dd06b04, // guli
-    0x3deb1226, // kepa
-    0x3dfaad2a, // du
-    0x3e1b056e, // teng
-    0x3e202fe0, // chengjiu
-    0x3e27a350, // jieguo
-    0x3e2f25f5, // yaoqing
-    0x3e341754, // shijuan
-    0x3e8160c4, // ku
-    0x3ea0533c, // da
-    0x3ea8fd6e, // yuandan
-    0x3ecd8f70, // bei
-    0x3eda8d81, // xijie
-    0x3efd3d07, // jinxing
-    0x3f0010b7, // zaijian
-    0x3f44f44f, // lijie
-    0x3f7b8e22, // shuju
-    0x3f835c8e, // jiekou
-    0x3fa3f5f0, // zenmeyang
-    0x3fd4e7d5, // kongzhi
-    0x3ff8bb31, // renyuan
-    0x3ffcb9de, // chun
-    0x401d5c86, // kan
-    0x40407dd1, // chuzuche
-    0x404cd1f4, // jirou
-    0x404e8c07, // yashua
-    0x4051a757, // caifang
-    0x4052b7ef, // guangda
-    0x40758501, // biye
-    0x4080c7f3, // zhanmushinaishimisi
-    0x408b74a1, // wangfan
-    0x40a715a3, // dazhen
-    0x40c18c23, // qilai
-    0x40cdb646, // can
-    0x40cdd35f, // wushu
-    0x410b715c, // niandai
-    0x41103e45, // guzhang
-    0x411bdacc, // wending
-    0x41239d1f, // zhengzai
-    0x4134f0c3, // luxu
-    0x41660f6b, // miaotiao
-    0x41982ff6, // gei
-    0x41a5766e, // jilingdai
-    0x41a7eb65, // qiu
-    0x41dd2e88, // zhenglun
-    0x422f78c1, // dedao
-    0x423f8b81, // pang
-    0x424934f2, // qubie
-    0x42560658, // luobo
-    0x4267dbd0, // xiayu
-    0x428ecfbc, // liuyiertongjie
-    0x42925447, // putong
-    0x42a78f37, // milu
-    0x42ae3705, // lianhuanhua
-    0x42cb1686, // tudou
-    0x42de69fc, // xiguan
-    0x42f14302, // weixia
-    0x433b044f, // shuliang
-    0x4340fc85, // nvshi
-    0x436332e3, // gongtong
-    0x4364c18f, // fangjian
-    0x4383a6cd, // yuan
-    0x438bc310, // junyun
-    0x43a88cbf, // baoyu
-    0x441e7427, // yangtai
-    0x4422a8d7, // xinqing
-    0x4446c252, // zhanguo
-    0x446ad11e, // yingpin
-    0x4486fac9, // guiju
-    0x4489afdc, // nandao
-    0x44d6183f, // ye
-    0x44f9bd32, // fengjing
-    0x4504b4a0, // biguan
-    0x45160251, // naru
-    0x451e890d, // lingyu
-    0x4522481b, // liulan
-    0x45735c0a, // qizhong
-    0x457a7cb1, // jingshen
-    0x457cdc66, // jianglai
-    0x459bd1a2, // xuexi
-    0x45a67a9a, // banli
-    0x45a878d2, // gexing
-    0x45ad4586, // gouwu
-    0x45b8ae77, // taojiahuanjia
-    0x45cb4a97, // juede
-    0x45d98c14, // qinzi
-    0x45e0a7fb, // mama
-    0x45ef573f, // ;
-    0x45fb32d9, // jingran
-    0x46013be6, // bangwan
-    0x4608f313, // wanju
-    0x46254d78, // kongr
-    0x4648d963, // zuowei
-    0x4680771d, // feiji
-    0x46828705, // beifang
-    0x46b85ca4, // quan
-    0x46c058ed, // huabei
-    0x46c58c7c, // baozhi
-    0x46e91d8d, // yingzi
-    0x46f91ddc, // changqi
-    0x46fff4df, // chanpin
-    0x4702367e, // mingming
-    0x4736ac0c, // ciyu
-    0x47518cad, // linlijinzhi
-    0x47672f98, // lianxu
-    0x47a0b066, // chazi
-    0x47baba69, // qiaokeli
-    0x47bb935c, // zhengge
-    0x47c9f3a0, // mingtian
-    0x47e7e652, // che
-    0x4802f27f, // ri
-    0x4822a0eb, // zhuzi
-    0x4840e769, // hutu
-    0x4849c911, // dengyu
-    0x48970cd6, // gebie
-    0x489af6b9, // xiaoqi
-    0x48a96e49, // fadou
-    0x48b2f867, // xingge
-    0x48df8c54, // xiaopengyou
-    0x48e8768e, // cesuo
-    0x48ea5aec, // qifa
-    0x48efb1f5, // hunyin
-    0x4911ec49, // naozhong
-    0x493bfe19, // nainai
-    0x495e2f55, // sanbu
-    0x49660670, // fapiao
-    0x49683caa, // xianzhi
-    0x4981da75, // shoutao
-    0x4986e51d, // niunai
-    0x498befc1, // bisai
-    0x498ccedf, // keren
-    0x498ffd8f, // kua
-    0x4992173e, // mima
-    0x499b4ff5, // zancheng
-    0x49a77388, // putao
-    0x49c9baac, // dafang
-    0x49d42ae9, // xihongshi
-    0x4a132ab2, // bacheng
-    0x4a36e35a, // yanjing
-    0x4a3c1f9f, // yizai
-    0x4a4d0545, // huida
-    0x4a4ec1cd, // miantiao
-    0x4a583d3b, // jingji
-    0x4a8d0c54, // zhaor
-    0x4b2eef21, // bujin
-    0x4b4bdfc4, // zuoke
-    0x4b52ab6f, // dang
-    0x4b62b5d4, // jushuo
-    0x4b646cf1, // hen
-    0x4b81a181, // tiwen
-    0x4b881b3b, // chen
-    0x4b8a6980, // nanguai
-    0x4b9d7f61, // chaoguo
-    0x4bc7e70d, // shoudujichang
-    0x4bdb20e5, // caidan
-    0x4c504f62, // biaoqing
-    0x4c870bfd, // ti
-    0x4cc6d030, // baocun
-    0x4cd86be1, // jiaoshi
-    0x4d003eeb, // waijiao
-    0x4d0e6fa7, // ping
-    0x4d132bff, // yanse
-    0x4d35ecd9, // feng
-    0x4d3ea9d1, // yingfu
-    0x4d403eca, // huiyi
-    0x4d41cab2, // qingyi
-    0x4d61f58e, // long
-    0x4d850071, // fayuan
-    0x4d8a0520, // lichade`xiersi
-    0x4d8b28e0, // weidao
-    0x4d8ce0a2, // gaibian
-    0x4d9f7e75, // chujizhongxue
-    0x4db8a1d2, // keguan
-    0x4dba017f, // zhen
-    0x4dfddac7, // zhuiqiu
-    0x4e0a5ec8, // xiaoying
-    0x4e1fdbf0, // shirun
-    0x4e40f4bd, // cui
-    0x4e717a5a, // jiandao
-    0x4e7bd03c, // fengzhongdinglv
-    0x4ea7f874, // tushuguan
-    0x4eaa4b89, // xianxiang
-    0x4eabed83, // xuesheng
-    0x4ebe4829, // xianggua
-    0x4ebfe8f7, // mi
-    0x4ee55a27, // er
-    0x4f0472a1, // fangmian
-    0x4f0cedd4, // yonggong
-    0x4f0da5f5, // biaoxian
-    0x4f11ea91, // yushi
-    0x4f1a698d, // shuangfang
-    0x4f253418, // chifan
-    0x4f5230b0, // bianpao
-    0x4f66c0b5, // maobing
-    0x4f730389, // shoubuliao
-    0x4f75bb3b, // yuding
-    0x4f9b7078, // shenme
-    0x4fc3f6d6, // xiongmao
-    0x4fd47286, // qiang
-    0x4ffa8e8f, // xiangdang
-    0x501d4a07, // yiner
-    0x503331da, // jingzi
-    0x50603d0b, // cong
-    0x50639e3e, // oumeng
-    0x507128dd, // weixiao
-    0x5075b2e5, // mingxian
-    0x5081d364, // fanfu
-    0x5083f59b, // xiawu
-    0x50ad1b4b, // youeryuan
-    0x50bfb398, // huahua
-    0x50c255d6, // keku
-    0x50cc4aca, // fuzhuang
-    0x50dd5424, // xiaoxin
-    0x511a0060, // zhidao
-    0x5166139a, // gaoxing
-    0x517cf932, // qingchu
-    0x5180fa56, // genben
-    0x51b02698, // zhigengniao
-    0x51b36741, // taijie
-    0x51e75c37, // ge
-    0x51e7b249, // fangshujia
-    0x5203db78, // rizi
-    0x520a3fb4, // nvxing
-    0x527b4d3d, // fen
-    0x529f0306, // pingjia
-    0x52c4b867, // fuhe
-    0x52cbec2b, // gebo
-    0x52ed9733, // shuijiao
-    0x52ef53ff, // haishi
-    0x52f6b88d, // goumai
-    0x530f241b, // gangcai
-    0x5342e129, // laideji
-    0x537e5991, // zhunshi
-    0x53812c95, // guanbi
-    0x53934624, // boli
-    0x53b0d6ce, // zhaogu
-    0x53bbcee2, // zhidai
-    0x53dc824d, // wenhua
-    0x53f0a38d, // yingxiong
-    0x53f3356b, // youhao
-    0x54102385, // tongkuai
-    0x5428516a, // weilai
-    0x546298e2, // wenxue
-    0x5463d9b6, // hulianwang
-    0x5473b4ac, // shiqing
-    0x5488a9c8, // buzu
-    0x54984eb3, // duimian
-    0x549e21d0, // xianyan
-    0x54b50f22, // xiangmu
-    0x54c92a18, // fanying
-    0x54da69f0, // jiao
-    0x550c465d, // shuxi
-    0x55344930, // quefa
-    0x55583905, // xingqi
-    0x55624db2, // tichang
-    0x55702282, // mingling
-    0x5570e771, // ju
-    0x5574814e, // fengci
-    0x55750a89, // buzhou
-    0x55da4c2b, // taoqi
-    0x55e29439, // zeren
-    0x55fe2303, // tigang
-    0x55fea07a, // jiankang
-    0x564b45a9, // dengjipai
-    0x565a2f07, // jishu
-    0x56697aba, // xiaofei
-    0x566af38a, // shouru
-    0x5683a5e2, // yanhui
-    0x56851684, // difang
-    0x56cd7969, // gai
-    0x56edbba9, // zhubu
-    0x573f589d, // ke
-    0x577e194e, // jingse
-    0x57885cab, // shengqi
-    0x57980e5e, // weile
-    0x579bbd6f, // jichu
-    0x57b5e5ac, // anquan
-    0x57d981b6, // gongbu
-    0x57eab98a, // keshi
-    0x580463b4, // lajitong
-    0x5807fd44, // shuizhao
-    0x582c7d73, // zhengti
-    0x58332fe2, // buduan
-    0x58668a34, // xingcheng
-    0x58784d5d, // meirenyu
-    0x588f2273, // qiji
-    0x58c3dc4b, // daban
-    0x58c91a93, // zhexue
-    0x58d1cdf7, // xiaoshou
-    0x590ce7be, // fanhang
-    0x59290d00, // jieyue
-    0x59345b2a, // zeng
-    0x5939a20e, // zhangbei
-    0x59542ac5, // huainian
-    0x596be418, // wenjian
-    0x5975bdcc, // li
-    0x599424f0, // chufa
-    0x59a540ad, // zhishangtanbing
-    0x59ab0241, // taobi
-    0x5a1d2ed0, // jinliang
-    0x5a32ea82, // qiao
-    0x5a3a0d79, // suishen
-    0x5a549221, // chuse
-    0x5a5a3956, // jinshu
-    0x5a7c6a5c, // chuang
-    0x5abdff6a, // youming
-    0x5ac30418, // zhenxi
-    0x5ada0069, // weiyang
-    0x5ae9e5b9, // zhaokuo
-    0x5af31437, // budeliao
-    0x5b010ebd, // zhaochang
-    0x5b080384, // tongguo
-    0x5b11b835, // na
-    0x5b1f4f22, // taoyan
-    0x5b20adc1, // kaishui
-    0x5b21d9cd, // ming
-    0x5b223166, // bixu
-    0x5b4c33f3, // xueli
-    0x5b517d15, // qijian
-    0x5b89eb5b, // weixin
-    0x5b8b7e6a, // liangcha
-    0x5b8c7393, // piping
-    0x5bc5023b, // chidao
-    0x5bfb6dc7, // renmin
-    0x5c0bf6ff, // ze
-    0x5c1309b1, // wenwen
-    0x5c16af7d, // shangchang
-    0x5c26e17a, // kongqi
-    0x5c36dc00, // xinwen
-    0x5c4820f6, // gongjin
-    0x5c732326, // shui
-    0x5c989bec, // liuxue
-    0x5ccff280, // jinyu
-    0x5d11a08a, // xingshi
-    0x5d177784, // yubao
-    0x5d238ea5, // muqin
-    0x5d364508, // biaoge
-    0x5d40765e, // changjiang
-    0x5d5b7bdb, // mianbao
-    0x5d662a2d, // zhaofugen
-    0x5da2611a, // tongyi
-    0x5db7d36f, // yijing
-    0x5db8ddde, // zhongqiujie
-    0x5ddd9eed, // kongpa
-    0x5dfa15f6, // dou
-    0x5e148b16, // yunqi
-    0x5e1ba0dd, // tuici
-    0x5e284dff, // gongxian
-    0x5e3f9fe9, // aodaliya
-    0x5e9033a7, // zilu
-    0x5e977bb1, // zixi
-    0x5ecdd217, // shangye
-    0x5ece1f41, // liu
-    0x5ed6dc1c, // jichang
-    0x5f33d9ce, // zhangxiaolong
-    0x5f3eeb44, // guafeng
-    0x5f4ee28b, // yijian
-    0x5f8a589e, // guina
-    0x5fb77bf0, // shucai
-    0x5fcb6601, // biran
-    0x5fd4237b, // nei
-    0x5ff89d5e, // yanchu
-    0x6025ee42, // lilun
-    0x6033bed1, // chushi
-    0x605c343c, // wanr
-    0x605f6511, // zhuanye
-    0x606e4950, // guozhi
-    0x6070885a, // xiao
-    0x60761940, // guocheng
-    0x60dcf8fb, // manyi
-    0x60de5e89, // juhua
-    0x60e8754d, // jinbu
-    0x60f63615, // zuotian
-    0x60f66228, // jiang
-    0x611bf763, // jiangluo
-    0x61658f30, // luoji
-    0x61abd5af, // youlan
-    0x61af2d33, // rili
-    0x61bedf2e, // wulun
-    0x61d8d67e, // lai
-    0x61dd9a98, // guandian
-    0x61ea2deb, // ciwai
-    0x61fcae95, // pianyi
-    0x622c374c, // jianshen
-    0x624e001e, // hekuang
-    0x624e9084, // xiaohuozi
-    0x627e2e5d, // maihuochaidexiaonvhair
-    0x62a6e321, // pengzi
-    0x62c02f39, // fuxi
-    0x62e8f20a, // laibuji
-    0x62f7537f, // chuli
-    0x63118911, // yiji
-    0x6316db91, // fenshou
-    0x631bb1c8, // jiuhuche
-    0x63212086, // yuangong
-    0x632c9d20, // maoxian
-    0x636f6f38, // hezi
-    0x63722fd9, // ditie
-    0x63a5b31f, // gongzhu
-    0x63ad9042, // buchong
-    0x63d0aa34, // danwei
-    0x63e8a85c, // huochengyan
-    0x6400a0ee, // manzu
-    0x642c100c, // jingcai
-    0x643a0de5, // biru
-    0x64614f1f, // kongxian
-    0x64b0f25a, // danxiaogui
-    0x64b347ac, // cu
-    0x64ea0d3e, // daoju
-    0x65036394, // jiaxiang
-    0x652985ad, // ng
-    0x6545b0df, // zuozhe
-    0x65580ab2, // duo
-    0x655f8310, // banfa
-    0x656d2d96, // xiande
-    0x657aa6db, // faner
-    0x65a1becd, // shihou
-    0x65a4b2ec, // weibi
-    0x65a5b296, // weiqi
-    0x65b6597b, // ganxingqu
-    0x65cb4634, // gainian
-    0x65cc3c60, // qu
-    0x65f1061b, // dazhe
-    0x661b01ea, // daxiang
-    0x6622c830, // huiguniang
-    0x664f5fda, // zhaodai
-    0x6662cfc6, // yangzi
-    0x6666c7e6, // senlin
-    0x6671a041, // shijinr
-    0x66866568, // tongyang
-    0x66903d6b, // gua
-    0x669da4f3, // zhangwo
-    0x66a0c4fa, // youju
-    0x66a70ed7, // qingsong
-    0x66a9f259, // dianziyoujian
-    0x66aa3937, // baoquan
-    0x66aaf7ba, // yongyuan
-    0x66b6fa1e, // zhonglei
-    0x66efe745, // yigong
-    0x67032bc3, // yunyong
-    0x670ddde6, // laobaixing
-    0x672163ce, // bijiao
-    0x67252a52, // youlai
-    0x674c2c27, // zebei
-    0x6754d280, // xian
-    0x67a25ec2, // mang
-    0x67bad25b, // xiaoqu
-    0x67d2194e, // xiangzi
-    0x68411b22, // youguan
-    0x685b42c0, // yuanyi
-    0x68940d2f, // qinqi
-    0x6894ab37, // guowang
-    0x68a34725, // liangkuai
-    0x68b73f5b, // feichang
-    0x68ebf44a, // jianshe
-    0x692fecca, // tigao
-    0x69353e72, // xingfen
-    0x693643c9, // haixian
-    0x695bf5b7, // bai
-    0x6963f13b, // chuchai
-    0x696b5fae, // shiqu
-    0x69837086, // keai
-    0x69a13a3d, // yajin
-    0x69a63c3b, // jiaowang
-    0x69d00d8c, // yinyue
-    0x69ebf985, // zang
-    0x6a2811e8, // shenduan
-    0x6a4884bf, // wazi
-    0x6a8dedc4, // zhuangxiu
-    0x6acf341e, // yuxi
-    0x6b0548dd, // ba
-    0x6b230d9c, // hai
-    0x6b2443a9, // jizhen
-    0x6b27c9da, // jialifuniyazhou
-    0x6b81e976, // tiananmendong
-    0x6ba31e94, // ren
-    0x6ba9fa87, // zhendui
-    0x6bb47f59, // zhuan
-    0x6bd50d6e, // tiyuguan
-    0x6bed8fdb, // binggan
-    0x6c03dfc4, // ganhuoer
-    0x6c09bd83, // baozheng
-    0x6c11a78e, // huiyuanka
-    0x6c54b99a, // hong
-    0x6c5cde4c, // fangxiang
-    0x6c71eb84, // yilai
-    0x6c78827a, // yuanliang
-    0x6c7a8607, // nuli
-    0x6cdd5fa2, // bianhua
-    0x6ce60fc1, // kaolv
-    0x6cfdd95f, // juda
-    0x6d13c529, // tengai
-    0x6d15fe50, // chengji
-    0x6d6084c3, // she
-    0x6d6dd82e, // yinzi
-    0x6d782ac0, // qifen
-    0x6d87d5b9, // liaotian
-    0x6db0456b, // yiqian
-    0x6dd8b8cb, // sousuo
-    0x6de82b21, // zhongguo
-    0x6e0f5c22, // langdu
-    0x6e3b05e5, // huaxue
-    0x6e6fe2a0, // gaosugonglu
-    0x6e7fdb24, // nongmin
-    0x6e9d450b, // zhiyu
-    0x6eabc1ce, // chengzan
-    0x6f09ac76, // yiqie
-    0x6f243409, // qizi
-    0x6f2cf67e, // juxing
-    0x6f458eed, // zanmen
-    0x6f61d0ab, // yihan
-    0x6f788f92, // daoxiangcun
-    0x6fad1699, // jiaolian
-    0x6fc44ca2, // hongse
-    0x6fda9bab, // xuxin
-    0x6ffb057c, // mingpian
-    0x700167e7, // xhileng
-    0x700e5482, // xiangjingrubin
-    0x7016c87d, // cheng
-    0x7020bfaf, // paobu
-    0x702b0254, // qinglv
-    0x704f478c, // titie
-    0x7052d470, // mao
-    0x7068a2ad, // benke
-    0x70793127, // xiaoguo
-    0x70d7cfc6, // chengfen
-    0x70dda04c, // zhongdian
-    0x71180ad7, // zimu
-    0x7123af3c, // lirun
-    0x7178cdb9, // zhenghao
-    0x71aeb78b, // ruguo
-    0x71cb0967, // reqing
-    0x71e69095, // zhi
-    0x7205221d, // zuichu
-    0x7210a02f, // zhiwu
-    0x721c1950, // ayi
-    0x72257568, // zhengshi
-    0x7225d325, // erqie
-    0x7243d2be, // congci
-    0x72770863, // woshou
-    0x72bb4254, // likai
-    0x72bd7076, // qianmian
-    0x72cc6f1f, // neng
-    0x72e24c00, // fandui
-    0x72eb21ef, // jianburufei
-    0x72f722ad, // hetong
-    0x72f8de4b, // hangjia
-    0x7314ceff, // shenqing
-    0x73353ca8, // ca
-    0x734ff3a7, // ziyou
-    0x7366e9e3, // gongzi
-    0x73a1b079, // jiejin
-    0x73c793e0, // yizi
-    0x73ddd682, // relie
-    0x73f4be6d, // xiaoshuo
-    0x740666d4, // diren
-    0x7430420a, // shide
-    0x745f8573, // chao
-    0x746af66e, // pingjing
-    0x747f191f, // bangzhu
-    0x748b6e2f, // bufen
-    0x7490fb18, // chengdan
-    0x7495f312, // feizao
-    0x74ad811f, // zhishao
-    0x74b9c102, // yufang
-    0x74cbf133, // anshi
-    0x74e40413, // yan
-    0x74f2d7c8, // tuiguang
-    0x75041f3b, // shi
-    0x7505d5e5, // pai
-    0x75344d45, // qita
-    0x757ffc05, // xie
-    0x7583f6f5, // duanduanxuxu
-    0x758fa66b, // jintian
-    0x75c1d2f0, // lajiao
-    0x75e81e7b, // qingjia
-    0x7610c441, // xiongdi
-    0x7619b084, // bodong
-    0x762078ea, // shouhuoyuan
-    0x763c7626, // xunzhao
-    0x763cc5ef, // mofang
-    0x7654ada5, // diaocha
-    0x767c3486, // fengsu
-    0x7694398a, // ziran
-    0x76952dd0, // suo
-    0x76959834, // rang
-    0x76d92d04, // liuxie
-    0x76f44787, // shizai
-    0x76fb9d72, // song
-    0x770a71a9, // zuhe
-    0x770c64ef, // kending
-    0x77135f42, // xiangnian
-    0x771a370d, // richang
-    0x772ec434, // touzi
-    0x77997e12, // xingwei
-    0x779c370c, // huixin
-    0x77b5d6d8, // suliaodai
-    0x77c71f77, // fouren
-    0x77c80353, // anna
-    0x77d084dc, // yinggai
-    0x77db539b, // zhantie
-    0x77e6b733, // guniang
-    0x780f45eb, // danshi
-    0x781d66a9, // mo
-    0x78215536, // zongtong
-    0x7833c2b3, // cailiao
-    0x78578d9e, // kache
-    0x7860b56f, // zhihui
-    0x7886ef54, // zhuo
-    0x78b05f75, // zhe
-    0x78b1ed00, // shatan
-    0x78c9613b, // yiqi
-    0x78d1f983, // jisheng
-    0x78df3ac1, // guloudajie
-    0x78f21b81, // yukuai
-    0x78f656da, // yunshu
-    0x78f668f1, // shazi
-    0x790db6cf, // zhongliang
-    0x7921d59c, // hunli
-    0x79314099, // diluo
-    0x7937141b, // guojun
-    0x7963af41, // lengjing
-    0x79766554, // gaishan
-    0x799298a4, // shanchu
-    0x799be6fb, // zhaopian
-    0x79a72591, // lvyou
-    0x79a8dab7, // huilv
-    0x79befddb, // huxiang
-    0x79de7109, // menglong
-    0x79e58a42, // zazhi
-    0x79ed48dd, // xingzhuang
-    0x79f8600a, // gongsi
-    0x79feeed1, // jinian
-    0x7a1f4161, // yanyuan
-    0x7a206d78, // mianfei
-    0x7a20f5c3, // butong
-    0x7a3385f4, // duishou
-    0x7a4513fa, // wanmei
-    0x7a45d9ce, // yangshi
-    0x7a5f795c, // ranshao
-    0x7a64c888, // shishang
-    0x7a681a2a, // jiejie
-    0x7aa343a4, // huodong
-    0x7abf9117, // pei
-    0x7adbd611, // lianxi
-    0x7ae05d22, // zhua
-    0x7ae9cb6c, // xiang
-    0x7af1b1b0, // cizhi
-    0x7b0f0d0d, // rengran
-    0x7b291301, // cushi
-    0x7b4b3c93, // kecheng
-    0x7b50924f, // zanshi
-    0x7b50fa41, // jiyi
-    0x7b6a60fc, // gao
-    0x7b777e53, // huode
-    0x7b78c87e, // erhuan
-    0x7b7a7f2b, // kexi
-    0x7b863127, // buran
-    0x7b913ade, // qihou
-    0x7b9599ba, // zuo
-    0x7bb7ad39, // linghuo
-    0x7bbbd15c, // yudao
-    0x7bd18374, // jiehun
-    0x7be82dd9, // guangbo
-    0x7c020b43, // xihan
-    0x7c02ce07, // jinkuai
-    0x7c076e99, // caiyi
-    0x7c0b3dd5, // shijie
-    0x7c2cc04d, // shuma
-    0x7c46eae0, // biaoda
-    0x7c5ee8f7, // fangfu
-    0x7c694de8, // fenbu
-    0x7cc8ea46, // jueding
-    0x7cd44817, // zou
-    0x7d093749, // hangban
-    0x7d2251e9, // bianji
-    0x7d2d684c, // luyin
-    0x7d4fd369, // heying
-    0x7d6b9de1, // cheku
-    0x7d8557f9, // benling
-    0x7d86757f, // weirao
-    0x7d9ad8d7, // jiafang
-    0x7db1c025, // shenfen
-    0x7dbfee5c, // heshang
-    0x7dd2b783, // jihui
-    0x7e501271, // bijing
-    0x7e70b7e3, // zhuanjia
-    0x7e8248d8, // chabuduo
-    0x7e834be6, // minju
-    0x7e96d28d, // guanggao
-    0x7ea2cc90, // shijian
-    0x7ec47027, // biergaici
-    0x7f0742bb, // shouxu
-    0x7f133fdf, // jiaju
-    0x7f32e802, // tongchang
-    0x7f892426, // bi
-    0x7faca4fb, // meitian
-    0x7ff21395, // qingqu
-    0x802bbf49, // hongjiu
-    0x80354a78, // bie
-    0x80405073, // hua
-    0x804744c4, // e
-    0x8048c6bb, // huannanyugong
-    0x80620030, // zhijiao
-    0x80a2d70f, // tiankong
-    0x80bec536, // daoli
-    0x8117b45d, // dayue
-    0x81229102, // chaxun
-    0x8123001f, // chongman
-    0x814c32de, // yinliao
-    0x81705da0, // jie
-    0x81b5412f, // jige
-    0x81d000ca, // chengguo
-    0x81ddc281, // sushe
-    0x8222f960, // ganzao
-    0x8231ff85, // jiaohuan
-    0x8256cadb, // yong
-    0x82c1940a, // tongqing
-    0x832cc2f6, // faming
-    0x835174e5, // xiyin
-    0x835f0ed6, // limi
-    0x837a612d, // huayi
-    0x837ff88f, // donghailongwang
-    0x8392eff8, // taidu
-    0x83aadb62, // yang
-    0x83b2e60e, // peifu
-    0x83d27a43, // qingjing
-    0x83d55bb0, // quanli
-    0x83f33f46, // panwang
-    0x845079e8, // dajia
-    0x84539fcc, // haoxiang
-    0x8477cf48, // dong
-    0x8483fd69, // guo
-    0x84cd426d, // xiaoxi
-    0x84e58f29, // ditu
-    0x84f0ab9a, // que
-    0x84f0c8df, // zhuanzhu
-    0x84fe8bc5, // guan
-    0x851bd243, // bing
-    0x852b8946, // luoshanji
-    0x853dfec4, // yewu
-    0x85400cb1, // qiangdiao
-    0x854913fb, // baifenzhi
-    0x855c3f07, // xiaweiyi
-    0x8598740d, // xinxilan
-    0x85e46248, // ruanjian
-    0x85ef6284, // yanzhong
-    0x85f06cb9, // daiti
-    0x8607a96a, // shifu
-    0x8629b4dd, // meng
-    0x8645e7d0, // cankao
-    0x86575e15, // guaiwu
-    0x86a4b392, // yingxiang
-    0x86a8b043, // ,
-    0x86b63cc6, // duche
-    0x86e0f80b, // mou
-    0x86e73439, // chuanzhen
-    0x86f8ea22, // duan
-    0x86fabfa6, // dun
-    0x87014331, // huozhe
-    0x87123599, // kaixin
-    0x87784bcf, // shadingyu
-    0x87965d59, // aixi
-    0x87967a64, // shicha
-    0x87b53c9f, // jianmian
-    0x87cab238, // pijiu
-    0x87cea3ca, // houzi
-    0x87d47131, // shiye
-    0x880c6c04, // jiaocai
-    0x88207048, // zhaokai
-    0x8823a34f, // beishang
-    0x883c337d, // yiwai
-    0x8897c6ca, // yundong
-    0x88d2865d, // naixin
-    0x890073fc, // ningke
-    0x8907650d, // yangcheng
-    0x89091e46, // fumu
-    0x891db7b0, // kuoda
-    0x8928ad18, // qici
-    0x892f3657, // zhaoliang
-    0x89383a24, // baokuo
-    0x8957f473, // qun
-    0x89bcd3ed, // daode
-    0x89e54673, // mifeng
-    0x8a0ed8de, // fayan
-    0x8a14beed, // pingan
-    0x8a8b1043, // duli
-    0x8aa7dad3, // kunnan
-    0x8aad5ac7, // haixiu
-    0x8ab649b1, // fachou
-    0x8ac05f7a, // dayin
-    0x8acf1427, // qinai
-    0x8b1620cc, // zhengzhi
-    0x8b39b337, // yanjiang
-    0x8ba36723, // rao
-    0x8bb8da89, // jianfei
-    0x8bc4d38b, // rexin
-    0x8bd50111, // huo
-    0x8bebc618, // re
-    0x8bf2dc4d, // sheng
-    0x8bf41cd9, // suizhe
-    0x8c20671a, // benpao
-    0x8c219810, // xinshang
-    0x8c24074b, // gang
-    0x8c82e761, // jizhi
-    0x8cac02bf, // xigua
-    0x8ccb1eaf, // queshao
-    0x8cd93cb5, // zhengfu
-    0x8d1319e4, // shoubi
-    0x8d1567d6, // daizi
-    0x8d444b30, // chenggong
-    0x8d55c4f3, // yangrou
-    0x8d74e91e, // siren
-    0x8d8d5fb9, // guomao
-    0x8db20a6b, // !
-    0x8e567e1a, // huochai
-    0x8e76b055, // tiaopi
-    0x8e800a6f, // caiyong
-    0x8e85d189, // linju
-    0x8eb616fd, // shoudao
-    0x8ee5d57c, // zuowen
-    0x8ef6f951, // yin
-    0x8f54ca71, // weixiu
-    0x8f716e0f, // zhongyu
-    0x8f853336, // kesou
-    0x8f9e0e37, // zongcai
-    0x8fad9710, // bishi
-    0x8fbfe743, // fakuan
-    0x8fdfbfa1, // jiaoyu
-    0x8ff7f7ec, // shan
-    0x8fffd9b1, // xishou
-    0x90017f36, // toufa
-    0x9014d5a5, // yiban
-    0x9015432c, // weida
-    0x9039e2ce, // chusheng
-    0x904acd9f, // qingyuan
-    0x906633a2, // shushi
-    0x90714406, // chengba
-    0x9074d186, // shouxian
-    0x9078f71d, // yinshua
-    0x9087d380, // duanlian
-    0x90a1d389, // zhengteng
-    0x90a342a8, // pinyin
-    0x90a712ae, // ganmao
-    0x90fba515, // xili
-    0x90fec216, // xingdong
-    0x91160744, // sikao
-    0x9121fd7c, // xinli
-    0x914f4586, // zuobian
-    0x9166b383, // wanzhang
-    0x916abc26, // baoshe
-    0x916e8f20, // bangmang
-    0x91703613, // hanzi
-    0x91716227, // naodai
-    0x9182ef9d, // zonghe
-    0x9188c552, // meiguo
-    0x91e1f099, // chuangban
-    0x91ec4031, // mianji
-    0x91f4b733, // yueqi
-    0x9206c60c, // qianwan
-    0x920706c8, // xingrong
-    0x92130dc5, // tongku
-    0x9215b90f, // kanwang
-    0x92373b19, // lingqian
-    0x9244fde3, // daxing
-    0x926369c2, // youxiao
-    0x927a267a, // qianzheng
-    0x92a44dd7, // shengzi
-    0x92a6e898, // shuoshi
-    0x92b1c3ab, // dan
-    0x92e26e59, // chongxin
-    0x92e829ab, // ershou
-    0x92ed3254, // shuxue
-    0x92f70732, // mingshengguji
-    0x931ca258, // daoshihou
-    0x93299194, // xuduo
-    0x9357fe85, // xiazai
-    0x9369aec6, // zixin
-    0x9370ab8b, // dianshi
-    0x938292e6, // huopo
-    0x938c4780, // chuji
-    0x93e2cd21, // shuyu
-    0x941009e2, // jide
-    0x9455c583, // mengxiang
-    0x94589327, // genzhi
-    0x9465794c, // fendou
-    0x9482e250, // lei
-    0x94969f2f, // po
-    0x94b117d4, // buxing
-    0x94be0858, // reng
-    0x94ddd9df, // duocang
-    0x94f02768, // jiesheng
-    0x94f190a8, // zhizao
-    0x9500c437, // <
-    0x95277f56, // youle
-    0x9527b913, // yishu
-    0x95305f96, // weifan
-    0x953c6b66, // peiyang
-    0x955000b1, // jiujiu
-    0x9555989e, // pochan
-    0x9565a42e, // chufei
-    0x95b067af, // wenzi
-    0x95ce8623, // biao
-    0x95e0d221, // yidianr
-    0x95f7ff38, // kaifa
-    0x9608a7a3, // yingjie
-    0x9649d0ee, // wanggong
-    0x9665600f, // guoshi
-    0x968af58f, // dangdi
-    0x968c10fc, // tongxue
-    0x96a40bd5, // wen
-    0x96aa5387, // fuzhi
-    0x96b25b3c, // dongxi
-    0x96d4b796, // fuwuyuan
-    0x96da5ff3, // lian
-    0x9703773f, // wanyi
-    0x972b02f1, // bang
-    0x972be7f2, // wuli
-    0x972bf611, // jielun
-    0x97421210, // fangai
-    0x975f0121, // baozi
-    0x9781e200, // xuanbu
-    0x978f4c48, // zhang
-    0x979a96ad, // ling
-    0x97c3d221, // tai
-    0x97d1c7c9, // guanghua
-    0x97fd4fb6, // mohu
-    0x9816d70e, // piao
-    0x98453fd6, // ?
-    0x9863d07e, // shengri
-    0x98aa2ab1, // wangwang
-    0x9935279e, // lan
-    0x994975ad, // man
-    0x9966784b, // canyu
-    0x9968715e, // wenhuo
-    0x996b87be, // jinkou
-    0x998afa3f, // huati
-    0x999515ef, // conglai
-    0x99bda133, // xunwen
-    0x9a1f093b, // qinguo
-    0x9a44ad3f, // zaoshang
-    0x9a61926f, // zhenshi
-    0x9a6ef647, // meishu
-    0x9a73395f, // dangao
-    0x9a79bc32, // shunchang
-    0x9a7a8ce0, // ne
-    0x9a813b22, // dangshi
-    0x9a94c33e, // zijue
-    0x9ab196f7, // miandui
-    0x9ab31b6e, // bangongshi
-    0x9ab6563d, // dongzuo
-    0x9ad5a475, // xinxiangan
-    0x9aea3ee0, // shuiguo
-    0x9b41271e, // jin
-    0x9b5613f0, // zhunbei
-    0x9b6b1e59, // zengjia
-    0x9b755af5, // jilv
-    0x9b880007, // kanbuqi
-    0x9b91eef3, // xiangqi
-    0x9b9741e3, // xiaohua
-    0x9ba50c1e, // jiedai
-    0x9badda8e, // zhichi
-    0x9bdbb708, // huanjie
-    0x9be5e847, // xiaojie
-    0x9bea0a70, // zhoudao
-    0x9c04850b, // shike
-    0x9c1584fb, // menkan
-    0x9c1a6012, // dajiaodao
-    0x9c211f6f, // chu
-    0x9c627012, // hanxian
-    0x9c6db128, // fan
-    0x9c73f803, // wuhui
-    0x9c793211, // shiyong
-    0x9ca8655e, // jiage
-    0x9cb48661, // qianglie
-    0x9cbc23ff, // caoxin
-    0x9cd7837d, // qingchun
-    0x9ceb5b24, // siheyuan
-    0x9cec3981, // houlai
-    0x9cf3c6e1, // shangdang
-    0x9d0dcb2c, // zhigan
-    0x9d135428, // zhide
-    0x9d3b081f, // zhedie
-    0x9d4ceff8, // miaoxie
-    0x9d4e2c66, // dizhen
-    0x9d6eed79, // bili
-    0x9d9db27a, // shibangongbei
-    0x9db153c2, // chuan
-    0x9dd21b18, // dalanqiu
-    0x9de0e792, // jianqiang
-    0x9dea3884, // liguang
-    0x9ded99fd, // guiding
-    0x9e4c30e0, // biji
-    0x9e54efec, // yinyuehui
-    0x9e9a9ce3, // niuzaiku
-    0x9e9eb322, // jiucan
-    0x9eb469c4, // hui
-    0x9edba30d, // shuowenjiezi
-    0x9ee1adc9, // xingzhi
-    0x9eea529c, // zhipiao
-    0x9eea8e2e, // maodun
-    0x9ef9c0a5, // baohan
-    0x9f043b1d, // yunnan
-    0x9f2f5e58, // jianwei
-    0x9f5133f9, // jiuba
-    0x9f82f509, // shibing
-    0x9f89369c, // chongdianqi
-    0x9f8e8b93, // shenmi
-    0x9f947295, // huangjin
-    0x9f9f784d, // chuxi
-    0x9faedb07, // bowuguan
-    0x9fd2e93b, // mudi
-    0x9fd9a2c3, // erduo
-    0x9fe5e354, // aoye
-    0x9ff5b136, // qingqiu
-    0xa002a756, // changge
-    0xa03812ca, // ying
-    0xa03af657, // guanzhong
-    0xa047d6f5, // putonghua
-    0xa0570049, // muqian
-    0xa05c2ac5, // yueliang
-    0xa0821c4d, // fenglang
-    0xa08930f5, // gongfu
-    0xa08f7be9, // shengli
-    0xa0a827c0, // gongye
-    0xa0a9dba6, // nianqing
-    0xa0b6dca1, // conglin
-    0xa0ccb01c, // dianqiu
-    0xa0e52d75, // daikuan
-    0xa0e9e85b, // guangdongsheng
-    0xa0fabb7d, // sudu
-    0xa108bd2d, // fang
-    0xa10a62b8, // guoqingjie
-    0xa10cceb0, // buguo
-    0xa113280b, // caichan
-    0xa1479a3f, // nianyu
-    0xa1c4e516, // tanpan
-    0xa1deb863, // fuyin
-    0xa1ef34e1, // gui
-    0xa1f15f54, // yexu
-    0xa20b18e7, // taiyang
-    0xa242b6ac, // fudao
-    0xa2649ea7, // lixi
-    0xa275d2c7, // jia
-    0xa27e9174, // yibanlaishuo
-    0xa28f6d0c, // yuanze
-    0xa2c47207, // pashan
-    0xa32d80cf, // gancui
-    0xa35c6e25, // chuzhong
-    0xa3723203, // renlei
-    0xa37d7db2, // haolinshuo
-    0xa3ae3959, // qilang
-    0xa3b76c06, // jiaoao
-    0xa3e58071, // shouzhi
-    0xa403b18c, // liwu
-    0xa40ad9c1, // doufu
-    0xa43c85e7, // goutong
-    0xa44b48c0, // jiaoxun
-    0xa4748bb7, // tigong
-    0xa477c429, // gongyu
-    0xa47ca216, // nuan
-    0xa485eaf0, // ouzhou
-    0xa499f34b, // duoyu
-    0xa4bbc227, // jianyi
-    0xa4cd1c47, // zhuxi
-    0xa4d2880b, // jingchang
-    0xa4d69b54, // pailie
-    0xa4e62c5b, // congming
-    0xa4f05658, // laopo
-    0xa596316d, // heping
-    0xa5b5b625, // shanghai
-    0xa5c48454, // shunxu
-    0xa5c6e509, // zhengqu
-    0xa5f7f5a6, // jiaru
-    0xa5f8f54a, // pingzi
-    0xa5fa4717, // qinfen
-    0xa5fdcaff, // lun
-    0xa6343682, // gu
-    0xa64c34e0, // guang
-    0xa6514d51, // hupenghuanyou
-    0xa65a5200, // wang
-    0xa65d5380, // yingye
-    0xa6c50a47, // daoyu
-    0xa6d467bc, // lukou
-    0xa6da987e, // pingwei
-    0xa6e03d3c, // liuchen
-    0xa6e16dd1, // dizhi
-    0xa70eea9d, // jiaban
-    0xa718505f, // dannierkaneiman
-    0xa726301e, // liuchuan
-    0xa78be7dd, // baogao
-    0xa7925668, // budan
-    0xa7d2495a, // chengli
-    0xa809ac3b, // xiuli
-    0xa82446a6, // daiyu
-    0xa85ad049, // duihuan
-    0xa860d93c, // jiaoshou
-    0xa8e0ad5f, // liuchiping
-    0xa8e3f4ba, // benzhi
-    0xa8e4485f, // tianqi
-    0xa8f4dffe, // gankuai
-    0xa8fe8794, // fangsong
-    0xa967d647, // zhiyou
-    0xa9a157bd, // dadianhua
-    0xa9a32412, // cengjing
-    0xa9af5df2, // guyi
-    0xa9bd9061, // duihua
-    0xa9dc9884, // jiaodu
-    0xa9f269d4, // tongshi
-    0xa9fdebc9, // ouer
-    0xaa1c840c, // xitong
-    0xaa2ca6f1, // guanjian
-    0xaa369db7, // minzu
-    0xaa526e60, // lianmang
-    0xaa54d8a2, // fouze
-    0xaab64a11, // weishengjian
-    0xaabfc20d, // tanhua
-    0xaad748a4, // waiwainiuniu
-    0xab096557, // peichang
-    0xab485a9d, // dei
-    0xab5a0475, // masazhusaizhou
-    0xab5df67d, // yanchang
-    0xab6285ba, // shun
-    0xab9ead85, // guai
-    0xabacc178, // chongfu
-    0xabad9b71, // shenhua
-    0xabbe28ef, // nengyuan
-    0xabc34874, // yidong
-    0xabd9b778, // pixie
-    0xabdff14c, // wo
-    0xabea6287, // yangxiong
-    0xac0a2f3f, // yao
-    0xac38acaa, // huzhao
-    0xac539d2e, // shangxinyuemu
-    0xac58c0dd, // jiaqian
-    0xac90ab3d, // dawei
-    0xacbdb110, // guojia
-    0xad02ce27, // fangzi
-    0xad083e96, // jieri
-    0xad0ae689, // jieshou
-    0xad25814d, // fouding
-    0xad263ca4, // congmang
-    0xad3442b5, // xiaomai
-    0xad448376, // shiwu
-    0xad50bf49, // dianti
-    0xad7663de, // changcheng
-    0xad85b8ba, // ciji
-    0xad8b03fc, // diyi
-    0xad8f091f, // youjiu
-    0xad9862de, // shulian
-    0xad9de12b, // tianjinweishi
-    0xadc64d42, // xiaoxiao
-    0xade575df, // cuowu
-    0xae0283fa, // xianzai
-    0xae33ea32, // wangzhan
-    0xae3e620e, // lumiaier
-    0xae4013dd, // jing
-    0xae43c6cc, // jiezhang
-    0xae58d824, // xinyongka
-    0xae5c11e3, // xiangxi
-    0xae66cf86, // hefa
-    0xae707a35, // shou
-    0xae7c13e4, // haiguan
-    0xae7d8902, // luan
-    0xae8418c2, // zhexuejia
-    0xae867574, // cunhuo
-    0xaebebab4, // huxi
-    0xaed6cb02, // juesai
-    0xaed6d413, // chitang
-    0xaef61419, // kefu
-    0xaf18af99, // baogui
-    0xaf1e03cc, // xuewen
-    0xaf2088fe, // xiuxian
-    0xaf300a1c, // yongtu
-    0xaf3b6010, // duiyu
-    0xaf441119, // chikui
-    0xaf5edad9, // jinji
-    0xaf869b13, // dasha
-    0xaf94f6b0, // zan
-    0xaf98f63e, // chenmo
-    0xafbb6cca, // wenzhang
-    0xafbd5923, // anjing
-    0xb004695c, // zhizhao
-    0xb0290937, // zu
-    0xb04e5654, // chuangzao
-    0xb05149a1, // panghuang
-    0xb07e5e44, // zunshou
-    0xb09682ea, // cujin
-    0xb09b51c0, // yimin
-    0xb0a3433b, // xiaozhang
-    0xb0d3dd93, // liaobuqi
-    0xb0f18c03, // pi
-    0xb0f20e3e, // genji
-    0xb10b3adf, // yonggan
-    0xb1293058, // tiyu
-    0xb153d292, // shihuiyan
-    0xb1989693, // jianchi
-    0xb1a3e2ce, // zhaoji
-    0xb1b89163, // zanmai
-    0xb1d453ef, // zuoyong
-    0xb1e33675, // duokui
-    0xb22e2a6f, // zhuantou
-    0xb2319ed7, // fei
-    0xb255b5e8, // maokong
-    0xb2649721, // liji
-    0xb2a0f2c0, // zhaifeng
-    0xb2ad1b42, // xiangshou
-    0xb2c71154, // shenshangxiansu
-    0xb2e49787, // wanshang
-    0xb2e997d7, // chaoji
-    0xb2fa8b7f, // dao
-    0xb31806ab, // diwei
-    0xb32ab687, // dian
-    0xb34e22e8, // mai
-    0xb3a027cc, // sunyue
-    0xb3a5b02b, // wenming
-    0xb3aa2f17, // diannao
-    0xb3b96c38, // le
-    0xb419eab9, // houguo
-    0xb462a1f6, // jiu
-    0xb4737dcd, // zhuzhang
-    0xb4890163, // shouji
-    0xb491b801, // zhenzheng
-    0xb49eea72, // renminbi
-    0xb4be39b4, // woshi
-    0xb4db6711, // pingchang
-    0xb4f4decb, // aihu
-    0xb52aae38, // zuzhi
-    0xb5b634b7, // zhunque
-    0xb5c5ec82, // zicong
-    0xb5cf27a5, // wan
-    0xb5f1e6dc, // xiaojing
-    0xb606543d, // zuoyou
-    0xb606a933, // duoshao
-    0xb607d133, // gaodang
-    0xb60b35f7, // xiaolv
-    0xb620d462, // waidi
-    0xb638fc37, // shebei
-    0xb63e9487, // jieshi
-    0xb65669ff, // yudafu
-    0xb674e084, // tixing
-    0xb682d0b4, // gou
-    0xb68da2fc, // chibang
-    0xb694f29c, // zhufu
-    0xb6980cc3, // zhuanxin
-    0xb6bbc43a, // renao
-    0xb6c23fc0, // xihuan
-    0xb6d08b50, // jingshang
-    0xb6d1ddc4, // dengji
-    0xb6f04ee7, // zhongxun
-    0xb728f4be, // turan
-    0xb7468d14, // nver
-    0xb7586e58, // wendu
-    0xb77022cd, // shitou
-    0xb7a42296, // shoudu
-    0xb7b8687a, // huan
-    0xb7c36a86, // yibeizi
-    0xb7ca20b8, // fuze
-    0xb7e0cb12, // changjiangdaqiao
-    0xb7ed2768, // chuanbo
-    0xb7edf0f5, // teshu
-    0xb7fa807b, // you
-    0xb8242b2f, // peixun
-    0xb83489ba, // zhenduan
-    0xb854c53f, // mosheng
-    0xb85aa917, // yilun
-    0xb874ea29, // geren
-    0xb875cef4, // tuixiu
-    0xb87c6743, // fuzhong
-    0xb886ff80, // anan
-    0xb8ac08b8, // duguo
-    0xb8ce02f1, // huifu
-    0xb8dff90f, // moter
-    0xb8f0c61d, // jishi
-    0xb9032e73, // tiaowu
-    0xb91335db, // fenbie
-    0xb921d0aa, // huanjing
-    0xb921f54b, // dongwu
-    0xb961a0ee, // jizhong
-    0xb96e9502, // zijin
-    0xb97b0ccd, // zhongxin
-    0xb987ff4d, // gaikuo
-    0xb98d4097, // dique
-    0xb99b2405, // chuzi
-    0xb9aabbba, // xingxiang
-    0xb9c8c737, // zhongjie
-    0xb9d416b0, // gaizheng
-    0xb9e20d30, // chuanghu
-    0xb9f6b320, // xianhua
-    0xba071aac, // zhijin
-    0xba14ea01, // bu
-    0xba2e7598, // lingdao
-    0xba3839fa, // nanmian
-    0xba3b14f9, // zhai
-    0xba48dc97, // yinhang
-    0xbada5f78, // elie
-    0xbae0bb57, // fandian
-    0xbae17435, // kun
-    0xbb003fc5, // biaozhi
-    0xbb0a934b, // aixin
-    0xbb3c9f2f, // jihe
-    0xbb98cd6d, // zhoumo
-    0xbbaf703c, // zuihao
-    0xbbbc9ef5, // ditan
-    0xbc2adf33, // xiong
-    0xbc3b89ed, // tiandi
-    0xbc4a8775, // ganjin
-    0xbc4b39bf, // lin
-    0xbc4cc028, // yuanwang
-    0xbc765873, // dianxin
-    0xbc860009, // kele
-    0xbc919796, // tuzi
-    0xbc98b9f4, // chai
-    0xbca01044, // anzhuang
-    0xbca44288, // buguan
-    0xbcb0f2db, // lao
-    0xbce6edcf, // jiangjin
-    0xbd0c7bca, // jingli
-    0xbd25ac90, // liru
-    0xbd2caa64, // zhui
-    0xbd61412a, // shanyu
-    0xbd7a957f, // tiaozheng
-    0xbd81810e, // jiemu
-    0xbd845e8c, // ganxie
-    0xbd860937, // shangxin
-    0xbda12edd, // bushao
-    0xbda6b79b, // dili
-    0xbdab26c0, // xianshi
-    0xbdb54025, // fangdong
-    0xbdb5e511, // haochi
-    0xbdea8363, // bozi
-    0xbdf5f795, // limao
-    0xbe3490e2, // bimian
-    0xbe3b109f, // didao
-    0xbe53bf53, // zuijin
-    0xbedeecb4, // keyi
-    0xbeecd8a3, // zheng
-    0xbef2af1c, // shiwang
-    0xbf10f7b6, // quxiao
-    0xbf1175fa, // guji
-    0xbf56008d, // zhuming
-    0xbf82340c, // pengyou
-    0xbf82bad3, // chixu
-    0xbf98090d, // shuofu
-    0xbf9e3781, // youzha
-    0xbfc246ee, // zai
-    0xbfcb1771, // wai
-    0xbfd2132f, // guanjun
-    0xbfe32c31, // sha
-    0xc0208a66, // zucheng
-    0xc05ed29e, // nvhai
-    0xc0a5b14c, // hei
-    0xc0ca457d, // wenti
-    0xc116c6da, // panduan
-    0xc118ca3d, // taitai
-    0xc169c6bd, // kaoya
-    0xc17173bf, // kai
-    0xc18d5ad1, // zhanzheng
-    0xc1c09089, // suoyou
-    0xc1e3f2b1, // bingxiang
-    0xc1fd490d, // ya
-    0xc2603cc5, // xueshu
-    0xc27ce0a0, // bianlun
-    0xc2a17ef4, // shufu
-    0xc2dfb63d, // xing
-    0xc2e6c128, // lieche
-    0xc30cacd4, // renhe
-    0xc31b08e0, // danchun
-    0xc3292078, // zhouwei
-    0xc3379e70, // yufa
-    0xc37bf012, // danwu
-    0xc3c5e911, // duibuqi
-    0xc3f742cc, // wuzi
-    0xc3fab99f, // kaoshi
-    0xc3fafe97, // nong
-    0xc411d849, // zhengque
-    0xc424a1a4, // gongchang
-    0xc4476132, // fengge
-    0xc44b3041, // qichuang
-    0xc481e5cb, // caochang
-    0xc48db364, // shunli
-    0xc4dd82d7, // zunjing
-    0xc5134808, // gaoji
-    0xc5568f38, // zhuguan
-    0xc58b2017, // kuan
-    0xc58bc5e9, // raner
-    0xc5dff325, // buyaojin
-    0xc6477348, // jiegou
-    0xc66e4647, // laojia
-    0xc676d17c, // duidai
-    0xc6aea49d, // funv
-    0xc6b82d35, // tianzhen
-    0xc6c173bd, // kaishi
-    0xc6d8c4ca, // qushi
-    0xc6e17bd7, // chengken
-    0xc6eb8d31, // zuojia
-    0xc70e1747, // neirong
-    0xc753ce29, // kafei
-    0xc75a8a5d, // xianmu
-    0xc75d6c41, // kongzi
-    0xc75f7576, // xinku
-    0xc76d2224, // ludi
-    0xc76da511, // tiaojian
-    0xc787632a, // gongzuo
-    0xc804aa8f, // shimian
-    0xc83994b1, // jiandan
-    0xc8404f06, // zhuce
-    0xc840d5aa, // xianglian
-    0xc850b631, // dianying
-    0xc8778213, // zhaopin
-    0xc890e3dd, // >
-    0xc8a0eee9, // jiaohua
-    0xc8a12456, // huaiyi
-    0xc8a63f38, // jingying
-    0xc8a94a3e, // dagai
-    0xc91b2d35, // guanxi
-    0xc92a3ab2, // peng
-    0xc9451acf, // shuru
-    0xc94f89ac, // guofen
-    0xc9bc3ba4, // fasheng
-    0xc9e7c288, // tao
-    0xc9f2602b, // caihong
-    0xca221c20, // wangzi
-    0xca2f57ac, // hao
-    0xca92ce33, // haipa
-    0xca984e22, // jilu
-    0xcaaceea1, // guize
-    0xcac0f3cb, // bofang
-    0xcb2f3e7a, // shunbian
-    0xcb3c1453, // guaibude
-    0xcb4c20be, // canting
-    0xcb53c011, // guangfan
-    0xcb90a48e, // xianjin
-    0xcba88322, // duzi
-    0xcbaac199, // en
-    0xcbb3b656, // gongyuan
-    0xcbd259ef, // jinan
-    0xcc07b367, // chijing
-    0xcc172003, // juhui
-    0xcc315533, // baoqian
-    0xcc352efa, // kanjian
-    0xcc4c626c, // qinqie
-    0xcc4f8a8d, // zhuti
-    0xcc65a9b7, // tan
-    0xcc8e4c2a, // chunjie
-    0xccbd7c5e, // jijie
-    0xccc34006, // huochezhan
-    0xccc91b10, // laodong
-    0xcce20d32, // shengzhang
-    0xcd114877, // niujindaxue
-    0xcd217f7e, // fuza
-    0xcd69afeb, // fangbian
-    0xcd6aebbf, // shebude
-    0xcd712031, // gongneng
-    0xcdc56e2c, // shehui
-    0xcdc6d9af, // zihao
-    0xcdd3e180, // canjia
-    0xcdda305e, // mulu
-    0xce08ec47, // haoqi
-    0xce678f53, // rensheng
-    0xce700457, // wenrou
-    0xce7f3ff6, // renshi
-    0xcec55f91, // shaozi
-    0xcec71cfe, // waimian
-    0xcec90cb7, // shidai
-    0xcee8eb15, // shoushang
-    0xcef4a2c9, // fujin
-    0xcf252a59, // fangqi
-    0xcf2b67f9, // bujiande
-    0xcf6d40ef, // tanshuai
-    0xcf8520e6, // xuexiao
-    0xcfb8a1d3, // meiti
-    0xcfbc3f24, // yinjia
-    0xcfbfd7db, // rongyi
-    0xcfdee068, // jiashi
-    0xd004cd1a, // ji
-    0xd05d7fe3, // fuqin
-    0xd0643106, // erzi
-    0xd0828988, // panjiayuan
-    0xd085e915, // sheying
-    0xd0c3681d, // dasuan
-    0xd0cb7b07, // shu
-    0xd0e5cf52, // qingzhuangnian
-    0xd0f03efa, // zhidu
-    0xd0f376f6, // gutou
-    0xd12b2952, // dazhaohu
-    0xd1559c5c, // chizi
-    0xd159ec94, // jinguan
-    0xd15e7c96, // jiangyou
-    0xd1b713b2, // zuozhan
-    0xd1c82a79, // gaige
-    0xd1fb4564, // qunian
-    0xd22b13ff, // daibiao
-    0xd29410ea, // qi
-    0xd2e71433, // zi
-    0xd33c3f80, // zhu
-    0xd350b943, // weihai
-    0xd35847c6, // xianran
-    0xd37a9ef2, // qidai
-    0xd37c9262, // wanzheng
-    0xd38001c0, // changshi
-    0xd3c48b06, // mingbai
-    0xd3ce85bb, // zhengli
-    0xd3f9cc91, // chengzhang
-    0xd4061506, // mafan
-    0xd4067042, // sihu
-    0xd44f3c2f, // ci
-    0xd459dbb6, // guwu
-    0xd4947e78, // fa
-    0xd4bfd00f, // motuoche
-    0xd4c025f3, // bieren
-    0xd4daa2b9, // yiyuan
-    0xd4fb06b3, // rukou
-    0xd4ffa898, // :
-    0xd50a2c57, // jianku
-    0xd5170c42, // faxian
-    0xd519cf2a, // jiehe
-    0xd52384c6, // jidong
-    0xd528049c, // gandong
-    0xd54d2d1e, // yihou
-    0xd553b350, // tese
-    0xd59a85f7, // youbian
-    0xd59f354d, // gaobie
-    0xd5c1c907, // guoran
-    0xd5c7dd0f, // cidian
-    0xd5e7acac, // guancha
-    0xd5fb3b8f, // xue
-    0xd664ebe5, // yingyong
-    0xd67e5c1b, // nar
-    0xd699a2e4, // shangban
-    0xd6a1777f, // pilao
-    0xd6bd32e0, // gun
-    0xd6be135a, // jiaoliu
-    0xd6c1883c, // gezi
-    0xd72a3c76, // zhan
-    0xd72b6f1b, // chayi
-    0xd751a589, // shangliang
-    0xd7698d6d, // huichen
-    0xd76ed891, // mingan
-    0xd792e86b, // heli
-    0xd79b9fa9, // zonggong
-    0xd7ba75a0, // guomin
-    0xd7c4f526, // tiqian
-    0xd7e77151, // peihe
-    0xd7fbf101, // yingyang
-    0xd80ed074, // jubei
-    0xd815041d, // shencai
-    0xd8e4499b, // shibai
-    0xd93ffe6d, // sui
-    0xd94b205b, // lv
-    0xd94e7307, // dating
-    0xd952e527, // jimang
-    0xd95c9a22, // yingjun
-    0xd964aa5e, // an
-    0xd96be96f, // hanjia
-    0xd972f50a, // tengxun
-    0xd99fe8c5, // xinzang
-    0xd9a25e57, // shanzi
-    0xd9a4f25e, // meixi
-    0xd9e8a13f, // zongshi
-    0xd9f5c6c9, // gongju
-    0xda0f11ae, // zuihou
-    0xda29a7e7, // duixiang
-    0xda2b151d, // \
-    0xda3e1bdd, // shangwu
-    0xda4282cc, // weiyu
-    0xda491eaa, // yansu
-    0xda7b2ed2, // huoyue
-    0xda843ae8, // chengwei
-    0xdaa78ebc, // lixiang
-    0xdaad2c9c, // zhengming
-    0xdafe3a60, // jidan
-    0xdb52f0a4, // xiangsi
-    0xdb5ceef1, // jiezhi
-    0xdb6fdaea, // qiwen
-    0xdb7038c0, // pingguo
-    0xdb71f3ac, // reai
-    0xdb76f5c2, // chongfen
-    0xdbc1781a, // shujia
-    0xdbccff96, // conger
-    0xdbeaa5f3, // chaojia
-    0xdc091cdb, // men
-    0xdc218cda, // houmian
-    0xdc23a67d, // wu
-    0xdc29114e, // daxue
-    0xdc717ea1, // pingshi
-    0xdc7b1283, // yinwei
-    0xdc7f1cf0, // tixian
-    0xdc8e9b9c, // hexin
-    0xdcb6d929, // zaogao
-    0xdcbc4498, // shenbian
-    0xdcbded0b, // shuaya
-    0xdcd657c4, // poqie
-    0xdcd83672, // make
-    0xdce0c848, // meide
-    0xdcef464c, // aiqing
-    0xdcfcd07b, // biaoshi
-    0xdd261a90, // xuanchuan
-    0xdd79dcca, // siji
-    0xdd83ddd9, // mingque
-    0xdda8e530, // jiawu
-    0xdde26ae2, // qingwa
-    0xde1f2d41, // lianpo
-    0xde24b6a5, // chou
-    0xde3b3058, // weiyi
-    0xde4e1666, // guanglin
-    0xde5ec244, // jiran
-    0xde913715, // baoguo
-    0xde96237a, // cihui
-    0xdec4ef10, // chong
-    0xdedb69f4, // pifu
-    0xdee66df6, // kongjian
-    0xdef0c7b7, // dandiao
-    0xdf1538da, // piqi
-    0xdf243001, // yinsu
-    0xdf251db5, // gongping
-    0xdf306f12, // langan
-    0xdf3f3085, // hudie
-    0xdf40de8a, // shifei
-    0xdf467483, // zhiyuanzhe
-    0xdf4960a7, // jile
-    0xdf54544f, // chouti
-    0xdf63929e, // fenfen
-    0xdf8eb3ac, // bachengr
-    0xdf8f7cad, // gushi
-    0xdfcfd695, // guanzhu
-    0xdfd3e989, // diqu
-    0xdfe40773, // maoyi
-    0xe019261a, // yinci
-    0xe0377fb9, // wufa
-    0xe03a3110, // ertong
-    0xe0549389, // touming
-    0xe05999e8, // nenggan
-    0xe05dc1e1, // shimao
-    0xe062c9dd, // jingdian
-    0xe08f4b73, // chengxin
-    0xe099ac4c, // pingpangqiu
-    0xe0a2273d, // zaocheng
-    0xe0a293e3, // keting
-    0xe0acb00e, // xiaoji
-    0xe0d29c56, // jiangdi
-    0xe1708234, // tuchu
-    0xe1ac6d10, // rencai
-    0xe1bc8ec5, // guangxian
-    0xe1c0d1b4, // ganshou
-    0xe1c82031, // xinfeng
-    0xe1f86bf6, // kaifang
-    0xe21127ab, // yuehui
-    0xe214b1b1, // jiaoqu
-    0xe228abf0, // renzhen
-    0xe2365c3b, // napa
-    0xe237445b, // maikefeng
-    0xe23c0785, // duibi
-    0xe2545efc, // zengzhang
-    0xe2aa1ebb, // .
-    0xe2c7aca3, // gangtie
-    0xe2ce6132, // chengshi
-    0xe2ced9ed, // gewu
-    0xe2cf5e12, // daoda
-    0xe2d74098, // shoushi
-    0xe2d99892, // gupiao
-    0xe2da2e91, // biaomian
-    0xe31102fa, // wangqiu
-    0xe31fa626, // riqi
-    0xe320a7ed, // mishu
-    0xe323da02, // libaitian
-    0xe33601c8, // langfei
-    0xe34bf336, // nuanhuo
-    0xe35dfd37, // lingshi
-    0xe36a89a1, // guangheju
-    0xe3acca3a, // haili
-    0xe3e945cf, // mingpai
-    0xe3ebee27, // youyi
-    0xe3f66bf2, // diu
-    0xe45d2234, // wenxuejia
-    0xe490fff4, // huoxu
-    0xe4974952, // zher
-    0xe4aa50a8, // xiangtong
-    0xe4b7cf65, // mingxing
-    0xe4c2ad4c, // meiguanxi
-    0xe4fad67e, // jiben
-    0xe5058f9f, // guding
-    0xe510fd31, // zhuangao
-    0xe5143a09, // zhendong
-    0xe51d94f6, // shoubiao
-    0xe52333a8, // liyi
-    0xe5253103, // chedi
-    0xe54199ee, // danxin
-    0xe5434120, // yali
-    0xe543c7d1, // cuoshi
-    0xe55d65aa, // jiancha
-    0xe57c2572, // huanbao
-    0xe59511ee, // pingjun
-    0xe5b4dccf, // shifen
-    0xe5d3ae6e, // wennuan
-    0xe5d40c64, // sunzi
-    0xe60f35ed, // ban
-    0xe623d7ff, // haoma
-    0xe64a915f, // mimi
-    0xe657e89e, // ding
-    0xe658b844, // <shuowenjiezi>
-    0xe6895700, // congqian
-    0xe6c9c065, // guanli
-    0xe6dbb2a1, // wuzhi
-    0xe70b33b7, // dangran
-    0xe7202d60, // zhou
-    0xe766a70f, // pianmian
-    0xe7755820, // chaju
-    0xe77f88e4, // jieduan
-    0xe7894f9b, // suibian
-    0xe79b3396, // lichadexiersi
-    0xe7a36e9a, // shandian
-    0xe7c86e43, // tihui
-    0xe7d7a325, // shamo
-    0xe7feb09e, // shichang
-    0xe824f5fb, // gebi
-    0xe842ed48, // yuedu
-    0xe8ba72e8, // yiwei
-    0xe8c084f8, // jiejue
-    0xe93f8619, // mingyun
-    0xe9887bfc, // lizi
-    0xe99cf5e9, // nianji
-    0xe99e8c8b, // meihao
-    0xe9aaad7e, // qingshi
-    0xe9ad9ceb, // cankui
-    0xe9b0f307, // xiwang
-    0xe9e04cf8, // xueqi
-    0xea06d0ec, // sitanfu
-    0xea1effaf, // jimo
-    0xea3fe0d1, // yinxiang
-    0xea7e8225, // xiansheng
-    0xea9aba44, // fu
-    0xeab0102f, // biaoyang
-    0xeab9c588, // xiangxiang
-    0xeac1e8d8, // daan
-    0xead960b1, // huanxiang
-    0xeaf5184c, // weizhi
-    0xeaf74612, // binguan
-    0xeb08e212, // dangxin
-    0xeb4931c3, // youyu
-    0xeb4a43d2, // baoliu
-    0xeb4fc2a5, // qingxu
-    0xeb5cf2ea, // tuichi
-    0xeb808b8d, // mubiao
-    0xeb82088b, // changtu
-    0xeb90e40b, // yumaoqiu
-    0xeb98da8a, // jinnian
-    0xeb9ef73d, // kuzi
-    0xebe113d9, // fazhan
-    0xebf6d760, // haoke
-    0xec10fcc7, // youxi
-    0xec1824bd, // junshi
-    0xec18fbe3, // zhuang
-    0xec4c053f, // renwu
-    0xec5b8bd0, // ganbei
-    0xec772349, // jinzhi
-    0xec90a46b, // chang
-    0xeca9ae7a, // xizao
-    0xecbd4a6b, // shixian
-    0xecbeb06b, // qifei
-    0xed13bc73, // juexin
-    0xed26713e, // jingyan
-    0xed2c63a8, // shenke
-    0xed4aa221, // wanshan
-    0xed65f34f, // xiangzheng
-    0xed7a4d33, // shiji
-    0xeda8bee5, // fenxi
-    0xedab1aa9, // yihuier
-    0xedb52d40, // lunwen
-    0xedf2e532, // meimao
-    0xedf580bb, // wangluo
-    0xee164044, // sa
-    0xee52907a, // lia
-    0xee5efca8, // kejian
-    0xee65070d, // mashang
-    0xee837b42, // fangzong
-    0xee85a44e, // de
-    0xee909f58, // duifang
-    0xee92354e, // gen
-    0xee9f7b06, // buru
-    0xeea63a7d, // benlai
-    0xeeaa9fd4, // shuohua
-    0xef157a07, // shang
-    0xef193a11, // maozi
-    0xef43bc12, // niao
-    0xef551fdf, // kuai
-    0xef553803, // <nahan>
-    0xef559cd4, // tuan
-    0xef6e69e0, // yuanlai
-    0xef76c650, // youdian
-    0xef8e8bf0, // fabiao
-    0xefb49b36, // jiangzuo
-    0xefc0c0bf, // suiran
-    0xefc50c8f, // yaoqiu
-    0xefdd6e33, // chuyuan
-    0xeffac9ff, // zhujian
-    0xf016d6c7, // zhuren
-    0xf021f93c, // huilai
-    0xf02bb7fe, // shifou
-    0xf02eb08a, // jiechu
-    0xf0301862, // biaodian
-    0xf036bece, // xiugai
-    0xf03a8e76, // qiye
-    0xf03c8900, // chule
-    0xf0744722, // women
-    0xf0d4b9ac, // tebie
-    0xf0d5582a, // tangangqin
-    0xf10693cb, // mingzi
-    0xf14c2810, // buan
-    0xf15ba6e3, // qiumi
-    0xf17cd0ce, // falv
-    0xf1c6b0c7, // like
-    0xf1e28882, // fanxing
-    0xf1f2bfbf, // ben
-    0xf206b1b1, // shuobuding
-    0xf22b87de, // qingzhu
-    0xf272adc2, // secai
-    0xf27808ab, // guangming
-    0xf2968d64, // liaojie
-    0xf296ff37, // ni
-    0xf29b70e3, // zhanxian
-    0xf2a37da0, // ouzhouhuanjingbaohushu
-    0xf2c9fc17, // xiangchu
-    0xf2cc4337, // chuanshuo
-    0xf2e0ee9d, // lingwai
-    0xf2fcb009, // jisuan
-    0xf31734ec, // jiashe
-    0xf3318256, // kuo
-    0xf33c34d7, // liulei
-    0xf33e8264, // xingxing
-    0xf34478df, // yibian
-    0xf345a719, // sangzi
-    0xf3494ba1, // fenxiang
-    0xf3c94102, // zao
-    0xf3d22753, // yunxu
-    0xf3ea41c0, // qianxu
-    0xf404af7b, // dengdai
-    0xf40a8edb, // chansheng
-    0xf420e8b8, // boshi
-    0xf427743c, // cao
-    0xf4542577, // hushi
-    0xf457db13, // liuxing
-    0xf469ad3a, // zenmeban
-    0xf4858adf, // pian
-    0xf50498c5, // zongzhi
-    0xf51433b4, // ruhe
-    0xf522fcab, // duome
-    0xf5246271, // zhong
-    0xf543a10c, // kuaile
-    0xf57d52e9, // daoqian
-    0xf6015c6f, // fangshi
-    0xf60d2fe0, // liang
-    0xf61f0408, // congshi
-    0xf63e91b3, // youmo
-    0xf6534f28, // nin
-    0xf6610b71, // di
-    0xf680bf70, // shuo
-    0xf685e750, // queshi
-    0xf6963817, // chengren
-    0xf71c7081, // shiying
-    0xf71e8afe, // <feinimoshu>
-    0xf72007b1, // tedian
-    0xf72f42a7, // yizhi
-    0xf781c9b4, // chouyan
-    0xf79391a5, // weishenme
-    0xf7964436, // pubian
-    0xf7e0f597, // ting
-    0xf7f614bf, // wanquan
-    0xf8136fc9, // chengdu
-    0xf81eea45, // youyong
-    0xf83be668, // fengkuang
-    0xf84370f9, // shengwuzhong
-    0xf87dc1e2, // liliang
-    0xf89d661e, // tongzhi
-    0xf8a34114, // wenhou
-    0xf8db01db, // duanxin
-    0xf8eecac9, // tezheng
-    0xf9120e16, // zhuangshi
-    0xf916c0b7, // queding
-    0xf91b66e5, // fada
-    0xf92ee2ab, // juzi
-    0xf93528d7, // gongren
-    0xf9927382, // youxiu
-    0xf99c19d0, // shengchan
-    0xfa24747b, // zixingche
-    0xfa3eebe1, // lifadian
-    0xfa620cbd, // ziji
-    0xfac5695c, // zanmei
-    0xfac62a9c, // liangshi
-    0xfb074907, // shidang
-    0xfb5257d3, // dasao
-    0xfb5f1c35, // daochu
-    0xfb753730, // qite
-    0xfba70e4a, // bumen
-    0xfbcf9911, // kouwei
-    0xfc02109b, // guoji
-    0xfc75a0c0, // si
-    0xfc7bc304, // gongkai
-    0xfc8dd498, // canguan
-    0xfd09b508, // bingdu
-    0xfd18e75f, // zixun
-    0xfd352cd8, // hezuo
-    0xfdac2f32, // fangan
-    0xfdad2d5c, // ziyuan
-    0xfdbb23df, // huanghe
-    0xfdc5e218, // xiaochi
-    0xfdc7db47, // yifu
-    0xfdf843f0, // chunqiu
-    0xfe161ba8, // xunlian
-    0xfe255a33, // xia
-    0xfe7e4391, // aidisheng
-    0xfe864a54, // chui
-    0xfed27924, // fukuan
-    0xfed818e7, // zongsuan
-    0xfeea035e, // zaihai
-    0xfef35f00, // xiangfan
-    0xfef71129, // tiyan
-    0xff1111b3, // youli
-    0xff138648, // houhui
-    0xff21f487, // lifa
-    0xff37a1a5, // jinshiweikai
-    0xff48560b, // lunliu
-    0xff633097, // gugu
-    0xff649877, // shuoming
-    0xff77d7ed, // shizi
-    0xff92ed17, // xiju
-    0xff972de4, // zhengchang
-    0xffa80004, // weijin
-    0xffb7fc44, // toulan
-    0xffb809b8, // bao
-    0xffb924dd, // zhaosanmusi
-    0xffbe5bb3, // zhuhe
-    0xfffc53b6, // xi
-];
-
-// Longest homophone choice size (choices joined by "\t")
-pub const CIYU_CHOICE_MAX: usize = 7;
-
-pub static CIYU: &[&'static str] = &[
-    &"弱",  // ruo
-    &"悲观",  // beiguan
-    &"收获",  // shouhuo
-    &"厢房",  // xiangfang
-    &"规模",  // guimo
-    &"玉米",  // yumi
-    &"哲理",  // zheli
-    &"老鹰",  // laoying
-    &"日记",  // riji
-    &"香蕉",  // xiangjiao
-    &"临时",  // linshi
-    &"损失",  // sunshi
-    &"胡同",  // hutong
-    &"直接",  // zhijie
-    &"兴趣",  // xingqu
-    &"成语",  // chengyu
-    &"胶水",  // jiaoshui
-    &"菜	才	猜	踩",  // cai
-    &"夸张",  // kuazhang
-    &"丑陋",  // choulou
-    &"旺盛",  // wangsheng
-    &"悄悄",  // qiaoqiao
-    &"摔倒",  // shuaidao
-    &"姥姥",  // laolao
-    &"再三",  // zaisan
-    &"对	堆",  // dui
-    &"性别",  // xingbie
-    &"悠悠",  // youyou
-    &"花生",  // huasheng
-    &"钱	前	千	签	浅	欠",  // qian
-    &"数字	梳子",  // shuzi
-    &"糖	躺	汤	趟	烫",  // tang
-    &"分钟",  // fenzhong
-    &"承受",  // chengshou
-    &"类型",  // leixing
-    &"角色",  // juese
-    &"举办",  // juban
-    &"好处",  // haochu
-    &"繁荣",  // fanrong
-    &"接着",  // jiezhe
-    &"乘坐",  // chengzuo
-    &"一旦",  // yidan
-    &"宠物",  // chongwu
-    &"语气",  // yuqi
-    &"放心",  // fangxin
-    &"微博",  // weibo
-    &"壶	糊",  // hu
-    &"日用品",  // riyongpin
-    &"寿命",  // shouming
-    &"办事",  // banshi
-    &"鲁迅",  // luxun
-    &"农业",  // nongye
-    &"地球",  // diqiu
-    &"坏",  // huai
-    &"变	遍	便",  // bian
-    &"年	念",  // nian
-    &"摄入",  // sheru
-    &"距离",  // juli
-    &"推荐",  // tuijian
-    &"状态",  // zhuangtai
-    &"厉害",  // lihai
-    &"忍不住",  // renbuzhu
-    &"建筑",  // jianzhu
-    &"公共汽车",  // gonggongqiche
-    &"实话",  // shihua
-    &"拒绝",  // jujue
-    &"行人",  // xingren
-    &"设计	射击",  // sheji
-    &"厨房",  // chufang
-    &"身体",  // shenti
-    &"手术",  // shoushu
-    &"偶然",  // ouran
-    &"超市	潮湿",  // chaoshi
-    &"不假思索",  // bujiasisuo
-    &"出现",  // chuxian
-    &"感想",  // ganxiang
-    &"行业",  // hangye
-    &"最	嘴	醉",  // zui
-    &"专门",  // zhuanmen
-    &"春游",  // chunyou
-    &"次要",  // ciyao
-    &"烦恼",  // fannao
-    &"科学",  // kexue
-    &"优美",  // youmei
-    &"保持",  // baochi
-    &"老板",  // laoban
-    &"麦布里奇",  // maibuliqi
-    &"茶	差	插",  // cha
-    &"现代",  // xiandai
-    &"家庭",  // jiating
-    &"稍微",  // shaowei
-    &"宝贝",  // baobei
-    &"面临",  // mianlin
-    &"状况",  // zhuangkuang
-    &"美丽	魅力",  // meili
-    &"力气",  // liqi
-    &"缩短",  // suoduan
-    &"整齐",  // zhengqi
-    &"胃口",  // weikou
-    &"盲人",  // mangren
-    &"几乎",  // jihu
-    &"丰富",  // fengfu
-    &"确认",  // queren
-    &"俱乐部",  // julebu
-    &"完成",  // wancheng
-    &"不客气",  // bukeqi
-    &"结束",  // jieshu
-    &"坚决",  // jianjue
-    &"程序",  // chengxu
-    &"没	每	美",  // mei
-    &"粗心",  // cuxin
-    &"展览",  // zhanlan
-    &"迅速",  // xunsu
-    &"加油站",  // jiayouzhan
-    &"赵奢",  // zhaoshe
-    &"历史",  // lishi
-    &"范围",  // fanwei
-    &"奇怪",  // qiguai
-    &"感到",  // gandao
-    &"半夜",  // banye
-    &"煤炭",  // meitan
-    &"传统",  // chuantong
-    &"科技",  // keji
-    &"平方",  // pingfang
-    &"良好",  // lianghao
-    &"无奈",  // wunai
-    &"甚至",  // shenzhi
-    &"过期",  // guoqi
-    &"激烈",  // jilie
-    &"盘子",  // panzi
-    &"中午",  // zhongwu
-    &"古代",  // gudai
-    &"爱好",  // aihao
-    &"开玩笑",  // kaiwanxiao
-    &"精诚所至",  // jingchengsuozhi
-    &"内科",  // neike
-    &"一连",  // yilian
-    &"来自",  // laizi
-    &"开幕式",  // kaimushi
-    &"舞蹈",  // wudao
-    &"在乎",  // zaihu
-    &"无意",  // wuyi
-    &"地点",  // didian
-    &"难受",  // nanshou
-    &"引起",  // yinqi
-    &"优势",  // youshi
-    &"层",  // ceng
-    &"饺子",  // jiaozi
-    &"重视",  // zhongshi
-    &"错",  // cuo
-    &"积累",  // jilei
-    &"研究",  // yanjiu
-    &"乐观",  // leguan
-    &"平衡",  // pingheng
-    &"出口",  // chukou
-    &"恭喜",  // gongxi
-    &"欢迎",  // huanying
-    &"旁边",  // pangbian
-    &"车厢",  // chexiang
-    &"海洋",  // haiyang
-    &"无聊",  // wuliao
-    &"摄影师",  // sheyingshi
-    &"脱",  // tuo
-    &"谨慎",  // jinshen
-    &"他	她	它",  // ta
-    &"工程师",  // gongchengshi
-    &"安排",  // anpai
-    &"北京师范大学",  // beijingshifandaxue
-    &"汉语",  // hanyu
-    &"幸运",  // xingyun
-    &"小雨",  // xiaoyu
-    &"有趣",  // youqu
-    &"喂	为	位	胃",  // wei
-    &"怎么",  // zenme
-    &"尊重",  // zunzhong
-    &"弟弟",  // didi
-    &"中间",  // zhongjian
-    &"单元",  // danyuan
-    &"展开",  // zhankai
-    &"吃",  // chi
-    &"空调",  // kongtiao
-    &"爱	矮	哎	唉",  // ai
-    &"缺点",  // quedian
-    &"理由",  // liyou
-    &"牙膏",  // yagao
-    &"导演",  // daoyan
-    &"中文",  // zhongwen
-    &"人口",  // renkou
-    &"有限",  // youxian
-    &"业余",  // yeyu
-    &"告诉",  // gaosu
-    &"老虎",  // laohu
-    &"柜台",  // guitai
-    &"胡说",  // hushuo
-    &"佩·詹森",  // peizhansen
-    &"日程",  // richeng
-    &"独特",  // dute
-    &"孝顺",  // xiaoshun
-    &"打扰",  // darao
-    &"需要",  // xuyao
-    &"只要",  // zhiyao
-    &"钱包",  // qianbao
-    &"有点儿",  // youdianr
-    &"佩·詹森",  // pei`zhansen
-    &"叙述",  // xushu
-    &"格外",  // gewai
-    &"自杀",  // zisha
-    &"这些",  // zhexie
-    &"难过",  // nanguo
-    &"竞争",  // jingzheng
-    &"要不",  // yaobu
-    &"帅	甩",  // shuai
-    &"发烧",  // fashao
-    &"垃圾",  // laji
-    &"马虎",  // mahu
-    &"简历	建立",  // jianli
-    &"·",  // `
-    &"商店",  // shangdian
-    &"少	勺",  // shao
-    &"幸亏",  // xingkui
-    &"合适",  // heshi
-    &"昆虫",  // kunchong
-    &"夹子",  // jiazi
-    &"报名",  // baoming
-    &"观念",  // guannian
-    &"称呼",  // chenghu
-    &"尾巴",  // weiba
-    &"导游",  // daoyou
-    &"感觉",  // ganjue
-    &"聊天儿",  // liaotianr
-    &"伙伴",  // huoban
-    &"分配",  // fenpei
-    &"穷",  // qiong
-    &"标准",  // biaozhun
-    &"保险",  // baoxian
-    &"集体",  // jiti
-    &"制定	制订",  // zhiding
-    &"姿势",  // zishi
-    &"荫凉",  // yinliang
-    &"天	甜	填",  // tian
-    &"征求",  // zhengqiu
-    &"总结",  // zongjie
-    &"主要",  // zhuyao
-    &"质量",  // zhiliang
-    &"照相机",  // zhaoxiangji
-    &"一下",  // yixia
-    &"（",  // (
-    &"橡皮",  // xiangpi
-    &"一会儿",  // yihuir
-    &"三	伞",  // san
-    &"声调",  // shengdiao
-    &"老师	老实",  // laoshi
-    &"粗糙",  // cucao
-    &"干净",  // ganjing
-    &"全面",  // quanmian
-    &"导致",  // daozhi
-    &"黄	慌",  // huang
-    &"册",  // ce
-    &"前途",  // qiantu
-    &"注意	主意",  // zhuyi
-    &"自私",  // zisi
-    &"挑战",  // tiaozhan
-    &"生活",  // shenghuo
-    &"始终",  // shizhong
-    &"思想",  // sixiang
-    &"女孩儿",  // nvhair
-    &"衬衫",  // chenshan
-    &"京剧",  // jingju
-    &"具体",  // juti
-    &"吸取",  // xiqu
-    &"作业",  // zuoye
-    &"把握",  // bawo
-    &"使劲",  // shijin
-    &"一	以	乙	亿",  // yi
-    &"毛巾",  // maojin
-    &"比尔·盖茨",  // bier`gaici
-    &"流利",  // liuli
-    &"巧妙",  // qiaomiao
-    &"重大",  // zhongda
-    &"裙子",  // qunzi
-    &"密切",  // miqie
-    &"废话",  // feihua
-    &"设施",  // sheshi
-    &"吗	马	骂",  // ma
-    &"）",  // )
-    &"冷",  // leng
-    &"土地",  // tudi
-    &"账户",  // zhanghu
-    &"居然",  // juran
-    &"到底",  // daodi
-    &"香山",  // xiangshan
-    &"要是	钥匙",  // yaoshi
-    &"口",  // kou
-    &"请愿书",  // qingyuanshu
-    &"拉	辣	啦",  // la
-    &"踢足球",  // tizuqiu
-    &"入围",  // ruwei
-    &"恩爱",  // enai
-    &"带	戴	袋	呆",  // dai
-    &"你们",  // nimen
-    &"语言	寓言",  // yuyan
-    &"楼	漏",  // lou
-    &"得意",  // deyi
-    &"继续	积蓄",  // jixu
-    &"丝绸",  // sichou
-    &"人名",  // renming
-    &"制作",  // zhizuo
-    &"商品",  // shangpin
-    &"键盘",  // jianpan
-    &"叔叔",  // shushu
-    &"假装",  // jiazhuang
-    &"相对",  // xiangdui
-    &"总理",  // zongli
-    &"资格",  // zige
-    &"木头",  // mutou
-    &"利用",  // liyong
-    &"题目",  // timu
-    &"然后",  // ranhou
-    &"原因",  // yuanyin
-    &"嘉宾",  // jiabin
-    &"汗	喊",  // han
-    &"黑猩猩",  // heixingxing
-    &"面试",  // mianshi
-    &"抱怨",  // baoyuan
-    &"捐",  // juan
-    &"能力",  // nengli
-    &"可能",  // keneng
-    &"新	信",  // xin
-    &"所谓",  // suowei
-    &"随手",  // suishou
-    &"楚国",  // chuguo
-    &"诺贝尔奖",  // nuobeierjiang
-    &"究竟",  // jiujing
-    &"文具",  // wenju
-    &"动画片",  // donghuapian
-    &"只好",  // zhihao
-    &"彼此",  // bici
-    &"相信",  // xiangxin
-    &"破坏",  // pohuai
-    &"后	厚",  // hou
-    &"杯子	被子",  // beizi
-    &"律师",  // lvshi
-    &"冷淡",  // lengdan
-    &"休息",  // xiuxi
-    &"成熟",  // chengshu
-    &"在于",  // zaiyu
-    &"积极",  // jiji
-    &"如今",  // rujin
-    &"全神贯注",  // quanshenguanzhu
-    &"加油",  // jiayou
-    &"浪漫",  // langman
-    &"认为",  // renwei
-    &"青少年",  // qingshaonian
-    &"秩序",  // zhixu
-    &"豪华",  // haohua
-    &"紧张",  // jinzhang
-    &"切",  // qie
-    &"报道	报到",  // baodao
-    &"落后",  // luohou
-    &"桌子",  // zhuozi
-    &"起居",  // qiju
-    &"义务",  // yiwu
-    &"情况",  // qingkuang
-    &"慌张",  // huangzhang
-    &"抓紧",  // zhuajin
-    &"北京	背景",  // beijing
-    &"旅行",  // lvxing
-    &"盆",  // pen
-    &"作品",  // zuopin
-    &"零件",  // lingjian
-    &"适合",  // shihe
-    &"天然",  // tianran
-    &"翻译",  // fanyi
-    &"丈夫",  // zhangfu
-    &"交际",  // jiaoji
-    &"迟早",  // chizao
-    &"讨论",  // taolun
-    &"大使馆",  // dashiguan
-    &"护国寺",  // huguosi
-    &"采取",  // caiqu
-    &"表明",  // biaoming
-    &"改进",  // gaijin
-    &"粉色",  // fense
-    &"路",  // lu
-    &"可靠",  // kekao
-    &"光盘",  // guangpan
-    &"鼻子",  // bizi
-    &"香肠",  // xiangchang
-    &"年龄",  // nianling
-    &"污染",  // wuran
-    &"窗帘",  // chuanglian
-    &"主动",  // zhudong
-    &"清淡",  // qingdan
-    &"喝	和	河",  // he
-    &"馆子	管子",  // guanzi
-    &"有意思",  // youyisi
-    &"留守",  // liushou
-    &"代言",  // daiyan
-    &"广场",  // guangchang
-    &"硬件",  // yingjian
-    &"平等",  // pingdeng
-    &"忽然",  // huran
-    &"忘记",  // wangji
-    &"警察",  // jingcha
-    &"太极拳",  // taijiquan
-    &"绝对",  // juedui
-    &"意义",  // yiyi
-    &"行李箱",  // xinglixiang
-    &"收据",  // shouju
-    &"月	越	约",  // yue
-    &"原料",  // yuanliao
-    &"孩子",  // haizi
-    &"着火",  // zhaohuo
-    &"答应",  // daying
-    &"大夫",  // daifu
-    &"简直	兼职",  // jianzhi
-    &"帆船",  // fanchuan
-    &"减轻",  // jianqing
-    &"自动",  // zidong
-    &"啊",  // a
-    &"找	照	招",  // zhao
-    &"艰巨",  // jianju
-    &"尽力",  // jinli
-    &"妹妹",  // meimei
-    &"不得不",  // budebu
-    &"手工",  // shougong
-    &"介绍",  // jieshao
-    &"女",  // nv
-    &"更",  // geng
-    &"尤其	与其",  // youqi
-    &"一律",  // yilv
-    &"丝毫",  // sihao
-    &"发挥",  // fahui
-    &"危险",  // weixian
-    &"《卖火柴的小女孩儿》",  // <maihuochaidexiaonvhaier>
-    &"记者",  // jizhe
-    &"计划",  // jihua
-    &"打工",  // dagong
-    &"秒",  // miao
-    &"双",  // shuang
-    &"意思",  // yisi
-    &"哥哥",  // gege
-    &"优惠",  // youhui
-    &"公里",  // gongli
-    &"肩膀",  // jianbang
-    &"构成",  // goucheng
-    &"并且",  // bingqie
-    &"风险",  // fengxian
-    &"恋爱",  // lianai
-    &"所以",  // suoyi
-    &"减少",  // jianshao
-    &"离婚",  // lihun
-    &"外公",  // waigong
-    &"安慰",  // anwei
-    &"看法",  // kanfa
-    &"重要",  // zhongyao
-    &"含意",  // hanyi
-    &"按照",  // anzhao
-    &"倒霉",  // daomei
-    &"蹄子",  // tizi
-    &"牙齿",  // yachi
-    &"信号",  // xinhao
-    &"电台",  // diantai
-    &"省略",  // shenglve
-    &"生病",  // shengbing
-    &"深渊",  // shenyuan
-    &"叶子",  // yezi
-    &"测验",  // ceyan
-    &"爸爸",  // baba
-    &"干活儿",  // ganhuor
-    &"其实",  // qishi
-    &"挂号",  // guahao
-    &"阳光",  // yangguang
-    &"矿泉水",  // kuangquanshui
-    &"新鲜",  // xinxian
-    &"不耐烦",  // bunaifan
-    &"洗手间",  // xishoujian
-    &"其余",  // qiyu
-    &"反正",  // fanzheng
-    &"存在",  // cunzai
-    &"下面",  // xiamian
-    &"掉	钓",  // diao
-    &"机器	极其",  // jiqi
-    &"拐弯",  // guaiwan
-    &"后背",  // houbei
-    &"馒头",  // mantou
-    &"件	减	捡	箭",  // jian
-    &"医生",  // yisheng
-    &"生命",  // shengming
-    &"小时	消失",  // xiaoshi
-    &"实习",  // shixi
-    &"顾客",  // guke
-    &"嫩",  // nen
-    &"相关",  // xiangguan
-    &"水平",  // shuiping
-    &"写作",  // xiezuo
-    &"冰激凌",  // bingjiling
-    &"表演",  // biaoyan
-    &"讲究",  // jiangjiu
-    &"筷子",  // kuaizi
-    &"赵国",  // zhaoguo
-    &"职业",  // zhiye
-    &"关心",  // guanxin
-    &"幸福",  // xingfu
-    &"委屈",  // weiqu
-    &"笔记本",  // bijiben
-    &"上网",  // shangwang
-    &"何必",  // hebi
-    &"不错",  // bucuo
-    &"电池",  // dianchi
-    &"谢谢",  // xiexie
-    &"拥挤",  // yongji
-    &"信心",  // xinxin
-    &"趵突泉",  // baotuquan
-    &"一样",  // yiyang
-    &"空",  // kong
-    &"汽油",  // qiyou
-    &"夏令营",  // xialingying
-    &"证据",  // zhengju
-    &"晒",  // shai
-    &"感情",  // ganqing
-    &"内部",  // neibu
-    &"交通",  // jiaotong
-    &"男	南	难",  // nan
-    &"酸	算",  // suan
-    &"无所谓",  // wusuowei
-    &"菜市口",  // caishikou
-    &"老舍",  // laoshe
-    &"怀孕",  // huaiyun
-    &"涂鸦",  // tuya
-    &"守岁",  // shousui
-    &"漂亮",  // piaoliang
-    &"信息",  // xinxi
-    &"实验",  // shiyan
-    &"主持",  // zhuchi
-    &"黑板",  // heiban
-    &"打喷嚏",  // dapenti
-    &"铅笔",  // qianbi
-    &"合格",  // hege
-    &"米饭",  // mifan
-    &"沙发",  // shafa
-    &"腿	推	退",  // tui
-    &"条",  // tiao
-    &"哪里",  // nali
-    &"拥抱",  // yongbao
-    &"偷",  // tou
-    &"会计",  // kuaiji
-    &"关于",  // guanyu
-    &"新年",  // xinnian
-    &"选择",  // xuanze
-    &"农村",  // nongcun
-    &"软",  // ruan
-    &"抽象",  // chouxiang
-    &"雨	鱼	与	于",  // yu
-    &"方法",  // fangfa
-    &"靠",  // kao
-    &"善良",  // shanliang
-    &"过去",  // guoqu
-    &"科恩",  // keen
-    &"达到",  // dadao
-    &"生动",  // shengdong
-    &"资料",  // ziliao
-    &"困扰",  // kunrao
-    &"出版",  // chuban
-    &"街道",  // jidao
-    &"听说",  // tingshuo
-    &"鼠标",  // shubiao
-    &"声音",  // shengyin
-    &"治疗",  // zhiliao
-    &"亚洲",  // yazhou
-    &"批准",  // peizhun
-    &"必要",  // biyao
-    &"感激",  // ganji
-    &"录取",  // luqu
-    &"事实",  // shishi
-    &"转变",  // zhuanbian
-    &"担任",  // danren
-    &"吐	图",  // tu
-    &"疑问",  // yiwen
-    &"少许",  // shaoxu
-    &"《非你莫属》",  // feinimoshu
-    &"可怜",  // kelian
-    &"车祸",  // chehuo
-    &"深	伸",  // shen
-    &"等	灯",  // deng
-    &"规律",  // guilv
-    &"校园",  // xiaoyuan
-    &"存",  // cun
-    &"经过",  // jingguo
-    &"一定",  // yiding
-    &"老鼠",  // laoshu
-    &"爷爷",  // yeye
-    &"没有",  // meiyou
-    &"投入",  // touru
-    &"根据",  // genju
-    &"时期",  // shiqi
-    &"退步",  // tuibu
-    &"哈",  // ha
-    &"赶	敢	干	杆	擀",  // gan
-    &"随时",  // suishi
-    &"云	晕",  // yun
-    &"古典",  // gudian
-    &"狂",  // kuang
-    &"单独",  // dandu
-    &"生意",  // shengyi
-    &"严格",  // yange
-    &"请	晴	轻	青",  // qing
-    &"全部",  // quanbu
-    &"谁",  // shei
-    &"勇气",  // yongqi
-    &"联合",  // lianhe
-    &"保护",  // baohu
-    &"知识",  // zhishi
-    &"信任",  // xinren
-    &"证件",  // zhengjian
-    &"传染",  // chuanran
-    &"频道",  // pindao
-    &"近代",  // jindai
-    &"排队",  // paidui
-    &"盆子",  // penzi
-    &"价值",  // jiazhi
-    &"依然",  // yiran
-    &"鼓励",  // guli
-    &"可怕",  // kepa
-    &"读	度	堵",  // du
-    &"疼",  // teng
-    &"成就",  // chengjiu
-    &"结果",  // jieguo
-    &"邀请",  // yaoqing
-    &"试卷",  // shijuan
-    &"哭	苦	库",  // ku
-    &"大	打",  // da
-    &"元旦",  // yuandan
-    &"被	倍	背	悲",  // bei
-    &"细节",  // xijie
-    &"进行",  // jinxing
-    &"再见",  // zaijian
-    &"理解",  // lijie
-    &"数据",  // shuju
-    &"借口",  // jiekou
-    &"怎么样",  // zenmeyang
-    &"控制",  // kongzhi
-    &"人员",  // renyuan
-    &"春",  // chun
-    &"看	砍",  // kan
-    &"出租车",  // chuzuche
-    &"肌肉",  // jirou
-    &"牙刷",  // yashua
-    &"采访",  // caifang
-    &"广大",  // guangda
-    &"毕业",  // biye
-    &"詹姆士·奈史密斯",  // zhanmushinaishimisi
-    &"往返",  // wangfan
-    &"打针",  // dazhen
-    &"起来",  // qilai
-    &"餐",  // can
-    &"无数	武术",  // wushu
-    &"年代",  // niandai
-    &"鼓掌",  // guzhang
-    &"稳定",  // wending
-    &"正在",  // zhengzai
-    &"陆续",  // luxu
-    &"苗条",  // miaotiao
-    &"给",  // gei
-    &"系领带",  // jilingdai
-    &"秋",  // qiu
-    &"争论",  // zhenglun
-    &"得到",  // dedao
-    &"胖",  // pang
-    &"区别",  // qubie
-    &"萝卜",  // luobo
-    &"下雨",  // xiayu
-    &"六一儿童节",  // liuyiertongjie
-    &"普通",  // putong
-    &"迷路",  // milu
-    &"连环画",  // lianhuanhua
-    &"土豆",  // tudou
-    &"习惯",  // xiguan
-    &"威胁",  // weixia
-    &"数量",  // shuliang
-    &"女士",  // nvshi
-    &"共同",  // gongtong
-    &"房间",  // fangjian
-    &"远	元	圆",  // yuan
-    &"均匀",  // junyun
-    &"暴雨",  // baoyu
-    &"阳台",  // yangtai
-    &"心情",  // xinqing
-    &"战国",  // zhanguo
-    &"应聘",  // yingpin
-    &"规矩",  // guiju
-    &"难道",  // nandao
-    &"也	页	夜",  // ye
-    &"风景",  // fengjing
-    &"闭关",  // biguan
-    &"纳入",  // naru
-    &"领域",  // lingyu
-    &"浏览",  // liulan
-    &"其中",  // qizhong
-    &"精神",  // jingshen
-    &"将来",  // jianglai
-    &"学习",  // xuexi
-    &"办理",  // banli
-    &"个性",  // gexing
-    &"购物",  // gouwu
-    &"讨价还价",  // taojiahuanjia
-    &"觉得",  // juede
-    &"亲自",  // qinzi
-    &"妈妈",  // mama
-    &"；",  // ;
-    &"竟然",  // jingran
-    &"傍晚",  // bangwan
-    &"玩具",  // wanju
-    &"空儿",  // kongr
-    &"座位	作为",  // zuowei
-    &"飞机",  // feiji
-    &"北方",  // beifang
-    &"全	圈	劝",  // quan
-    &"华北",  // huabei
-    &"报纸",  // baozhi
-    &"影子",  // yingzi
-    &"长期",  // changqi
-    &"产品",  // chanpin
-    &"明明",  // mingming
-    &"词语",  // ciyu
-    &"淋漓尽致",  // linlijinzhi
-    &"连续",  // lianxu
-    &"叉子",  // chazi
-    &"巧克力",  // qiaokeli
-    &"整个",  // zhengge
-    &"明天",  // mingtian
-    &"车",  // che
-    &"日",  // ri
-    &"竹子",  // zhuzi
-    &"糊涂",  // hutu
-    &"等于",  // dengyu
-    &"个别",  // gebie
-    &"小气",  // xiaoqi
-    &"发抖",  // fadou
-    &"性格",  // xingge
-    &"小朋友",  // xiaopengyou
-    &"厕所",  // cesuo
-    &"启发",  // qifa
-    &"婚姻",  // hunyin
-    &"闹钟",  // naozhong
-    &"奶奶",  // nainai
-    &"散步",  // sanbu
-    &"发票",  // fapiao
-    &"限制",  // xianzhi
-    &"手套",  // shoutao
-    &"牛奶",  // niunai
-    &"比赛",  // bisai
-    &"客人",  // keren
-    &"夸	垮",  // kua
-    &"密码",  // mima
-    &"赞成",  // zancheng
-    &"葡萄",  // putao
-    &"大方",  // dafang
-    &"西红柿",  // xihongshi
-    &"八成",  // bacheng
-    &"眼睛	眼镜",  // yanjing
-    &"一再",  // yizai
-    &"回答",  // huida
-    &"面条",  // miantiao
-    &"经济",  // jingji
-    &"招儿",  // zhaor
-    &"不仅",  // bujin
-    &"做客",  // zuoke
-    &"当	挡",  // dang
-    &"据说",  // jushuo
-    &"很	恨",  // hen
-    &"提问",  // tiwen
-    &"趁	沉",  // chen
-    &"难怪",  // nanguai
-    &"超过",  // chaoguo
-    &"首都机场",  // shoudujichang
-    &"菜单",  // caidan
-    &"表情",  // biaoqing
-    &"题	提	替	蹄",  // ti
-    &"保存",  // baocun
-    &"教室",  // jiaoshi
-    &"外交",  // waijiao
-    &"平	凭",  // ping
-    &"颜色",  // yanse
-    &"疯",  // feng
-    &"应付",  // yingfu
-    &"会议	回忆",  // huiyi
-    &"轻易",  // qingyi
-    &"龙",  // long
-    &"法院",  // fayuan
-    &"里查德·希尔斯",  // lichade`xiersi
-    &"味道",  // weidao
-    &"改变",  // gaibian
-    &"初级中学",  // chujizhongxue
-    &"客观",  // keguan
-    &"真	阵	镇",  // zhen
-    &"追求",  // zhuiqiu
-    &"效应",  // xiaoying
-    &"湿润",  // shirun
-    &"催",  // cui
-    &"剪刀",  // jiandao
-    &"峰终定律",  // fengzhongdinglv
-    &"图书馆",  // tushuguan
-    &"现象",  // xianxiang
-    &"学生",  // xuesheng
-    &"香瓜",  // xianggua
-    &"米",  // mi
-    &"二	而",  // er
-    &"方面",  // fangmian
-    &"用功",  // yonggong
-    &"表现",  // biaoxian
-    &"于是",  // yushi
-    &"双方",  // shuangfang
-    &"吃饭",  // chifan
-    &"鞭炮",  // bianpao
-    &"毛病",  // maobing
-    &"受不了",  // shoubuliao
-    &"预订",  // yuding
-    &"什么",  // shenme
-    &"熊猫",  // xiongmao
-    &"墙	抢	枪",  // qiang
-    &"相当",  // xiangdang
-    &"因而",  // yiner
-    &"镜子",  // jingzi
-    &"从",  // cong
-    &"欧盟",  // oumeng
-    &"微笑",  // weixiao
-    &"明显",  // mingxian
-    &"反复",  // fanfu
-    &"下午",  // xiawu
-    &"幼儿园",  // youeryuan
-    &"花花",  // huahua
-    &"刻苦",  // keku
-    &"服装",  // fuzhuang
-    &"小心",  // xiaoxin
-    &"知道	指导",  // zhidao
-    &"高兴",  // gaoxing
-    &"清楚",  // qingchu
-    &"根本",  // genben
-    &"知更鸟",  // zhigengniao
-    &"台阶",  // taijie
-    &"个	各",  // ge
-    &"放暑假",  // fangshujia
-    &"日子",  // rizi
-    &"女性",  // nvxing
-    &"粉	分	份",  // fen
-    &"评价",  // pingjia
-    &"符合",  // fuhe
-    &"胳膊",  // gebo
-    &"睡觉",  // shuijiao
-    &"还是",  // haishi
-    &"购买",  // goumai
-    &"刚才",  // gangcai
-    &"来得及",  // laideji
-    &"准时",  // zhunshi
-    &"关闭",  // guanbi
-    &"玻璃",  // boli
-    &"照顾",  // zhaogu
-    &"纸袋",  // zhidai
-    &"文化",  // wenhua
-    &"英雄",  // yingxiong
-    &"友好",  // youhao
-    &"痛快",  // tongkuai
-    &"未来",  // weilai
-    &"文学",  // wenxue
-    &"互联网",  // hulianwang
-    &"事情",  // shiqing
-    &"不足",  // buzu
-    &"对面",  // duimian
-    &"鲜艳",  // xianyan
-    &"项目",  // xiangmu
-    &"反应	反映",  // fanying
-    &"叫	教	角	脚	交	浇	焦",  // jiao
-    &"熟悉",  // shuxi
-    &"缺乏",  // quefa
-    &"星期",  // xingqi
-    &"提倡",  // tichang
-    &"命令",  // mingling
-    &"句	举	锯	局",  // ju
-    &"讽刺",  // fengci
-    &"步骤",  // buzhou
-    &"淘气",  // taoqi
-    &"责任",  // zeren
-    &"提纲",  // tigang
-    &"健康",  // jiankang
-    &"登机牌",  // dengjipai
-    &"技术",  // jishu
-    &"消费",  // xiaofei
-    &"收入",  // shouru
-    &"宴会",  // yanhui
-    &"地方",  // difang
-    &"改	盖",  // gai
-    &"逐步",  // zhubu
-    &"课	渴	刻	可	棵	颗	克",  // ke
-    &"景色",  // jingse
-    &"生气",  // shengqi
-    &"为了",  // weile
-    &"基础",  // jichu
-    &"安全",  // anquan
-    &"公布",  // gongbu
-    &"可是",  // keshi
-    &"垃圾桶",  // lajitong
-    &"睡着",  // shuizhao
-    &"整体",  // zhengti
-    &"不断",  // buduan
-    &"形成",  // xingcheng
-    &"美人鱼",  // meirenyu
-    &"奇迹",  // qiji
-    &"打扮",  // daban
-    &"哲学",  // zhexue
-    &"销售",  // xiaoshou
-    &"返航",  // fanhang
-    &"节约",  // jieyue
-    &"增",  // zeng
-    &"长辈",  // zhangbei
-    &"怀念",  // huainian
-    &"文件",  // wenjian
-    &"里	李	离	丽	梨",  // li
-    &"出发",  // chufa
-    &"纸上谈兵",  // zhishangtanbing
-    &"逃避",  // taobi
-    &"尽量",  // jinliang
-    &"敲	桥	瞧",  // qiao
-    &"随身",  // suishen
-    &"出色",  // chuse
-    &"金属",  // jinshu
-    &"窗	闯",  // chuang
-    &"有名",  // youming
-    &"珍惜",  // zhenxi
-    &"喂养",  // weiyang
-    &"赵括",  // zhaokuo
-    &"不得了",  // budeliao
-    &"照常",  // zhaochang
-    &"通过",  // tongguo
-    &"哪	那	拿",  // na
-    &"讨厌",  // taoyan
-    &"开水",  // kaishui
-    &"明",  // ming
-    &"必须",  // bixu
-    &"学历",  // xueli
-    &"期间",  // qijian
-    &"微信",  // weixin
-    &"凉茶",  // liangcha
-    &"批评",  // piping
-    &"迟到",  // chidao
-    &"人民",  // renmin
-    &"则",  // ze
-    &"文文",  // wenwen
-    &"商场",  // shangchang
-    &"空气",  // kongqi
-    &"新闻",  // xinwen
-    &"公斤",  // gongjin
-    &"水	税",  // shui
-    &"留学",  // liuxue
-    &"金鱼",  // jinyu
-    &"形式	形势",  // xingshi
-    &"预报",  // yubao
-    &"母亲",  // muqin
-    &"表格",  // biaoge
-    &"长江",  // changjiang
-    &"面包",  // mianbao
-    &"赵福根",  // zhaofugen
-    &"同意	统一",  // tongyi
-    &"已经",  // yijing
-    &"中秋节",  // zhongqiujie
-    &"恐怕",  // kongpa
-    &"都	逗",  // dou
-    &"运气",  // yunqi
-    &"推辞",  // tuici
-    &"贡献",  // gongxian
-    &"澳大利亚",  // aodaliya
-    &"子路",  // zilu
-    &"仔细",  // zixi
-    &"商业",  // shangye
-    &"六	留",  // liu
-    &"机场",  // jichang
-    &"张小龙",  // zhangxiaolong
-    &"刮风",  // guafeng
-    &"意见",  // yijian
-    &"归纳",  // guina
-    &"蔬菜",  // shucai
-    &"必然",  // biran
-    &"内",  // nei
-    &"演出",  // yanchu
-    &"理论",  // lilun
-    &"出示",  // chushi
-    &"玩儿",  // wanr
-    &"专业",  // zhuanye
-    &"果汁",  // guozhi
-    &"小	笑",  // xiao
-    &"过程",  // guocheng
-    &"满意",  // manyi
-    &"菊花",  // juhua
-    &"进步",  // jinbu
-    &"昨天",  // zuotian
-    &"讲	奖",  // jiang
-    &"降落",  // jiangluo
-    &"逻辑",  // luoji
-    &"游览",  // youlan
-    &"日历",  // rili
-    &"无论",  // wulun
-    &"来",  // lai
-    &"观点",  // guandian
-    &"此外",  // ciwai
-    &"便宜",  // pianyi
-    &"健身",  // jianshen
-    &"何况",  // hekuang
-    &"小伙子",  // xiaohuozi
-    &"《卖火柴的小女孩儿》",  // maihuochaidexiaonvhair
-    &"棚子",  // pengzi
-    &"复习",  // fuxi
-    &"来不及",  // laibuji
-    &"处理",  // chuli
-    &"以及",  // yiji
-    &"分手",  // fenshou
-    &"救护车",  // jiuhuche
-    &"员工",  // yuangong
-    &"冒险",  // maoxian
-    &"盒子",  // hezi
-    &"地铁",  // ditie
-    &"公主",  // gongzhu
-    &"补充",  // buchong
-    &"单位",  // danwei
-    &"火成岩",  // huochengyan
-    &"满足",  // manzu
-    &"精彩",  // jingcai
-    &"比如",  // biru
-    &"空闲",  // kongxian
-    &"胆小鬼",  // danxiaogui
-    &"醋",  // cu
-    &"道具",  // daoju
-    &"家乡",  // jiaxiang
-    &"嗯",  // ng
-    &"作者",  // zuozhe
-    &"多	朵",  // duo
-    &"办法",  // banfa
-    &"显得",  // xiande
-    &"反而",  // faner
-    &"时候",  // shihou
-    &"未必",  // weibi
-    &"尾气",  // weiqi
-    &"感兴趣",  // ganxingqu
-    &"概念",  // gainian
-    &"去	取	娶",  // qu
-    &"打折",  // dazhe
-    &"大象",  // daxiang
-    &"灰姑娘",  // huiguniang
-    &"招待",  // zhaodai
-    &"样子",  // yangzi
-    &"森林",  // senlin
-    &"使劲儿",  // shijinr
-    &"同样",  // tongyang
-    &"挂",  // gua
-    &"掌握",  // zhangwo
-    &"邮局",  // youju
-    &"轻松",  // qingsong
-    &"电子邮件",  // dianziyoujian
-    &"鲍全",  // baoquan
-    &"永远",  // yongyuan
-    &"种类",  // zhonglei
-    &"一共",  // yigong
-    &"运用",  // yunyong
-    &"老百姓",  // laobaixing
-    &"比较",  // bijiao
-    &"由来",  // youlai
-    &"责备",  // zebei
-    &"先	咸	县",  // xian
-    &"忙",  // mang
-    &"小区",  // xiaoqu
-    &"橡子",  // xiangzi
-    &"有关",  // youguan
-    &"愿意",  // yuanyi
-    &"亲戚",  // qinqi
-    &"国王",  // guowang
-    &"凉快",  // liangkuai
-    &"非常",  // feichang
-    &"建设",  // jianshe
-    &"提高",  // tigao
-    &"兴奋",  // xingfen
-    &"海鲜",  // haixian
-    &"白	百	摆",  // bai
-    &"出差",  // chuchai
-    &"失去",  // shiqu
-    &"可爱",  // keai
-    &"押金",  // yajin
-    &"交往",  // jiaowang
-    &"音乐",  // yinyue
-    &"脏",  // zang
-    &"身段",  // shenduan
-    &"袜子",  // wazi
-    &"装修",  // zhuangxiu
-    &"预习",  // yuxi
-    &"八	吧	把",  // ba
-    &"还	海",  // hai
-    &"急诊",  // jizhen
-    &"加利福尼亚州",  // jialifuniyazhou
-    &"天安门东",  // tiananmendong
-    &"人	认",  // ren
-    &"针对",  // zhendui
-    &"赚	转",  // zhuan
-    &"体育馆",  // tiyuguan
-    &"饼干",  // binggan
-    &"干活儿",  // ganhuoer
-    &"保证",  // baozheng
-    &"会员卡",  // huiyuanka
-    &"红",  // hong
-    &"方向",  // fangxiang
-    &"以来",  // yilai
-    &"原谅",  // yuanliang
-    &"努力",  // nuli
-    &"变化",  // bianhua
-    &"考虑",  // kaolv
-    &"巨大",  // juda
-    &"疼爱",  // tengai
-    &"成绩",  // chengji
-    &"射	蛇",  // she
-    &"银子",  // yinzi
-    &"气氛",  // qifen
-    &"聊天",  // liaotian
-    &"以前",  // yiqian
-    &"搜索",  // sousuo
-    &"中国",  // zhongguo
-    &"朗读",  // langdu
-    &"化学",  // huaxue
-    &"高速公路",  // gaosugonglu
-    &"农民",  // nongmin
-    &"至于",  // zhiyu
-    &"称赞",  // chengzan
-    &"一切",  // yiqie
-    &"妻子",  // qizi
-    &"举行",  // juxing
-    &"咱们",  // zanmen
-    &"遗憾",  // yihan
-    &"稻香村",  // daoxiangcun
-    &"教练",  // jiaolian
-    &"红色",  // hongse
-    &"虚心",  // xuxin
-    &"名片",  // mingpian
-    &"制冷",  // xhileng
-    &"相敬如宾",  // xiangjingrubin
-    &"成	称",  // cheng
-    &"跑步",  // paobu
-    &"情侣",  // qinglv
-    &"体贴",  // titie
-    &"猫	毛	冒",  // mao
-    &"本科",  // benke
-    &"效果",  // xiaoguo
-    &"成分",  // chengfen
-    &"重点",  // zhongdian
-    &"字母	字幕",  // zimu
-    &"利润",  // lirun
-    &"正好",  // zhenghao
-    &"如果",  // ruguo
-    &"热情",  // reqing
-    &"只	指	之	纸	支	治	直",  // zhi
-    &"最初",  // zuichu
-    &"植物",  // zhiwu
-    &"阿姨",  // ayi
-    &"正式",  // zhengshi
-    &"而且",  // erqie
-    &"从此",  // congci
-    &"握手",  // woshou
-    &"离开",  // likai
-    &"前面",  // qianmian
-    &"能",  // neng
-    &"反对",  // fandui
-    &"健步如飞",  // jianburufei
-    &"合同",  // hetong
-    &"行家",  // hangjia
-    &"申请",  // shenqing
-    &"擦",  // ca
-    &"自由",  // ziyou
-    &"工资",  // gongzi
-    &"接近",  // jiejin
-    &"椅子",  // yizi
-    &"热烈",  // relie
-    &"小说",  // xiaoshuo
-    &"敌人",  // diren
-    &"似的",  // shide
-    &"吵	朝	炒	抄",  // chao
-    &"平静",  // pingjing
-    &"帮助",  // bangzhu
-    &"部分",  // bufen
-    &"承担",  // chengdan
-    &"肥皂",  // feizao
-    &"至少",  // zhishao
-    &"预防",  // yufang
-    &"按时",  // anshi
-    &"盐",  // yan
-    &"推广",  // tuiguang
-    &"十	是	试	使	诗",  // shi
-    &"拍	派",  // pai
-    &"其他",  // qita
-    &"些	写	谢	斜	歇	血",  // xie
-    &"断断续续",  // duanduanxuxu
-    &"今天",  // jintian
-    &"辣椒",  // lajiao
-    &"请假",  // qingjia
-    &"兄弟",  // xiongdi
-    &"波动",  // bodong
-    &"售货员",  // shouhuoyuan
-    &"寻找",  // xunzhao
-    &"模仿",  // mofang
-    &"调查",  // diaocha
-    &"风俗",  // fengsu
-    &"自然",  // ziran
-    &"所	锁",  // suo
-    &"让",  // rang
-    &"流血",  // liuxie
-    &"实在",  // shizai
-    &"送",  // song
-    &"组合",  // zuhe
-    &"肯定",  // kending
-    &"想念",  // xiangnian
-    &"日常",  // richang
-    &"投资",  // touzi
-    &"行为",  // xingwei
-    &"灰心",  // huixin
-    &"塑料袋",  // suliaodai
-    &"否认",  // fouren
-    &"安娜",  // anna
-    &"应该",  // yinggai
-    &"粘贴",  // zhantie
-    &"姑娘",  // guniang
-    &"但是",  // danshi
-    &"摸",  // mo
-    &"总统",  // zongtong
-    &"材料",  // cailiao
-    &"卡车",  // kache
-    &"智慧	指挥",  // zhihui
-    &"啄",  // zhuo
-    &"这	着	折",  // zhe
-    &"沙滩",  // shatan
-    &"一起",  // yiqi
-    &"寄生",  // jisheng
-    &"鼓楼大街",  // guloudajie
-    &"愉快",  // yukuai
-    &"运输",  // yunshu
-    &"沙子",  // shazi
-    &"重量",  // zhongliang
-    &"婚礼",  // hunli
-    &"低落",  // diluo
-    &"国君",  // guojun
-    &"冷静",  // lengjing
-    &"改善",  // gaishan
-    &"删除",  // shanchu
-    &"照片",  // zhaopian
-    &"旅游",  // lvyou
-    &"汇率",  // huilv
-    &"互相",  // huxiang
-    &"蒙眬",  // menglong
-    &"杂志",  // zazhi
-    &"形状",  // xingzhuang
-    &"公司",  // gongsi
-    &"纪念",  // jinian
-    &"演员",  // yanyuan
-    &"免费",  // mianfei
-    &"不同",  // butong
-    &"对手",  // duishou
-    &"完美",  // wanmei
-    &"样式",  // yangshi
-    &"燃烧",  // ranshao
-    &"时尚",  // shishang
-    &"姐姐",  // jiejie
-    &"活动",  // huodong
-    &"陪",  // pei
-    &"练习	联系",  // lianxi
-    &"抓",  // zhua
-    &"想	向	像	香	响	项	象",  // xiang
-    &"辞职",  // cizhi
-    &"仍然",  // rengran
-    &"促使",  // cushi
-    &"课程",  // kecheng
-    &"暂时",  // zanshi
-    &"记忆",  // jiyi
-    &"高	搞",  // gao
-    &"获得",  // huode
-    &"耳环",  // erhuan
-    &"可惜",  // kexi
-    &"不然",  // buran
-    &"气候",  // qihou
-    &"坐	做	座",  // zuo
-    &"灵活",  // linghuo
-    &"遇到",  // yudao
-    &"结婚",  // jiehun
-    &"广播",  // guangbo
-    &"西汉",  // xihan
-    &"尽快",  // jinkuai
-    &"才艺",  // caiyi
-    &"世界",  // shijie
-    &"数码",  // shuma
-    &"表达",  // biaoda
-    &"仿佛",  // fangfu
-    &"分布",  // fenbu
-    &"决定",  // jueding
-    &"走",  // zou
-    &"航班",  // hangban
-    &"编辑",  // bianji
-    &"录音",  // luyin
-    &"合影",  // heying
-    &"车库",  // cheku
-    &"本领",  // benling
-    &"围绕",  // weirao
-    &"家访",  // jiafang
-    &"身份",  // shenfen
-    &"和尚",  // heshang
-    &"机会",  // jihui
-    &"毕竟",  // bijing
-    &"专家",  // zhuanjia
-    &"差不多",  // chabuduo
-    &"民居",  // minju
-    &"广告",  // guanggao
-    &"时间	实践",  // shijian
-    &"比尔·盖茨",  // biergaici
-    &"手续",  // shouxu
-    &"家具",  // jiaju
-    &"通常",  // tongchang
-    &"比",  // bi
-    &"每天",  // meitian
-    &"情趣",  // qingqu
-    &"红酒",  // hongjiu
-    &"别",  // bie
-    &"花	画	滑	划",  // hua
-    &"饿	额",  // e
-    &"患难与共",  // huannanyugong
-    &"支教",  // zhijiao
-    &"填空	天空",  // tiankong
-    &"道理",  // daoli
-    &"大约",  // dayue
-    &"查询",  // chaxun
-    &"充满",  // chongman
-    &"饮料",  // yinliao
-    &"接	借	节	街	戒	届",  // jie
-    &"及格",  // jige
-    &"成果",  // chengguo
-    &"宿舍",  // sushe
-    &"干燥",  // ganzao
-    &"交换",  // jiaohuan
-    &"用",  // yong
-    &"同情",  // tongqing
-    &"发明",  // faming
-    &"吸引",  // xiyin
-    &"厘米",  // limi
-    &"华裔",  // huayi
-    &"东海龙王",  // donghailongwang
-    &"态度",  // taidu
-    &"杨	养	痒",  // yang
-    &"佩服",  // peifu
-    &"情景",  // qingjing
-    &"权力	权利",  // quanli
-    &"盼望",  // panwang
-    &"大家",  // dajia
-    &"好像",  // haoxiang
-    &"懂	东	冬	冻	洞",  // dong
-    &"国	过	锅",  // guo
-    &"消息",  // xiaoxi
-    &"地图",  // ditu
-    &"却",  // que
-    &"专注",  // zhuanzhu
-    &"关	官",  // guan
-    &"并",  // bing
-    &"洛杉矶",  // luoshanji
-    &"业务",  // yewu
-    &"强调",  // qiangdiao
-    &"百分之",  // baifenzhi
-    &"夏威夷",  // xiaweiyi
-    &"新西兰",  // xinxilan
-    &"软件",  // ruanjian
-    &"严重",  // yanzhong
-    &"代替",  // daiti
-    &"师傅",  // shifu
-    &"梦",  // meng
-    &"参考",  // cankao
-    &"怪物",  // guaiwu
-    &"影响",  // yingxiang
-    &"，",  // ,
-    &"堵车",  // duche
-    &"某",  // mou
-    &"传真",  // chuanzhen
-    &"短	段	断",  // duan
-    &"蹲	顿	吨",  // dun
-    &"或者",  // huozhe
-    &"开心",  // kaixin
-    &"沙丁鱼",  // shadingyu
-    &"爱惜",  // aixi
-    &"时差",  // shicha
-    &"见面",  // jianmian
-    &"啤酒",  // pijiu
-    &"猴子",  // houzi
-    &"失业",  // shiye
-    &"教材",  // jiaocai
-    &"召开",  // zhaokai
-    &"悲伤",  // beishang
-    &"意外",  // yiwai
-    &"运动",  // yundong
-    &"耐心",  // naixin
-    &"宁可",  // ningke
-    &"养成",  // yangcheng
-    &"父母",  // fumu
-    &"扩大",  // kuoda
-    &"其次",  // qici
-    &"着凉",  // zhaoliang
-    &"包括",  // baokuo
-    &"群",  // qun
-    &"道德",  // daode
-    &"蜜蜂",  // mifeng
-    &"发言",  // fayan
-    &"平安",  // pingan
-    &"独立",  // duli
-    &"困难",  // kunnan
-    &"害羞",  // haixiu
-    &"发愁",  // fachou
-    &"打印",  // dayin
-    &"亲爱",  // qinai
-    &"政治",  // zhengzhi
-    &"演讲",  // yanjiang
-    &"绕",  // rao
-    &"减肥",  // jianfei
-    &"热心",  // rexin
-    &"火	货",  // huo
-    &"热",  // re
-    &"省	剩	升",  // sheng
-    &"随着",  // suizhe
-    &"奔跑",  // benpao
-    &"欣赏",  // xinshang
-    &"刚",  // gang
-    &"机制",  // jizhi
-    &"西瓜",  // xigua
-    &"缺少",  // queshao
-    &"政府",  // zhengfu
-    &"手笔",  // shoubi
-    &"袋子",  // daizi
-    &"成功",  // chenggong
-    &"羊肉",  // yangrou
-    &"私人",  // siren
-    &"国贸",  // guomao
-    &"！",  // !
-    &"火柴",  // huochai
-    &"调皮",  // tiaopi
-    &"采用",  // caiyong
-    &"邻居",  // linju
-    &"受到",  // shoudao
-    &"作文",  // zuowen
-    &"阴	银",  // yin
-    &"维修",  // weixiu
-    &"终于",  // zhongyu
-    &"咳嗽",  // kesou
-    &"总裁",  // zongcai
-    &"笔试",  // bishi
-    &"罚款",  // fakuan
-    &"教育",  // jiaoyu
-    &"山",  // shan
-    &"吸收",  // xishou
-    &"头发",  // toufa
-    &"一般",  // yiban
-    &"伟大",  // weida
-    &"出生",  // chusheng
-    &"情缘",  // qingyuan
-    &"舒适",  // shushi
-    &"称霸",  // chengba
-    &"首先",  // shouxian
-    &"印刷",  // yinshua
-    &"锻炼",  // duanlian
-    &"蒸腾",  // zhengteng
-    &"拼音",  // pinyin
-    &"感冒",  // ganmao
-    &"洗礼",  // xili
-    &"行动",  // xingdong
-    &"思考",  // sikao
-    &"心理",  // xinli
-    &"左边",  // zuobian
-    &"万丈",  // wanzhang
-    &"报社",  // baoshe
-    &"帮忙",  // bangmang
-    &"汉字",  // hanzi
-    &"脑袋",  // naodai
-    &"综合",  // zonghe
-    &"美国",  // meiguo
-    &"创办",  // chuangban
-    &"面积",  // mianji
-    &"乐器",  // yueqi
-    &"千万",  // qianwan
-    &"形容",  // xingrong
-    &"痛苦",  // tongku
-    &"看望",  // kanwang
-    &"零钱",  // lingqian
-    &"大型",  // daxing
-    &"有效",  // youxiao
-    &"签证",  // qianzheng
-    &"绳子",  // shengzi
-    &"硕士",  // shuoshi
-    &"淡",  // dan
-    &"重新",  // chongxin
-    &"二手",  // ershou
-    &"数学",  // shuxue
-    &"名胜古迹",  // mingshengguji
-    &"到时候",  // daoshihou
-    &"许多",  // xuduo
-    &"下载",  // xiazai
-    &"自信",  // zixin
-    &"电视",  // dianshi
-    &"活泼",  // huopo
-    &"初级",  // chuji
-    &"属于",  // shuyu
-    &"记得",  // jide
-    &"梦想",  // mengxiang
-    &"根治",  // genzhi
-    &"奋斗",  // fendou
-    &"累	雷",  // lei
-    &"破",  // po
-    &"不行",  // buxing
-    &"扔",  // reng
-    &"躲藏",  // duocang
-    &"节省",  // jiesheng
-    &"制造",  // zhizao
-    &"《",  // <
-    &"娱乐",  // youle
-    &"艺术",  // yishu
-    &"违反",  // weifan
-    &"培养",  // peiyang
-    &"舅舅",  // jiujiu
-    &"破产",  // pochan
-    &"除非",  // chufei
-    &"文字	蚊子",  // wenzi
-    &"表",  // biao
-    &"一点儿",  // yidianr
-    &"开发",  // kaifa
-    &"迎接",  // yingjie
-    &"王宫",  // wanggong
-    &"果实",  // guoshi
-    &"当地",  // dangdi
-    &"同学",  // tongxue
-    &"问	闻	吻",  // wen
-    &"复制",  // fuzhi
-    &"东西",  // dongxi
-    &"服务员",  // fuwuyuan
-    &"脸	连",  // lian
-    &"万一",  // wanyi
-    &"帮	棒",  // bang
-    &"物理",  // wuli
-    &"结论",  // jielun
-    &"妨碍",  // fangai
-    &"包子",  // baozi
-    &"宣布",  // xuanbu
-    &"张	长	涨",  // zhang
-    &"零	另	铃	令",  // ling
-    &"太	台	抬",  // tai
-    &"光滑",  // guanghua
-    &"模糊",  // mohu
-    &"票	飘",  // piao
-    &"？",  // ?
-    &"生日",  // shengri
-    &"往往",  // wangwang
-    &"蓝	懒	拦	烂",  // lan
-    &"慢	满",  // man
-    &"参与",  // canyu
-    &"文火",  // wenhuo
-    &"进口",  // jinkou
-    &"话题",  // huati
-    &"从来",  // conglai
-    &"询问",  // xunwen
-    &"秦国",  // qinguo
-    &"早上",  // zaoshang
-    &"真实",  // zhenshi
-    &"美术",  // meishu
-    &"蛋糕",  // dangao
-    &"顺畅",  // shunchang
-    &"呢",  // ne
-    &"当时",  // dangshi
-    &"自觉",  // zijue
-    &"面对",  // miandui
-    &"办公室",  // bangongshi
-    &"动作",  // dongzuo
-    &"新鲜感",  // xinxiangan
-    &"水果",  // shuiguo
-    &"进	近	尽",  // jin
-    &"准备",  // zhunbei
-    &"增加",  // zengjia
-    &"纪律",  // jilv
-    &"看不起",  // kanbuqi
-    &"象棋",  // xiangqi
-    &"笑话	消化",  // xiaohua
-    &"接待",  // jiedai
-    &"支持",  // zhichi
-    &"缓解",  // huanjie
-    &"小姐",  // xiaojie
-    &"周到",  // zhoudao
-    &"时刻",  // shike
-    &"门槛",  // menkan
-    &"打交道",  // dajiaodao
-    &"出	处	除",  // chu
-    &"汗腺",  // hanxian
-    &"翻	犯",  // fan
-    &"误会",  // wuhui
-    &"使用	实用",  // shiyong
-    &"价格",  // jiage
-    &"强烈",  // qianglie
-    &"操心",  // caoxin
-    &"青春",  // qingchun
-    &"四合院",  // siheyuan
-    &"后来",  // houlai
-    &"上当",  // shangdang
-    &"枝干	知感",  // zhigan
-    &"值得",  // zhide
-    &"折叠",  // zhedie
-    &"描写",  // miaoxie
-    &"地震",  // dizhen
-    &"比例",  // bili
-    &"事半功倍",  // shibangongbei
-    &"穿	船",  // chuan
-    &"打篮球",  // dalanqiu
-    &"坚强",  // jianqiang
-    &"李广",  // liguang
-    &"规定",  // guiding
-    &"笔记",  // biji
-    &"音乐会",  // yinyuehui
-    &"牛仔裤",  // niuzaiku
-    &"就餐",  // jiucan
-    &"回	会	挥	灰",  // hui
-    &"《说文解字》",  // shuowenjiezi
-    &"性质",  // xingzhi
-    &"支票",  // zhipiao
-    &"矛盾",  // maodun
-    &"包含",  // baohan
-    &"云南",  // yunnan
-    &"健伟",  // jianwei
-    &"酒吧",  // jiuba
-    &"士兵",  // shibing
-    &"充电器",  // chongdianqi
-    &"神秘",  // shenmi
-    &"黄金",  // huangjin
-    &"除夕	出席",  // chuxi
-    &"博物馆",  // bowuguan
-    &"目的",  // mudi
-    &"耳朵",  // erduo
-    &"熬夜",  // aoye
-    &"请求",  // qingqiu
-    &"唱歌",  // changge
-    &"赢	硬",  // ying
-    &"观众",  // guanzhong
-    &"普通话",  // putonghua
-    &"目前",  // muqian
-    &"月亮",  // yueliang
-    &"风浪",  // fenglang
-    &"功夫",  // gongfu
-    &"胜利",  // shengli
-    &"工业",  // gongye
-    &"年轻",  // nianqing
-    &"丛林",  // conglin
-    &"颠球",  // dianqiu
-    &"贷款",  // daikuan
-    &"广东省",  // guangdongsheng
-    &"速度",  // sudu
-    &"放	方",  // fang
-    &"国庆节",  // guoqingjie
-    &"不过",  // buguo
-    &"财产",  // caichan
-    &"鲇鱼",  // nianyu
-    &"谈判",  // tanpan
-    &"复印",  // fuyin
-    &"贵",  // gui
-    &"也许",  // yexu
-    &"太阳",  // taiyang
-    &"辅导",  // fudao
-    &"利息",  // lixi
-    &"家	假	加	甲	嫁	佳",  // jia
-    &"一般来说",  // yibanlaishuo
-    &"原则",  // yuanze
-    &"爬山",  // pashan
-    &"干脆",  // gancui
-    &"初中",  // chuzhong
-    &"人类",  // renlei
-    &"郝林说",  // haolinshuo
-    &"七郎",  // qilang
-    &"骄傲",  // jiaoao
-    &"手指",  // shouzhi
-    &"礼物",  // liwu
-    &"豆腐",  // doufu
-    &"沟通",  // goutong
-    &"教训",  // jiaoxun
-    &"提供",  // tigong
-    &"公寓",  // gongyu
-    &"暖",  // nuan
-    &"欧洲",  // ouzhou
-    &"多余",  // duoyu
-    &"建议",  // jianyi
-    &"主席",  // zhuxi
-    &"经常",  // jingchang
-    &"排列",  // pailie
-    &"聪明",  // congming
-    &"老婆",  // laopo
-    &"和平",  // heping
-    &"上海	伤害",  // shanghai
-    &"顺序",  // shunxu
-    &"争取",  // zhengqu
-    &"假如",  // jiaru
-    &"瓶子",  // pingzi
-    &"勤奋",  // qinfen
-    &"轮",  // lun
-    &"雇",  // gu
-    &"光	逛",  // guang
-    &"呼朋唤友",  // hupenghuanyou
-    &"王	往",  // wang
-    &"营业",  // yingye
-    &"岛屿",  // daoyu
-    &"路口",  // lukou
-    &"评委",  // pingwei
-    &"刘辰",  // liuchen
-    &"地址",  // dizhi
-    &"加班",  // jiaban
-    &"丹尼尔·卡内曼",  // dannierkaneiman
-    &"流传",  // liuchuan
-    &"报告",  // baogao
-    &"不但",  // budan
-    &"成立",  // chengli
-    &"修理",  // xiuli
-    &"待遇",  // daiyu
-    &"兑换",  // duihuan
-    &"教授",  // jiaoshou
-    &"刘炽平",  // liuchiping
-    &"本质",  // benzhi
-    &"天气",  // tianqi
-    &"赶快",  // gankuai
-    &"放松",  // fangsong
-    &"只有",  // zhiyou
-    &"打电话",  // dadianhua
-    &"曾经",  // cengjing
-    &"故意",  // guyi
-    &"对话",  // duihua
-    &"角度",  // jiaodu
-    &"同事	同时",  // tongshi
-    &"偶尔",  // ouer
-    &"系统",  // xitong
-    &"关键",  // guanjian
-    &"民族",  // minzu
-    &"连忙",  // lianmang
-    &"否则",  // fouze
-    &"卫生间",  // weishengjian
-    &"昙花",  // tanhua
-    &"歪歪扭扭",  // waiwainiuniu
-    &"赔偿",  // peichang
-    &"得",  // dei
-    &"马萨诸塞州",  // masazhusaizhou
-    &"延长",  // yanchang
-    &"舜",  // shun
-    &"怪	乖",  // guai
-    &"重复",  // chongfu
-    &"神话",  // shenhua
-    &"能源",  // nengyuan
-    &"移动",  // yidong
-    &"皮鞋",  // pixie
-    &"我",  // wo
-    &"杨雄",  // yangxiong
-    &"药	要	摇	咬	腰",  // yao
-    &"护照",  // huzhao
-    &"赏心悦目",  // shangxinyuemu
-    &"价钱",  // jiaqian
-    &"大卫",  // dawei
-    &"国家",  // guojia
-    &"房子",  // fangzi
-    &"节日",  // jieri
-    &"接受",  // jieshou
-    &"否定",  // fouding
-    &"匆忙",  // congmang
-    &"小麦",  // xiaomai
-    &"食物	事物",  // shiwu
-    &"电梯",  // dianti
-    &"长城",  // changcheng
-    &"刺激",  // ciji
-    &"第一",  // diyi
-    &"悠久",  // youjiu
-    &"熟练",  // shulian
-    &"天津卫视",  // tianjinweishi
-    &"笑笑",  // xiaoxiao
-    &"错误",  // cuowu
-    &"现在",  // xianzai
-    &"网站",  // wangzhan
-    &"卢米埃尔",  // lumiaier
-    &"静",  // jing
-    &"结账",  // jiezhang
-    &"信用卡",  // xinyongka
-    &"详细",  // xiangxi
-    &"合法",  // hefa
-    &"手	瘦	收	首	受	守",  // shou
-    &"海关",  // haiguan
-    &"乱",  // luan
-    &"哲学家",  // zhexuejia
-    &"存活",  // cunhuo
-    &"呼吸",  // huxi
-    &"决赛",  // juesai
-    &"池塘",  // chitang
-    &"克服",  // kefu
-    &"宝贵",  // baogui
-    &"学问",  // xuewen
-    &"休闲",  // xiuxian
-    &"用途",  // yongtu
-    &"对于",  // duiyu
-    &"吃亏",  // chikui
-    &"紧急",  // jinji
-    &"大厦",  // dasha
-    &"咱",  // zan
-    &"沉默",  // chenmo
-    &"文章",  // wenzhang
-    &"安静",  // anjing
-    &"执照",  // zhizhao
-    &"租	组",  // zu
-    &"创造",  // chuangzao
-    &"《彷徨》",  // panghuang
-    &"遵守",  // zunshou
-    &"促进",  // cujin
-    &"移民",  // yimin
-    &"校长",  // xiaozhang
-    &"了不起",  // liaobuqi
-    &"匹	批	披",  // pi
-    &"根基",  // genji
-    &"勇敢",  // yonggan
-    &"体育",  // tiyu
-    &"石灰岩",  // shihuiyan
-    &"坚持",  // jianchi
-    &"着急",  // zhaoji
-    &"赞美",  // zanmai
-    &"作用",  // zuoyong
-    &"多亏",  // duokui
-    &"转头",  // zhuantou
-    &"非	废",  // fei
-    &"毛孔",  // maokong
-    &"立即",  // liji
-    &"翟峰",  // zhaifeng
-    &"享受",  // xiangshou
-    &"肾上腺素",  // shenshangxiansu
-    &"晚上",  // wanshang
-    &"超级",  // chaoji
-    &"到	刀	倒",  // dao
-    &"地位",  // diwei
-    &"点",  // dian
-    &"买	卖	埋",  // mai
-    &"孙月",  // sunyue
-    &"文明",  // wenming
-    &"电脑",  // diannao
-    &"了",  // le
-    &"后果",  // houguo
-    &"九	就	久	旧	酒	救",  // jiu
-    &"主张",  // zhuzhang
-    &"手机	收集",  // shouji
-    &"真正",  // zhenzheng
-    &"人民币",  // renminbi
-    &"卧室",  // woshi
-    &"平常",  // pingchang
-    &"爱护",  // aihu
-    &"阻止	组织",  // zuzhi
-    &"准确",  // zhunque
-    &"自从",  // zicong
-    &"完	玩	碗	万",  // wan
-    &"孝敬",  // xiaojing
-    &"左右",  // zuoyou
-    &"多少",  // duoshao
-    &"高档",  // gaodang
-    &"效率",  // xiaolv
-    &"外地",  // waidi
-    &"设备",  // shebei
-    &"解释	结实	节食",  // jieshi
-    &"郁达夫",  // yudafu
-    &"提醒",  // tixing
-    &"狗	够	购",  // gou
-    &"翅膀",  // chibang
-    &"祝福",  // zhufu
-    &"专心",  // zhuanxin
-    &"热闹",  // renao
-    &"喜欢",  // xihuan
-    &"经商",  // jingshang
-    &"登记",  // dengji
-    &"中旬",  // zhongxun
-    &"突然",  // turan
-    &"女儿",  // nver
-    &"温度",  // wendu
-    &"石头",  // shitou
-    &"首都",  // shoudu
-    &"还	换",  // huan
-    &"一辈子",  // yibeizi
-    &"负责",  // fuze
-    &"长江大桥",  // changjiangdaqiao
-    &"传播",  // chuanbo
-    &"特殊",  // teshu
-    &"有	又	由",  // you
-    &"培训",  // peixun
-    &"诊断",  // zhenduan
-    &"陌生",  // mosheng
-    &"议论",  // yilun
-    &"个人",  // geren
-    &"退休",  // tuixiu
-    &"负重",  // fuzhong
-    &"暗暗",  // anan
-    &"度过",  // duguo
-    &"恢复",  // huifu
-    &"模特",  // moter
-    &"及时	即使",  // jishi
-    &"跳舞",  // tiaowu
-    &"分别",  // fenbie
-    &"环境",  // huanjing
-    &"动物",  // dongwu
-    &"集中",  // jizhong
-    &"资金",  // zijin
-    &"中心",  // zhongxin
-    &"概括",  // gaikuo
-    &"的确",  // dique
-    &"出自",  // chuzi
-    &"形象",  // xingxiang
-    &"中介",  // zhongjie
-    &"改正",  // gaizheng
-    &"窗户",  // chuanghu
-    &"鲜花",  // xianhua
-    &"至今",  // zhijin
-    &"不	部	布",  // bu
-    &"领导",  // lingdao
-    &"难免",  // nanmian
-    &"窄	摘",  // zhai
-    &"银行",  // yinhang
-    &"恶劣",  // elie
-    &"饭店",  // fandian
-    &"困",  // kun
-    &"标志",  // biaozhi
-    &"爱心",  // aixin
-    &"集合",  // jihe
-    &"周末",  // zhoumo
-    &"最好",  // zuihao
-    &"地毯",  // ditan
-    &"胸",  // xiong
-    &"天敌",  // tiandi
-    &"赶紧",  // ganjin
-    &"林	临",  // lin
-    &"愿望",  // yuanwang
-    &"点心",  // dianxin
-    &"可乐",  // kele
-    &"兔子",  // tuzi
-    &"拆",  // chai
-    &"安装",  // anzhuang
-    &"不管",  // buguan
-    &"老",  // lao
-    &"奖金",  // jiangjin
-    &"经理	经历	精力",  // jingli
-    &"例如",  // liru
-    &"追",  // zhui
-    &"善于",  // shanyu
-    &"调整",  // tiaozheng
-    &"节目",  // jiemu
-    &"感谢",  // ganxie
-    &"伤心",  // shangxin
-    &"不少",  // bushao
-    &"地理",  // dili
-    &"显示	现实",  // xianshi
-    &"房东",  // fangdong
-    &"好吃",  // haochi
-    &"脖子",  // bozi
-    &"礼貌",  // limao
-    &"避免",  // bimian
-    &"地道",  // didao
-    &"最近",  // zuijin
-    &"可以",  // keyi
-    &"挣	睁	正",  // zheng
-    &"失望",  // shiwang
-    &"取消",  // quxiao
-    &"估计",  // guji
-    &"著名",  // zhuming
-    &"朋友",  // pengyou
-    &"持续",  // chixu
-    &"说服",  // shuofu
-    &"油炸",  // youzha
-    &"在	再",  // zai
-    &"外	歪",  // wai
-    &"冠军",  // guanjun
-    &"杀	傻",  // sha
-    &"组成",  // zucheng
-    &"女孩",  // nvhai
-    &"黑",  // hei
-    &"问题",  // wenti
-    &"判断",  // panduan
-    &"太太",  // taitai
-    &"烤鸭",  // kaoya
-    &"开",  // kai
-    &"战争",  // zhanzheng
-    &"所有",  // suoyou
-    &"冰箱",  // bingxiang
-    &"呀",  // ya
-    &"学术",  // xueshu
-    &"辩论",  // bianlun
-    &"舒服",  // shufu
-    &"姓	行	醒",  // xing
-    &"列车",  // lieche
-    &"任何",  // renhe
-    &"单纯",  // danchun
-    &"周围",  // zhouwei
-    &"语法",  // yufa
-    &"耽误",  // danwu
-    &"对不起",  // duibuqi
-    &"屋子",  // wuzi
-    &"考试",  // kaoshi
-    &"弄	浓",  // nong
-    &"正确",  // zhengque
-    &"工厂",  // gongchang
-    &"风格",  // fengge
-    &"起床",  // qichuang
-    &"操场",  // caochang
-    &"顺利",  // shunli
-    &"尊敬",  // zunjing
-    &"高级",  // gaoji
-    &"主观",  // zhuguan
-    &"宽",  // kuan
-    &"然而",  // raner
-    &"不要紧",  // buyaojin
-    &"结构",  // jiegou
-    &"劳驾",  // laojia
-    &"对待",  // duidai
-    &"妇女",  // funv
-    &"天真",  // tianzhen
-    &"开始",  // kaishi
-    &"趋势	去世",  // qushi
-    &"诚恳",  // chengken
-    &"作家",  // zuojia
-    &"内容",  // neirong
-    &"咖啡",  // kafei
-    &"羡慕",  // xianmu
-    &"孔子",  // kongzi
-    &"辛苦",  // xinku
-    &"陆地",  // ludi
-    &"条件",  // tiaojian
-    &"工作",  // gongzuo
-    &"失眠",  // shimian
-    &"简单",  // jiandan
-    &"注册",  // zhuce
-    &"项链",  // xianglian
-    &"电影",  // dianying
-    &"招聘",  // zhaopin
-    &"》",  // >
-    &"狡猾",  // jiaohua
-    &"怀疑",  // huaiyi
-    &"经营",  // jingying
-    &"大概",  // dagai
-    &"关系",  // guanxi
-    &"朋	碰",  // peng
-    &"输入",  // shuru
-    &"过分",  // guofen
-    &"发生",  // fasheng
-    &"逃	桃	套",  // tao
-    &"彩虹",  // caihong
-    &"王子",  // wangzi
-    &"好	号",  // hao
-    &"害怕",  // haipa
-    &"记录	纪录",  // jilu
-    &"规则",  // guize
-    &"播放",  // bofang
-    &"顺便",  // shunbian
-    &"怪不得",  // guaibude
-    &"餐厅",  // canting
-    &"广泛",  // guangfan
-    &"现金",  // xianjin
-    &"肚子",  // duzi
-    &"嗯",  // en
-    &"公园	公元",  // gongyuan
-    &"济南",  // jinan
-    &"吃惊",  // chijing
-    &"聚会",  // juhui
-    &"抱歉",  // baoqian
-    &"看见",  // kanjian
-    &"亲切",  // qinqie
-    &"主题",  // zhuti
-    &"谈	痰",  // tan
-    &"春节",  // chunjie
-    &"季节",  // jijie
-    &"火车站",  // huochezhan
-    &"劳动",  // laodong
-    &"生长",  // shengzhang
-    &"牛津大学",  // niujindaxue
-    &"复杂",  // fuza
-    &"方便",  // fangbian
-    &"舍不得",  // shebude
-    &"功能",  // gongneng
-    &"社会",  // shehui
-    &"自豪",  // zihao
-    &"参加",  // canjia
-    &"目录",  // mulu
-    &"好奇",  // haoqi
-    &"人生",  // rensheng
-    &"温柔",  // wenrou
-    &"认识	人事",  // renshi
-    &"勺子",  // shaozi
-    &"外面",  // waimian
-    &"时代",  // shidai
-    &"受伤",  // shoushang
-    &"附近",  // fujin
-    &"放弃",  // fangqi
-    &"不见得",  // bujiande
-    &"坦率",  // tanshuai
-    &"学校",  // xuexiao
-    &"媒体",  // meiti
-    &"印加",  // yinjia
-    &"容易",  // rongyi
-    &"驾驶",  // jiashi
-    &"几	极	寄	即	击	系	挤",  // ji
-    &"父亲",  // fuqin
-    &"儿子",  // erzi
-    &"潘家园",  // panjiayuan
-    &"摄影",  // sheying
-    &"打算",  // dasuan
-    &"书	树	输	数",  // shu
-    &"青壮年",  // qingzhuangnian
-    &"制度",  // zhidu
-    &"骨头",  // gutou
-    &"打招呼",  // dazhaohu
-    &"尺子",  // chizi
-    &"尽管",  // jinguan
-    &"酱油",  // jiangyou
-    &"作战",  // zuozhan
-    &"改革",  // gaige
-    &"去年",  // qunian
-    &"代表",  // daibiao
-    &"七	骑	棋	起",  // qi
-    &"字	紫",  // zi
-    &"住	祝	猪	煮",  // zhu
-    &"危害",  // weihai
-    &"显然",  // xianran
-    &"期待",  // qidai
-    &"完整",  // wanzheng
-    &"常识",  // changshi
-    &"明白",  // mingbai
-    &"整理",  // zhengli
-    &"成长",  // chengzhang
-    &"麻烦",  // mafan
-    &"似乎",  // sihu
-    &"次",  // ci
-    &"鼓舞",  // guwu
-    &"发",  // fa
-    &"摩托车",  // motuoche
-    &"别人",  // bieren
-    &"医院",  // yiyuan
-    &"入口",  // rukou
-    &"：",  // :
-    &"艰苦",  // jianku
-    &"发现",  // faxian
-    &"结合",  // jiehe
-    &"激动",  // jidong
-    &"感动",  // gandong
-    &"以后",  // yihou
-    &"特色",  // tese
-    &"右边",  // youbian
-    &"告别",  // gaobie
-    &"果然",  // guoran
-    &"词典",  // cidian
-    &"观察",  // guancha
-    &"学	雪",  // xue
-    &"应用",  // yingyong
-    &"哪儿	那儿",  // nar
-    &"上班",  // shangban
-    &"疲劳",  // pilao
-    &"滚	棍",  // gun
-    &"交流",  // jiaoliu
-    &"个子	各自",  // gezi
-    &"站	占",  // zhan
-    &"差异",  // chayi
-    &"商量",  // shangliang
-    &"灰尘",  // huichen
-    &"敏感",  // mingan
-    &"合理",  // heli
-    &"总共",  // zonggong
-    &"过敏",  // guomin
-    &"提前",  // tiqian
-    &"配合",  // peihe
-    &"营养",  // yingyang
-    &"具备",  // jubei
-    &"身材",  // shencai
-    &"失败",  // shibai
-    &"岁	碎",  // sui
-    &"绿	屡",  // lv
-    &"打听",  // dating
-    &"急忙",  // jimang
-    &"英俊",  // yingjun
-    &"按	岸	暗",  // an
-    &"寒假",  // hanjia
-    &"腾讯",  // tengxun
-    &"心脏",  // xinzang
-    &"扇子",  // shanzi
-    &"梅西",  // meixi
-    &"总是",  // zongshi
-    &"工具",  // gongju
-    &"最后",  // zuihou
-    &"对象",  // duixiang
-    &"、",  // \
-    &"上午	商务",  // shangwu
-    &"位于",  // weiyu
-    &"严肃",  // yansu
-    &"活跃",  // huoyue
-    &"成为",  // chengwei
-    &"理想",  // lixiang
-    &"证明",  // zhengming
-    &"鸡蛋",  // jidan
-    &"相似",  // xiangsi
-    &"戒指",  // jiezhi
-    &"气温",  // qiwen
-    &"苹果",  // pingguo
-    &"热爱",  // reai
-    &"充分",  // chongfen
-    &"书架",  // shujia
-    &"从而",  // conger
-    &"吵架",  // chaojia
-    &"门",  // men
-    &"后面",  // houmian
-    &"五	无	屋	勿	雾",  // wu
-    &"大学",  // daxue
-    &"平时",  // pingshi
-    &"因为",  // yinwei
-    &"体现",  // tixian
-    &"核心",  // hexin
-    &"糟糕",  // zaogao
-    &"身边",  // shenbian
-    &"刷牙",  // shuaya
-    &"迫切",  // poqie
-    &"马克",  // make
-    &"美德",  // meide
-    &"爱情",  // aiqing
-    &"表示",  // biaoshi
-    &"宣传",  // xuanchuan
-    &"司机",  // siji
-    &"明确",  // mingque
-    &"家务",  // jiawu
-    &"青蛙",  // qingwa
-    &"廉颇",  // lianpo
-    &"丑	臭	抽",  // chou
-    &"唯一",  // weiyi
-    &"光临",  // guanglin
-    &"既然",  // jiran
-    &"包裹",  // baoguo
-    &"词汇",  // cihui
-    &"冲",  // chong
-    &"皮肤",  // pifu
-    &"空间",  // kongjian
-    &"单调",  // dandiao
-    &"脾气",  // piqi
-    &"因素",  // yinsu
-    &"公平",  // gongping
-    &"栏杆",  // langan
-    &"蝴蝶",  // hudie
-    &"施肥",  // shifei
-    &"志愿者",  // zhiyuanzhe
-    &"极了",  // jile
-    &"抽屉",  // chouti
-    &"纷纷",  // fenfen
-    &"八成儿",  // bachengr
-    &"故事",  // gushi
-    &"关注",  // guanzhu
-    &"地区",  // diqu
-    &"贸易",  // maoyi
-    &"因此",  // yinci
-    &"无法",  // wufa
-    &"儿童",  // ertong
-    &"透明",  // touming
-    &"能干",  // nenggan
-    &"时髦",  // shimao
-    &"经典",  // jingdian
-    &"诚信",  // chengxin
-    &"乒乓球",  // pingpangqiu
-    &"造成",  // zaocheng
-    &"客厅",  // keting
-    &"消极",  // xiaoji
-    &"降低",  // jiangdi
-    &"突出",  // tuchu
-    &"人才",  // rencai
-    &"光线",  // guangxian
-    &"感受",  // ganshou
-    &"信封",  // xinfeng
-    &"开放",  // kaifang
-    &"约会",  // yuehui
-    &"郊区",  // jiaoqu
-    &"认真",  // renzhen
-    &"哪怕",  // napa
-    &"麦克风",  // maikefeng
-    &"对比",  // duibi
-    &"增长",  // zengzhang
-    &"。",  // .
-    &"钢铁",  // gangtie
-    &"城市	诚实",  // chengshi
-    &"歌舞",  // gewu
-    &"到达",  // daoda
-    &"收拾",  // shoushi
-    &"股票",  // gupiao
-    &"表面",  // biaomian
-    &"网球",  // wangqiu
-    &"日期",  // riqi
-    &"秘书",  // mishu
-    &"礼拜天",  // libaitian
-    &"浪费",  // langfei
-    &"暖和",  // nuanhuo
-    &"零食",  // lingshi
-    &"广和居",  // guangheju
-    &"海里",  // haili
-    &"名牌",  // mingpai
-    &"友谊",  // youyi
-    &"丢",  // diu
-    &"文学家",  // wenxuejia
-    &"或许",  // huoxu
-    &"这儿",  // zher
-    &"相同",  // xiangtong
-    &"明星",  // mingxing
-    &"没关系",  // meiguanxi
-    &"基本",  // jiben
-    &"固定",  // guding
-    &"转告",  // zhuangao
-    &"振动",  // zhendong
-    &"手表",  // shoubiao
-    &"利益",  // liyi
-    &"彻底",  // chedi
-    &"担心",  // danxin
-    &"压力",  // yali
-    &"措施",  // cuoshi
-    &"检查",  // jiancha
-    &"环保",  // huanbao
-    &"平均",  // pingjun
-    &"十分",  // shifen
-    &"温暖",  // wennuan
-    &"孙子",  // sunzi
-    &"班	搬	半	般",  // ban
-    &"号码",  // haoma
-    &"秘密",  // mimi
-    &"顶	叮",  // ding
-    &"《说文解字》",  // <shuowenjiezi>
-    &"从前",  // congqian
-    &"管理",  // guanli
-    &"物质",  // wuzhi
-    &"当然",  // dangran
-    &"周",  // zhou
-    &"片面",  // pianmian
-    &"差距",  // chaju
-    &"阶段",  // jieduan
-    &"随便",  // suibian
-    &"里查德·希尔斯",  // lichadexiersi
-    &"闪电",  // shandian
-    &"体会",  // tihui
-    &"沙漠",  // shamo
-    &"市场",  // shichang
-    &"隔壁",  // gebi
-    &"阅读",  // yuedu
-    &"以为",  // yiwei
-    &"解决",  // jiejue
-    &"命运",  // mingyun
-    &"例子",  // lizi
-    &"年级	年纪",  // nianji
-    &"美好",  // meihao
-    &"轻视",  // qingshi
-    &"惭愧",  // cankui
-    &"希望",  // xiwang
-    &"学期",  // xueqi
-    &"斯坦福",  // sitanfu
-    &"寂寞",  // jimo
-    &"印象",  // yinxiang
-    &"先生",  // xiansheng
-    &"富	扶	幅",  // fu
-    &"表扬",  // biaoyang
-    &"想象",  // xiangxiang
-    &"答案",  // daan
-    &"幻想",  // huanxiang
-    &"位置",  // weizhi
-    &"宾馆",  // binguan
-    &"当心",  // dangxin
-    &"由于	犹豫",  // youyu
-    &"保留",  // baoliu
-    &"情绪",  // qingxu
-    &"推迟",  // tuichi
-    &"目标",  // mubiao
-    &"长途",  // changtu
-    &"羽毛球",  // yumaoqiu
-    &"今年",  // jinnian
-    &"裤子",  // kuzi
-    &"发展",  // fazhan
-    &"好客",  // haoke
-    &"游戏",  // youxi
-    &"军事",  // junshi
-    &"装	撞",  // zhuang
-    &"任务	人物",  // renwu
-    &"干杯",  // ganbei
-    &"禁止",  // jinzhi
-    &"长	尝	场",  // chang
-    &"洗澡",  // xizao
-    &"实现	事先",  // shixian
-    &"起飞",  // qifei
-    &"决心",  // juexin
-    &"经验",  // jingyan
-    &"深刻",  // shenke
-    &"完善",  // wanshan
-    &"象征",  // xiangzheng
-    &"实际	世纪",  // shiji
-    &"分析",  // fenxi
-    &"一会儿",  // yihuier
-    &"论文",  // lunwen
-    &"眉毛",  // meimao
-    &"网络",  // wangluo
-    &"洒",  // sa
-    &"俩",  // lia
-    &"可见",  // kejian
-    &"马上",  // mashang
-    &"放纵",  // fangzong
-    &"的	得	地",  // de
-    &"对方",  // duifang
-    &"跟	根",  // gen
-    &"不如",  // buru
-    &"本来",  // benlai
-    &"说话",  // shuohua
-    &"上",  // shang
-    &"帽子",  // maozi
-    &"鸟",  // niao
-    &"块	快",  // kuai
-    &"《呐喊》",  // <nahan>
-    &"团",  // tuan
-    &"原来",  // yuanlai
-    &"有点	优点",  // youdian
-    &"发表",  // fabiao
-    &"讲座",  // jiangzuo
-    &"虽然",  // suiran
-    &"要求",  // yaoqiu
-    &"出院",  // chuyuan
-    &"逐渐",  // zhujian
-    &"主人	主任",  // zhuren
-    &"回来",  // huilai
-    &"是否",  // shifou
-    &"接触",  // jiechu
-    &"标点",  // biaodian
-    &"修改",  // xiugai
-    &"企业",  // qiye
-    &"除了",  // chule
-    &"我们",  // women
-    &"特别",  // tebie
-    &"弹钢琴",  // tangangqin
-    &"名字",  // mingzi
-    &"不安",  // buan
-    &"球迷",  // qiumi
-    &"法律",  // falv
-    &"立刻",  // like
-    &"反省",  // fanxing
-    &"本	笨",  // ben
-    &"说不定",  // shuobuding
-    &"庆祝",  // qingzhu
-    &"色彩",  // secai
-    &"光明",  // guangming
-    &"了解",  // liaojie
-    &"你",  // ni
-    &"占线",  // zhanxian
-    &"欧洲环境保护署",  // ouzhouhuanjingbaohushu
-    &"相处",  // xiangchu
-    &"传说",  // chuanshuo
-    &"另外",  // lingwai
-    &"计算",  // jisuan
-    &"假设",  // jiashe
-    &"扩",  // kuo
-    &"流泪",  // liulei
-    &"星星",  // xingxing
-    &"一边",  // yibian
-    &"嗓子",  // sangzi
-    &"分享",  // fenxiang
-    &"早",  // zao
-    &"允许",  // yunxu
-    &"谦虚",  // qianxu
-    &"等待",  // dengdai
-    &"产生",  // chansheng
-    &"博士",  // boshi
-    &"草",  // cao
-    &"护士	忽视",  // hushi
-    &"流行",  // liuxing
-    &"怎么办",  // zenmeban
-    &"篇	骗	片",  // pian
-    &"总之",  // zongzhi
-    &"如何",  // ruhe
-    &"多么",  // duome
-    &"种	重	钟",  // zhong
-    &"快乐",  // kuaile
-    &"道歉",  // daoqian
-    &"方式",  // fangshi
-    &"两	辆	亮",  // liang
-    &"从事",  // congshi
-    &"幽默",  // youmo
-    &"您",  // nin
-    &"低	底	递	滴",  // di
-    &"说",  // shuo
-    &"确实",  // queshi
-    &"成人	承认",  // chengren
-    &"适应",  // shiying
-    &"《非你莫属》",  // <feinimoshu>
-    &"特点",  // tedian
-    &"一直	一致",  // yizhi
-    &"抽烟",  // chouyan
-    &"为什么",  // weishenme
-    &"普遍",  // pubian
-    &"听	挺	停",  // ting
-    &"完全",  // wanquan
-    &"程度",  // chengdu
-    &"游泳",  // youyong
-    &"疯狂",  // fengkuang
-    &"生物钟",  // shengwuzhong
-    &"力量",  // liliang
-    &"通知",  // tongzhi
-    &"问候",  // wenhou
-    &"短信",  // duanxin
-    &"特征",  // tezheng
-    &"装饰",  // zhuangshi
-    &"确定",  // queding
-    &"发达",  // fada
-    &"句子	橘子	桔子	锯子",  // juzi
-    &"工人",  // gongren
-    &"优秀",  // youxiu
-    &"生产",  // shengchan
-    &"自行车",  // zixingche
-    &"理发店",  // lifadian
-    &"自己",  // ziji
-    &"赞美",  // zanmei
-    &"粮食",  // liangshi
-    &"适当",  // shidang
-    &"打扫",  // dasao
-    &"到处",  // daochu
-    &"奇特",  // qite
-    &"部门",  // bumen
-    &"口味",  // kouwei
-    &"国际	国籍",  // guoji
-    &"四	死	私	撕	丝",  // si
-    &"公开",  // gongkai
-    &"参观",  // canguan
-    &"病毒",  // bingdu
-    &"咨询",  // zixun
-    &"合作",  // hezuo
-    &"方案",  // fangan
-    &"资源	自愿",  // ziyuan
-    &"黄河",  // huanghe
-    &"小吃",  // xiaochi
-    &"衣服",  // yifu
-    &"春秋",  // chunqiu
-    &"训练",  // xunlian
-    &"下	夏	瞎	吓",  // xia
-    &"爱迪生",  // aidisheng
-    &"吹",  // chui
-    &"付款",  // fukuan
-    &"总算",  // zongsuan
-    &"灾害",  // zaihai
-    &"相反",  // xiangfan
-    &"体验",  // tiyan
-    &"有利",  // youli
-    &"后悔",  // houhui
-    &"理发",  // lifa
-    &"金石为开",  // jinshiweikai
-    &"轮流",  // lunliu
-    &"姑姑",  // gugu
-    &"说明",  // shuoming
-    &"狮子",  // shizi
-    &"戏剧",  // xiju
-    &"正常",  // zhengchang
-    &"围巾",  // weijin
-    &"投篮",  // toulan
-    &"包	饱	抱	保	薄",  // bao
-    &"朝三暮四",  // zhaosanmusi
-    &"祝贺",  // zhuhe
-    &"洗	西	系	夕",  // xi
-];
+// Built-in HSK vocab in the binary dictionary format of dictionary.rs,
+// with 4701 pinyin search keys. The longest one has 25 characters.
+pub static DICTIONARY: &[u8] = include_bytes!("autogen_hsk.dict");
 
 // Tuples are (normalized_pinyin, 词语) from early in vocab file code
 // generation precompute pipeline. These correspond closely to lines of
@@ -6432,7 +1310,7 @@ pub static PINYIN_CIYU_TEST_DATA: &[(&'static str, &'static str)] = &[
     (&"ji", &"即"),
     (&"liuxie", &"流血"),
     (&"xingxing", &"星星"),
-    (&"xhileng", &"制冷"),
+    (&"zhileng", &"制冷"),
     (&"an", &"按"),
     (&"bao", &"保"),
     (&"bishi", &"笔试"),
@@ -7922,6 +2800,7 @@ pub static PINYIN_CIYU_TEST_DATA: &[(&'static str, &'static str)] = &[
     (&"tengxun", &"腾讯"),
     (&"weixin", &"微信"),
     (&"xi", &"夕"),
+    (&"xian", &"西安"),
     (&"xihan", &"西汉"),
     (&"xinxilan", &"新西兰"),
     (&"yangxiong", &"杨雄"),
@@ -8130,6 +3009,2506 @@ pub static PINYIN_CIYU_TEST_DATA: &[(&'static str, &'static str)] = &[
     (&"zhuanzhu", &"专注"),
     (&"zhuantou", &"转头"),
     (&"zhuo", &"啄"),
+    (&"ai", &"挨"),
+    (&"aizheng", &"癌症"),
+    (&"aibushishou", &"爱不释手"),
+    (&"aidai", &"爱戴"),
+    (&"aimei", &"暧昧"),
+    (&"anning", &"安宁"),
+    (&"anxiang", &"安详"),
+    (&"anzhi", &"安置"),
+    (&"anmo", &"按摩"),
+    (&"anjian", &"案件"),
+    (&"anli", &"案例"),
+    (&"anshi", &"暗示"),
+    (&"anggui", &"昂贵"),
+    (&"aotu", &"凹凸"),
+    (&"ao", &"熬"),
+    (&"aomi", &"奥秘"),
+    (&"babude", &"巴不得"),
+    (&"bajie", &"巴结"),
+    (&"ba", &"扒"),
+    (&"ba", &"疤"),
+    (&"bamiaozhuzhang", &"拔苗助长"),
+    (&"baguan", &"把关"),
+    (&"bashou", &"把手"),
+    (&"bagong", &"罢工"),
+    (&"badao", &"霸道"),
+    (&"bai", &"掰"),
+    (&"baituo", &"摆脱"),
+    (&"baihuai", &"败坏"),
+    (&"baifang", &"拜访"),
+    (&"bainian", &"拜年"),
+    (&"baituo", &"拜托"),
+    (&"banbu", &"颁布"),
+    (&"banfa", &"颁发"),
+    (&"ban", &"斑"),
+    (&"banben", &"版本"),
+    (&"bantuerfei", &"半途而废"),
+    (&"banyan", &"扮演"),
+    (&"banlv", &"伴侣"),
+    (&"bansui", &"伴随"),
+    (&"bangjia", &"绑架"),
+    (&"bangyang", &"榜样"),
+    (&"bang", &"磅"),
+    (&"baobi", &"包庇"),
+    (&"baofu", &"包袱"),
+    (&"baowei", &"包围"),
+    (&"baozhuang", &"包装"),
+    (&"baohe", &"饱和"),
+    (&"baojingcangsang", &"饱经沧桑"),
+    (&"baoguan", &"保管"),
+    (&"baomi", &"保密"),
+    (&"baomu", &"保姆"),
+    (&"baoshou", &"保守"),
+    (&"baowei", &"保卫"),
+    (&"baoyang", &"保养"),
+    (&"baozhang", &"保障"),
+    (&"baozhong", &"保重"),
+    (&"baochou", &"报仇"),
+    (&"baochou", &"报酬"),
+    (&"baoda", &"报答"),
+    (&"baofu", &"报复"),
+    (&"baojing", &"报警"),
+    (&"baoxiao", &"报销"),
+    (&"baofu", &"抱负"),
+    (&"baoli", &"暴力"),
+    (&"baolu", &"暴露"),
+    (&"baoguang", &"曝光"),
+    (&"baofa", &"爆发"),
+    (&"baozha", &"爆炸"),
+    (&"beibi", &"卑鄙"),
+    (&"beiai", &"悲哀"),
+    (&"beican", &"悲惨"),
+    (&"beiji", &"北极"),
+    (&"beike", &"贝壳"),
+    (&"beifen", &"备份"),
+    (&"beiwanglu", &"备忘录"),
+    (&"beipan", &"背叛"),
+    (&"beisong", &"背诵"),
+    (&"beidong", &"被动"),
+    (&"beigao", &"被告"),
+    (&"benbo", &"奔波"),
+    (&"benchi", &"奔驰"),
+    (&"benneng", &"本能"),
+    (&"benqian", &"本钱"),
+    (&"benren", &"本人"),
+    (&"benshen", &"本身"),
+    (&"benshi", &"本事"),
+    (&"benzhuo", &"笨拙"),
+    (&"bengkui", &"崩溃"),
+    (&"beng", &"甭"),
+    (&"bengfa", &"迸发"),
+    (&"beng", &"蹦"),
+    (&"bipo", &"逼迫"),
+    (&"biti", &"鼻涕"),
+    (&"bifang", &"比方"),
+    (&"biyu", &"比喻"),
+    (&"bizhong", &"比重"),
+    (&"bishi", &"鄙视"),
+    (&"bise", &"闭塞"),
+    (&"bibing", &"弊病"),
+    (&"biduan", &"弊端"),
+    (&"bi", &"臂"),
+    (&"bianjiang", &"边疆"),
+    (&"bianjie", &"边界"),
+    (&"bianjing", &"边境"),
+    (&"bianyuan", &"边缘"),
+    (&"bianzhi", &"编织"),
+    (&"biance", &"鞭策"),
+    (&"biandi", &"贬低"),
+    (&"bianyi", &"贬义"),
+    (&"bian", &"扁"),
+    (&"biangu", &"变故"),
+    (&"bianqian", &"变迁"),
+    (&"bianzhi", &"变质"),
+    (&"bianli", &"便利"),
+    (&"biantiao", &"便条"),
+    (&"bianyu", &"便于"),
+    (&"bianbu", &"遍布"),
+    (&"bianren", &"辨认"),
+    (&"bianhu", &"辩护"),
+    (&"bianjie", &"辩解"),
+    (&"bianzheng", &"辩证"),
+    (&"bianzi", &"辫子"),
+    (&"biaoben", &"标本"),
+    (&"biaoji", &"标记"),
+    (&"biaoti", &"标题"),
+    (&"biaojue", &"表决"),
+    (&"biaotai", &"表态"),
+    (&"biaozhang", &"表彰"),
+    (&"bie", &"憋"),
+    (&"bieshu", &"别墅"),
+    (&"biezhi", &"别致"),
+    (&"bieniu", &"别扭"),
+    (&"binlin", &"濒临"),
+    (&"bingbao", &"冰雹"),
+    (&"bing", &"丙"),
+    (&"bingfei", &"并非"),
+    (&"binglie", &"并列"),
+    (&"bo", &"拨"),
+    (&"bolang", &"波浪"),
+    (&"botao", &"波涛"),
+    (&"boxue", &"剥削"),
+    (&"bozhong", &"播种"),
+    (&"bomu", &"伯母"),
+    (&"bodajingshen", &"博大精深"),
+    (&"bolanhui", &"博览会"),
+    (&"bodou", &"搏斗"),
+    (&"boruo", &"薄弱"),
+    (&"buchang", &"补偿"),
+    (&"bujiu", &"补救"),
+    (&"butie", &"补贴"),
+    (&"buzhuo", &"捕捉"),
+    (&"buru", &"哺乳"),
+    (&"budeyi", &"不得已"),
+    (&"bufang", &"不妨"),
+    (&"bugandang", &"不敢当"),
+    (&"bugu", &"不顾"),
+    (&"bujin", &"不禁"),
+    (&"bukan", &"不堪"),
+    (&"bukesiyi", &"不可思议"),
+    (&"bukui", &"不愧"),
+    (&"buliao", &"不料"),
+    (&"bumian", &"不免"),
+    (&"bushi", &"不时"),
+    (&"buxi", &"不惜"),
+    (&"buxiangshangxia", &"不相上下"),
+    (&"buxianghua", &"不像话"),
+    (&"buxieyigu", &"不屑一顾"),
+    (&"buyaneryu", &"不言而喻"),
+    (&"buyoude", &"不由得"),
+    (&"buzeshouduan", &"不择手段"),
+    (&"buzhi", &"不止"),
+    (&"bugao", &"布告"),
+    (&"buju", &"布局"),
+    (&"buzhi", &"布置"),
+    (&"bufa", &"步伐"),
+    (&"bushu", &"部署"),
+    (&"buwei", &"部位"),
+    (&"caigan", &"才干"),
+    (&"caifu", &"财富"),
+    (&"caiwu", &"财务"),
+    (&"caizheng", &"财政"),
+    (&"caifeng", &"裁缝"),
+    (&"caipan", &"裁判"),
+    (&"caiyuan", &"裁员"),
+    (&"caigou", &"采购"),
+    (&"caiji", &"采集"),
+    (&"caina", &"采纳"),
+    (&"caipiao", &"彩票"),
+    (&"canmou", &"参谋"),
+    (&"canzhao", &"参照"),
+    (&"canji", &"残疾"),
+    (&"canku", &"残酷"),
+    (&"canliu", &"残留"),
+    (&"canren", &"残忍"),
+    (&"canlan", &"灿烂"),
+    (&"cangcu", &"仓促"),
+    (&"cangku", &"仓库"),
+    (&"cangbai", &"苍白"),
+    (&"cang", &"舱"),
+    (&"caolao", &"操劳"),
+    (&"caolian", &"操练"),
+    (&"caozong", &"操纵"),
+    (&"caozuo", &"操作"),
+    (&"caoza", &"嘈杂"),
+    (&"caoan", &"草案"),
+    (&"caoshuai", &"草率"),
+    (&"cemian", &"侧面"),
+    (&"celiang", &"测量"),
+    (&"cehua", &"策划"),
+    (&"celve", &"策略"),
+    (&"cengchubuqiong", &"层出不穷"),
+    (&"cengci", &"层次"),
+    (&"chabie", &"差别"),
+    (&"chazuo", &"插座"),
+    (&"chahuo", &"查获"),
+    (&"cha", &"岔"),
+    (&"chana", &"刹那"),
+    (&"chayi", &"诧异"),
+    (&"chaiyou", &"柴油"),
+    (&"chan", &"搀"),
+    (&"chan", &"馋"),
+    (&"chanrao", &"缠绕"),
+    (&"chanye", &"产业"),
+    (&"chanshu", &"阐述"),
+    (&"chandou", &"颤抖"),
+    (&"changsheng", &"昌盛"),
+    (&"changshi", &"尝试"),
+    (&"changhuan", &"偿还"),
+    (&"changhe", &"场合"),
+    (&"changmian", &"场面"),
+    (&"changsuo", &"场所"),
+    (&"changkai", &"敞开"),
+    (&"changtong", &"畅通"),
+    (&"changxiao", &"畅销"),
+    (&"changdao", &"倡导"),
+    (&"changyi", &"倡议"),
+    (&"chaopiao", &"钞票"),
+    (&"chaoyue", &"超越"),
+    (&"chaoxue", &"巢穴"),
+    (&"chaodai", &"朝代"),
+    (&"chaoxiao", &"嘲笑"),
+    (&"chaoliu", &"潮流"),
+    (&"chetui", &"撤退"),
+    (&"chexiao", &"撤销"),
+    (&"chendian", &"沉淀"),
+    (&"chenmen", &"沉闷"),
+    (&"chensi", &"沉思"),
+    (&"chenzhong", &"沉重"),
+    (&"chenzhuo", &"沉着"),
+    (&"chenjiu", &"陈旧"),
+    (&"chenlie", &"陈列"),
+    (&"chenshu", &"陈述"),
+    (&"chentuo", &"衬托"),
+    (&"chenxinruyi", &"称心如意"),
+    (&"chenghao", &"称号"),
+    (&"chengben", &"成本"),
+    (&"chengjiao", &"成交"),
+    (&"chengtian", &"成天"),
+    (&"chengxiao", &"成效"),
+    (&"chengxin", &"成心"),
+    (&"chengyuan", &"成员"),
+    (&"chengxian", &"呈现"),
+    (&"chengzhi", &"诚挚"),
+    (&"chengban", &"承办"),
+    (&"chengbao", &"承包"),
+    (&"chengnuo", &"承诺"),
+    (&"chengbao", &"城堡"),
+    (&"cheng", &"乘"),
+    (&"cheng", &"盛"),
+    (&"chengfa", &"惩罚"),
+    (&"chengqing", &"澄清"),
+    (&"cheng", &"橙"),
+    (&"cheng", &"秤"),
+    (&"chiku", &"吃苦"),
+    (&"chili", &"吃力"),
+    (&"chidun", &"迟钝"),
+    (&"chihuan", &"迟缓"),
+    (&"chiyi", &"迟疑"),
+    (&"chijiu", &"持久"),
+    (&"chidao", &"赤道"),
+    (&"chizi", &"赤字"),
+    (&"chongdong", &"冲动"),
+    (&"chongji", &"冲击"),
+    (&"chongtu", &"冲突"),
+    (&"chongdang", &"充当"),
+    (&"chongpei", &"充沛"),
+    (&"chongshi", &"充实"),
+    (&"chongzu", &"充足"),
+    (&"chongdie", &"重叠"),
+    (&"chongbai", &"崇拜"),
+    (&"chonggao", &"崇高"),
+    (&"chongjing", &"崇敬"),
+    (&"choumi", &"稠密"),
+    (&"choubei", &"筹备"),
+    (&"choue", &"丑恶"),
+    (&"chulu", &"出路"),
+    (&"chumai", &"出卖"),
+    (&"chushen", &"出身"),
+    (&"chushen", &"出神"),
+    (&"chuxi", &"出息"),
+    (&"chubu", &"初步"),
+    (&"chu", &"除"),
+    (&"chufen", &"处分"),
+    (&"chujing", &"处境"),
+    (&"chuzhi", &"处置"),
+    (&"chubei", &"储备"),
+    (&"chucun", &"储存"),
+    (&"chuxu", &"储蓄"),
+    (&"chufan", &"触犯"),
+    (&"chuanliubuxi", &"川流不息"),
+    (&"chuanyue", &"穿越"),
+    (&"chuanda", &"传达"),
+    (&"chuandan", &"传单"),
+    (&"chuanshou", &"传授"),
+    (&"chuanbo", &"船舶"),
+    (&"chuanqi", &"喘气"),
+    (&"chuan", &"串"),
+    (&"chuangdan", &"床单"),
+    (&"chuangli", &"创立"),
+    (&"chuangxin", &"创新"),
+    (&"chuangye", &"创业"),
+    (&"chuangzuo", &"创作"),
+    (&"chuiniu", &"吹牛"),
+    (&"chuipeng", &"吹捧"),
+    (&"chuiyan", &"炊烟"),
+    (&"chuizhi", &"垂直"),
+    (&"chui", &"锤"),
+    (&"chuncui", &"纯粹"),
+    (&"chunjie", &"纯洁"),
+    (&"cishan", &"慈善"),
+    (&"cixiang", &"慈祥"),
+    (&"cidai", &"磁带"),
+    (&"cixiong", &"雌雄"),
+    (&"cipin", &"次品"),
+    (&"cixu", &"次序"),
+    (&"cihou", &"伺候"),
+    (&"ci", &"刺"),
+    (&"congrong", &"从容"),
+    (&"cong", &"丛"),
+    (&"couhe", &"凑合"),
+    (&"culu", &"粗鲁"),
+    (&"cuan", &"窜"),
+    (&"cuican", &"摧残"),
+    (&"cuiruo", &"脆弱"),
+    (&"cuo", &"搓"),
+    (&"cuoshang", &"磋商"),
+    (&"cuozhe", &"挫折"),
+    (&"da", &"搭"),
+    (&"dadang", &"搭档"),
+    (&"dapei", &"搭配"),
+    (&"dacheng", &"达成"),
+    (&"dabian", &"答辩"),
+    (&"dafu", &"答复"),
+    (&"dabao", &"打包"),
+    (&"daguansi", &"打官司"),
+    (&"daji", &"打击"),
+    (&"dajia", &"打架"),
+    (&"daliang", &"打量"),
+    (&"dalie", &"打猎"),
+    (&"dazhang", &"打仗"),
+    (&"dabuliao", &"大不了"),
+    (&"dachen", &"大臣"),
+    (&"dahuor", &"大伙儿"),
+    (&"dasi", &"大肆"),
+    (&"dati", &"大体"),
+    (&"dayi", &"大意"),
+    (&"dazhi", &"大致"),
+    (&"daitu", &"歹徒"),
+    (&"daijia", &"代价"),
+    (&"daili", &"代理"),
+    (&"dailing", &"带领"),
+    (&"daiman", &"怠慢"),
+    (&"daibu", &"逮捕"),
+    (&"danbao", &"担保"),
+    (&"danqie", &"胆怯"),
+    (&"danchen", &"诞辰"),
+    (&"dansheng", &"诞生"),
+    (&"danji", &"淡季"),
+    (&"danshui", &"淡水"),
+    (&"danbaizhi", &"蛋白质"),
+    (&"dangchang", &"当场"),
+    (&"dangchu", &"当初"),
+    (&"dangdai", &"当代"),
+    (&"dangmian", &"当面"),
+    (&"dangqian", &"当前"),
+    (&"dangshiren", &"当事人"),
+    (&"dangwuzhiji", &"当务之急"),
+    (&"dangxuan", &"当选"),
+    (&"dang", &"党"),
+    (&"dangan", &"档案"),
+    (&"dangci", &"档次"),
+    (&"daodan", &"导弹"),
+    (&"daohang", &"导航"),
+    (&"daoxiang", &"导向"),
+    (&"daoluan", &"捣乱"),
+    (&"daobi", &"倒闭"),
+    (&"daoqie", &"盗窃"),
+    (&"daogu", &"稻谷"),
+    (&"debuchangshi", &"得不偿失"),
+    (&"deli", &"得力"),
+    (&"detianduhou", &"得天独厚"),
+    (&"dezui", &"得罪"),
+    (&"denglong", &"灯笼"),
+    (&"denglu", &"登陆"),
+    (&"denglu", &"登录"),
+    (&"deng", &"蹬"),
+    (&"denghou", &"等候"),
+    (&"dengji", &"等级"),
+    (&"deng", &"瞪"),
+    (&"diba", &"堤坝"),
+    (&"dishi", &"敌视"),
+    (&"dida", &"抵达"),
+    (&"dikang", &"抵抗"),
+    (&"dizhi", &"抵制"),
+    (&"dibu", &"地步"),
+    (&"dishi", &"地势"),
+    (&"dizhi", &"地质"),
+    (&"dizeng", &"递增"),
+    (&"dianbo", &"颠簸"),
+    (&"diandao", &"颠倒"),
+    (&"dianli", &"典礼"),
+    (&"dianxing", &"典型"),
+    (&"dianzhui", &"点缀"),
+    (&"dianyuan", &"电源"),
+    (&"dian", &"垫"),
+    (&"dianji", &"惦记"),
+    (&"dianding", &"奠定"),
+    (&"diao", &"叼"),
+    (&"diaoke", &"雕刻"),
+    (&"diaosu", &"雕塑"),
+    (&"diao", &"吊"),
+    (&"diaodong", &"调动"),
+    (&"die", &"跌"),
+    (&"ding", &"丁"),
+    (&"dingzhu", &"叮嘱"),
+    (&"ding", &"盯"),
+    (&"dingqi", &"定期"),
+    (&"dingyi", &"定义"),
+    (&"diuren", &"丢人"),
+    (&"diusanlasi", &"丢三落四"),
+    (&"dongdaozhu", &"东道主"),
+    (&"dongzhangxiwang", &"东张西望"),
+    (&"dongshizhang", &"董事长"),
+    (&"dongdang", &"动荡"),
+    (&"dongji", &"动机"),
+    (&"dongjing", &"动静"),
+    (&"dongli", &"动力"),
+    (&"dongmai", &"动脉"),
+    (&"dongshen", &"动身"),
+    (&"dongshou", &"动手"),
+    (&"dongtai", &"动态"),
+    (&"dongyuan", &"动员"),
+    (&"dongjie", &"冻结"),
+    (&"dong", &"栋"),
+    (&"dou", &"兜"),
+    (&"douqiao", &"陡峭"),
+    (&"douzheng", &"斗争"),
+    (&"ducu", &"督促"),
+    (&"dupin", &"毒品"),
+    (&"ducai", &"独裁"),
+    (&"duse", &"堵塞"),
+    (&"dubo", &"赌博"),
+    (&"dujue", &"杜绝"),
+    (&"duan", &"端"),
+    (&"duanwujie", &"端午节"),
+    (&"duanzheng", &"端正"),
+    (&"duancu", &"短促"),
+    (&"duanding", &"断定"),
+    (&"duanjue", &"断绝"),
+    (&"duiji", &"堆积"),
+    (&"duiwu", &"队伍"),
+    (&"duice", &"对策"),
+    (&"duichen", &"对称"),
+    (&"duifu", &"对付"),
+    (&"duikang", &"对抗"),
+    (&"duili", &"对立"),
+    (&"duilian", &"对联"),
+    (&"duiying", &"对应"),
+    (&"duizhao", &"对照"),
+    (&"duixian", &"兑现"),
+    (&"dunshi", &"顿时"),
+    (&"duoyuanhua", &"多元化"),
+    (&"duosuo", &"哆嗦"),
+    (&"duoluo", &"堕落"),
+    (&"ewai", &"额外"),
+    (&"exin", &"恶心"),
+    (&"ehua", &"恶化"),
+    (&"ezhi", &"遏制"),
+    (&"enyuan", &"恩怨"),
+    (&"eryi", &"而已"),
+    (&"eryanghuatan", &"二氧化碳"),
+    (&"fabu", &"发布"),
+    (&"facai", &"发财"),
+    (&"fadai", &"发呆"),
+    (&"fadong", &"发动"),
+    (&"fajue", &"发觉"),
+    (&"fashe", &"发射"),
+    (&"fashi", &"发誓"),
+    (&"faxing", &"发行"),
+    (&"fayan", &"发炎"),
+    (&"fayang", &"发扬"),
+    (&"fayu", &"发育"),
+    (&"faren", &"法人"),
+    (&"fan", &"番"),
+    (&"fanshi", &"凡是"),
+    (&"fanhua", &"繁华"),
+    (&"fanmang", &"繁忙"),
+    (&"fantizi", &"繁体字"),
+    (&"fanzhi", &"繁殖"),
+    (&"fanbo", &"反驳"),
+    (&"fanchang", &"反常"),
+    (&"fangan", &"反感"),
+    (&"fankang", &"反抗"),
+    (&"fankui", &"反馈"),
+    (&"fanmian", &"反面"),
+    (&"fanshe", &"反射"),
+    (&"fansi", &"反思"),
+    (&"fanwen", &"反问"),
+    (&"fanzhi", &"反之"),
+    (&"fanlan", &"泛滥"),
+    (&"fanchou", &"范畴"),
+    (&"fanmai", &"贩卖"),
+    (&"fangwei", &"方位"),
+    (&"fangyan", &"方言"),
+    (&"fangyuan", &"方圆"),
+    (&"fangzhen", &"方针"),
+    (&"fangshou", &"防守"),
+    (&"fangyu", &"防御"),
+    (&"fangzhi", &"防止"),
+    (&"fangzhi", &"防治"),
+    (&"fangwen", &"访问"),
+    (&"fangzhi", &"纺织"),
+    (&"fangda", &"放大"),
+    (&"fangshe", &"放射"),
+    (&"feiqinzoushou", &"飞禽走兽"),
+    (&"feixiang", &"飞翔"),
+    (&"feiyue", &"飞跃"),
+    (&"feifa", &"非法"),
+    (&"feiwo", &"肥沃"),
+    (&"feibang", &"诽谤"),
+    (&"fei", &"肺"),
+    (&"feichu", &"废除"),
+    (&"feiqinwangshi", &"废寝忘食"),
+    (&"feixu", &"废墟"),
+    (&"feiteng", &"沸腾"),
+    (&"fenbian", &"分辨"),
+    (&"fencun", &"分寸"),
+    (&"fenhong", &"分红"),
+    (&"fenjie", &"分解"),
+    (&"fenlie", &"分裂"),
+    (&"fenmi", &"分泌"),
+    (&"fenming", &"分明"),
+    (&"fenqi", &"分歧"),
+    (&"fensan", &"分散"),
+    (&"fenfu", &"吩咐"),
+    (&"fenmu", &"坟墓"),
+    (&"fenmo", &"粉末"),
+    (&"fense", &"粉色"),
+    (&"fensui", &"粉碎"),
+    (&"fenliang", &"分量"),
+    (&"fennu", &"愤怒"),
+    (&"fengman", &"丰满"),
+    (&"fengsheng", &"丰盛"),
+    (&"fengshou", &"丰收"),
+    (&"fengbao", &"风暴"),
+    (&"fengdu", &"风度"),
+    (&"fengguang", &"风光"),
+    (&"fengqi", &"风气"),
+    (&"fengqu", &"风趣"),
+    (&"fengturenqing", &"风土人情"),
+    (&"fengwei", &"风味"),
+    (&"fengbi", &"封闭"),
+    (&"fengjian", &"封建"),
+    (&"fengsuo", &"封锁"),
+    (&"fengli", &"锋利"),
+    (&"feng", &"逢"),
+    (&"fengxian", &"奉献"),
+    (&"foujue", &"否决"),
+    (&"fufu", &"夫妇"),
+    (&"furen", &"夫人"),
+    (&"fuyan", &"敷衍"),
+    (&"fucong", &"服从"),
+    (&"fuqi", &"服气"),
+    (&"fulu", &"俘虏"),
+    (&"fuhao", &"符号"),
+    (&"fudu", &"幅度"),
+    (&"fushe", &"辐射"),
+    (&"fuli", &"福利"),
+    (&"fuqi", &"福气"),
+    (&"fumo", &"抚摸"),
+    (&"fuyang", &"抚养"),
+    (&"fushi", &"俯视"),
+    (&"fuzhu", &"辅助"),
+    (&"fubai", &"腐败"),
+    (&"fulan", &"腐烂"),
+    (&"fushi", &"腐蚀"),
+    (&"fuxiu", &"腐朽"),
+    (&"fudan", &"负担"),
+    (&"fuhe", &"附和"),
+    (&"fujian", &"附件"),
+    (&"fushu", &"附属"),
+    (&"fuhuo", &"复活"),
+    (&"fuxing", &"复兴"),
+    (&"fu", &"副"),
+    (&"fuyu", &"赋予"),
+    (&"fuyu", &"富裕"),
+    (&"fuxie", &"腹泻"),
+    (&"fugai", &"覆盖"),
+    (&"gailiang", &"改良"),
+    (&"gai", &"钙"),
+    (&"gaizhang", &"盖章"),
+    (&"ganhan", &"干旱"),
+    (&"ganrao", &"干扰"),
+    (&"ganshe", &"干涉"),
+    (&"ganyu", &"干预"),
+    (&"ganga", &"尴尬"),
+    (&"gankai", &"感慨"),
+    (&"ganran", &"感染"),
+    (&"ganjin", &"干劲"),
+    (&"gangling", &"纲领"),
+    (&"gangwei", &"岗位"),
+    (&"gangkou", &"港口"),
+    (&"gangwan", &"港湾"),
+    (&"ganggan", &"杠杆"),
+    (&"gaochao", &"高超"),
+    (&"gaochao", &"高潮"),
+    (&"gaofeng", &"高峰"),
+    (&"gaoming", &"高明"),
+    (&"gaoshang", &"高尚"),
+    (&"gaozhang", &"高涨"),
+    (&"gaojian", &"稿件"),
+    (&"gaoci", &"告辞"),
+    (&"gaojie", &"告诫"),
+    (&"geda", &"疙瘩"),
+    (&"gezi", &"鸽子"),
+    (&"ge", &"搁"),
+    (&"ge", &"割"),
+    (&"gesong", &"歌颂"),
+    (&"geming", &"革命"),
+    (&"geju", &"格局"),
+    (&"geshi", &"格式"),
+    (&"gehe", &"隔阂"),
+    (&"geli", &"隔离"),
+    (&"geti", &"个体"),
+    (&"geshujijian", &"各抒己见"),
+    (&"genshendigu", &"根深蒂固"),
+    (&"genyuan", &"根源"),
+    (&"genqian", &"跟前"),
+    (&"gensui", &"跟随"),
+    (&"genzong", &"跟踪"),
+    (&"gengxin", &"更新"),
+    (&"gengzheng", &"更正"),
+    (&"gengdi", &"耕地"),
+    (&"gongyipin", &"工艺品"),
+    (&"gonganju", &"公安局"),
+    (&"gongdao", &"公道"),
+    (&"gonggao", &"公告"),
+    (&"gongguan", &"公关"),
+    (&"gongmin", &"公民"),
+    (&"gongran", &"公然"),
+    (&"gongren", &"公认"),
+    (&"gongshi", &"公式"),
+    (&"gongwu", &"公务"),
+    (&"gongzheng", &"公正"),
+    (&"gongzheng", &"公证"),
+    (&"gonglao", &"功劳"),
+    (&"gongxiao", &"功效"),
+    (&"gongji", &"攻击"),
+    (&"gongke", &"攻克"),
+    (&"gongbuyingqiu", &"供不应求"),
+    (&"gongji", &"供给"),
+    (&"gongdian", &"宫殿"),
+    (&"gongjing", &"恭敬"),
+    (&"gonggu", &"巩固"),
+    (&"gongheguo", &"共和国"),
+    (&"gongji", &"共计"),
+    (&"gongming", &"共鸣"),
+    (&"goujie", &"勾结"),
+    (&"gouzi", &"钩子"),
+    (&"gousi", &"构思"),
+    (&"gudu", &"孤独"),
+    (&"guli", &"孤立"),
+    (&"guqie", &"姑且"),
+    (&"gufu", &"辜负"),
+    (&"gudong", &"古董"),
+    (&"guguai", &"古怪"),
+    (&"gudong", &"股东"),
+    (&"gufen", &"股份"),
+    (&"gugan", &"骨干"),
+    (&"gudong", &"鼓动"),
+    (&"guran", &"固然"),
+    (&"guti", &"固体"),
+    (&"guyou", &"固有"),
+    (&"guzhi", &"固执"),
+    (&"guxiang", &"故乡"),
+    (&"guzhang", &"故障"),
+    (&"gulv", &"顾虑"),
+    (&"guwen", &"顾问"),
+    (&"guyong", &"雇佣"),
+    (&"guaizhang", &"拐杖"),
+    (&"guanhuai", &"关怀"),
+    (&"guanzhao", &"关照"),
+    (&"guanguang", &"观光"),
+    (&"guanfang", &"官方"),
+    (&"guanxia", &"管辖"),
+    (&"guanche", &"贯彻"),
+    (&"guanli", &"惯例"),
+    (&"guangai", &"灌溉"),
+    (&"guan", &"罐"),
+    (&"guangcai", &"光彩"),
+    (&"guanghui", &"光辉"),
+    (&"guangmang", &"光芒"),
+    (&"guangrong", &"光荣"),
+    (&"guangkuo", &"广阔"),
+    (&"guigendaodi", &"归根到底"),
+    (&"guihuan", &"归还"),
+    (&"guifan", &"规范"),
+    (&"guige", &"规格"),
+    (&"guihua", &"规划"),
+    (&"guizhang", &"规章"),
+    (&"guidao", &"轨道"),
+    (&"guizu", &"贵族"),
+    (&"gui", &"跪"),
+    (&"gunbang", &"棍棒"),
+    (&"guofang", &"国防"),
+    (&"guowuyuan", &"国务院"),
+    (&"guoduan", &"果断"),
+    (&"guodu", &"过度"),
+    (&"guodu", &"过渡"),
+    (&"guojiang", &"过奖"),
+    (&"guolv", &"过滤"),
+    (&"guoshi", &"过失"),
+    (&"guowen", &"过问"),
+    (&"guoyin", &"过瘾"),
+    (&"guoyu", &"过于"),
+    (&"hai", &"嗨"),
+    (&"haiba", &"海拔"),
+    (&"haibin", &"海滨"),
+    (&"hanhu", &"含糊"),
+    (&"hanyi", &"含义"),
+    (&"hanxuan", &"寒暄"),
+    (&"hanjian", &"罕见"),
+    (&"hanwei", &"捍卫"),
+    (&"hanglie", &"行列"),
+    (&"hangkong", &"航空"),
+    (&"hangtian", &"航天"),
+    (&"hangxing", &"航行"),
+    (&"haomi", &"毫米"),
+    (&"haowu", &"毫无"),
+    (&"haomai", &"豪迈"),
+    (&"haozhao", &"号召"),
+    (&"haofei", &"耗费"),
+    (&"he", &"呵"),
+    (&"hebing", &"合并"),
+    (&"hecheng", &"合成"),
+    (&"hehuo", &"合伙"),
+    (&"hesuan", &"合算"),
+    (&"heai", &"和蔼"),
+    (&"hejie", &"和解"),
+    (&"hemu", &"和睦"),
+    (&"heqi", &"和气"),
+    (&"hexie", &"和谐"),
+    (&"hei", &"嘿"),
+    (&"henji", &"痕迹"),
+    (&"henxin", &"狠心"),
+    (&"henbude", &"恨不得"),
+    (&"heng", &"横"),
+    (&"heng", &"哼"),
+    (&"hongdong", &"轰动"),
+    (&"hong", &"烘"),
+    (&"hongguan", &"宏观"),
+    (&"hongwei", &"宏伟"),
+    (&"hongshui", &"洪水"),
+    (&"hong", &"哄"),
+    (&"houlong", &"喉咙"),
+    (&"hou", &"吼"),
+    (&"houdai", &"后代"),
+    (&"houguzhiyou", &"后顾之忧"),
+    (&"houqin", &"后勤"),
+    (&"houxuan", &"候选"),
+    (&"huhuan", &"呼唤"),
+    (&"huxiao", &"呼啸"),
+    (&"huyu", &"呼吁"),
+    (&"hulve", &"忽略"),
+    (&"huluan", &"胡乱"),
+    (&"huxu", &"胡须"),
+    (&"hupo", &"湖泊"),
+    (&"huaban", &"花瓣"),
+    (&"hualei", &"花蕾"),
+    (&"huali", &"华丽"),
+    (&"huaqiao", &"华侨"),
+    (&"huafei", &"化肥"),
+    (&"huashi", &"化石"),
+    (&"huayan", &"化验"),
+    (&"huazhuang", &"化妆"),
+    (&"huafen", &"划分"),
+    (&"huashetianzu", &"画蛇添足"),
+    (&"huatong", &"话筒"),
+    (&"huanle", &"欢乐"),
+    (&"huanyuan", &"还原"),
+    (&"huanjie", &"环节"),
+    (&"huanhe", &"缓和"),
+    (&"huanzhe", &"患者"),
+    (&"huangliang", &"荒凉"),
+    (&"huangmiu", &"荒谬"),
+    (&"huangtang", &"荒唐"),
+    (&"huangdi", &"皇帝"),
+    (&"huanghou", &"皇后"),
+    (&"huanghun", &"黄昏"),
+    (&"huangrandawu", &"恍然大悟"),
+    (&"huang", &"晃"),
+    (&"huihuo", &"挥霍"),
+    (&"huihuang", &"辉煌"),
+    (&"huibao", &"回报"),
+    (&"huibi", &"回避"),
+    (&"huigu", &"回顾"),
+    (&"huishou", &"回收"),
+    (&"huihen", &"悔恨"),
+    (&"huimie", &"毁灭"),
+    (&"huibao", &"汇报"),
+    (&"huiwu", &"会晤"),
+    (&"huilu", &"贿赂"),
+    (&"hunmi", &"昏迷"),
+    (&"hun", &"荤"),
+    (&"hunshen", &"浑身"),
+    (&"hunhe", &"混合"),
+    (&"hunluan", &"混乱"),
+    (&"hunxiao", &"混淆"),
+    (&"hunzhuo", &"混浊"),
+    (&"huogai", &"活该"),
+    (&"huoli", &"活力"),
+    (&"huojian", &"火箭"),
+    (&"huoyan", &"火焰"),
+    (&"huoyao", &"火药"),
+    (&"huobi", &"货币"),
+    (&"jixiao", &"讥笑"),
+    (&"jie", &"饥饿"),
+    (&"jidong", &"机动"),
+    (&"jigou", &"机构"),
+    (&"jiling", &"机灵"),
+    (&"jimi", &"机密"),
+    (&"jixie", &"机械"),
+    (&"jiyu", &"机遇"),
+    (&"jizhi", &"机智"),
+    (&"jidi", &"基地"),
+    (&"jijin", &"基金"),
+    (&"jiyin", &"基因"),
+    (&"jifa", &"激发"),
+    (&"jili", &"激励"),
+    (&"jiqing", &"激情"),
+    (&"jizao", &"及早"),
+    (&"jixiang", &"吉祥"),
+    (&"jibie", &"级别"),
+    (&"jiduan", &"极端"),
+    (&"jixian", &"极限"),
+    (&"jibian", &"即便"),
+    (&"jijiang", &"即将"),
+    (&"jigongjinli", &"急功近利"),
+    (&"jiju", &"急剧"),
+    (&"jiqie", &"急切"),
+    (&"jiyuqiucheng", &"急于求成"),
+    (&"jizao", &"急躁"),
+    (&"jibing", &"疾病"),
+    (&"jituan", &"集团"),
+    (&"jidu", &"嫉妒"),
+    (&"jiguan", &"籍贯"),
+    (&"jiyu", &"给予"),
+    (&"jijiao", &"计较"),
+    (&"jixing", &"记性"),
+    (&"jizai", &"记载"),
+    (&"jiyao", &"纪要"),
+    (&"jiqiao", &"技巧"),
+    (&"jihui", &"忌讳"),
+    (&"jidu", &"季度"),
+    (&"jijun", &"季军"),
+    (&"jixiang", &"迹象"),
+    (&"jicheng", &"继承"),
+    (&"jituo", &"寄托"),
+    (&"jijing", &"寂静"),
+    (&"jiagong", &"加工"),
+    (&"jiaju", &"加剧"),
+    (&"jiaza", &"夹杂"),
+    (&"jiayao", &"佳肴"),
+    (&"jiachang", &"家常"),
+    (&"jiahuo", &"家伙"),
+    (&"jiashu", &"家属"),
+    (&"jiayuhuxiao", &"家喻户晓"),
+    (&"jianduan", &"尖端"),
+    (&"jianrui", &"尖锐"),
+    (&"jianding", &"坚定"),
+    (&"jiangu", &"坚固"),
+    (&"jianren", &"坚韧"),
+    (&"jianshi", &"坚实"),
+    (&"jianying", &"坚硬"),
+    (&"jiannan", &"艰难"),
+    (&"jiandu", &"监督"),
+    (&"jianshi", &"监视"),
+    (&"jianyu", &"监狱"),
+    (&"jian", &"煎"),
+    (&"jian", &"拣"),
+    (&"jiantao", &"检讨"),
+    (&"jianyan", &"检验"),
+    (&"jiancai", &"剪彩"),
+    (&"jianhua", &"简化"),
+    (&"jianlou", &"简陋"),
+    (&"jiantizi", &"简体字"),
+    (&"jianyao", &"简要"),
+    (&"jianduoshiguang", &"见多识广"),
+    (&"jianjie", &"见解"),
+    (&"jianwen", &"见闻"),
+    (&"jianyiyongwei", &"见义勇为"),
+    (&"jiandie", &"间谍"),
+    (&"jiange", &"间隔"),
+    (&"jianjie", &"间接"),
+    (&"jian", &"剑"),
+    (&"jianquan", &"健全"),
+    (&"jianting", &"舰艇"),
+    (&"jianta", &"践踏"),
+    (&"jian", &"溅"),
+    (&"jianbie", &"鉴别"),
+    (&"jianding", &"鉴定"),
+    (&"jianyu", &"鉴于"),
+    (&"jiangjin", &"将近"),
+    (&"jiangjiu", &"将就"),
+    (&"jiangjun", &"将军"),
+    (&"jiangying", &"僵硬"),
+    (&"jiangli", &"奖励"),
+    (&"jiangshang", &"奖赏"),
+    (&"jiang", &"桨"),
+    (&"jianglin", &"降临"),
+    (&"jiaocha", &"交叉"),
+    (&"jiaodai", &"交代"),
+    (&"jiaoshe", &"交涉"),
+    (&"jiaoyi", &"交易"),
+    (&"jiaoqi", &"娇气"),
+    (&"jiaodian", &"焦点"),
+    (&"jiaoji", &"焦急"),
+    (&"jiaoluo", &"角落"),
+    (&"jiaoxing", &"侥幸"),
+    (&"jiaoban", &"搅拌"),
+    (&"jiaona", &"缴纳"),
+    (&"jiaoliang", &"较量"),
+    (&"jiaoyang", &"教养"),
+    (&"jieceng", &"阶层"),
+    (&"jie", &"皆"),
+    (&"jielian", &"接连"),
+    (&"jielu", &"揭露"),
+    (&"jiezhi", &"节制"),
+    (&"jiezou", &"节奏"),
+    (&"jiechu", &"杰出"),
+    (&"jiejing", &"结晶"),
+    (&"jieju", &"结局"),
+    (&"jiesuan", &"结算"),
+    (&"jiezhi", &"截止"),
+    (&"jiezhi", &"截至"),
+    (&"jiejinquanli", &"竭尽全力"),
+    (&"jiechu", &"解除"),
+    (&"jiefang", &"解放"),
+    (&"jiegu", &"解雇"),
+    (&"jiepou", &"解剖"),
+    (&"jiesan", &"解散"),
+    (&"jieti", &"解体"),
+    (&"jiebei", &"戒备"),
+    (&"jiexian", &"界限"),
+    (&"jiejian", &"借鉴"),
+    (&"jiezhu", &"借助"),
+    (&"jinrong", &"金融"),
+    (&"jinjinyouwei", &"津津有味"),
+    (&"jinpo", &"紧迫"),
+    (&"jinshangtianhua", &"锦上添花"),
+    (&"jiner", &"进而"),
+    (&"jingong", &"进攻"),
+    (&"jinhua", &"进化"),
+    (&"jinzhan", &"进展"),
+    (&"jinlai", &"近来"),
+    (&"jinsheng", &"晋升"),
+    (&"jinpao", &"浸泡"),
+    (&"jing", &"茎"),
+    (&"jingfei", &"经费"),
+    (&"jingwei", &"经纬"),
+    (&"jingdong", &"惊动"),
+    (&"jingqi", &"惊奇"),
+    (&"jingya", &"惊讶"),
+    (&"jingjingyeye", &"兢兢业业"),
+    (&"jingdaxisuan", &"精打细算"),
+    (&"jinghua", &"精华"),
+    (&"jingjian", &"精简"),
+    (&"jingmi", &"精密"),
+    (&"jingque", &"精确"),
+    (&"jingtong", &"精通"),
+    (&"jingxin", &"精心"),
+    (&"jingyiqiujing", &"精益求精"),
+    (&"jingzhi", &"精致"),
+    (&"jing", &"井"),
+    (&"jingzhui", &"颈椎"),
+    (&"jinggao", &"警告"),
+    (&"jingti", &"警惕"),
+    (&"jingsai", &"竞赛"),
+    (&"jingxuan", &"竞选"),
+    (&"jingli", &"敬礼"),
+    (&"jingye", &"敬业"),
+    (&"jingjie", &"境界"),
+    (&"jingtou", &"镜头"),
+    (&"jiufen", &"纠纷"),
+    (&"jiuzheng", &"纠正"),
+    (&"jiujing", &"酒精"),
+    (&"jiuji", &"救济"),
+    (&"jiujin", &"就近"),
+    (&"jiuye", &"就业"),
+    (&"jiuzhi", &"就职"),
+    (&"juliu", &"拘留"),
+    (&"jushu", &"拘束"),
+    (&"jumin", &"居民"),
+    (&"juzhu", &"居住"),
+    (&"jugong", &"鞠躬"),
+    (&"jubu", &"局部"),
+    (&"jumian", &"局面"),
+    (&"jushi", &"局势"),
+    (&"juxian", &"局限"),
+    (&"jujue", &"咀嚼"),
+    (&"jusang", &"沮丧"),
+    (&"judong", &"举动"),
+    (&"jushizhumu", &"举世瞩目"),
+    (&"juzuqingzhong", &"举足轻重"),
+    (&"juben", &"剧本"),
+    (&"julie", &"剧烈"),
+    (&"juxi", &"据悉"),
+    (&"jujinghuishen", &"聚精会神"),
+    (&"juan", &"卷"),
+    (&"juece", &"决策"),
+    (&"juewu", &"觉悟"),
+    (&"juexing", &"觉醒"),
+    (&"juewang", &"绝望"),
+    (&"juejiang", &"倔强"),
+    (&"jundui", &"军队"),
+    (&"junzi", &"君子"),
+    (&"katong", &"卡通"),
+    (&"kaicai", &"开采"),
+    (&"kaichu", &"开除"),
+    (&"kaikuo", &"开阔"),
+    (&"kailang", &"开朗"),
+    (&"kaiming", &"开明"),
+    (&"kaipi", &"开辟"),
+    (&"kaituo", &"开拓"),
+    (&"kaizhan", &"开展"),
+    (&"kaizhi", &"开支"),
+    (&"kandeng", &"刊登"),
+    (&"kanwu", &"刊物"),
+    (&"kantan", &"勘探"),
+    (&"kankanertan", &"侃侃而谈"),
+    (&"kanfa", &"砍伐"),
+    (&"kandai", &"看待"),
+    (&"kangkai", &"慷慨"),
+    (&"kang", &"扛"),
+    (&"kangyi", &"抗议"),
+    (&"kaocha", &"考察"),
+    (&"kaogu", &"考古"),
+    (&"kaohe", &"考核"),
+    (&"kaoyan", &"考验"),
+    (&"kaolong", &"靠拢"),
+    (&"kemu", &"科目"),
+    (&"ke", &"磕"),
+    (&"keguan", &"可观"),
+    (&"kekou", &"可口"),
+    (&"kewu", &"可恶"),
+    (&"kexing", &"可行"),
+    (&"kewang", &"渴望"),
+    (&"kezhi", &"克制"),
+    (&"keburonghuan", &"刻不容缓"),
+    (&"kehu", &"客户"),
+    (&"keti", &"课题"),
+    (&"kenqie", &"恳切"),
+    (&"ken", &"啃"),
+    (&"keng", &"坑"),
+    (&"kongdong", &"空洞"),
+    (&"kongqianjuehou", &"空前绝后"),
+    (&"kongxiang", &"空想"),
+    (&"kongxu", &"空虚"),
+    (&"kong", &"孔"),
+    (&"kongbu", &"恐怖"),
+    (&"konghe", &"恐吓"),
+    (&"kongju", &"恐惧"),
+    (&"kongbai", &"空白"),
+    (&"kongxi", &"空隙"),
+    (&"kouqi", &"口气"),
+    (&"kouqiang", &"口腔"),
+    (&"koutou", &"口头"),
+    (&"kouyin", &"口音"),
+    (&"kou", &"扣"),
+    (&"kuwei", &"枯萎"),
+    (&"kuzao", &"枯燥"),
+    (&"kuqi", &"哭泣"),
+    (&"kujinganlai", &"苦尽甘来"),
+    (&"kuse", &"苦涩"),
+    (&"kua", &"挎"),
+    (&"kua", &"跨"),
+    (&"kuaihuo", &"快活"),
+    (&"kuanchang", &"宽敞"),
+    (&"kuanrong", &"宽容"),
+    (&"kuandai", &"款待"),
+    (&"kuanshi", &"款式"),
+    (&"kuang", &"筐"),
+    (&"kuangke", &"旷课"),
+    (&"kuangqie", &"况且"),
+    (&"kuangchan", &"矿产"),
+    (&"kuangjia", &"框架"),
+    (&"kuidai", &"亏待"),
+    (&"kuisun", &"亏损"),
+    (&"kunbang", &"捆绑"),
+    (&"kuochong", &"扩充"),
+    (&"kuosan", &"扩散"),
+    (&"kuozhang", &"扩张"),
+    (&"laba", &"喇叭"),
+    (&"lazhu", &"蜡烛"),
+    (&"la", &"啦"),
+    (&"laili", &"来历"),
+    (&"laiyuan", &"来源"),
+    (&"lanmu", &"栏目"),
+    (&"landuo", &"懒惰"),
+    (&"langbei", &"狼狈"),
+    (&"langtunhuyan", &"狼吞虎咽"),
+    (&"lao", &"捞"),
+    (&"laogu", &"牢固"),
+    (&"laosao", &"牢骚"),
+    (&"laodao", &"唠叨"),
+    (&"lequ", &"乐趣"),
+    (&"leyi", &"乐意"),
+    (&"leida", &"雷达"),
+    (&"leisi", &"类似"),
+    (&"lengku", &"冷酷"),
+    (&"lengluo", &"冷落"),
+    (&"lengque", &"冷却"),
+    (&"leng", &"愣"),
+    (&"liming", &"黎明"),
+    (&"lijie", &"礼节"),
+    (&"lishangwanglai", &"礼尚往来"),
+    (&"lichengbei", &"里程碑"),
+    (&"licai", &"理睬"),
+    (&"lisuodangran", &"理所当然"),
+    (&"lizhiqizhuang", &"理直气壮"),
+    (&"lizhi", &"理智"),
+    (&"liqiu", &"力求"),
+    (&"lisuonengji", &"力所能及"),
+    (&"lizheng", &"力争"),
+    (&"lidai", &"历代"),
+    (&"lilai", &"历来"),
+    (&"lichang", &"立场"),
+    (&"lifang", &"立方"),
+    (&"lijiaoqiao", &"立交桥"),
+    (&"liti", &"立体"),
+    (&"lizu", &"立足"),
+    (&"lihai", &"利害"),
+    (&"liwai", &"例外"),
+    (&"li", &"粒"),
+    (&"liannian", &"连年"),
+    (&"liansuo", &"连锁"),
+    (&"liantong", &"连同"),
+    (&"lianhuan", &"联欢"),
+    (&"lianluo", &"联络"),
+    (&"lianmeng", &"联盟"),
+    (&"lianxiang", &"联想"),
+    (&"lianjie", &"廉洁"),
+    (&"liangxin", &"良心"),
+    (&"liangjie", &"谅解"),
+    (&"liang", &"晾"),
+    (&"liaokuo", &"辽阔"),
+    (&"lieju", &"列举"),
+    (&"linchuang", &"临床"),
+    (&"lin", &"淋"),
+    (&"linse", &"吝啬"),
+    (&"lingli", &"伶俐"),
+    (&"linggan", &"灵感"),
+    (&"linghun", &"灵魂"),
+    (&"lingmin", &"灵敏"),
+    (&"lingchen", &"凌晨"),
+    (&"lingxing", &"零星"),
+    (&"linghui", &"领会"),
+    (&"lingshiguan", &"领事馆"),
+    (&"lingtu", &"领土"),
+    (&"lingwu", &"领悟"),
+    (&"lingxian", &"领先"),
+    (&"lingxiu", &"领袖"),
+    (&"liu", &"溜"),
+    (&"liulian", &"留恋"),
+    (&"liunian", &"留念"),
+    (&"liushen", &"留神"),
+    (&"liulang", &"流浪"),
+    (&"liulu", &"流露"),
+    (&"liumang", &"流氓"),
+    (&"liutong", &"流通"),
+    (&"longya", &"聋哑"),
+    (&"longzhong", &"隆重"),
+    (&"longduan", &"垄断"),
+    (&"longzhao", &"笼罩"),
+    (&"lou", &"搂"),
+    (&"luzao", &"炉灶"),
+    (&"lvci", &"屡次"),
+    (&"lvxing", &"履行"),
+    (&"lveduo", &"掠夺"),
+    (&"lunchuan", &"轮船"),
+    (&"lunkuo", &"轮廓"),
+    (&"luntai", &"轮胎"),
+    (&"luntan", &"论坛"),
+    (&"lunzheng", &"论证"),
+    (&"luosuo", &"啰唆"),
+    (&"luoyibujue", &"络绎不绝"),
+    (&"luocheng", &"落成"),
+    (&"luoshi", &"落实"),
+    (&"mabi", &"麻痹"),
+    (&"mamu", &"麻木"),
+    (&"mazui", &"麻醉"),
+    (&"matou", &"码头"),
+    (&"mayi", &"蚂蚁"),
+    (&"ma", &"嘛"),
+    (&"maifu", &"埋伏"),
+    (&"maimo", &"埋没"),
+    (&"maizang", &"埋葬"),
+    (&"mai", &"迈"),
+    (&"maibo", &"脉搏"),
+    (&"manyuan", &"埋怨"),
+    (&"manyan", &"蔓延"),
+    (&"manchang", &"漫长"),
+    (&"manhua", &"漫画"),
+    (&"manxing", &"慢性"),
+    (&"manglu", &"忙碌"),
+    (&"mangmu", &"盲目"),
+    (&"mangmang", &"茫茫"),
+    (&"mangran", &"茫然"),
+    (&"maosheng", &"茂盛"),
+    (&"maochong", &"冒充"),
+    (&"maofan", &"冒犯"),
+    (&"mei", &"枚"),
+    (&"meijie", &"媒介"),
+    (&"meiguan", &"美观"),
+    (&"meiman", &"美满"),
+    (&"meimiao", &"美妙"),
+    (&"mengya", &"萌芽"),
+    (&"menglie", &"猛烈"),
+    (&"mi", &"眯"),
+    (&"mibu", &"弥补"),
+    (&"miman", &"弥漫"),
+    (&"mihuo", &"迷惑"),
+    (&"miren", &"迷人"),
+    (&"mixin", &"迷信"),
+    (&"miyu", &"谜语"),
+    (&"midu", &"密度"),
+    (&"mifeng", &"密封"),
+    (&"mianhua", &"棉花"),
+    (&"miande", &"免得"),
+    (&"mianyi", &"免疫"),
+    (&"mianli", &"勉励"),
+    (&"mianqiang", &"勉强"),
+    (&"mianmao", &"面貌"),
+    (&"mianzi", &"面子"),
+    (&"miaohui", &"描绘"),
+    (&"miaozhun", &"瞄准"),
+    (&"miaoxiao", &"渺小"),
+    (&"miaoshi", &"藐视"),
+    (&"miewang", &"灭亡"),
+    (&"mieshi", &"蔑视"),
+    (&"minjian", &"民间"),
+    (&"minzhu", &"民主"),
+    (&"minjie", &"敏捷"),
+    (&"minrui", &"敏锐"),
+    (&"mingci", &"名次"),
+    (&"minge", &"名额"),
+    (&"mingfuqishi", &"名副其实"),
+    (&"mingyu", &"名誉"),
+    (&"mingming", &"明明"),
+    (&"mingzhi", &"明智"),
+    (&"mingming", &"命名"),
+    (&"mosuo", &"摸索"),
+    (&"mofan", &"模范"),
+    (&"moshi", &"模式"),
+    (&"moxing", &"模型"),
+    (&"mo", &"膜"),
+    (&"moca", &"摩擦"),
+    (&"mohe", &"磨合"),
+    (&"mogui", &"魔鬼"),
+    (&"moshu", &"魔术"),
+    (&"mosha", &"抹杀"),
+    (&"momingqimiao", &"莫名其妙"),
+    (&"moshuir", &"墨水儿"),
+    (&"momo", &"默默"),
+    (&"mouqiu", &"谋求"),
+    (&"muyang", &"模样"),
+    (&"muyu", &"母语"),
+    (&"mudu", &"目睹"),
+    (&"muguang", &"目光"),
+    (&"muyu", &"沐浴"),
+    (&"nashou", &"拿手"),
+    (&"namenr", &"纳闷儿"),
+    (&"naiyong", &"耐用"),
+    (&"nanyuanbeizhe", &"南辕北辙"),
+    (&"nande", &"难得"),
+    (&"nankan", &"难堪"),
+    (&"nannengkegui", &"难能可贵"),
+    (&"naohuo", &"恼火"),
+    (&"neihan", &"内涵"),
+    (&"neimu", &"内幕"),
+    (&"neizai", &"内在"),
+    (&"nengliang", &"能量"),
+    (&"niding", &"拟定"),
+    (&"nixing", &"逆行"),
+    (&"niandu", &"年度"),
+    (&"nie", &"捏"),
+    (&"ninggu", &"凝固"),
+    (&"ningju", &"凝聚"),
+    (&"ningshi", &"凝视"),
+    (&"ning", &"拧"),
+    (&"ningken", &"宁肯"),
+    (&"ningyuan", &"宁愿"),
+    (&"niuzhuan", &"扭转"),
+    (&"niukour", &"纽扣儿"),
+    (&"nongli", &"农历"),
+    (&"nonghou", &"浓厚"),
+    (&"nuli", &"奴隶"),
+    (&"nvedai", &"虐待"),
+    (&"nuo", &"挪"),
+    (&"o", &"哦"),
+    (&"ouda", &"殴打"),
+    (&"outu", &"呕吐"),
+    (&"ouxiang", &"偶像"),
+    (&"pa", &"趴"),
+    (&"paichi", &"排斥"),
+    (&"paichu", &"排除"),
+    (&"paifang", &"排放"),
+    (&"pailian", &"排练"),
+    (&"paihuai", &"徘徊"),
+    (&"paibie", &"派别"),
+    (&"paiqian", &"派遣"),
+    (&"pandeng", &"攀登"),
+    (&"panxuan", &"盘旋"),
+    (&"panjue", &"判决"),
+    (&"pan", &"畔"),
+    (&"pangda", &"庞大"),
+    (&"paoqi", &"抛弃"),
+    (&"paomo", &"泡沫"),
+    (&"peiyu", &"培育"),
+    (&"peibei", &"配备"),
+    (&"peiou", &"配偶"),
+    (&"peitao", &"配套"),
+    (&"pendi", &"盆地"),
+    (&"pengren", &"烹饪"),
+    (&"peng", &"捧"),
+    (&"pifa", &"批发"),
+    (&"pipan", &"批判"),
+    (&"pi", &"劈"),
+    (&"pige", &"皮革"),
+    (&"pibei", &"疲惫"),
+    (&"pijuan", &"疲倦"),
+    (&"pigu", &"屁股"),
+    (&"piru", &"譬如"),
+    (&"piancha", &"偏差"),
+    (&"pianjian", &"偏见"),
+    (&"pianpi", &"偏僻"),
+    (&"pianpian", &"偏偏"),
+    (&"pianduan", &"片断"),
+    (&"pianke", &"片刻"),
+    (&"piaofu", &"漂浮"),
+    (&"piaoyang", &"飘扬"),
+    (&"pie", &"撇"),
+    (&"pinbo", &"拼搏"),
+    (&"pinming", &"拼命"),
+    (&"pinfa", &"贫乏"),
+    (&"pinkun", &"贫困"),
+    (&"pinfan", &"频繁"),
+    (&"pinlv", &"频率"),
+    (&"pinchang", &"品尝"),
+    (&"pinde", &"品德"),
+    (&"pinzhi", &"品质"),
+    (&"pinzhong", &"品种"),
+    (&"pingfan", &"平凡"),
+    (&"pingmian", &"平面"),
+    (&"pingtan", &"平坦"),
+    (&"pingxing", &"平行"),
+    (&"pingyong", &"平庸"),
+    (&"pingyuan", &"平原"),
+    (&"pinggu", &"评估"),
+    (&"pinglun", &"评论"),
+    (&"pingmu", &"屏幕"),
+    (&"pingzhang", &"屏障"),
+    (&"po", &"坡"),
+    (&"po", &"泼"),
+    (&"po", &"颇"),
+    (&"pobujidai", &"迫不及待"),
+    (&"pohai", &"迫害"),
+    (&"poli", &"破例"),
+    (&"poli", &"魄力"),
+    (&"pu", &"扑"),
+    (&"pu", &"铺"),
+    (&"pushi", &"朴实"),
+    (&"pusu", &"朴素"),
+    (&"puji", &"普及"),
+    (&"pubu", &"瀑布"),
+    (&"qiliang", &"凄凉"),
+    (&"qiwang", &"期望"),
+    (&"qixian", &"期限"),
+    (&"qifu", &"欺负"),
+    (&"qipian", &"欺骗"),
+    (&"qiquan", &"齐全"),
+    (&"qixinxieli", &"齐心协力"),
+    (&"qimiao", &"奇妙"),
+    (&"qishi", &"歧视"),
+    (&"qipao", &"旗袍"),
+    (&"qizhi", &"旗帜"),
+    (&"qigai", &"乞丐"),
+    (&"qiyoucili", &"岂有此理"),
+    (&"qitu", &"企图"),
+    (&"qicheng", &"启程"),
+    (&"qimeng", &"启蒙"),
+    (&"qishi", &"启示"),
+    (&"qishi", &"启事"),
+    (&"qicao", &"起草"),
+    (&"qichu", &"起初"),
+    (&"qifu", &"起伏"),
+    (&"qihong", &"起哄"),
+    (&"qima", &"起码"),
+    (&"qiyuan", &"起源"),
+    (&"qigai", &"气概"),
+    (&"qigong", &"气功"),
+    (&"qipo", &"气魄"),
+    (&"qise", &"气色"),
+    (&"qishi", &"气势"),
+    (&"qiwei", &"气味"),
+    (&"qixiang", &"气象"),
+    (&"qiya", &"气压"),
+    (&"qizhi", &"气质"),
+    (&"qijinweizhi", &"迄今为止"),
+    (&"qicai", &"器材"),
+    (&"qiguan", &"器官"),
+    (&"qia", &"掐"),
+    (&"qiatan", &"洽谈"),
+    (&"qiadang", &"恰当"),
+    (&"qiadaohaochu", &"恰到好处"),
+    (&"qiaqiao", &"恰巧"),
+    (&"qianfangbaiji", &"千方百计"),
+    (&"qianjiu", &"迁就"),
+    (&"qianxi", &"迁徙"),
+    (&"qian", &"牵"),
+    (&"qianche", &"牵扯"),
+    (&"qianzhi", &"牵制"),
+    (&"qianxun", &"谦逊"),
+    (&"qianshu", &"签署"),
+    (&"qianjing", &"前景"),
+    (&"qianti", &"前提"),
+    (&"qianli", &"潜力"),
+    (&"qianshui", &"潜水"),
+    (&"qianyimohua", &"潜移默化"),
+    (&"qianze", &"谴责"),
+    (&"qiangzhi", &"强制"),
+    (&"qiangjie", &"抢劫"),
+    (&"qiangjiu", &"抢救"),
+    (&"qiangpo", &"强迫"),
+    (&"qiaoliang", &"桥梁"),
+    (&"qiaomen", &"窍门"),
+    (&"qiao", &"翘"),
+    (&"qieshi", &"切实"),
+    (&"qieerbushe", &"锲而不舍"),
+    (&"qinpei", &"钦佩"),
+    (&"qinfan", &"侵犯"),
+    (&"qinlve", &"侵略"),
+    (&"qinmi", &"亲密"),
+    (&"qinre", &"亲热"),
+    (&"qinjian", &"勤俭"),
+    (&"qinlao", &"勤劳"),
+    (&"qingting", &"倾听"),
+    (&"qingxiang", &"倾向"),
+    (&"qingxie", &"倾斜"),
+    (&"qingche", &"清澈"),
+    (&"qingchen", &"清晨"),
+    (&"qingchu", &"清除"),
+    (&"qingjie", &"清洁"),
+    (&"qingli", &"清理"),
+    (&"qingxi", &"清晰"),
+    (&"qingxing", &"清醒"),
+    (&"qingzhen", &"清真"),
+    (&"qingbao", &"情报"),
+    (&"qingjie", &"情节"),
+    (&"qingli", &"情理"),
+    (&"qingxing", &"情形"),
+    (&"qinglang", &"晴朗"),
+    (&"qingjian", &"请柬"),
+    (&"qingjiao", &"请教"),
+    (&"qingshi", &"请示"),
+    (&"qingtie", &"请帖"),
+    (&"qiuling", &"丘陵"),
+    (&"qufen", &"区分"),
+    (&"quyu", &"区域"),
+    (&"quzhe", &"曲折"),
+    (&"quzhu", &"驱逐"),
+    (&"qufu", &"屈服"),
+    (&"qudao", &"渠道"),
+    (&"quzi", &"曲子"),
+    (&"qudi", &"取缔"),
+    (&"quwei", &"趣味"),
+    (&"quantao", &"圈套"),
+    (&"quanheng", &"权衡"),
+    (&"quanwei", &"权威"),
+    (&"quanju", &"全局"),
+    (&"quanliyifu", &"全力以赴"),
+    (&"quantou", &"拳头"),
+    (&"quan", &"犬"),
+    (&"quekou", &"缺口"),
+    (&"quexi", &"缺席"),
+    (&"quexian", &"缺陷"),
+    (&"que", &"瘸"),
+    (&"quebao", &"确保"),
+    (&"queli", &"确立"),
+    (&"queqie", &"确切"),
+    (&"quexin", &"确信"),
+    (&"qunzhong", &"群众"),
+    (&"ran", &"染"),
+    (&"rang", &"嚷"),
+    (&"rangbu", &"让步"),
+    (&"raoshu", &"饶恕"),
+    (&"raoluan", &"扰乱"),
+    (&"rehuo", &"惹祸"),
+    (&"releiyingkuang", &"热泪盈眶"),
+    (&"remen", &"热门"),
+    (&"rendao", &"人道"),
+    (&"renge", &"人格"),
+    (&"rengong", &"人工"),
+    (&"renjia", &"人家"),
+    (&"renjian", &"人间"),
+    (&"renshi", &"人士"),
+    (&"renwei", &"人为"),
+    (&"renxing", &"人性"),
+    (&"renzhi", &"人质"),
+    (&"renci", &"仁慈"),
+    (&"rennai", &"忍耐"),
+    (&"renshou", &"忍受"),
+    (&"rending", &"认定"),
+    (&"renke", &"认可"),
+    (&"renming", &"任命"),
+    (&"renxing", &"任性"),
+    (&"renyi", &"任意"),
+    (&"renzhongdaoyuan", &"任重道远"),
+    (&"rengjiu", &"仍旧"),
+    (&"rixinyueyi", &"日新月异"),
+    (&"riyi", &"日益"),
+    (&"rongxing", &"荣幸"),
+    (&"rongyu", &"荣誉"),
+    (&"rongmao", &"容貌"),
+    (&"rongna", &"容纳"),
+    (&"rongqi", &"容器"),
+    (&"rongren", &"容忍"),
+    (&"rongjie", &"溶解"),
+    (&"ronghua", &"融化"),
+    (&"rongqia", &"融洽"),
+    (&"rouhe", &"柔和"),
+    (&"rou", &"揉"),
+    (&"rujia", &"儒家"),
+    (&"ruogan", &"若干"),
+    (&"ruodian", &"弱点"),
+    (&"sahuang", &"撒谎"),
+    (&"sanwen", &"散文"),
+    (&"sanbu", &"散布"),
+    (&"sanfa", &"散发"),
+    (&"sangshi", &"丧失"),
+    (&"saorao", &"骚扰"),
+    (&"saozi", &"嫂子"),
+    (&"shache", &"刹车"),
+    (&"sha", &"啥"),
+    (&"shaixuan", &"筛选"),
+    (&"shanmai", &"山脉"),
+    (&"shanshuo", &"闪烁"),
+    (&"shanchang", &"擅长"),
+    (&"shanzi", &"擅自"),
+    (&"shangnaojin", &"伤脑筋"),
+    (&"shangbiao", &"商标"),
+    (&"shangji", &"上级"),
+    (&"shangjin", &"上进"),
+    (&"shangren", &"上任"),
+    (&"shangyin", &"上瘾"),
+    (&"shangyou", &"上游"),
+    (&"shangqie", &"尚且"),
+    (&"shao", &"捎"),
+    (&"shao", &"梢"),
+    (&"shao", &"哨"),
+    (&"shechi", &"奢侈"),
+    (&"shetou", &"舌头"),
+    (&"sheli", &"设立"),
+    (&"shexiang", &"设想"),
+    (&"shezhi", &"设置"),
+    (&"shequ", &"社区"),
+    (&"sheji", &"涉及"),
+    (&"sheshidu", &"摄氏度"),
+    (&"shenbao", &"申报"),
+    (&"shenyin", &"呻吟"),
+    (&"shenshi", &"绅士"),
+    (&"shenao", &"深奥"),
+    (&"shenchen", &"深沉"),
+    (&"shenqinghouyi", &"深情厚谊"),
+    (&"shenjing", &"神经"),
+    (&"shenqi", &"神奇"),
+    (&"shenqi", &"神气"),
+    (&"shensheng", &"神圣"),
+    (&"shentai", &"神态"),
+    (&"shenxian", &"神仙"),
+    (&"shencha", &"审查"),
+    (&"shenli", &"审理"),
+    (&"shenmei", &"审美"),
+    (&"shenpan", &"审判"),
+    (&"shentou", &"渗透"),
+    (&"shenzhong", &"慎重"),
+    (&"shengcun", &"生存"),
+    (&"shengji", &"生机"),
+    (&"shengli", &"生理"),
+    (&"shengshu", &"生疏"),
+    (&"shengtai", &"生态"),
+    (&"shengwu", &"生物"),
+    (&"shengxiao", &"生肖"),
+    (&"shengxiao", &"生效"),
+    (&"shengxiu", &"生锈"),
+    (&"shengyu", &"生育"),
+    (&"shengming", &"声明"),
+    (&"shengshi", &"声势"),
+    (&"shengyu", &"声誉"),
+    (&"shengchu", &"牲畜"),
+    (&"shenghui", &"省会"),
+    (&"shengfu", &"胜负"),
+    (&"shengchan", &"盛产"),
+    (&"shengkai", &"盛开"),
+    (&"shengqing", &"盛情"),
+    (&"shengxing", &"盛行"),
+    (&"shiti", &"尸体"),
+    (&"shishi", &"失事"),
+    (&"shiwu", &"失误"),
+    (&"shizong", &"失踪"),
+    (&"shifan", &"师范"),
+    (&"shijia", &"施加"),
+    (&"shizhan", &"施展"),
+    (&"shizu", &"十足"),
+    (&"shiyou", &"石油"),
+    (&"shichang", &"时常"),
+    (&"shier", &"时而"),
+    (&"shiguang", &"时光"),
+    (&"shiji", &"时机"),
+    (&"shishi", &"时事"),
+    (&"shibie", &"识别"),
+    (&"shihui", &"实惠"),
+    (&"shili", &"实力"),
+    (&"shishi", &"实施"),
+    (&"shishiqiushi", &"实事求是"),
+    (&"shixing", &"实行"),
+    (&"shizhi", &"实质"),
+    (&"shi", &"拾"),
+    (&"shiming", &"使命"),
+    (&"shifan", &"示范"),
+    (&"shiwei", &"示威"),
+    (&"shiyi", &"示意"),
+    (&"shidai", &"世代"),
+    (&"shibi", &"势必"),
+    (&"shili", &"势力"),
+    (&"shigu", &"事故"),
+    (&"shiji", &"事迹"),
+    (&"shijian", &"事件"),
+    (&"shitai", &"事态"),
+    (&"shiwu", &"事务"),
+    (&"shixiang", &"事项"),
+    (&"shiye", &"事业"),
+    (&"shitu", &"试图"),
+    (&"shiyan", &"试验"),
+    (&"shili", &"视力"),
+    (&"shipin", &"视频"),
+    (&"shixian", &"视线"),
+    (&"shiye", &"视野"),
+    (&"shifei", &"是非"),
+    (&"shiyi", &"适宜"),
+    (&"shishi", &"逝世"),
+    (&"shifang", &"释放"),
+    (&"shoucang", &"收藏"),
+    (&"shousuo", &"收缩"),
+    (&"shouyi", &"收益"),
+    (&"shouyinji", &"收音机"),
+    (&"shoufa", &"手法"),
+    (&"shoushi", &"手势"),
+    (&"shouyi", &"手艺"),
+    (&"shouhu", &"守护"),
+    (&"shoushi", &"首饰"),
+    (&"shouyao", &"首要"),
+    (&"shouzui", &"受罪"),
+    (&"shouyu", &"授予"),
+    (&"shufa", &"书法"),
+    (&"shuji", &"书籍"),
+    (&"shuji", &"书记"),
+    (&"shumian", &"书面"),
+    (&"shuchang", &"舒畅"),
+    (&"shuhu", &"疏忽"),
+    (&"shuyuan", &"疏远"),
+    (&"shu", &"束"),
+    (&"shufu", &"束缚"),
+    (&"shuli", &"树立"),
+    (&"shu", &"竖"),
+    (&"shue", &"数额"),
+    (&"shua", &"耍"),
+    (&"shuailao", &"衰老"),
+    (&"shuaitui", &"衰退"),
+    (&"shuailing", &"率领"),
+    (&"shuanhuoguo", &"涮火锅"),
+    (&"shuangbaotai", &"双胞胎"),
+    (&"shuangkuai", &"爽快"),
+    (&"shuili", &"水利"),
+    (&"shuilongtou", &"水龙头"),
+    (&"shuini", &"水泥"),
+    (&"shunjian", &"瞬间"),
+    (&"sifa", &"司法"),
+    (&"siling", &"司令"),
+    (&"sizi", &"私自"),
+    (&"sinian", &"思念"),
+    (&"sisuo", &"思索"),
+    (&"siwei", &"思维"),
+    (&"siwen", &"斯文"),
+    (&"siwang", &"死亡"),
+    (&"sizhi", &"四肢"),
+    (&"simiao", &"寺庙"),
+    (&"siyang", &"饲养"),
+    (&"siwujidan", &"肆无忌惮"),
+    (&"song", &"耸"),
+    (&"sou", &"艘"),
+    (&"suxing", &"苏醒"),
+    (&"suhua", &"俗话"),
+    (&"susong", &"诉讼"),
+    (&"sushi", &"素食"),
+    (&"suzhi", &"素质"),
+    (&"suzao", &"塑造"),
+    (&"suanshu", &"算数"),
+    (&"suiji", &"随即"),
+    (&"suiyi", &"随意"),
+    (&"suiyue", &"岁月"),
+    (&"suidao", &"隧道"),
+    (&"sunhuai", &"损坏"),
+    (&"suoqu", &"索取"),
+    (&"suoxing", &"索性"),
+    (&"ta", &"塌"),
+    (&"tashi", &"踏实"),
+    (&"ta", &"塔"),
+    (&"taifeng", &"台风"),
+    (&"taikong", &"太空"),
+    (&"taidou", &"泰斗"),
+    (&"tanlan", &"贪婪"),
+    (&"tanwu", &"贪污"),
+    (&"tan", &"摊"),
+    (&"tanhuan", &"瘫痪"),
+    (&"tanxing", &"弹性"),
+    (&"tanbai", &"坦白"),
+    (&"tanqi", &"叹气"),
+    (&"tance", &"探测"),
+    (&"tansuo", &"探索"),
+    (&"tantao", &"探讨"),
+    (&"tanwang", &"探望"),
+    (&"tangruo", &"倘若"),
+    (&"tao", &"掏"),
+    (&"taotaobujue", &"滔滔不绝"),
+    (&"taoci", &"陶瓷"),
+    (&"taozui", &"陶醉"),
+    (&"taotai", &"淘汰"),
+    (&"taohao", &"讨好"),
+    (&"techang", &"特长"),
+    (&"teding", &"特定"),
+    (&"teyi", &"特意"),
+    (&"tiba", &"提拔"),
+    (&"tilian", &"提炼"),
+    (&"tishi", &"提示"),
+    (&"tiyi", &"提议"),
+    (&"ticai", &"题材"),
+    (&"ticai", &"体裁"),
+    (&"tiji", &"体积"),
+    (&"tiliang", &"体谅"),
+    (&"timian", &"体面"),
+    (&"tixi", &"体系"),
+    (&"tiancai", &"天才"),
+    (&"tianfu", &"天赋"),
+    (&"tianlunzhile", &"天伦之乐"),
+    (&"tianranqi", &"天然气"),
+    (&"tiansheng", &"天生"),
+    (&"tiantang", &"天堂"),
+    (&"tianwen", &"天文"),
+    (&"tianjing", &"田径"),
+    (&"tianye", &"田野"),
+    (&"tian", &"舔"),
+    (&"tiaoti", &"挑剔"),
+    (&"tiaokuan", &"条款"),
+    (&"tiaoli", &"条理"),
+    (&"tiaoyue", &"条约"),
+    (&"tiaohe", &"调和"),
+    (&"tiaoji", &"调剂"),
+    (&"tiaojie", &"调节"),
+    (&"tiaojie", &"调解"),
+    (&"tiaoliao", &"调料"),
+    (&"tiaobo", &"挑拨"),
+    (&"tiaoxin", &"挑衅"),
+    (&"tiaoyue", &"跳跃"),
+    (&"tingzi", &"亭子"),
+    (&"tingbo", &"停泊"),
+    (&"tingdun", &"停顿"),
+    (&"tingzhi", &"停滞"),
+    (&"tingba", &"挺拔"),
+    (&"tonghuopengzhang", &"通货膨胀"),
+    (&"tongji", &"通缉"),
+    (&"tongsu", &"通俗"),
+    (&"tongxun", &"通讯"),
+    (&"tongyong", &"通用"),
+    (&"tongbao", &"同胞"),
+    (&"tongzhi", &"同志"),
+    (&"tong", &"铜"),
+    (&"tonghua", &"童话"),
+    (&"tongchoujiangu", &"统筹兼顾"),
+    (&"tongji", &"统计"),
+    (&"tongtong", &"统统"),
+    (&"tongzhi", &"统治"),
+    (&"touji", &"投机"),
+    (&"toupiao", &"投票"),
+    (&"tousu", &"投诉"),
+    (&"touxiang", &"投降"),
+    (&"touzhi", &"投掷"),
+    (&"toulu", &"透露"),
+    (&"tu", &"秃"),
+    (&"tupo", &"突破"),
+    (&"tuan", &"图案"),
+    (&"tudi", &"徒弟"),
+    (&"tujing", &"途径"),
+    (&"tumo", &"涂抹"),
+    (&"turang", &"土壤"),
+    (&"tuanjie", &"团结"),
+    (&"tuanti", &"团体"),
+    (&"tuanyuan", &"团圆"),
+    (&"tuice", &"推测"),
+    (&"tuifan", &"推翻"),
+    (&"tuili", &"推理"),
+    (&"tuilun", &"推论"),
+    (&"tuixiao", &"推销"),
+    (&"tuntuntutu", &"吞吞吐吐"),
+    (&"tuoyun", &"托运"),
+    (&"tuoyan", &"拖延"),
+    (&"tuoli", &"脱离"),
+    (&"tuodang", &"妥当"),
+    (&"tuoshan", &"妥善"),
+    (&"tuoxie", &"妥协"),
+    (&"tuoyuan", &"椭圆"),
+    (&"tuoqi", &"唾弃"),
+    (&"wajue", &"挖掘"),
+    (&"wa", &"哇"),
+    (&"wawa", &"娃娃"),
+    (&"wajie", &"瓦解"),
+    (&"waiqu", &"歪曲"),
+    (&"waibiao", &"外表"),
+    (&"waihang", &"外行"),
+    (&"waijie", &"外界"),
+    (&"waixiang", &"外向"),
+    (&"wan", &"丸"),
+    (&"wanbei", &"完备"),
+    (&"wanbi", &"完毕"),
+    (&"wannong", &"玩弄"),
+    (&"wanyir", &"玩意儿"),
+    (&"wangu", &"顽固"),
+    (&"wanqiang", &"顽强"),
+    (&"wanhui", &"挽回"),
+    (&"wanjiu", &"挽救"),
+    (&"wanxi", &"惋惜"),
+    (&"wanfen", &"万分"),
+    (&"wangchang", &"往常"),
+    (&"wangshi", &"往事"),
+    (&"wangxiang", &"妄想"),
+    (&"weiji", &"危机"),
+    (&"weifeng", &"威风"),
+    (&"weili", &"威力"),
+    (&"weiwang", &"威望"),
+    (&"weixin", &"威信"),
+    (&"weibuzudao", &"微不足道"),
+    (&"weiguan", &"微观"),
+    (&"weinan", &"为难"),
+    (&"weiqi", &"为期"),
+    (&"weibei", &"违背"),
+    (&"weidu", &"唯独"),
+    (&"weichi", &"维持"),
+    (&"weihu", &"维护"),
+    (&"weishengsu", &"维生素"),
+    (&"weizao", &"伪造"),
+    (&"weituo", &"委托"),
+    (&"weiyuan", &"委员"),
+    (&"weixing", &"卫星"),
+    (&"weimian", &"未免"),
+    (&"weiju", &"畏惧"),
+    (&"wei", &"喂"),
+    (&"weilan", &"蔚蓝"),
+    (&"weiwen", &"慰问"),
+    (&"wendai", &"温带"),
+    (&"wenhe", &"温和"),
+    (&"wenping", &"文凭"),
+    (&"wenwu", &"文物"),
+    (&"wenxian", &"文献"),
+    (&"wenya", &"文雅"),
+    (&"wenyi", &"文艺"),
+    (&"wenshi", &"问世"),
+    (&"wo", &"窝"),
+    (&"wuhei", &"乌黑"),
+    (&"wumie", &"污蔑"),
+    (&"wuxian", &"诬陷"),
+    (&"wubi", &"无比"),
+    (&"wuchang", &"无偿"),
+    (&"wuchi", &"无耻"),
+    (&"wudongyuzhong", &"无动于衷"),
+    (&"wufei", &"无非"),
+    (&"wugu", &"无辜"),
+    (&"wujingdacai", &"无精打采"),
+    (&"wulai", &"无赖"),
+    (&"wuliqunao", &"无理取闹"),
+    (&"wunengweili", &"无能为力"),
+    (&"wuqiongwujin", &"无穷无尽"),
+    (&"wuweibuzhi", &"无微不至"),
+    (&"wuyouwulv", &"无忧无虑"),
+    (&"wuzhi", &"无知"),
+    (&"wuqi", &"武器"),
+    (&"wuxia", &"武侠"),
+    (&"wuzhuang", &"武装"),
+    (&"wuru", &"侮辱"),
+    (&"wudao", &"舞蹈"),
+    (&"wubi", &"务必"),
+    (&"wumeijialian", &"物美价廉"),
+    (&"wuye", &"物业"),
+    (&"wuzi", &"物资"),
+    (&"wucha", &"误差"),
+    (&"wujie", &"误解"),
+    (&"xiyang", &"夕阳"),
+    (&"xiri", &"昔日"),
+    (&"xisheng", &"牺牲"),
+    (&"xi", &"溪"),
+    (&"ximie", &"熄灭"),
+    (&"xigai", &"膝盖"),
+    (&"xisu", &"习俗"),
+    (&"xiji", &"袭击"),
+    (&"xifu", &"媳妇"),
+    (&"xiwenlejian", &"喜闻乐见"),
+    (&"xiyue", &"喜悦"),
+    (&"xilie", &"系列"),
+    (&"xibao", &"细胞"),
+    (&"xijun", &"细菌"),
+    (&"xizhi", &"细致"),
+    (&"xiagu", &"峡谷"),
+    (&"xiaai", &"狭隘"),
+    (&"xiazhai", &"狭窄"),
+    (&"xia", &"霞"),
+    (&"xiashu", &"下属"),
+    (&"xianjin", &"先进"),
+    (&"xianqian", &"先前"),
+    (&"xianwei", &"纤维"),
+    (&"xianqi", &"掀起"),
+    (&"xianming", &"鲜明"),
+    (&"xianhua", &"闲话"),
+    (&"xianhui", &"贤惠"),
+    (&"xian", &"弦"),
+    (&"xianjie", &"衔接"),
+    (&"xian", &"嫌"),
+    (&"xianyi", &"嫌疑"),
+    (&"xianzhu", &"显著"),
+    (&"xianchang", &"现场"),
+    (&"xiancheng", &"现成"),
+    (&"xianzhuang", &"现状"),
+    (&"xiansuo", &"线索"),
+    (&"xianfa", &"宪法"),
+    (&"xianhai", &"陷害"),
+    (&"xianjing", &"陷阱"),
+    (&"xianru", &"陷入"),
+    (&"xianr", &"馅儿"),
+    (&"xiangzhen", &"乡镇"),
+    (&"xiangcha", &"相差"),
+    (&"xiangdeng", &"相等"),
+    (&"xiangfuxiangcheng", &"相辅相成"),
+    (&"xiangying", &"相应"),
+    (&"xiangqian", &"镶嵌"),
+    (&"xiangliang", &"响亮"),
+    (&"xiangying", &"响应"),
+    (&"xiangfangshefa", &"想方设法"),
+    (&"xiangdao", &"向导"),
+    (&"xianglai", &"向来"),
+    (&"xiangwang", &"向往"),
+    (&"xiang", &"巷"),
+    (&"xiangsheng", &"相声"),
+    (&"xue", &"削"),
+    (&"xiaochu", &"消除"),
+    (&"xiaodu", &"消毒"),
+    (&"xiaofang", &"消防"),
+    (&"xiaohao", &"消耗"),
+    (&"xiaomie", &"消灭"),
+    (&"xiaohui", &"销毁"),
+    (&"xiaosa", &"潇洒"),
+    (&"xiaoxinyiyi", &"小心翼翼"),
+    (&"xiaoxiang", &"肖像"),
+    (&"xiaoyi", &"效益"),
+    (&"xiehui", &"协会"),
+    (&"xieshang", &"协商"),
+    (&"xietiao", &"协调"),
+    (&"xieyi", &"协议"),
+    (&"xiezhu", &"协助"),
+    (&"xiedai", &"携带"),
+    (&"xielou", &"泄露"),
+    (&"xieqi", &"泄气"),
+    (&"xie", &"屑"),
+    (&"xiejue", &"谢绝"),
+    (&"xinde", &"心得"),
+    (&"xinganqingyuan", &"心甘情愿"),
+    (&"xinling", &"心灵"),
+    (&"xintai", &"心态"),
+    (&"xinteng", &"心疼"),
+    (&"xinxue", &"心血"),
+    (&"xinyanr", &"心眼儿"),
+    (&"xinqin", &"辛勤"),
+    (&"xinwei", &"欣慰"),
+    (&"xinxinxiangrong", &"欣欣向荣"),
+    (&"xinchendaixie", &"新陈代谢"),
+    (&"xinlang", &"新郎"),
+    (&"xinniang", &"新娘"),
+    (&"xinying", &"新颖"),
+    (&"xinshui", &"薪水"),
+    (&"xinlai", &"信赖"),
+    (&"xinnian", &"信念"),
+    (&"xinyang", &"信仰"),
+    (&"xinyu", &"信誉"),
+    (&"xinglong", &"兴隆"),
+    (&"xingwang", &"兴旺"),
+    (&"xing", &"腥"),
+    (&"xingshi", &"刑事"),
+    (&"xingzheng", &"行政"),
+    (&"xingtai", &"形态"),
+    (&"xinggaocailie", &"兴高采烈"),
+    (&"xingzhibobo", &"兴致勃勃"),
+    (&"xinggan", &"性感"),
+    (&"xingming", &"性命"),
+    (&"xingneng", &"性能"),
+    (&"xionge", &"凶恶"),
+    (&"xiongshou", &"凶手"),
+    (&"xiongyong", &"汹涌"),
+    (&"xionghuai", &"胸怀"),
+    (&"xiongtang", &"胸膛"),
+    (&"xionghou", &"雄厚"),
+    (&"xiongwei", &"雄伟"),
+    (&"xiufu", &"修复"),
+    (&"xiujian", &"修建"),
+    (&"xiuyang", &"修养"),
+    (&"xiuchi", &"羞耻"),
+    (&"xiu", &"绣"),
+    (&"xiujue", &"嗅觉"),
+    (&"xuzhi", &"须知"),
+    (&"xujia", &"虚假"),
+    (&"xurong", &"虚荣"),
+    (&"xuwei", &"虚伪"),
+    (&"xuqiu", &"需求"),
+    (&"xuke", &"许可"),
+    (&"xuyan", &"序言"),
+    (&"xumu", &"畜牧"),
+    (&"xujiu", &"酗酒"),
+    (&"xuanshi", &"宣誓"),
+    (&"xuanyang", &"宣扬"),
+    (&"xuanhua", &"喧哗"),
+    (&"xuangua", &"悬挂"),
+    (&"xuannian", &"悬念"),
+    (&"xuanshu", &"悬殊"),
+    (&"xuanyaqiaobi", &"悬崖峭壁"),
+    (&"xuanlv", &"旋律"),
+    (&"xuanzhuan", &"旋转"),
+    (&"xuanba", &"选拔"),
+    (&"xuanju", &"选举"),
+    (&"xuanshou", &"选手"),
+    (&"xuanyao", &"炫耀"),
+    (&"xueruo", &"削弱"),
+    (&"xueshuo", &"学说"),
+    (&"xuewei", &"学位"),
+    (&"xueshangjiashuang", &"雪上加霜"),
+    (&"xueya", &"血压"),
+    (&"xuntao", &"熏陶"),
+    (&"xunmi", &"寻觅"),
+    (&"xunluo", &"巡逻"),
+    (&"xunhuan", &"循环"),
+    (&"xunxujianjin", &"循序渐进"),
+    (&"yapo", &"压迫"),
+    (&"yasuiqian", &"压岁钱"),
+    (&"yasuo", &"压缩"),
+    (&"yayi", &"压抑"),
+    (&"yazha", &"压榨"),
+    (&"yazhi", &"压制"),
+    (&"yaquewusheng", &"鸦雀无声"),
+    (&"yajun", &"亚军"),
+    (&"yanhuabaozhu", &"烟花爆竹"),
+    (&"yanmo", &"淹没"),
+    (&"yanqi", &"延期"),
+    (&"yanshen", &"延伸"),
+    (&"yanxu", &"延续"),
+    (&"yanhan", &"严寒"),
+    (&"yanjin", &"严禁"),
+    (&"yanjun", &"严峻"),
+    (&"yanli", &"严厉"),
+    (&"yanmi", &"严密"),
+    (&"yanlun", &"言论"),
+    (&"yanshi", &"岩石"),
+    (&"yanre", &"炎热"),
+    (&"yanhai", &"沿海"),
+    (&"yangai", &"掩盖"),
+    (&"yanhu", &"掩护"),
+    (&"yanshi", &"掩饰"),
+    (&"yanguang", &"眼光"),
+    (&"yanse", &"眼色"),
+    (&"yanshen", &"眼神"),
+    (&"yanbian", &"演变"),
+    (&"yanxi", &"演习"),
+    (&"yanyi", &"演绎"),
+    (&"yanzou", &"演奏"),
+    (&"yanwu", &"厌恶"),
+    (&"yanshou", &"验收"),
+    (&"yanzheng", &"验证"),
+    (&"yangqi", &"氧气"),
+    (&"yangpin", &"样品"),
+    (&"yaoyan", &"谣言"),
+    (&"yaobai", &"摇摆"),
+    (&"yaogun", &"摇滚"),
+    (&"yaokong", &"遥控"),
+    (&"yaoyuan", &"遥远"),
+    (&"yaodian", &"要点"),
+    (&"yaoming", &"要命"),
+    (&"yaosu", &"要素"),
+    (&"yaoyan", &"耀眼"),
+    (&"yeman", &"野蛮"),
+    (&"yexin", &"野心"),
+    (&"yeti", &"液体"),
+    (&"yidu", &"一度"),
+    (&"yifanfengshun", &"一帆风顺"),
+    (&"yiguan", &"一贯"),
+    (&"yijuliangde", &"一举两得"),
+    (&"yiliu", &"一流"),
+    (&"yimuliaoran", &"一目了然"),
+    (&"yirujiwang", &"一如既往"),
+    (&"yisibugou", &"一丝不苟"),
+    (&"yixiang", &"一向"),
+    (&"yishang", &"衣裳"),
+    (&"yijiu", &"依旧"),
+    (&"yiju", &"依据"),
+    (&"yikao", &"依靠"),
+    (&"yilai", &"依赖"),
+    (&"yituo", &"依托"),
+    (&"yiqi", &"仪器"),
+    (&"yishi", &"仪式"),
+    (&"yichan", &"遗产"),
+    (&"yichuan", &"遗传"),
+    (&"yiliu", &"遗留"),
+    (&"yishi", &"遗失"),
+    (&"yihuo", &"疑惑"),
+    (&"yibian", &"以便"),
+    (&"yimian", &"以免"),
+    (&"yiwang", &"以往"),
+    (&"yizhi", &"以至"),
+    (&"yizhi", &"以致"),
+    (&"yi", &"亦"),
+    (&"yichang", &"异常"),
+    (&"yiliao", &"意料"),
+    (&"yishi", &"意识"),
+    (&"yitu", &"意图"),
+    (&"yiweizhe", &"意味着"),
+    (&"yixiang", &"意向"),
+    (&"yizhi", &"意志"),
+    (&"yili", &"毅力"),
+    (&"yiran", &"毅然"),
+    (&"yi", &"翼"),
+    (&"yinmou", &"阴谋"),
+    (&"yinxiang", &"音响"),
+    (&"yindao", &"引导"),
+    (&"yinqing", &"引擎"),
+    (&"yinyong", &"引用"),
+    (&"yinshi", &"饮食"),
+    (&"yinbi", &"隐蔽"),
+    (&"yinhuan", &"隐患"),
+    (&"yinman", &"隐瞒"),
+    (&"yinsi", &"隐私"),
+    (&"yinyue", &"隐约"),
+    (&"yingming", &"英明"),
+    (&"yingyong", &"英勇"),
+    (&"yinger", &"婴儿"),
+    (&"yingmian", &"迎面"),
+    (&"yingli", &"盈利"),
+    (&"yingchou", &"应酬"),
+    (&"yingyao", &"应邀"),
+    (&"yonghu", &"拥护"),
+    (&"yongyou", &"拥有"),
+    (&"yongsu", &"庸俗"),
+    (&"yongheng", &"永恒"),
+    (&"yongyu", &"勇于"),
+    (&"yongxian", &"涌现"),
+    (&"yongyue", &"踊跃"),
+    (&"yonghu", &"用户"),
+    (&"youshenglietai", &"优胜劣汰"),
+    (&"youxian", &"优先"),
+    (&"youyi", &"优异"),
+    (&"youyue", &"优越"),
+    (&"youyu", &"忧郁"),
+    (&"youru", &"犹如"),
+    (&"youni", &"油腻"),
+    (&"youqi", &"油漆"),
+    (&"youtiaobuwen", &"有条不紊"),
+    (&"youzhi", &"幼稚"),
+    (&"youhuo", &"诱惑"),
+    (&"yumin", &"渔民"),
+    (&"yuchun", &"愚蠢"),
+    (&"yumei", &"愚昧"),
+    (&"yulun", &"舆论"),
+    (&"yurijuzeng", &"与日俱增"),
+    (&"yuzhou", &"宇宙"),
+    (&"yurongfu", &"羽绒服"),
+    (&"yu", &"玉"),
+    (&"yuliao", &"预料"),
+    (&"yuqi", &"预期"),
+    (&"yusuan", &"预算"),
+    (&"yuxian", &"预先"),
+    (&"yuyan", &"预言"),
+    (&"yuzhao", &"预兆"),
+    (&"yuwang", &"欲望"),
+    (&"yuyan", &"寓言"),
+    (&"yu", &"愈"),
+    (&"yuanwang", &"冤枉"),
+    (&"yuanshou", &"元首"),
+    (&"yuansu", &"元素"),
+    (&"yuanxiaojie", &"元宵节"),
+    (&"yuanlin", &"园林"),
+    (&"yuangao", &"原告"),
+    (&"yuanli", &"原理"),
+    (&"yuanshi", &"原始"),
+    (&"yuanxian", &"原先"),
+    (&"yuanman", &"圆满"),
+    (&"yuangu", &"缘故"),
+    (&"yuanquan", &"源泉"),
+    (&"yueshu", &"约束"),
+    (&"yuepu", &"乐谱"),
+    (&"yuemu", &"岳母"),
+    (&"yunyu", &"孕育"),
+    (&"yunsuan", &"运算"),
+    (&"yunxing", &"运行"),
+    (&"yunniang", &"酝酿"),
+    (&"yuncang", &"蕴藏"),
+    (&"yun", &"熨"),
+    (&"zaji", &"杂技"),
+    (&"zajiao", &"杂交"),
+    (&"za", &"砸"),
+    (&"za", &"咋"),
+    (&"zainan", &"灾难"),
+    (&"zaipei", &"栽培"),
+    (&"zai", &"宰"),
+    (&"zaijiezaili", &"再接再厉"),
+    (&"zaiyi", &"在意"),
+    (&"zan", &"攒"),
+    (&"zanqie", &"暂且"),
+    (&"zantan", &"赞叹"),
+    (&"zanzhu", &"赞助"),
+    (&"zaoshou", &"遭受"),
+    (&"zaoyang", &"遭殃"),
+    (&"zaoyu", &"遭遇"),
+    (&"zaota", &"糟蹋"),
+    (&"zaoxing", &"造型"),
+    (&"zaoyin", &"噪音"),
+    (&"zeguai", &"责怪"),
+    (&"zei", &"贼"),
+    (&"zengtian", &"增添"),
+    (&"zengsong", &"赠送"),
+    (&"zha", &"扎"),
+    (&"zhashi", &"扎实"),
+    (&"zha", &"渣"),
+    (&"zha", &"眨"),
+    (&"zhapian", &"诈骗"),
+    (&"zhaiyao", &"摘要"),
+    (&"zhaiquan", &"债券"),
+    (&"zhanguang", &"沾光"),
+    (&"zhanyang", &"瞻仰"),
+    (&"zhandingjietie", &"斩钉截铁"),
+    (&"zhanshi", &"展示"),
+    (&"zhanwang", &"展望"),
+    (&"zhanxian", &"展现"),
+    (&"zhanxin", &"崭新"),
+    (&"zhanju", &"占据"),
+    (&"zhanling", &"占领"),
+    (&"zhandou", &"战斗"),
+    (&"zhanlve", &"战略"),
+    (&"zhanshu", &"战术"),
+    (&"zhanyi", &"战役"),
+    (&"zhangcheng", &"章程"),
+    (&"zhangpeng", &"帐篷"),
+    (&"zhangai", &"障碍"),
+    (&"zhaobiao", &"招标"),
+    (&"zhaoshou", &"招收"),
+    (&"zhaoqipengbo", &"朝气蓬勃"),
+    (&"zhaomi", &"着迷"),
+    (&"zhaoze", &"沼泽"),
+    (&"zhaoyang", &"照样"),
+    (&"zhaoyao", &"照耀"),
+    (&"zheteng", &"折腾"),
+    (&"zhedang", &"遮挡"),
+    (&"zhe", &"折"),
+    (&"zhemo", &"折磨"),
+    (&"zhentan", &"侦探"),
+    (&"zhengui", &"珍贵"),
+    (&"zhenxi", &"珍稀"),
+    (&"zhenzhu", &"珍珠"),
+    (&"zhenli", &"真理"),
+    (&"zhenxiang", &"真相"),
+    (&"zhenzhi", &"真挚"),
+    (&"zhenzhuo", &"斟酌"),
+    (&"zhentou", &"枕头"),
+    (&"zhendi", &"阵地"),
+    (&"zhenrong", &"阵容"),
+    (&"zhenfen", &"振奋"),
+    (&"zhenxing", &"振兴"),
+    (&"zhenhan", &"震撼"),
+    (&"zhenjing", &"震惊"),
+    (&"zhending", &"镇定"),
+    (&"zhenjing", &"镇静"),
+    (&"zhengyue", &"正月"),
+    (&"zhengduan", &"争端"),
+    (&"zhengduo", &"争夺"),
+    (&"zhengqi", &"争气"),
+    (&"zhengxiankonghou", &"争先恐后"),
+    (&"zhengyi", &"争议"),
+    (&"zhengfu", &"征服"),
+    (&"zhengshou", &"征收"),
+    (&"zhengzha", &"挣扎"),
+    (&"zhengfa", &"蒸发"),
+    (&"zhengdun", &"整顿"),
+    (&"zhengdang", &"正当"),
+    (&"zhengfu", &"正负"),
+    (&"zhenggui", &"正规"),
+    (&"zhengjing", &"正经"),
+    (&"zhengqi", &"正气"),
+    (&"zhengyi", &"正义"),
+    (&"zhengzong", &"正宗"),
+    (&"zhengshi", &"证实"),
+    (&"zhengshu", &"证书"),
+    (&"zhengzhong", &"郑重"),
+    (&"zhengce", &"政策"),
+    (&"zhengquan", &"政权"),
+    (&"zhengzhuang", &"症状"),
+    (&"zhiji", &"之际"),
+    (&"zhicheng", &"支撑"),
+    (&"zhichu", &"支出"),
+    (&"zhiliu", &"支流"),
+    (&"zhipei", &"支配"),
+    (&"zhiyuan", &"支援"),
+    (&"zhizhu", &"支柱"),
+    (&"zhi", &"枝"),
+    (&"zhijue", &"知觉"),
+    (&"zhizuchangle", &"知足常乐"),
+    (&"zhifang", &"脂肪"),
+    (&"zhixing", &"执行"),
+    (&"zhizhuo", &"执着"),
+    (&"zhibo", &"直播"),
+    (&"zhijing", &"直径"),
+    (&"zhizi", &"侄子"),
+    (&"zhiban", &"值班"),
+    (&"zhineng", &"职能"),
+    (&"zhiwei", &"职位"),
+    (&"zhiwu", &"职务"),
+    (&"zhimindi", &"殖民地"),
+    (&"zhibiao", &"指标"),
+    (&"zhiding", &"指定"),
+    (&"zhijia", &"指甲"),
+    (&"zhiling", &"指令"),
+    (&"zhinanzhen", &"指南针"),
+    (&"zhishi", &"指示"),
+    (&"zhiwang", &"指望"),
+    (&"zhize", &"指责"),
+    (&"zhiqi", &"志气"),
+    (&"zhicai", &"制裁"),
+    (&"zhifu", &"制服"),
+    (&"zhiyue", &"制约"),
+    (&"zhizhi", &"制止"),
+    (&"zhian", &"治安"),
+    (&"zhili", &"治理"),
+    (&"zhici", &"致辞"),
+    (&"zhili", &"致力"),
+    (&"zhishi", &"致使"),
+    (&"zhili", &"智力"),
+    (&"zhineng", &"智能"),
+    (&"zhishang", &"智商"),
+    (&"zhiliu", &"滞留"),
+    (&"zhongduan", &"中断"),
+    (&"zhongli", &"中立"),
+    (&"zhongyang", &"中央"),
+    (&"zhongcheng", &"忠诚"),
+    (&"zhongshi", &"忠实"),
+    (&"zhongdian", &"终点"),
+    (&"zhongjiu", &"终究"),
+    (&"zhongshen", &"终身"),
+    (&"zhongzhi", &"终止"),
+    (&"zhongxin", &"衷心"),
+    (&"zhongliu", &"肿瘤"),
+    (&"zhongzi", &"种子"),
+    (&"zhongzu", &"种族"),
+    (&"zhongsuozhouzhi", &"众所周知"),
+    (&"zhongzhi", &"种植"),
+    (&"zhongxin", &"重心"),
+    (&"zhou", &"舟"),
+    (&"zhou", &"州"),
+    (&"zhoubian", &"周边"),
+    (&"zhoumi", &"周密"),
+    (&"zhounian", &"周年"),
+    (&"zhouqi", &"周期"),
+    (&"zhouzhe", &"周折"),
+    (&"zhouzhuan", &"周转"),
+    (&"zhou", &"粥"),
+    (&"zhouye", &"昼夜"),
+    (&"zhouwen", &"皱纹"),
+    (&"zhu", &"株"),
+    (&"zhuwei", &"诸位"),
+    (&"zhunian", &"逐年"),
+    (&"zhuban", &"主办"),
+    (&"zhudao", &"主导"),
+    (&"zhuguan", &"主管"),
+    (&"zhuliu", &"主流"),
+    (&"zhuquan", &"主权"),
+    (&"zhuyi", &"主义"),
+    (&"zhu", &"拄"),
+    (&"zhufu", &"嘱咐"),
+    (&"zhuli", &"助理"),
+    (&"zhushou", &"助手"),
+    (&"zhuzhai", &"住宅"),
+    (&"zhushe", &"注射"),
+    (&"zhushi", &"注视"),
+    (&"zhushi", &"注释"),
+    (&"zhuzhong", &"注重"),
+    (&"zhuzha", &"驻扎"),
+    (&"zhuzuo", &"著作"),
+    (&"zhuzao", &"铸造"),
+    (&"zhuai", &"拽"),
+    (&"zhuanchang", &"专长"),
+    (&"zhuancheng", &"专程"),
+    (&"zhuanli", &"专利"),
+    (&"zhuanti", &"专题"),
+    (&"zhuan", &"砖"),
+    (&"zhuanda", &"转达"),
+    (&"zhuanrang", &"转让"),
+    (&"zhuanyi", &"转移"),
+    (&"zhuanzhe", &"转折"),
+    (&"zhuanji", &"传记"),
+    (&"zhuangjia", &"庄稼"),
+    (&"zhuangyan", &"庄严"),
+    (&"zhuangzhong", &"庄重"),
+    (&"zhuangbei", &"装备"),
+    (&"zhuangxie", &"装卸"),
+    (&"zhuangguan", &"壮观"),
+    (&"zhuangli", &"壮丽"),
+    (&"zhuanglie", &"壮烈"),
+    (&"zhuang", &"幢"),
+    (&"zhuidao", &"追悼"),
+    (&"zhuijiu", &"追究"),
+    (&"zhui", &"坠"),
+    (&"zhunze", &"准则"),
+    (&"zhuoyue", &"卓越"),
+    (&"zhuoshou", &"着手"),
+    (&"zhuoxiang", &"着想"),
+    (&"zhuozhong", &"着重"),
+    (&"zitai", &"姿态"),
+    (&"ziben", &"资本"),
+    (&"zichan", &"资产"),
+    (&"zishen", &"资深"),
+    (&"zizhu", &"资助"),
+    (&"zirun", &"滋润"),
+    (&"ziwei", &"滋味"),
+    (&"zidan", &"子弹"),
+    (&"zibei", &"自卑"),
+    (&"zifa", &"自发"),
+    (&"ziligengsheng", &"自力更生"),
+    (&"ziman", &"自满"),
+    (&"zizhu", &"自主"),
+    (&"zongjiao", &"宗教"),
+    (&"zongzhi", &"宗旨"),
+    (&"zongse", &"棕色"),
+    (&"zongji", &"踪迹"),
+    (&"zongeryanzhi", &"总而言之"),
+    (&"zonghe", &"总和"),
+    (&"zongheng", &"纵横"),
+    (&"zoulang", &"走廊"),
+    (&"zoulou", &"走漏"),
+    (&"zousi", &"走私"),
+    (&"zou", &"揍"),
+    (&"zulin", &"租赁"),
+    (&"zuyi", &"足以"),
+    (&"zuai", &"阻碍"),
+    (&"zulan", &"阻拦"),
+    (&"zunao", &"阻挠"),
+    (&"zufu", &"祖父"),
+    (&"zuguo", &"祖国"),
+    (&"zuxian", &"祖先"),
+    (&"zuanyan", &"钻研"),
+    (&"zuanshi", &"钻石"),
+    (&"zuichun", &"嘴唇"),
+    (&"zuifan", &"罪犯"),
+    (&"zunyan", &"尊严"),
+    (&"zunxun", &"遵循"),
+    (&"zuomo", &"琢磨"),
+    (&"zuobi", &"作弊"),
+    (&"zuofei", &"作废"),
+    (&"zuofeng", &"作风"),
+    (&"zuoxi", &"作息"),
+    (&"zuoyouming", &"座右铭"),
+    (&"zuozhu", &"做主"),
     (&"<", &"《"),
     (&">", &"》"),
     (&".", &"。"),
@@ -8143,3 +5522,5009 @@ pub static PINYIN_CIYU_TEST_DATA: &[(&'static str, &'static str)] = &[
     (&"(", &"（"),
     (&")", &"）"),
 ];
+
+// Tuples are (HSK level, word) for words that are new at each level of the
+// official HSK word lists in vocab/official, spelled as they are there.
+#[cfg(test)]
+pub static OFFICIAL_HSK_TEST_DATA: &[(usize, &'static str)] = &[
+    (1, &"爱"),
+    (1, &"八"),
+    (1, &"爸爸"),
+    (1, &"杯子"),
+    (1, &"北京"),
+    (1, &"本"),
+    (1, &"不"),
+    (1, &"不客气"),
+    (1, &"菜"),
+    (1, &"茶"),
+    (1, &"吃"),
+    (1, &"出租车"),
+    (1, &"打电话"),
+    (1, &"大"),
+    (1, &"的"),
+    (1, &"点"),
+    (1, &"电脑"),
+    (1, &"电视"),
+    (1, &"电影"),
+    (1, &"东西"),
+    (1, &"都"),
+    (1, &"读"),
+    (1, &"对不起"),
+    (1, &"多"),
+    (1, &"多少"),
+    (1, &"儿子"),
+    (1, &"二"),
+    (1, &"饭店"),
+    (1, &"飞机"),
+    (1, &"分钟"),
+    (1, &"高兴"),
+    (1, &"个"),
+    (1, &"工作"),
+    (1, &"狗"),
+    (1, &"汉语"),
+    (1, &"好"),
+    (1, &"号"),
+    (1, &"喝"),
+    (1, &"和"),
+    (1, &"很"),
+    (1, &"后面"),
+    (1, &"回"),
+    (1, &"会"),
+    (1, &"几"),
+    (1, &"家"),
+    (1, &"叫"),
+    (1, &"今天"),
+    (1, &"九"),
+    (1, &"开"),
+    (1, &"看"),
+    (1, &"看见"),
+    (1, &"块"),
+    (1, &"来"),
+    (1, &"老师"),
+    (1, &"了"),
+    (1, &"冷"),
+    (1, &"里"),
+    (1, &"六"),
+    (1, &"妈妈"),
+    (1, &"吗"),
+    (1, &"买"),
+    (1, &"猫"),
+    (1, &"没关系"),
+    (1, &"没有"),
+    (1, &"米饭"),
+    (1, &"名字"),
+    (1, &"明天"),
+    (1, &"哪"),
+    (1, &"哪儿"),
+    (1, &"那"),
+    (1, &"呢"),
+    (1, &"能"),
+    (1, &"你"),
+    (1, &"年"),
+    (1, &"女儿"),
+    (1, &"朋友"),
+    (1, &"漂亮"),
+    (1, &"苹果"),
+    (1, &"七"),
+    (1, &"前面"),
+    (1, &"钱"),
+    (1, &"请"),
+    (1, &"去"),
+    (1, &"热"),
+    (1, &"人"),
+    (1, &"认识"),
+    (1, &"三"),
+    (1, &"商店"),
+    (1, &"上"),
+    (1, &"上午"),
+    (1, &"少"),
+    (1, &"谁"),
+    (1, &"什么"),
+    (1, &"十"),
+    (1, &"时候"),
+    (1, &"是"),
+    (1, &"书"),
+    (1, &"水"),
+    (1, &"水果"),
+    (1, &"睡觉"),
+    (1, &"说"),
+    (1, &"四"),
+    (1, &"岁"),
+    (1, &"他"),
+    (1, &"她"),
+    (1, &"太"),
+    (1, &"天气"),
+    (1, &"听"),
+    (1, &"同学"),
+    (1, &"喂（叹词）"),
+    (1, &"我"),
+    (1, &"我们"),
+    (1, &"五"),
+    (1, &"喜欢"),
+    (1, &"下"),
+    (1, &"下午"),
+    (1, &"下雨"),
+    (1, &"先生"),
+    (1, &"现在"),
+    (1, &"想"),
+    (1, &"小"),
+    (1, &"小姐"),
+    (1, &"些"),
+    (1, &"写"),
+    (1, &"谢谢"),
+    (1, &"星期"),
+    (1, &"学生"),
+    (1, &"学习"),
+    (1, &"学校"),
+    (1, &"一"),
+    (1, &"一点儿"),
+    (1, &"衣服"),
+    (1, &"医生"),
+    (1, &"医院"),
+    (1, &"椅子"),
+    (1, &"有"),
+    (1, &"月"),
+    (1, &"再见"),
+    (1, &"在"),
+    (1, &"怎么"),
+    (1, &"怎么样"),
+    (1, &"这"),
+    (1, &"中国"),
+    (1, &"中午"),
+    (1, &"住"),
+    (1, &"桌子"),
+    (1, &"字"),
+    (1, &"昨天"),
+    (1, &"坐"),
+    (1, &"做"),
+    (2, &"吧"),
+    (2, &"白"),
+    (2, &"百"),
+    (2, &"帮助"),
+    (2, &"报纸"),
+    (2, &"比"),
+    (2, &"别"),
+    (2, &"宾馆"),
+    (2, &"长（形容词）"),
+    (2, &"唱歌"),
+    (2, &"出"),
+    (2, &"穿"),
+    (2, &"次"),
+    (2, &"从"),
+    (2, &"错"),
+    (2, &"打篮球"),
+    (2, &"大家"),
+    (2, &"到"),
+    (2, &"得（助词）"),
+    (2, &"等（动词）"),
+    (2, &"弟弟"),
+    (2, &"第一"),
+    (2, &"懂"),
+    (2, &"对（形容词）"),
+    (2, &"对（介词）"),
+    (2, &"房间"),
+    (2, &"非常"),
+    (2, &"服务员"),
+    (2, &"高"),
+    (2, &"告诉"),
+    (2, &"哥哥"),
+    (2, &"给"),
+    (2, &"公共汽车"),
+    (2, &"公司"),
+    (2, &"贵"),
+    (2, &"过（助词）"),
+    (2, &"还（副词）"),
+    (2, &"孩子"),
+    (2, &"好吃"),
+    (2, &"黑"),
+    (2, &"红"),
+    (2, &"火车站"),
+    (2, &"机场"),
+    (2, &"鸡蛋"),
+    (2, &"件"),
+    (2, &"教室"),
+    (2, &"姐姐"),
+    (2, &"介绍"),
+    (2, &"进"),
+    (2, &"近"),
+    (2, &"就"),
+    (2, &"觉得"),
+    (2, &"咖啡"),
+    (2, &"开始"),
+    (2, &"考试"),
+    (2, &"可能"),
+    (2, &"可以"),
+    (2, &"课"),
+    (2, &"快"),
+    (2, &"快乐"),
+    (2, &"累"),
+    (2, &"离"),
+    (2, &"两"),
+    (2, &"零"),
+    (2, &"路"),
+    (2, &"旅游"),
+    (2, &"卖"),
+    (2, &"慢"),
+    (2, &"忙"),
+    (2, &"每"),
+    (2, &"妹妹"),
+    (2, &"门"),
+    (2, &"面条"),
+    (2, &"男"),
+    (2, &"您"),
+    (2, &"牛奶"),
+    (2, &"女"),
+    (2, &"旁边"),
+    (2, &"跑步"),
+    (2, &"便宜"),
+    (2, &"票"),
+    (2, &"妻子"),
+    (2, &"起床"),
+    (2, &"千"),
+    (2, &"铅笔"),
+    (2, &"晴"),
+    (2, &"去年"),
+    (2, &"让"),
+    (2, &"日"),
+    (2, &"上班"),
+    (2, &"身体"),
+    (2, &"生病"),
+    (2, &"生日"),
+    (2, &"时间"),
+    (2, &"事情"),
+    (2, &"手表"),
+    (2, &"手机"),
+    (2, &"说话"),
+    (2, &"送"),
+    (2, &"虽然……但是……"),
+    (2, &"它"),
+    (2, &"踢足球"),
+    (2, &"题"),
+    (2, &"跳舞"),
+    (2, &"外"),
+    (2, &"完"),
+    (2, &"玩"),
+    (2, &"晚上"),
+    (2, &"往"),
+    (2, &"为什么"),
+    (2, &"问"),
+    (2, &"问题"),
+    (2, &"西瓜"),
+    (2, &"希望"),
+    (2, &"洗"),
+    (2, &"小时"),
+    (2, &"笑"),
+    (2, &"新"),
+    (2, &"姓"),
+    (2, &"休息"),
+    (2, &"雪"),
+    (2, &"颜色"),
+    (2, &"眼睛"),
+    (2, &"羊肉"),
+    (2, &"药"),
+    (2, &"要"),
+    (2, &"也"),
+    (2, &"一起"),
+    (2, &"一下"),
+    (2, &"已经"),
+    (2, &"意思"),
+    (2, &"因为……所以……"),
+    (2, &"阴"),
+    (2, &"游泳"),
+    (2, &"右边"),
+    (2, &"鱼"),
+    (2, &"远"),
+    (2, &"运动"),
+    (2, &"再"),
+    (2, &"早上"),
+    (2, &"丈夫"),
+    (2, &"找"),
+    (2, &"着"),
+    (2, &"真"),
+    (2, &"正在"),
+    (2, &"知道"),
+    (2, &"准备"),
+    (2, &"走"),
+    (2, &"最"),
+    (2, &"左边"),
+    (3, &"阿姨"),
+    (3, &"啊"),
+    (3, &"矮"),
+    (3, &"爱好"),
+    (3, &"安静"),
+    (3, &"把"),
+    (3, &"班"),
+    (3, &"搬"),
+    (3, &"办法"),
+    (3, &"办公室"),
+    (3, &"半"),
+    (3, &"帮忙"),
+    (3, &"包"),
+    (3, &"饱"),
+    (3, &"北方"),
+    (3, &"被"),
+    (3, &"鼻子"),
+    (3, &"比较"),
+    (3, &"比赛"),
+    (3, &"笔记本"),
+    (3, &"必须"),
+    (3, &"变化"),
+    (3, &"别人"),
+    (3, &"冰箱"),
+    (3, &"不但……而且……"),
+    (3, &"菜单"),
+    (3, &"参加"),
+    (3, &"草"),
+    (3, &"层"),
+    (3, &"差"),
+    (3, &"超市"),
+    (3, &"衬衫"),
+    (3, &"成绩"),
+    (3, &"城市"),
+    (3, &"迟到"),
+    (3, &"除了"),
+    (3, &"船"),
+    (3, &"春"),
+    (3, &"词典"),
+    (3, &"聪明"),
+    (3, &"打扫"),
+    (3, &"打算"),
+    (3, &"带"),
+    (3, &"担心"),
+    (3, &"蛋糕"),
+    (3, &"当然"),
+    (3, &"地（助词）"),
+    (3, &"灯"),
+    (3, &"地方"),
+    (3, &"地铁"),
+    (3, &"地图"),
+    (3, &"电梯"),
+    (3, &"电子邮件"),
+    (3, &"东"),
+    (3, &"冬"),
+    (3, &"动物"),
+    (3, &"短"),
+    (3, &"段"),
+    (3, &"锻炼"),
+    (3, &"多么"),
+    (3, &"饿"),
+    (3, &"耳朵"),
+    (3, &"发"),
+    (3, &"发烧"),
+    (3, &"发现"),
+    (3, &"方便"),
+    (3, &"放"),
+    (3, &"放心"),
+    (3, &"分"),
+    (3, &"附近"),
+    (3, &"复习"),
+    (3, &"干净"),
+    (3, &"感冒"),
+    (3, &"感兴趣"),
+    (3, &"刚才"),
+    (3, &"个子"),
+    (3, &"根据"),
+    (3, &"跟"),
+    (3, &"更"),
+    (3, &"公斤"),
+    (3, &"公园"),
+    (3, &"故事"),
+    (3, &"刮风"),
+    (3, &"关"),
+    (3, &"关系"),
+    (3, &"关心"),
+    (3, &"关于"),
+    (3, &"国家"),
+    (3, &"过（动词）"),
+    (3, &"过去"),
+    (3, &"还是"),
+    (3, &"害怕"),
+    (3, &"黑板"),
+    (3, &"后来"),
+    (3, &"护照"),
+    (3, &"花（名词）"),
+    (3, &"花（动词）"),
+    (3, &"画"),
+    (3, &"坏"),
+    (3, &"欢迎"),
+    (3, &"还（动词）"),
+    (3, &"环境"),
+    (3, &"换"),
+    (3, &"黄河"),
+    (3, &"回答"),
+    (3, &"会议"),
+    (3, &"或者"),
+    (3, &"几乎"),
+    (3, &"机会"),
+    (3, &"极"),
+    (3, &"记得"),
+    (3, &"季节"),
+    (3, &"检查"),
+    (3, &"简单"),
+    (3, &"见面"),
+    (3, &"健康"),
+    (3, &"讲"),
+    (3, &"教"),
+    (3, &"角"),
+    (3, &"脚"),
+    (3, &"接"),
+    (3, &"街道"),
+    (3, &"节目"),
+    (3, &"节日"),
+    (3, &"结婚"),
+    (3, &"结束"),
+    (3, &"解决"),
+    (3, &"借"),
+    (3, &"经常"),
+    (3, &"经过"),
+    (3, &"经理"),
+    (3, &"久"),
+    (3, &"旧"),
+    (3, &"句子"),
+    (3, &"决定"),
+    (3, &"可爱"),
+    (3, &"渴"),
+    (3, &"刻"),
+    (3, &"客人"),
+    (3, &"空调"),
+    (3, &"口"),
+    (3, &"哭"),
+    (3, &"裤子"),
+    (3, &"筷子"),
+    (3, &"蓝"),
+    (3, &"老"),
+    (3, &"离开"),
+    (3, &"礼物"),
+    (3, &"历史"),
+    (3, &"脸"),
+    (3, &"练习"),
+    (3, &"辆"),
+    (3, &"聊天"),
+    (3, &"了解"),
+    (3, &"邻居"),
+    (3, &"留学"),
+    (3, &"楼"),
+    (3, &"绿"),
+    (3, &"马"),
+    (3, &"马上"),
+    (3, &"满意"),
+    (3, &"帽子"),
+    (3, &"米"),
+    (3, &"面包"),
+    (3, &"明白"),
+    (3, &"拿"),
+    (3, &"奶奶"),
+    (3, &"南"),
+    (3, &"难"),
+    (3, &"难过"),
+    (3, &"年级"),
+    (3, &"年轻"),
+    (3, &"鸟"),
+    (3, &"努力"),
+    (3, &"爬山"),
+    (3, &"盘子"),
+    (3, &"胖"),
+    (3, &"皮鞋"),
+    (3, &"啤酒"),
+    (3, &"瓶子"),
+    (3, &"其实"),
+    (3, &"其他"),
+    (3, &"奇怪"),
+    (3, &"骑"),
+    (3, &"起飞"),
+    (3, &"起来"),
+    (3, &"清楚"),
+    (3, &"请假"),
+    (3, &"秋"),
+    (3, &"裙子"),
+    (3, &"然后"),
+    (3, &"热情"),
+    (3, &"认为"),
+    (3, &"认真"),
+    (3, &"容易"),
+    (3, &"如果"),
+    (3, &"伞"),
+    (3, &"上网"),
+    (3, &"生气"),
+    (3, &"声音"),
+    (3, &"世界"),
+    (3, &"试"),
+    (3, &"瘦"),
+    (3, &"叔叔"),
+    (3, &"舒服"),
+    (3, &"树"),
+    (3, &"数学"),
+    (3, &"刷牙"),
+    (3, &"双"),
+    (3, &"水平"),
+    (3, &"司机"),
+    (3, &"太阳"),
+    (3, &"特别"),
+    (3, &"疼"),
+    (3, &"提高"),
+    (3, &"体育"),
+    (3, &"甜"),
+    (3, &"条"),
+    (3, &"同事"),
+    (3, &"同意"),
+    (3, &"头发"),
+    (3, &"突然"),
+    (3, &"图书馆"),
+    (3, &"腿"),
+    (3, &"完成"),
+    (3, &"碗"),
+    (3, &"万"),
+    (3, &"忘记"),
+    (3, &"为"),
+    (3, &"为了"),
+    (3, &"位"),
+    (3, &"文化"),
+    (3, &"西"),
+    (3, &"习惯"),
+    (3, &"洗手间"),
+    (3, &"洗澡"),
+    (3, &"夏"),
+    (3, &"先"),
+    (3, &"相信"),
+    (3, &"香蕉"),
+    (3, &"向"),
+    (3, &"像"),
+    (3, &"小心"),
+    (3, &"校长"),
+    (3, &"新闻"),
+    (3, &"新鲜"),
+    (3, &"信用卡"),
+    (3, &"行李箱"),
+    (3, &"熊猫"),
+    (3, &"需要"),
+    (3, &"选择"),
+    (3, &"要求"),
+    (3, &"爷爷"),
+    (3, &"一般"),
+    (3, &"一边"),
+    (3, &"一定"),
+    (3, &"一共"),
+    (3, &"一会儿"),
+    (3, &"一样"),
+    (3, &"一直"),
+    (3, &"以前"),
+    (3, &"音乐"),
+    (3, &"银行"),
+    (3, &"饮料"),
+    (3, &"应该"),
+    (3, &"影响"),
+    (3, &"用"),
+    (3, &"游戏"),
+    (3, &"有名"),
+    (3, &"又"),
+    (3, &"遇到"),
+    (3, &"元"),
+    (3, &"愿意"),
+    (3, &"月亮"),
+    (3, &"越"),
+    (3, &"站"),
+    (3, &"张"),
+    (3, &"长（动词）"),
+    (3, &"着急"),
+    (3, &"照顾"),
+    (3, &"照片"),
+    (3, &"照相机"),
+    (3, &"只（量词）"),
+    (3, &"只（副词）"),
+    (3, &"只有……才……"),
+    (3, &"中间"),
+    (3, &"中文"),
+    (3, &"终于"),
+    (3, &"种（量词）"),
+    (3, &"重要"),
+    (3, &"周末"),
+    (3, &"主要"),
+    (3, &"注意"),
+    (3, &"自己"),
+    (3, &"自行车"),
+    (3, &"总是"),
+    (3, &"嘴"),
+    (3, &"最后"),
+    (3, &"最近"),
+    (3, &"作业"),
+    (4, &"爱情"),
+    (4, &"安排"),
+    (4, &"安全"),
+    (4, &"按时"),
+    (4, &"按照"),
+    (4, &"百分之"),
+    (4, &"棒"),
+    (4, &"包子"),
+    (4, &"保护"),
+    (4, &"保证"),
+    (4, &"报名"),
+    (4, &"抱"),
+    (4, &"抱歉"),
+    (4, &"倍"),
+    (4, &"本来"),
+    (4, &"笨"),
+    (4, &"比如"),
+    (4, &"毕业"),
+    (4, &"遍"),
+    (4, &"标准"),
+    (4, &"表格"),
+    (4, &"表示"),
+    (4, &"表演"),
+    (4, &"表扬"),
+    (4, &"饼干"),
+    (4, &"并且"),
+    (4, &"博士"),
+    (4, &"不得不"),
+    (4, &"不管"),
+    (4, &"不过"),
+    (4, &"不仅"),
+    (4, &"部分"),
+    (4, &"擦"),
+    (4, &"猜"),
+    (4, &"材料"),
+    (4, &"参观"),
+    (4, &"餐厅"),
+    (4, &"厕所"),
+    (4, &"差不多"),
+    (4, &"长城"),
+    (4, &"长江"),
+    (4, &"尝"),
+    (4, &"场"),
+    (4, &"超过"),
+    (4, &"成功"),
+    (4, &"成为"),
+    (4, &"诚实"),
+    (4, &"乘坐"),
+    (4, &"吃惊"),
+    (4, &"重新"),
+    (4, &"抽烟"),
+    (4, &"出差"),
+    (4, &"出发"),
+    (4, &"出生"),
+    (4, &"出现"),
+    (4, &"厨房"),
+    (4, &"传真"),
+    (4, &"窗户"),
+    (4, &"词语"),
+    (4, &"从来"),
+    (4, &"粗心"),
+    (4, &"存"),
+    (4, &"错误"),
+    (4, &"答案"),
+    (4, &"打扮"),
+    (4, &"打扰"),
+    (4, &"打印"),
+    (4, &"打招呼"),
+    (4, &"打折"),
+    (4, &"打针"),
+    (4, &"大概"),
+    (4, &"大使馆"),
+    (4, &"大约"),
+    (4, &"大夫"),
+    (4, &"戴"),
+    (4, &"当"),
+    (4, &"当时"),
+    (4, &"刀"),
+    (4, &"导游"),
+    (4, &"到处"),
+    (4, &"到底"),
+    (4, &"倒"),
+    (4, &"道歉"),
+    (4, &"得意"),
+    (4, &"得（助动词）"),
+    (4, &"登机牌"),
+    (4, &"等（助词）"),
+    (4, &"低"),
+    (4, &"底"),
+    (4, &"地点"),
+    (4, &"地球"),
+    (4, &"地址"),
+    (4, &"调查"),
+    (4, &"掉"),
+    (4, &"丢"),
+    (4, &"动作"),
+    (4, &"堵车"),
+    (4, &"肚子"),
+    (4, &"短信"),
+    (4, &"对话"),
+    (4, &"对面"),
+    (4, &"对于"),
+    (4, &"儿童"),
+    (4, &"而"),
+    (4, &"发生"),
+    (4, &"发展"),
+    (4, &"法律"),
+    (4, &"翻译"),
+    (4, &"烦恼"),
+    (4, &"反对"),
+    (4, &"方法"),
+    (4, &"方面"),
+    (4, &"方向"),
+    (4, &"房东"),
+    (4, &"放弃"),
+    (4, &"放暑假"),
+    (4, &"放松"),
+    (4, &"份"),
+    (4, &"丰富"),
+    (4, &"否则"),
+    (4, &"符合"),
+    (4, &"父亲"),
+    (4, &"付款"),
+    (4, &"负责"),
+    (4, &"复印"),
+    (4, &"复杂"),
+    (4, &"富"),
+    (4, &"改变"),
+    (4, &"干杯"),
+    (4, &"赶"),
+    (4, &"敢"),
+    (4, &"感动"),
+    (4, &"感觉"),
+    (4, &"感情"),
+    (4, &"感谢"),
+    (4, &"干"),
+    (4, &"刚"),
+    (4, &"高速公路"),
+    (4, &"胳膊"),
+    (4, &"各"),
+    (4, &"工资"),
+    (4, &"公里"),
+    (4, &"功夫"),
+    (4, &"共同"),
+    (4, &"购物"),
+    (4, &"够"),
+    (4, &"估计"),
+    (4, &"鼓励"),
+    (4, &"故意"),
+    (4, &"顾客"),
+    (4, &"挂"),
+    (4, &"关键"),
+    (4, &"观众"),
+    (4, &"管理"),
+    (4, &"光"),
+    (4, &"广播"),
+    (4, &"广告"),
+    (4, &"逛"),
+    (4, &"规定"),
+    (4, &"国籍"),
+    (4, &"国际"),
+    (4, &"果汁"),
+    (4, &"过程"),
+    (4, &"海洋"),
+    (4, &"害羞"),
+    (4, &"寒假"),
+    (4, &"汗"),
+    (4, &"航班"),
+    (4, &"好处"),
+    (4, &"好像"),
+    (4, &"号码"),
+    (4, &"合格"),
+    (4, &"合适"),
+    (4, &"盒子"),
+    (4, &"后悔"),
+    (4, &"厚"),
+    (4, &"互联网"),
+    (4, &"互相"),
+    (4, &"护士"),
+    (4, &"怀疑"),
+    (4, &"回忆"),
+    (4, &"活动"),
+    (4, &"活泼"),
+    (4, &"火"),
+    (4, &"获得"),
+    (4, &"积极"),
+    (4, &"积累"),
+    (4, &"基础"),
+    (4, &"激动"),
+    (4, &"及时"),
+    (4, &"即使"),
+    (4, &"计划"),
+    (4, &"记者"),
+    (4, &"技术"),
+    (4, &"既然"),
+    (4, &"继续"),
+    (4, &"寄"),
+    (4, &"加班"),
+    (4, &"加油站"),
+    (4, &"家具"),
+    (4, &"假"),
+    (4, &"价格"),
+    (4, &"坚持"),
+    (4, &"减肥"),
+    (4, &"减少"),
+    (4, &"建议"),
+    (4, &"将来"),
+    (4, &"奖金"),
+    (4, &"降低"),
+    (4, &"降落"),
+    (4, &"交"),
+    (4, &"交流"),
+    (4, &"交通"),
+    (4, &"郊区"),
+    (4, &"骄傲"),
+    (4, &"饺子"),
+    (4, &"教授"),
+    (4, &"教育"),
+    (4, &"接受"),
+    (4, &"接着"),
+    (4, &"节"),
+    (4, &"节约"),
+    (4, &"结果"),
+    (4, &"解释"),
+    (4, &"尽管"),
+    (4, &"紧张"),
+    (4, &"进行"),
+    (4, &"禁止"),
+    (4, &"京剧"),
+    (4, &"经济"),
+    (4, &"经历"),
+    (4, &"经验"),
+    (4, &"精彩"),
+    (4, &"景色"),
+    (4, &"警察"),
+    (4, &"竞争"),
+    (4, &"竟然"),
+    (4, &"镜子"),
+    (4, &"究竟"),
+    (4, &"举"),
+    (4, &"举办"),
+    (4, &"举行"),
+    (4, &"拒绝"),
+    (4, &"距离"),
+    (4, &"聚会"),
+    (4, &"开玩笑"),
+    (4, &"开心"),
+    (4, &"看法"),
+    (4, &"考虑"),
+    (4, &"烤鸭"),
+    (4, &"科学"),
+    (4, &"棵"),
+    (4, &"咳嗽"),
+    (4, &"可怜"),
+    (4, &"可是"),
+    (4, &"可惜"),
+    (4, &"客厅"),
+    (4, &"肯定"),
+    (4, &"空"),
+    (4, &"空气"),
+    (4, &"恐怕"),
+    (4, &"苦"),
+    (4, &"矿泉水"),
+    (4, &"困"),
+    (4, &"困难"),
+    (4, &"垃圾桶"),
+    (4, &"拉"),
+    (4, &"辣"),
+    (4, &"来不及"),
+    (4, &"来得及"),
+    (4, &"来自"),
+    (4, &"懒"),
+    (4, &"浪费"),
+    (4, &"浪漫"),
+    (4, &"老虎"),
+    (4, &"冷静"),
+    (4, &"礼拜天"),
+    (4, &"礼貌"),
+    (4, &"理发"),
+    (4, &"理解"),
+    (4, &"理想"),
+    (4, &"力气"),
+    (4, &"厉害"),
+    (4, &"例如"),
+    (4, &"俩"),
+    (4, &"连"),
+    (4, &"联系"),
+    (4, &"凉快"),
+    (4, &"零钱"),
+    (4, &"另外"),
+    (4, &"留"),
+    (4, &"流利"),
+    (4, &"流行"),
+    (4, &"旅行"),
+    (4, &"律师"),
+    (4, &"乱"),
+    (4, &"麻烦"),
+    (4, &"马虎"),
+    (4, &"满"),
+    (4, &"毛"),
+    (4, &"毛巾"),
+    (4, &"美丽"),
+    (4, &"梦"),
+    (4, &"迷路"),
+    (4, &"密码"),
+    (4, &"免费"),
+    (4, &"秒"),
+    (4, &"民族"),
+    (4, &"母亲"),
+    (4, &"目的"),
+    (4, &"耐心"),
+    (4, &"难道"),
+    (4, &"难受"),
+    (4, &"内"),
+    (4, &"内容"),
+    (4, &"能力"),
+    (4, &"年龄"),
+    (4, &"弄"),
+    (4, &"暖和"),
+    (4, &"偶尔"),
+    (4, &"排队"),
+    (4, &"排列"),
+    (4, &"判断"),
+    (4, &"陪"),
+    (4, &"批评"),
+    (4, &"皮肤"),
+    (4, &"脾气"),
+    (4, &"篇"),
+    (4, &"骗"),
+    (4, &"乒乓球"),
+    (4, &"平时"),
+    (4, &"破"),
+    (4, &"葡萄"),
+    (4, &"普遍"),
+    (4, &"普通话"),
+    (4, &"其次"),
+    (4, &"其中"),
+    (4, &"气候"),
+    (4, &"千万"),
+    (4, &"签证"),
+    (4, &"敲"),
+    (4, &"桥"),
+    (4, &"巧克力"),
+    (4, &"亲戚"),
+    (4, &"轻"),
+    (4, &"轻松"),
+    (4, &"情况"),
+    (4, &"穷"),
+    (4, &"区别"),
+    (4, &"取"),
+    (4, &"全部"),
+    (4, &"缺点"),
+    (4, &"缺少"),
+    (4, &"却"),
+    (4, &"确实"),
+    (4, &"然而"),
+    (4, &"热闹"),
+    (4, &"任何"),
+    (4, &"任务"),
+    (4, &"扔"),
+    (4, &"仍然"),
+    (4, &"日记"),
+    (4, &"入口"),
+    (4, &"散步"),
+    (4, &"森林"),
+    (4, &"沙发"),
+    (4, &"伤心"),
+    (4, &"商量"),
+    (4, &"稍微"),
+    (4, &"勺子"),
+    (4, &"社会"),
+    (4, &"申请"),
+    (4, &"深"),
+    (4, &"甚至"),
+    (4, &"生活"),
+    (4, &"生命"),
+    (4, &"生意"),
+    (4, &"省"),
+    (4, &"剩"),
+    (4, &"失败"),
+    (4, &"失望"),
+    (4, &"师傅"),
+    (4, &"十分"),
+    (4, &"实际"),
+    (4, &"实在"),
+    (4, &"使"),
+    (4, &"使用"),
+    (4, &"世纪"),
+    (4, &"是否"),
+    (4, &"适合"),
+    (4, &"适应"),
+    (4, &"收"),
+    (4, &"收入"),
+    (4, &"收拾"),
+    (4, &"首都"),
+    (4, &"首先"),
+    (4, &"受不了"),
+    (4, &"受到"),
+    (4, &"售货员"),
+    (4, &"输"),
+    (4, &"熟悉"),
+    (4, &"数量"),
+    (4, &"数字"),
+    (4, &"帅"),
+    (4, &"顺便"),
+    (4, &"顺利"),
+    (4, &"顺序"),
+    (4, &"说明"),
+    (4, &"硕士"),
+    (4, &"死"),
+    (4, &"速度"),
+    (4, &"塑料袋"),
+    (4, &"酸"),
+    (4, &"随便"),
+    (4, &"随着"),
+    (4, &"孙子"),
+    (4, &"所有"),
+    (4, &"台"),
+    (4, &"抬"),
+    (4, &"态度"),
+    (4, &"谈"),
+    (4, &"弹钢琴"),
+    (4, &"汤"),
+    (4, &"糖"),
+    (4, &"躺"),
+    (4, &"趟"),
+    (4, &"讨论"),
+    (4, &"讨厌"),
+    (4, &"特点"),
+    (4, &"提"),
+    (4, &"提供"),
+    (4, &"提前"),
+    (4, &"提醒"),
+    (4, &"填空"),
+    (4, &"条件"),
+    (4, &"停"),
+    (4, &"挺"),
+    (4, &"通过"),
+    (4, &"通知"),
+    (4, &"同情"),
+    (4, &"同时"),
+    (4, &"推"),
+    (4, &"推迟"),
+    (4, &"脱"),
+    (4, &"袜子"),
+    (4, &"完全"),
+    (4, &"网球"),
+    (4, &"网站"),
+    (4, &"往往"),
+    (4, &"危险"),
+    (4, &"卫生间"),
+    (4, &"味道"),
+    (4, &"温度"),
+    (4, &"文章"),
+    (4, &"污染"),
+    (4, &"无"),
+    (4, &"无聊"),
+    (4, &"无论"),
+    (4, &"误会"),
+    (4, &"西红柿"),
+    (4, &"吸引"),
+    (4, &"咸"),
+    (4, &"现金"),
+    (4, &"羡慕"),
+    (4, &"相反"),
+    (4, &"相同"),
+    (4, &"香"),
+    (4, &"详细"),
+    (4, &"响"),
+    (4, &"橡皮"),
+    (4, &"消息"),
+    (4, &"小吃"),
+    (4, &"小伙子"),
+    (4, &"小说"),
+    (4, &"笑话"),
+    (4, &"效果"),
+    (4, &"心情"),
+    (4, &"辛苦"),
+    (4, &"信封"),
+    (4, &"信息"),
+    (4, &"信心"),
+    (4, &"兴奋"),
+    (4, &"行"),
+    (4, &"醒"),
+    (4, &"幸福"),
+    (4, &"性别"),
+    (4, &"性格"),
+    (4, &"修理"),
+    (4, &"许多"),
+    (4, &"学期"),
+    (4, &"压力"),
+    (4, &"呀"),
+    (4, &"牙膏"),
+    (4, &"亚洲"),
+    (4, &"严格"),
+    (4, &"严重"),
+    (4, &"研究"),
+    (4, &"盐"),
+    (4, &"眼镜"),
+    (4, &"演出"),
+    (4, &"演员"),
+    (4, &"阳光"),
+    (4, &"养成"),
+    (4, &"样子"),
+    (4, &"邀请"),
+    (4, &"要是"),
+    (4, &"钥匙"),
+    (4, &"也许"),
+    (4, &"叶子"),
+    (4, &"页"),
+    (4, &"一切"),
+    (4, &"以"),
+    (4, &"以为"),
+    (4, &"艺术"),
+    (4, &"意见"),
+    (4, &"因此"),
+    (4, &"引起"),
+    (4, &"印象"),
+    (4, &"赢"),
+    (4, &"应聘"),
+    (4, &"永远"),
+    (4, &"勇敢"),
+    (4, &"优点"),
+    (4, &"优秀"),
+    (4, &"幽默"),
+    (4, &"尤其"),
+    (4, &"由"),
+    (4, &"由于"),
+    (4, &"邮局"),
+    (4, &"友好"),
+    (4, &"友谊"),
+    (4, &"有趣"),
+    (4, &"于是"),
+    (4, &"愉快"),
+    (4, &"与"),
+    (4, &"羽毛球"),
+    (4, &"语法"),
+    (4, &"语言"),
+    (4, &"预习"),
+    (4, &"原来"),
+    (4, &"原谅"),
+    (4, &"原因"),
+    (4, &"约会"),
+    (4, &"阅读"),
+    (4, &"云"),
+    (4, &"允许"),
+    (4, &"杂志"),
+    (4, &"咱们"),
+    (4, &"暂时"),
+    (4, &"脏"),
+    (4, &"责任"),
+    (4, &"增加"),
+    (4, &"占线"),
+    (4, &"招聘"),
+    (4, &"照"),
+    (4, &"真正"),
+    (4, &"整理"),
+    (4, &"正常"),
+    (4, &"正好"),
+    (4, &"正确"),
+    (4, &"正式"),
+    (4, &"证明"),
+    (4, &"之"),
+    (4, &"支持"),
+    (4, &"知识"),
+    (4, &"直接"),
+    (4, &"值得"),
+    (4, &"职业"),
+    (4, &"植物"),
+    (4, &"只好"),
+    (4, &"只要"),
+    (4, &"指"),
+    (4, &"至少"),
+    (4, &"质量"),
+    (4, &"重"),
+    (4, &"重点"),
+    (4, &"重视"),
+    (4, &"周围"),
+    (4, &"主意"),
+    (4, &"祝贺"),
+    (4, &"著名"),
+    (4, &"专门"),
+    (4, &"专业"),
+    (4, &"转"),
+    (4, &"赚"),
+    (4, &"准确"),
+    (4, &"准时"),
+    (4, &"仔细"),
+    (4, &"自然"),
+    (4, &"自信"),
+    (4, &"总结"),
+    (4, &"租"),
+    (4, &"最好"),
+    (4, &"尊重"),
+    (4, &"左右"),
+    (4, &"作家"),
+    (4, &"作用"),
+    (4, &"作者"),
+    (4, &"座"),
+    (4, &"座位"),
+    (5, &"哎"),
+    (5, &"唉"),
+    (5, &"爱护"),
+    (5, &"爱惜"),
+    (5, &"爱心"),
+    (5, &"安慰"),
+    (5, &"安装"),
+    (5, &"岸"),
+    (5, &"暗"),
+    (5, &"熬夜"),
+    (5, &"把握"),
+    (5, &"摆"),
+    (5, &"办理"),
+    (5, &"傍晚"),
+    (5, &"包裹"),
+    (5, &"包含"),
+    (5, &"包括"),
+    (5, &"薄"),
+    (5, &"宝贝"),
+    (5, &"宝贵"),
+    (5, &"保持"),
+    (5, &"保存"),
+    (5, &"保留"),
+    (5, &"保险"),
+    (5, &"报到"),
+    (5, &"报道"),
+    (5, &"报告"),
+    (5, &"报社"),
+    (5, &"抱怨"),
+    (5, &"背"),
+    (5, &"悲观"),
+    (5, &"背景"),
+    (5, &"被子"),
+    (5, &"本科"),
+    (5, &"本领"),
+    (5, &"本质"),
+    (5, &"比例"),
+    (5, &"彼此"),
+    (5, &"必然"),
+    (5, &"必要"),
+    (5, &"毕竟"),
+    (5, &"避免"),
+    (5, &"编辑"),
+    (5, &"鞭炮"),
+    (5, &"便"),
+    (5, &"辩论"),
+    (5, &"标点"),
+    (5, &"标志"),
+    (5, &"表达"),
+    (5, &"表面"),
+    (5, &"表明"),
+    (5, &"表情"),
+    (5, &"表现"),
+    (5, &"冰激凌"),
+    (5, &"病毒"),
+    (5, &"玻璃"),
+    (5, &"播放"),
+    (5, &"脖子"),
+    (5, &"博物馆"),
+    (5, &"补充"),
+    (5, &"不安"),
+    (5, &"不得了"),
+    (5, &"不断"),
+    (5, &"不见得"),
+    (5, &"不耐烦"),
+    (5, &"不然"),
+    (5, &"不如"),
+    (5, &"不要紧"),
+    (5, &"不足"),
+    (5, &"布"),
+    (5, &"步骤"),
+    (5, &"部门"),
+    (5, &"财产"),
+    (5, &"采访"),
+    (5, &"采取"),
+    (5, &"彩虹"),
+    (5, &"踩"),
+    (5, &"参考"),
+    (5, &"参与"),
+    (5, &"惭愧"),
+    (5, &"操场"),
+    (5, &"操心"),
+    (5, &"册"),
+    (5, &"测验"),
+    (5, &"曾经"),
+    (5, &"叉子"),
+    (5, &"差距"),
+    (5, &"插"),
+    (5, &"拆"),
+    (5, &"产品"),
+    (5, &"产生"),
+    (5, &"长途"),
+    (5, &"常识"),
+    (5, &"抄"),
+    (5, &"超级"),
+    (5, &"朝"),
+    (5, &"潮湿"),
+    (5, &"吵"),
+    (5, &"吵架"),
+    (5, &"炒"),
+    (5, &"车库"),
+    (5, &"车厢"),
+    (5, &"彻底"),
+    (5, &"沉默"),
+    (5, &"趁"),
+    (5, &"称"),
+    (5, &"称呼"),
+    (5, &"称赞"),
+    (5, &"成分"),
+    (5, &"成果"),
+    (5, &"成就"),
+    (5, &"成立"),
+    (5, &"成人"),
+    (5, &"成熟"),
+    (5, &"成语"),
+    (5, &"成长"),
+    (5, &"诚恳"),
+    (5, &"承担"),
+    (5, &"承认"),
+    (5, &"承受"),
+    (5, &"程度"),
+    (5, &"程序"),
+    (5, &"吃亏"),
+    (5, &"池塘"),
+    (5, &"迟早"),
+    (5, &"持续"),
+    (5, &"尺子"),
+    (5, &"翅膀"),
+    (5, &"冲"),
+    (5, &"充电器"),
+    (5, &"充分"),
+    (5, &"充满"),
+    (5, &"重复"),
+    (5, &"宠物"),
+    (5, &"抽屉"),
+    (5, &"抽象"),
+    (5, &"丑"),
+    (5, &"臭"),
+    (5, &"出版"),
+    (5, &"出口"),
+    (5, &"出色"),
+    (5, &"出示"),
+    (5, &"出席"),
+    (5, &"初级"),
+    (5, &"除非"),
+    (5, &"除夕"),
+    (5, &"处理"),
+    (5, &"传播"),
+    (5, &"传染"),
+    (5, &"传说"),
+    (5, &"传统"),
+    (5, &"窗帘"),
+    (5, &"闯"),
+    (5, &"创造"),
+    (5, &"吹"),
+    (5, &"词汇"),
+    (5, &"辞职"),
+    (5, &"此外"),
+    (5, &"次要"),
+    (5, &"刺激"),
+    (5, &"匆忙"),
+    (5, &"从此"),
+    (5, &"从而"),
+    (5, &"从前"),
+    (5, &"从事"),
+    (5, &"粗糙"),
+    (5, &"促进"),
+    (5, &"促使"),
+    (5, &"醋"),
+    (5, &"催"),
+    (5, &"存在"),
+    (5, &"措施"),
+    (5, &"答应"),
+    (5, &"达到"),
+    (5, &"打工"),
+    (5, &"打交道"),
+    (5, &"打喷嚏"),
+    (5, &"打听"),
+    (5, &"大方"),
+    (5, &"大厦"),
+    (5, &"大象"),
+    (5, &"大型"),
+    (5, &"呆"),
+    (5, &"代表"),
+    (5, &"代替"),
+    (5, &"贷款"),
+    (5, &"待遇"),
+    (5, &"担任"),
+    (5, &"单纯"),
+    (5, &"单调"),
+    (5, &"单独"),
+    (5, &"单位"),
+    (5, &"单元"),
+    (5, &"耽误"),
+    (5, &"胆小鬼"),
+    (5, &"淡"),
+    (5, &"当地"),
+    (5, &"当心"),
+    (5, &"挡"),
+    (5, &"导演"),
+    (5, &"导致"),
+    (5, &"岛屿"),
+    (5, &"倒霉"),
+    (5, &"到达"),
+    (5, &"道德"),
+    (5, &"道理"),
+    (5, &"登记"),
+    (5, &"等待"),
+    (5, &"等于"),
+    (5, &"滴"),
+    (5, &"的确"),
+    (5, &"敌人"),
+    (5, &"地道"),
+    (5, &"地理"),
+    (5, &"地区"),
+    (5, &"地毯"),
+    (5, &"地位"),
+    (5, &"地震"),
+    (5, &"递"),
+    (5, &"点心"),
+    (5, &"电池"),
+    (5, &"电台"),
+    (5, &"钓"),
+    (5, &"顶"),
+    (5, &"动画片"),
+    (5, &"冻"),
+    (5, &"洞"),
+    (5, &"豆腐"),
+    (5, &"逗"),
+    (5, &"独立"),
+    (5, &"独特"),
+    (5, &"度过"),
+    (5, &"断"),
+    (5, &"堆"),
+    (5, &"对比"),
+    (5, &"对待"),
+    (5, &"对方"),
+    (5, &"对手"),
+    (5, &"对象"),
+    (5, &"兑换"),
+    (5, &"吨"),
+    (5, &"蹲"),
+    (5, &"顿"),
+    (5, &"多亏"),
+    (5, &"多余"),
+    (5, &"朵"),
+    (5, &"躲藏"),
+    (5, &"恶劣"),
+    (5, &"耳环"),
+    (5, &"发表"),
+    (5, &"发愁"),
+    (5, &"发达"),
+    (5, &"发抖"),
+    (5, &"发挥"),
+    (5, &"发明"),
+    (5, &"发票"),
+    (5, &"发言"),
+    (5, &"罚款"),
+    (5, &"法院"),
+    (5, &"翻"),
+    (5, &"繁荣"),
+    (5, &"反而"),
+    (5, &"反复"),
+    (5, &"反应"),
+    (5, &"反映"),
+    (5, &"反正"),
+    (5, &"范围"),
+    (5, &"方"),
+    (5, &"方案"),
+    (5, &"方式"),
+    (5, &"妨碍"),
+    (5, &"仿佛"),
+    (5, &"非"),
+    (5, &"肥皂"),
+    (5, &"废话"),
+    (5, &"分别"),
+    (5, &"分布"),
+    (5, &"分配"),
+    (5, &"分手"),
+    (5, &"分析"),
+    (5, &"纷纷"),
+    (5, &"奋斗"),
+    (5, &"风格"),
+    (5, &"风景"),
+    (5, &"风俗"),
+    (5, &"风险"),
+    (5, &"疯狂"),
+    (5, &"讽刺"),
+    (5, &"否定"),
+    (5, &"否认"),
+    (5, &"扶"),
+    (5, &"服装"),
+    (5, &"幅"),
+    (5, &"辅导"),
+    (5, &"妇女"),
+    (5, &"复制"),
+    (5, &"改革"),
+    (5, &"改进"),
+    (5, &"改善"),
+    (5, &"改正"),
+    (5, &"盖"),
+    (5, &"概括"),
+    (5, &"概念"),
+    (5, &"干脆"),
+    (5, &"干燥"),
+    (5, &"赶紧"),
+    (5, &"赶快"),
+    (5, &"感激"),
+    (5, &"感受"),
+    (5, &"感想"),
+    (5, &"干活儿"),
+    (5, &"钢铁"),
+    (5, &"高档"),
+    (5, &"高级"),
+    (5, &"搞"),
+    (5, &"告别"),
+    (5, &"格外"),
+    (5, &"隔壁"),
+    (5, &"个别"),
+    (5, &"个人"),
+    (5, &"个性"),
+    (5, &"各自"),
+    (5, &"根"),
+    (5, &"根本"),
+    (5, &"工厂"),
+    (5, &"工程师"),
+    (5, &"工具"),
+    (5, &"工人"),
+    (5, &"工业"),
+    (5, &"公布"),
+    (5, &"公开"),
+    (5, &"公平"),
+    (5, &"公寓"),
+    (5, &"公元"),
+    (5, &"公主"),
+    (5, &"功能"),
+    (5, &"恭喜"),
+    (5, &"贡献"),
+    (5, &"沟通"),
+    (5, &"构成"),
+    (5, &"姑姑"),
+    (5, &"姑娘"),
+    (5, &"古代"),
+    (5, &"古典"),
+    (5, &"股票"),
+    (5, &"骨头"),
+    (5, &"鼓舞"),
+    (5, &"鼓掌"),
+    (5, &"固定"),
+    (5, &"挂号"),
+    (5, &"乖"),
+    (5, &"拐弯"),
+    (5, &"怪不得"),
+    (5, &"关闭"),
+    (5, &"观察"),
+    (5, &"观点"),
+    (5, &"观念"),
+    (5, &"官"),
+    (5, &"管子"),
+    (5, &"冠军"),
+    (5, &"光滑"),
+    (5, &"光临"),
+    (5, &"光明"),
+    (5, &"光盘"),
+    (5, &"广场"),
+    (5, &"广大"),
+    (5, &"广泛"),
+    (5, &"归纳"),
+    (5, &"规矩"),
+    (5, &"规律"),
+    (5, &"规模"),
+    (5, &"规则"),
+    (5, &"柜台"),
+    (5, &"滚"),
+    (5, &"锅"),
+    (5, &"国庆节"),
+    (5, &"国王"),
+    (5, &"果然"),
+    (5, &"果实"),
+    (5, &"过分"),
+    (5, &"过敏"),
+    (5, &"过期"),
+    (5, &"哈"),
+    (5, &"海关"),
+    (5, &"海鲜"),
+    (5, &"喊"),
+    (5, &"行业"),
+    (5, &"豪华"),
+    (5, &"好客"),
+    (5, &"好奇"),
+    (5, &"合法"),
+    (5, &"合理"),
+    (5, &"合同"),
+    (5, &"合影"),
+    (5, &"合作"),
+    (5, &"何必"),
+    (5, &"何况"),
+    (5, &"和平"),
+    (5, &"核心"),
+    (5, &"恨"),
+    (5, &"猴子"),
+    (5, &"后背"),
+    (5, &"后果"),
+    (5, &"呼吸"),
+    (5, &"忽然"),
+    (5, &"忽视"),
+    (5, &"胡说"),
+    (5, &"胡同"),
+    (5, &"壶"),
+    (5, &"蝴蝶"),
+    (5, &"糊涂"),
+    (5, &"花生"),
+    (5, &"划"),
+    (5, &"华裔"),
+    (5, &"滑"),
+    (5, &"化学"),
+    (5, &"话题"),
+    (5, &"怀念"),
+    (5, &"怀孕"),
+    (5, &"缓解"),
+    (5, &"幻想"),
+    (5, &"慌张"),
+    (5, &"黄金"),
+    (5, &"灰"),
+    (5, &"灰尘"),
+    (5, &"灰心"),
+    (5, &"挥"),
+    (5, &"恢复"),
+    (5, &"汇率"),
+    (5, &"婚礼"),
+    (5, &"婚姻"),
+    (5, &"活跃"),
+    (5, &"火柴"),
+    (5, &"伙伴"),
+    (5, &"或许"),
+    (5, &"机器"),
+    (5, &"肌肉"),
+    (5, &"基本"),
+    (5, &"激烈"),
+    (5, &"及格"),
+    (5, &"极其"),
+    (5, &"急忙"),
+    (5, &"急诊"),
+    (5, &"集合"),
+    (5, &"集体"),
+    (5, &"集中"),
+    (5, &"计算"),
+    (5, &"记录"),
+    (5, &"记忆"),
+    (5, &"纪录"),
+    (5, &"纪律"),
+    (5, &"纪念"),
+    (5, &"系领带"),
+    (5, &"寂寞"),
+    (5, &"夹子"),
+    (5, &"家庭"),
+    (5, &"家务"),
+    (5, &"家乡"),
+    (5, &"嘉宾"),
+    (5, &"甲"),
+    (5, &"假如"),
+    (5, &"假设"),
+    (5, &"假装"),
+    (5, &"价值"),
+    (5, &"驾驶"),
+    (5, &"嫁"),
+    (5, &"坚决"),
+    (5, &"坚强"),
+    (5, &"肩膀"),
+    (5, &"艰巨"),
+    (5, &"艰苦"),
+    (5, &"兼职"),
+    (5, &"捡"),
+    (5, &"剪刀"),
+    (5, &"简历"),
+    (5, &"简直"),
+    (5, &"建立"),
+    (5, &"建设"),
+    (5, &"建筑"),
+    (5, &"健身"),
+    (5, &"键盘"),
+    (5, &"讲究"),
+    (5, &"讲座"),
+    (5, &"酱油"),
+    (5, &"交换"),
+    (5, &"交际"),
+    (5, &"交往"),
+    (5, &"浇"),
+    (5, &"胶水"),
+    (5, &"角度"),
+    (5, &"狡猾"),
+    (5, &"教材"),
+    (5, &"教练"),
+    (5, &"教训"),
+    (5, &"阶段"),
+    (5, &"结实"),
+    (5, &"接触"),
+    (5, &"接待"),
+    (5, &"接近"),
+    (5, &"节省"),
+    (5, &"结构"),
+    (5, &"结合"),
+    (5, &"结论"),
+    (5, &"结账"),
+    (5, &"戒"),
+    (5, &"戒指"),
+    (5, &"届"),
+    (5, &"借口"),
+    (5, &"金属"),
+    (5, &"尽快"),
+    (5, &"尽量"),
+    (5, &"紧急"),
+    (5, &"谨慎"),
+    (5, &"尽力"),
+    (5, &"进步"),
+    (5, &"进口"),
+    (5, &"近代"),
+    (5, &"经典"),
+    (5, &"经商"),
+    (5, &"经营"),
+    (5, &"精力"),
+    (5, &"精神"),
+    (5, &"酒吧"),
+    (5, &"救"),
+    (5, &"救护车"),
+    (5, &"舅舅"),
+    (5, &"居然"),
+    (5, &"桔子"),
+    (5, &"巨大"),
+    (5, &"具备"),
+    (5, &"具体"),
+    (5, &"俱乐部"),
+    (5, &"据说"),
+    (5, &"捐"),
+    (5, &"决赛"),
+    (5, &"决心"),
+    (5, &"角色"),
+    (5, &"绝对"),
+    (5, &"军事"),
+    (5, &"均匀"),
+    (5, &"卡车"),
+    (5, &"开发"),
+    (5, &"开放"),
+    (5, &"开幕式"),
+    (5, &"开水"),
+    (5, &"砍"),
+    (5, &"看不起"),
+    (5, &"看望"),
+    (5, &"靠"),
+    (5, &"颗"),
+    (5, &"可见"),
+    (5, &"可靠"),
+    (5, &"可怕"),
+    (5, &"克"),
+    (5, &"克服"),
+    (5, &"刻苦"),
+    (5, &"客观"),
+    (5, &"课程"),
+    (5, &"空间"),
+    (5, &"空闲"),
+    (5, &"控制"),
+    (5, &"口味"),
+    (5, &"夸"),
+    (5, &"夸张"),
+    (5, &"会计"),
+    (5, &"宽"),
+    (5, &"昆虫"),
+    (5, &"扩大"),
+    (5, &"辣椒"),
+    (5, &"拦"),
+    (5, &"烂"),
+    (5, &"朗读"),
+    (5, &"劳动"),
+    (5, &"劳驾"),
+    (5, &"老百姓"),
+    (5, &"老板"),
+    (5, &"老婆"),
+    (5, &"老实"),
+    (5, &"老鼠"),
+    (5, &"姥姥"),
+    (5, &"乐观"),
+    (5, &"雷"),
+    (5, &"类型"),
+    (5, &"冷淡"),
+    (5, &"厘米"),
+    (5, &"离婚"),
+    (5, &"梨"),
+    (5, &"理论"),
+    (5, &"理由"),
+    (5, &"力量"),
+    (5, &"立即"),
+    (5, &"立刻"),
+    (5, &"利润"),
+    (5, &"利息"),
+    (5, &"利益"),
+    (5, &"利用"),
+    (5, &"连忙"),
+    (5, &"连续"),
+    (5, &"联合"),
+    (5, &"恋爱"),
+    (5, &"良好"),
+    (5, &"粮食"),
+    (5, &"亮"),
+    (5, &"了不起"),
+    (5, &"列车"),
+    (5, &"临时"),
+    (5, &"灵活"),
+    (5, &"铃"),
+    (5, &"零件"),
+    (5, &"零食"),
+    (5, &"领导"),
+    (5, &"领域"),
+    (5, &"浏览"),
+    (5, &"流传"),
+    (5, &"流泪"),
+    (5, &"龙"),
+    (5, &"漏"),
+    (5, &"陆地"),
+    (5, &"陆续"),
+    (5, &"录取"),
+    (5, &"录音"),
+    (5, &"轮流"),
+    (5, &"论文"),
+    (5, &"逻辑"),
+    (5, &"落后"),
+    (5, &"骂"),
+    (5, &"麦克风"),
+    (5, &"馒头"),
+    (5, &"满足"),
+    (5, &"毛病"),
+    (5, &"矛盾"),
+    (5, &"冒险"),
+    (5, &"贸易"),
+    (5, &"眉毛"),
+    (5, &"媒体"),
+    (5, &"煤炭"),
+    (5, &"美术"),
+    (5, &"魅力"),
+    (5, &"梦想"),
+    (5, &"秘密"),
+    (5, &"秘书"),
+    (5, &"密切"),
+    (5, &"蜜蜂"),
+    (5, &"面对"),
+    (5, &"面积"),
+    (5, &"面临"),
+    (5, &"苗条"),
+    (5, &"描写"),
+    (5, &"敏感"),
+    (5, &"名牌"),
+    (5, &"名片"),
+    (5, &"名胜古迹"),
+    (5, &"明确"),
+    (5, &"明显"),
+    (5, &"明星"),
+    (5, &"命令"),
+    (5, &"命运"),
+    (5, &"摸"),
+    (5, &"模仿"),
+    (5, &"模糊"),
+    (5, &"模特"),
+    (5, &"摩托车"),
+    (5, &"陌生"),
+    (5, &"某"),
+    (5, &"木头"),
+    (5, &"目标"),
+    (5, &"目录"),
+    (5, &"目前"),
+    (5, &"哪怕"),
+    (5, &"难怪"),
+    (5, &"难免"),
+    (5, &"脑袋"),
+    (5, &"内部"),
+    (5, &"内科"),
+    (5, &"嫩"),
+    (5, &"能干"),
+    (5, &"能源"),
+    (5, &"嗯"),
+    (5, &"年代"),
+    (5, &"年纪"),
+    (5, &"念"),
+    (5, &"宁可"),
+    (5, &"牛仔裤"),
+    (5, &"农村"),
+    (5, &"农民"),
+    (5, &"农业"),
+    (5, &"浓"),
+    (5, &"女士"),
+    (5, &"欧洲"),
+    (5, &"偶然"),
+    (5, &"拍"),
+    (5, &"派"),
+    (5, &"盼望"),
+    (5, &"培训"),
+    (5, &"培养"),
+    (5, &"赔偿"),
+    (5, &"佩服"),
+    (5, &"配合"),
+    (5, &"盆"),
+    (5, &"碰"),
+    (5, &"批"),
+    (5, &"批准"),
+    (5, &"披"),
+    (5, &"疲劳"),
+    (5, &"匹"),
+    (5, &"片"),
+    (5, &"片面"),
+    (5, &"飘"),
+    (5, &"拼音"),
+    (5, &"频道"),
+    (5, &"平"),
+    (5, &"平安"),
+    (5, &"平常"),
+    (5, &"平等"),
+    (5, &"平方"),
+    (5, &"平衡"),
+    (5, &"平静"),
+    (5, &"平均"),
+    (5, &"评价"),
+    (5, &"凭"),
+    (5, &"迫切"),
+    (5, &"破产"),
+    (5, &"破坏"),
+    (5, &"期待"),
+    (5, &"期间"),
+    (5, &"其余"),
+    (5, &"奇迹"),
+    (5, &"企业"),
+    (5, &"启发"),
+    (5, &"气氛"),
+    (5, &"汽油"),
+    (5, &"谦虚"),
+    (5, &"签"),
+    (5, &"前途"),
+    (5, &"浅"),
+    (5, &"欠"),
+    (5, &"枪"),
+    (5, &"强调"),
+    (5, &"强烈"),
+    (5, &"墙"),
+    (5, &"抢"),
+    (5, &"悄悄"),
+    (5, &"瞧"),
+    (5, &"巧妙"),
+    (5, &"切"),
+    (5, &"亲爱"),
+    (5, &"亲切"),
+    (5, &"亲自"),
+    (5, &"勤奋"),
+    (5, &"青"),
+    (5, &"青春"),
+    (5, &"青少年"),
+    (5, &"轻视"),
+    (5, &"轻易"),
+    (5, &"清淡"),
+    (5, &"情景"),
+    (5, &"情绪"),
+    (5, &"请求"),
+    (5, &"庆祝"),
+    (5, &"球迷"),
+    (5, &"趋势"),
+    (5, &"取消"),
+    (5, &"娶"),
+    (5, &"去世"),
+    (5, &"圈"),
+    (5, &"权力"),
+    (5, &"权利"),
+    (5, &"全面"),
+    (5, &"劝"),
+    (5, &"缺乏"),
+    (5, &"确定"),
+    (5, &"确认"),
+    (5, &"群"),
+    (5, &"燃烧"),
+    (5, &"绕"),
+    (5, &"热爱"),
+    (5, &"热烈"),
+    (5, &"热心"),
+    (5, &"人才"),
+    (5, &"人口"),
+    (5, &"人类"),
+    (5, &"人民币"),
+    (5, &"人生"),
+    (5, &"人事"),
+    (5, &"人物"),
+    (5, &"人员"),
+    (5, &"忍不住"),
+    (5, &"日常"),
+    (5, &"日程"),
+    (5, &"日历"),
+    (5, &"日期"),
+    (5, &"日用品"),
+    (5, &"日子"),
+    (5, &"如何"),
+    (5, &"如今"),
+    (5, &"软"),
+    (5, &"软件"),
+    (5, &"弱"),
+    (5, &"洒"),
+    (5, &"嗓子"),
+    (5, &"色彩"),
+    (5, &"杀"),
+    (5, &"沙漠"),
+    (5, &"沙滩"),
+    (5, &"傻"),
+    (5, &"晒"),
+    (5, &"删除"),
+    (5, &"闪电"),
+    (5, &"扇子"),
+    (5, &"善良"),
+    (5, &"善于"),
+    (5, &"伤害"),
+    (5, &"商品"),
+    (5, &"商务"),
+    (5, &"商业"),
+    (5, &"上当"),
+    (5, &"蛇"),
+    (5, &"舍不得"),
+    (5, &"设备"),
+    (5, &"设计"),
+    (5, &"设施"),
+    (5, &"射击"),
+    (5, &"摄影"),
+    (5, &"伸"),
+    (5, &"身材"),
+    (5, &"身份"),
+    (5, &"深刻"),
+    (5, &"神话"),
+    (5, &"神秘"),
+    (5, &"升"),
+    (5, &"生产"),
+    (5, &"生动"),
+    (5, &"生长"),
+    (5, &"声调"),
+    (5, &"绳子"),
+    (5, &"省略"),
+    (5, &"胜利"),
+    (5, &"失眠"),
+    (5, &"失去"),
+    (5, &"失业"),
+    (5, &"诗"),
+    (5, &"狮子"),
+    (5, &"湿润"),
+    (5, &"石头"),
+    (5, &"时差"),
+    (5, &"时代"),
+    (5, &"时刻"),
+    (5, &"时髦"),
+    (5, &"时期"),
+    (5, &"时尚"),
+    (5, &"实话"),
+    (5, &"实践"),
+    (5, &"实习"),
+    (5, &"实现"),
+    (5, &"实验"),
+    (5, &"实用"),
+    (5, &"食物"),
+    (5, &"使劲儿"),
+    (5, &"始终"),
+    (5, &"士兵"),
+    (5, &"市场"),
+    (5, &"似的"),
+    (5, &"事实"),
+    (5, &"事物"),
+    (5, &"事先"),
+    (5, &"试卷"),
+    (5, &"收获"),
+    (5, &"收据"),
+    (5, &"手工"),
+    (5, &"手术"),
+    (5, &"手套"),
+    (5, &"手续"),
+    (5, &"手指"),
+    (5, &"首"),
+    (5, &"寿命"),
+    (5, &"受伤"),
+    (5, &"书架"),
+    (5, &"梳子"),
+    (5, &"舒适"),
+    (5, &"输入"),
+    (5, &"蔬菜"),
+    (5, &"熟练"),
+    (5, &"属于"),
+    (5, &"鼠标"),
+    (5, &"数"),
+    (5, &"数据"),
+    (5, &"数码"),
+    (5, &"摔倒"),
+    (5, &"甩"),
+    (5, &"双方"),
+    (5, &"税"),
+    (5, &"说不定"),
+    (5, &"说服"),
+    (5, &"丝绸"),
+    (5, &"丝毫"),
+    (5, &"私人"),
+    (5, &"思考"),
+    (5, &"思想"),
+    (5, &"撕"),
+    (5, &"似乎"),
+    (5, &"搜索"),
+    (5, &"宿舍"),
+    (5, &"随身"),
+    (5, &"随时"),
+    (5, &"随手"),
+    (5, &"碎"),
+    (5, &"损失"),
+    (5, &"缩短"),
+    (5, &"所"),
+    (5, &"锁"),
+    (5, &"台阶"),
+    (5, &"太极拳"),
+    (5, &"太太"),
+    (5, &"谈判"),
+    (5, &"坦率"),
+    (5, &"烫"),
+    (5, &"逃"),
+    (5, &"逃避"),
+    (5, &"桃"),
+    (5, &"淘气"),
+    (5, &"讨价还价"),
+    (5, &"套"),
+    (5, &"特色"),
+    (5, &"特殊"),
+    (5, &"特征"),
+    (5, &"疼爱"),
+    (5, &"提倡"),
+    (5, &"提纲"),
+    (5, &"提问"),
+    (5, &"题目"),
+    (5, &"体会"),
+    (5, &"体贴"),
+    (5, &"体现"),
+    (5, &"体验"),
+    (5, &"天空"),
+    (5, &"天真"),
+    (5, &"调皮"),
+    (5, &"调整"),
+    (5, &"挑战"),
+    (5, &"通常"),
+    (5, &"统一"),
+    (5, &"痛苦"),
+    (5, &"痛快"),
+    (5, &"偷"),
+    (5, &"投入"),
+    (5, &"投资"),
+    (5, &"透明"),
+    (5, &"突出"),
+    (5, &"土地"),
+    (5, &"土豆"),
+    (5, &"吐"),
+    (5, &"兔子"),
+    (5, &"团"),
+    (5, &"推辞"),
+    (5, &"推广"),
+    (5, &"推荐"),
+    (5, &"退"),
+    (5, &"退步"),
+    (5, &"退休"),
+    (5, &"歪"),
+    (5, &"外公"),
+    (5, &"外交"),
+    (5, &"完美"),
+    (5, &"完善"),
+    (5, &"完整"),
+    (5, &"玩具"),
+    (5, &"万一"),
+    (5, &"王子"),
+    (5, &"网络"),
+    (5, &"往返"),
+    (5, &"危害"),
+    (5, &"威胁"),
+    (5, &"微笑"),
+    (5, &"违反"),
+    (5, &"围巾"),
+    (5, &"围绕"),
+    (5, &"唯一"),
+    (5, &"维修"),
+    (5, &"伟大"),
+    (5, &"尾巴"),
+    (5, &"委屈"),
+    (5, &"未必"),
+    (5, &"未来"),
+    (5, &"位于"),
+    (5, &"位置"),
+    (5, &"胃"),
+    (5, &"胃口"),
+    (5, &"温暖"),
+    (5, &"温柔"),
+    (5, &"文件"),
+    (5, &"文具"),
+    (5, &"文明"),
+    (5, &"文学"),
+    (5, &"文字"),
+    (5, &"闻"),
+    (5, &"吻"),
+    (5, &"稳定"),
+    (5, &"问候"),
+    (5, &"卧室"),
+    (5, &"握手"),
+    (5, &"屋子"),
+    (5, &"无奈"),
+    (5, &"无数"),
+    (5, &"无所谓"),
+    (5, &"武术"),
+    (5, &"勿"),
+    (5, &"物理"),
+    (5, &"物质"),
+    (5, &"雾"),
+    (5, &"吸取"),
+    (5, &"吸收"),
+    (5, &"戏剧"),
+    (5, &"系"),
+    (5, &"系统"),
+    (5, &"细节"),
+    (5, &"瞎"),
+    (5, &"下载"),
+    (5, &"吓"),
+    (5, &"夏令营"),
+    (5, &"鲜艳"),
+    (5, &"显得"),
+    (5, &"显然"),
+    (5, &"显示"),
+    (5, &"县"),
+    (5, &"现代"),
+    (5, &"现实"),
+    (5, &"现象"),
+    (5, &"限制"),
+    (5, &"相处"),
+    (5, &"相当"),
+    (5, &"相对"),
+    (5, &"相关"),
+    (5, &"相似"),
+    (5, &"香肠"),
+    (5, &"享受"),
+    (5, &"想念"),
+    (5, &"想象"),
+    (5, &"项"),
+    (5, &"项链"),
+    (5, &"项目"),
+    (5, &"象棋"),
+    (5, &"象征"),
+    (5, &"消费"),
+    (5, &"消化"),
+    (5, &"消极"),
+    (5, &"消失"),
+    (5, &"销售"),
+    (5, &"小麦"),
+    (5, &"小气"),
+    (5, &"孝顺"),
+    (5, &"效率"),
+    (5, &"歇"),
+    (5, &"斜"),
+    (5, &"写作"),
+    (5, &"血"),
+    (5, &"心理"),
+    (5, &"心脏"),
+    (5, &"欣赏"),
+    (5, &"信号"),
+    (5, &"信任"),
+    (5, &"行动"),
+    (5, &"行人"),
+    (5, &"行为"),
+    (5, &"形成"),
+    (5, &"形容"),
+    (5, &"形式"),
+    (5, &"形势"),
+    (5, &"形象"),
+    (5, &"形状"),
+    (5, &"幸亏"),
+    (5, &"幸运"),
+    (5, &"性质"),
+    (5, &"兄弟"),
+    (5, &"胸"),
+    (5, &"休闲"),
+    (5, &"修改"),
+    (5, &"虚心"),
+    (5, &"叙述"),
+    (5, &"宣布"),
+    (5, &"宣传"),
+    (5, &"学历"),
+    (5, &"学术"),
+    (5, &"学问"),
+    (5, &"寻找"),
+    (5, &"询问"),
+    (5, &"训练"),
+    (5, &"迅速"),
+    (5, &"押金"),
+    (5, &"牙齿"),
+    (5, &"延长"),
+    (5, &"严肃"),
+    (5, &"演讲"),
+    (5, &"宴会"),
+    (5, &"阳台"),
+    (5, &"痒"),
+    (5, &"样式"),
+    (5, &"腰"),
+    (5, &"摇"),
+    (5, &"咬"),
+    (5, &"要不"),
+    (5, &"业务"),
+    (5, &"业余"),
+    (5, &"夜"),
+    (5, &"一辈子"),
+    (5, &"一旦"),
+    (5, &"一律"),
+    (5, &"一再"),
+    (5, &"一致"),
+    (5, &"依然"),
+    (5, &"移动"),
+    (5, &"移民"),
+    (5, &"遗憾"),
+    (5, &"疑问"),
+    (5, &"乙"),
+    (5, &"以及"),
+    (5, &"以来"),
+    (5, &"亿"),
+    (5, &"义务"),
+    (5, &"议论"),
+    (5, &"意外"),
+    (5, &"意义"),
+    (5, &"因而"),
+    (5, &"因素"),
+    (5, &"银"),
+    (5, &"印刷"),
+    (5, &"英俊"),
+    (5, &"英雄"),
+    (5, &"迎接"),
+    (5, &"营养"),
+    (5, &"营业"),
+    (5, &"影子"),
+    (5, &"应付"),
+    (5, &"应用"),
+    (5, &"硬"),
+    (5, &"硬件"),
+    (5, &"拥抱"),
+    (5, &"拥挤"),
+    (5, &"勇气"),
+    (5, &"用功"),
+    (5, &"用途"),
+    (5, &"优惠"),
+    (5, &"优美"),
+    (5, &"优势"),
+    (5, &"悠久"),
+    (5, &"犹豫"),
+    (5, &"油炸"),
+    (5, &"游览"),
+    (5, &"有利"),
+    (5, &"幼儿园"),
+    (5, &"娱乐"),
+    (5, &"与其"),
+    (5, &"语气"),
+    (5, &"玉米"),
+    (5, &"预报"),
+    (5, &"预订"),
+    (5, &"预防"),
+    (5, &"元旦"),
+    (5, &"员工"),
+    (5, &"原料"),
+    (5, &"原则"),
+    (5, &"圆"),
+    (5, &"愿望"),
+    (5, &"乐器"),
+    (5, &"晕"),
+    (5, &"运气"),
+    (5, &"运输"),
+    (5, &"运用"),
+    (5, &"灾害"),
+    (5, &"再三"),
+    (5, &"在乎"),
+    (5, &"在于"),
+    (5, &"赞成"),
+    (5, &"赞美"),
+    (5, &"糟糕"),
+    (5, &"造成"),
+    (5, &"则"),
+    (5, &"责备"),
+    (5, &"摘"),
+    (5, &"窄"),
+    (5, &"粘贴"),
+    (5, &"展开"),
+    (5, &"展览"),
+    (5, &"占"),
+    (5, &"战争"),
+    (5, &"长辈"),
+    (5, &"涨"),
+    (5, &"掌握"),
+    (5, &"账户"),
+    (5, &"招待"),
+    (5, &"着火"),
+    (5, &"着凉"),
+    (5, &"召开"),
+    (5, &"照常"),
+    (5, &"哲学"),
+    (5, &"针对"),
+    (5, &"珍惜"),
+    (5, &"真实"),
+    (5, &"诊断"),
+    (5, &"阵"),
+    (5, &"振动"),
+    (5, &"争论"),
+    (5, &"争取"),
+    (5, &"征求"),
+    (5, &"睁"),
+    (5, &"整个"),
+    (5, &"整齐"),
+    (5, &"整体"),
+    (5, &"正"),
+    (5, &"证件"),
+    (5, &"证据"),
+    (5, &"政府"),
+    (5, &"政治"),
+    (5, &"挣"),
+    (5, &"支"),
+    (5, &"支票"),
+    (5, &"执照"),
+    (5, &"直"),
+    (5, &"指导"),
+    (5, &"指挥"),
+    (5, &"至今"),
+    (5, &"至于"),
+    (5, &"志愿者"),
+    (5, &"制定"),
+    (5, &"制度"),
+    (5, &"制造"),
+    (5, &"制作"),
+    (5, &"治疗"),
+    (5, &"秩序"),
+    (5, &"智慧"),
+    (5, &"中介"),
+    (5, &"中心"),
+    (5, &"中旬"),
+    (5, &"种类"),
+    (5, &"重大"),
+    (5, &"重量"),
+    (5, &"周到"),
+    (5, &"猪"),
+    (5, &"竹子"),
+    (5, &"逐步"),
+    (5, &"逐渐"),
+    (5, &"主持"),
+    (5, &"主动"),
+    (5, &"主观"),
+    (5, &"主人"),
+    (5, &"主任"),
+    (5, &"主题"),
+    (5, &"主席"),
+    (5, &"主张"),
+    (5, &"煮"),
+    (5, &"注册"),
+    (5, &"祝福"),
+    (5, &"抓"),
+    (5, &"抓紧"),
+    (5, &"专家"),
+    (5, &"专心"),
+    (5, &"转变"),
+    (5, &"转告"),
+    (5, &"装"),
+    (5, &"装饰"),
+    (5, &"装修"),
+    (5, &"状况"),
+    (5, &"状态"),
+    (5, &"撞"),
+    (5, &"追"),
+    (5, &"追求"),
+    (5, &"咨询"),
+    (5, &"姿势"),
+    (5, &"资格"),
+    (5, &"资金"),
+    (5, &"资料"),
+    (5, &"资源"),
+    (5, &"紫"),
+    (5, &"自从"),
+    (5, &"自动"),
+    (5, &"自豪"),
+    (5, &"自觉"),
+    (5, &"自私"),
+    (5, &"自由"),
+    (5, &"自愿"),
+    (5, &"字母"),
+    (5, &"字幕"),
+    (5, &"综合"),
+    (5, &"总裁"),
+    (5, &"总共"),
+    (5, &"总理"),
+    (5, &"总算"),
+    (5, &"总统"),
+    (5, &"总之"),
+    (5, &"阻止"),
+    (5, &"组"),
+    (5, &"组成"),
+    (5, &"组合"),
+    (5, &"组织"),
+    (5, &"最初"),
+    (5, &"醉"),
+    (5, &"尊敬"),
+    (5, &"遵守"),
+    (5, &"作品"),
+    (5, &"作为"),
+    (5, &"作文"),
+    (6, &"挨"),
+    (6, &"癌症"),
+    (6, &"爱不释手"),
+    (6, &"爱戴"),
+    (6, &"暧昧"),
+    (6, &"安宁"),
+    (6, &"安详"),
+    (6, &"安置"),
+    (6, &"按摩"),
+    (6, &"案件"),
+    (6, &"案例"),
+    (6, &"暗示"),
+    (6, &"昂贵"),
+    (6, &"凹凸"),
+    (6, &"熬"),
+    (6, &"奥秘"),
+    (6, &"巴不得"),
+    (6, &"巴结"),
+    (6, &"扒"),
+    (6, &"疤"),
+    (6, &"拔苗助长"),
+    (6, &"把关"),
+    (6, &"把手"),
+    (6, &"罢工"),
+    (6, &"霸道"),
+    (6, &"掰"),
+    (6, &"摆脱"),
+    (6, &"败坏"),
+    (6, &"拜访"),
+    (6, &"拜年"),
+    (6, &"拜托"),
+    (6, &"颁布"),
+    (6, &"颁发"),
+    (6, &"斑"),
+    (6, &"版本"),
+    (6, &"半途而废"),
+    (6, &"扮演"),
+    (6, &"伴侣"),
+    (6, &"伴随"),
+    (6, &"绑架"),
+    (6, &"榜样"),
+    (6, &"磅"),
+    (6, &"包庇"),
+    (6, &"包袱"),
+    (6, &"包围"),
+    (6, &"包装"),
+    (6, &"饱和"),
+    (6, &"饱经沧桑"),
+    (6, &"保管"),
+    (6, &"保密"),
+    (6, &"保姆"),
+    (6, &"保守"),
+    (6, &"保卫"),
+    (6, &"保养"),
+    (6, &"保障"),
+    (6, &"保重"),
+    (6, &"报仇"),
+    (6, &"报酬"),
+    (6, &"报答"),
+    (6, &"报复"),
+    (6, &"报警"),
+    (6, &"报销"),
+    (6, &"抱负"),
+    (6, &"暴力"),
+    (6, &"暴露"),
+    (6, &"曝光"),
+    (6, &"爆发"),
+    (6, &"爆炸"),
+    (6, &"卑鄙"),
+    (6, &"悲哀"),
+    (6, &"悲惨"),
+    (6, &"北极"),
+    (6, &"贝壳"),
+    (6, &"备份"),
+    (6, &"备忘录"),
+    (6, &"背叛"),
+    (6, &"背诵"),
+    (6, &"被动"),
+    (6, &"被告"),
+    (6, &"奔波"),
+    (6, &"奔驰"),
+    (6, &"本能"),
+    (6, &"本钱"),
+    (6, &"本人"),
+    (6, &"本身"),
+    (6, &"本事"),
+    (6, &"笨拙"),
+    (6, &"崩溃"),
+    (6, &"甭"),
+    (6, &"迸发"),
+    (6, &"蹦"),
+    (6, &"逼迫"),
+    (6, &"鼻涕"),
+    (6, &"比方"),
+    (6, &"比喻"),
+    (6, &"比重"),
+    (6, &"鄙视"),
+    (6, &"闭塞"),
+    (6, &"弊病"),
+    (6, &"弊端"),
+    (6, &"臂"),
+    (6, &"边疆"),
+    (6, &"边界"),
+    (6, &"边境"),
+    (6, &"边缘"),
+    (6, &"编织"),
+    (6, &"鞭策"),
+    (6, &"贬低"),
+    (6, &"贬义"),
+    (6, &"扁"),
+    (6, &"变故"),
+    (6, &"变迁"),
+    (6, &"变质"),
+    (6, &"便利"),
+    (6, &"便条"),
+    (6, &"便于"),
+    (6, &"遍布"),
+    (6, &"辨认"),
+    (6, &"辩护"),
+    (6, &"辩解"),
+    (6, &"辩证"),
+    (6, &"辫子"),
+    (6, &"标本"),
+    (6, &"标记"),
+    (6, &"标题"),
+    (6, &"表决"),
+    (6, &"表态"),
+    (6, &"表彰"),
+    (6, &"憋"),
+    (6, &"别墅"),
+    (6, &"别致"),
+    (6, &"别扭"),
+    (6, &"濒临"),
+    (6, &"冰雹"),
+    (6, &"丙"),
+    (6, &"并非"),
+    (6, &"并列"),
+    (6, &"拨"),
+    (6, &"波浪"),
+    (6, &"波涛"),
+    (6, &"剥削"),
+    (6, &"播种"),
+    (6, &"伯母"),
+    (6, &"博大精深"),
+    (6, &"博览会"),
+    (6, &"搏斗"),
+    (6, &"薄弱"),
+    (6, &"补偿"),
+    (6, &"补救"),
+    (6, &"补贴"),
+    (6, &"捕捉"),
+    (6, &"哺乳"),
+    (6, &"不得已"),
+    (6, &"不妨"),
+    (6, &"不敢当"),
+    (6, &"不顾"),
+    (6, &"不禁"),
+    (6, &"不堪"),
+    (6, &"不可思议"),
+    (6, &"不愧"),
+    (6, &"不料"),
+    (6, &"不免"),
+    (6, &"不时"),
+    (6, &"不惜"),
+    (6, &"不相上下"),
+    (6, &"不像话"),
+    (6, &"不屑一顾"),
+    (6, &"不言而喻"),
+    (6, &"不由得"),
+    (6, &"不择手段"),
+    (6, &"不止"),
+    (6, &"布告"),
+    (6, &"布局"),
+    (6, &"布置"),
+    (6, &"步伐"),
+    (6, &"部署"),
+    (6, &"部位"),
+    (6, &"才干"),
+    (6, &"财富"),
+    (6, &"财务"),
+    (6, &"财政"),
+    (6, &"裁缝"),
+    (6, &"裁判"),
+    (6, &"裁员"),
+    (6, &"采购"),
+    (6, &"采集"),
+    (6, &"采纳"),
+    (6, &"彩票"),
+    (6, &"参谋"),
+    (6, &"参照"),
+    (6, &"残疾"),
+    (6, &"残酷"),
+    (6, &"残留"),
+    (6, &"残忍"),
+    (6, &"灿烂"),
+    (6, &"仓促"),
+    (6, &"仓库"),
+    (6, &"苍白"),
+    (6, &"舱"),
+    (6, &"操劳"),
+    (6, &"操练"),
+    (6, &"操纵"),
+    (6, &"操作"),
+    (6, &"嘈杂"),
+    (6, &"草案"),
+    (6, &"草率"),
+    (6, &"侧面"),
+    (6, &"测量"),
+    (6, &"策划"),
+    (6, &"策略"),
+    (6, &"层出不穷"),
+    (6, &"层次"),
+    (6, &"差别"),
+    (6, &"插座"),
+    (6, &"查获"),
+    (6, &"岔"),
+    (6, &"刹那"),
+    (6, &"诧异"),
+    (6, &"柴油"),
+    (6, &"搀"),
+    (6, &"馋"),
+    (6, &"缠绕"),
+    (6, &"产业"),
+    (6, &"阐述"),
+    (6, &"颤抖"),
+    (6, &"昌盛"),
+    (6, &"尝试"),
+    (6, &"偿还"),
+    (6, &"场合"),
+    (6, &"场面"),
+    (6, &"场所"),
+    (6, &"敞开"),
+    (6, &"畅通"),
+    (6, &"畅销"),
+    (6, &"倡导"),
+    (6, &"倡议"),
+    (6, &"钞票"),
+    (6, &"超越"),
+    (6, &"巢穴"),
+    (6, &"朝代"),
+    (6, &"嘲笑"),
+    (6, &"潮流"),
+    (6, &"撤退"),
+    (6, &"撤销"),
+    (6, &"沉淀"),
+    (6, &"沉闷"),
+    (6, &"沉思"),
+    (6, &"沉重"),
+    (6, &"沉着"),
+    (6, &"陈旧"),
+    (6, &"陈列"),
+    (6, &"陈述"),
+    (6, &"衬托"),
+    (6, &"称心如意"),
+    (6, &"称号"),
+    (6, &"成本"),
+    (6, &"成交"),
+    (6, &"成天"),
+    (6, &"成效"),
+    (6, &"成心"),
+    (6, &"成员"),
+    (6, &"呈现"),
+    (6, &"诚挚"),
+    (6, &"承办"),
+    (6, &"承包"),
+    (6, &"承诺"),
+    (6, &"城堡"),
+    (6, &"乘"),
+    (6, &"盛"),
+    (6, &"惩罚"),
+    (6, &"澄清"),
+    (6, &"橙"),
+    (6, &"秤"),
+    (6, &"吃苦"),
+    (6, &"吃力"),
+    (6, &"迟钝"),
+    (6, &"迟缓"),
+    (6, &"迟疑"),
+    (6, &"持久"),
+    (6, &"赤道"),
+    (6, &"赤字"),
+    (6, &"冲动"),
+    (6, &"冲击"),
+    (6, &"冲突"),
+    (6, &"充当"),
+    (6, &"充沛"),
+    (6, &"充实"),
+    (6, &"充足"),
+    (6, &"重叠"),
+    (6, &"崇拜"),
+    (6, &"崇高"),
+    (6, &"崇敬"),
+    (6, &"稠密"),
+    (6, &"筹备"),
+    (6, &"丑恶"),
+    (6, &"出路"),
+    (6, &"出卖"),
+    (6, &"出身"),
+    (6, &"出神"),
+    (6, &"出息"),
+    (6, &"初步"),
+    (6, &"除"),
+    (6, &"处分"),
+    (6, &"处境"),
+    (6, &"处置"),
+    (6, &"储备"),
+    (6, &"储存"),
+    (6, &"储蓄"),
+    (6, &"触犯"),
+    (6, &"川流不息"),
+    (6, &"穿越"),
+    (6, &"传达"),
+    (6, &"传单"),
+    (6, &"传授"),
+    (6, &"船舶"),
+    (6, &"喘气"),
+    (6, &"串"),
+    (6, &"床单"),
+    (6, &"创立"),
+    (6, &"创新"),
+    (6, &"创业"),
+    (6, &"创作"),
+    (6, &"吹牛"),
+    (6, &"吹捧"),
+    (6, &"炊烟"),
+    (6, &"垂直"),
+    (6, &"锤"),
+    (6, &"纯粹"),
+    (6, &"纯洁"),
+    (6, &"慈善"),
+    (6, &"慈祥"),
+    (6, &"磁带"),
+    (6, &"雌雄"),
+    (6, &"次品"),
+    (6, &"次序"),
+    (6, &"伺候"),
+    (6, &"刺"),
+    (6, &"从容"),
+    (6, &"丛"),
+    (6, &"凑合"),
+    (6, &"粗鲁"),
+    (6, &"窜"),
+    (6, &"摧残"),
+    (6, &"脆弱"),
+    (6, &"搓"),
+    (6, &"磋商"),
+    (6, &"挫折"),
+    (6, &"搭"),
+    (6, &"搭档"),
+    (6, &"搭配"),
+    (6, &"达成"),
+    (6, &"答辩"),
+    (6, &"答复"),
+    (6, &"打包"),
+    (6, &"打官司"),
+    (6, &"打击"),
+    (6, &"打架"),
+    (6, &"打量"),
+    (6, &"打猎"),
+    (6, &"打仗"),
+    (6, &"大不了"),
+    (6, &"大臣"),
+    (6, &"大伙儿"),
+    (6, &"大肆"),
+    (6, &"大体"),
+    (6, &"大意"),
+    (6, &"大致"),
+    (6, &"歹徒"),
+    (6, &"代价"),
+    (6, &"代理"),
+    (6, &"带领"),
+    (6, &"怠慢"),
+    (6, &"逮捕"),
+    (6, &"担保"),
+    (6, &"胆怯"),
+    (6, &"诞辰"),
+    (6, &"诞生"),
+    (6, &"淡季"),
+    (6, &"淡水"),
+    (6, &"蛋白质"),
+    (6, &"当场"),
+    (6, &"当初"),
+    (6, &"当代"),
+    (6, &"当面"),
+    (6, &"当前"),
+    (6, &"当事人"),
+    (6, &"当务之急"),
+    (6, &"当选"),
+    (6, &"党"),
+    (6, &"档案"),
+    (6, &"档次"),
+    (6, &"导弹"),
+    (6, &"导航"),
+    (6, &"导向"),
+    (6, &"捣乱"),
+    (6, &"倒闭"),
+    (6, &"盗窃"),
+    (6, &"稻谷"),
+    (6, &"得不偿失"),
+    (6, &"得力"),
+    (6, &"得天独厚"),
+    (6, &"得罪"),
+    (6, &"灯笼"),
+    (6, &"登陆"),
+    (6, &"登录"),
+    (6, &"蹬"),
+    (6, &"等候"),
+    (6, &"等级"),
+    (6, &"瞪"),
+    (6, &"堤坝"),
+    (6, &"敌视"),
+    (6, &"抵达"),
+    (6, &"抵抗"),
+    (6, &"抵制"),
+    (6, &"地步"),
+    (6, &"地势"),
+    (6, &"地质"),
+    (6, &"递增"),
+    (6, &"颠簸"),
+    (6, &"颠倒"),
+    (6, &"典礼"),
+    (6, &"典型"),
+    (6, &"点缀"),
+    (6, &"电源"),
+    (6, &"垫"),
+    (6, &"惦记"),
+    (6, &"奠定"),
+    (6, &"叼"),
+    (6, &"雕刻"),
+    (6, &"雕塑"),
+    (6, &"吊"),
+    (6, &"调动"),
+    (6, &"跌"),
+    (6, &"丁"),
+    (6, &"叮嘱"),
+    (6, &"盯"),
+    (6, &"定期"),
+    (6, &"定义"),
+    (6, &"丢人"),
+    (6, &"丢三落四"),
+    (6, &"东道主"),
+    (6, &"东张西望"),
+    (6, &"董事长"),
+    (6, &"动荡"),
+    (6, &"动机"),
+    (6, &"动静"),
+    (6, &"动力"),
+    (6, &"动脉"),
+    (6, &"动身"),
+    (6, &"动手"),
+    (6, &"动态"),
+    (6, &"动员"),
+    (6, &"冻结"),
+    (6, &"栋"),
+    (6, &"兜"),
+    (6, &"陡峭"),
+    (6, &"斗争"),
+    (6, &"督促"),
+    (6, &"毒品"),
+    (6, &"独裁"),
+    (6, &"堵塞"),
+    (6, &"赌博"),
+    (6, &"杜绝"),
+    (6, &"端"),
+    (6, &"端午节"),
+    (6, &"端正"),
+    (6, &"短促"),
+    (6, &"断定"),
+    (6, &"断绝"),
+    (6, &"堆积"),
+    (6, &"队伍"),
+    (6, &"对策"),
+    (6, &"对称"),
+    (6, &"对付"),
+    (6, &"对抗"),
+    (6, &"对立"),
+    (6, &"对联"),
+    (6, &"对应"),
+    (6, &"对照"),
+    (6, &"兑现"),
+    (6, &"顿时"),
+    (6, &"多元化"),
+    (6, &"哆嗦"),
+    (6, &"堕落"),
+    (6, &"额外"),
+    (6, &"恶心"),
+    (6, &"恶化"),
+    (6, &"遏制"),
+    (6, &"恩怨"),
+    (6, &"而已"),
+    (6, &"二氧化碳"),
+    (6, &"发布"),
+    (6, &"发财"),
+    (6, &"发呆"),
+    (6, &"发动"),
+    (6, &"发觉"),
+    (6, &"发射"),
+    (6, &"发誓"),
+    (6, &"发行"),
+    (6, &"发炎"),
+    (6, &"发扬"),
+    (6, &"发育"),
+    (6, &"法人"),
+    (6, &"番"),
+    (6, &"凡是"),
+    (6, &"繁华"),
+    (6, &"繁忙"),
+    (6, &"繁体字"),
+    (6, &"繁殖"),
+    (6, &"反驳"),
+    (6, &"反常"),
+    (6, &"反感"),
+    (6, &"反抗"),
+    (6, &"反馈"),
+    (6, &"反面"),
+    (6, &"反射"),
+    (6, &"反思"),
+    (6, &"反问"),
+    (6, &"反之"),
+    (6, &"泛滥"),
+    (6, &"范畴"),
+    (6, &"贩卖"),
+    (6, &"方位"),
+    (6, &"方言"),
+    (6, &"方圆"),
+    (6, &"方针"),
+    (6, &"防守"),
+    (6, &"防御"),
+    (6, &"防止"),
+    (6, &"防治"),
+    (6, &"访问"),
+    (6, &"纺织"),
+    (6, &"放大"),
+    (6, &"放射"),
+    (6, &"飞禽走兽"),
+    (6, &"飞翔"),
+    (6, &"飞跃"),
+    (6, &"非法"),
+    (6, &"肥沃"),
+    (6, &"诽谤"),
+    (6, &"肺"),
+    (6, &"废除"),
+    (6, &"废寝忘食"),
+    (6, &"废墟"),
+    (6, &"沸腾"),
+    (6, &"分辨"),
+    (6, &"分寸"),
+    (6, &"分红"),
+    (6, &"分解"),
+    (6, &"分裂"),
+    (6, &"分泌"),
+    (6, &"分明"),
+    (6, &"分歧"),
+    (6, &"分散"),
+    (6, &"吩咐"),
+    (6, &"坟墓"),
+    (6, &"粉末"),
+    (6, &"粉色"),
+    (6, &"粉碎"),
+    (6, &"分量"),
+    (6, &"愤怒"),
+    (6, &"丰满"),
+    (6, &"丰盛"),
+    (6, &"丰收"),
+    (6, &"风暴"),
+    (6, &"风度"),
+    (6, &"风光"),
+    (6, &"风气"),
+    (6, &"风趣"),
+    (6, &"风土人情"),
+    (6, &"风味"),
+    (6, &"封闭"),
+    (6, &"封建"),
+    (6, &"封锁"),
+    (6, &"锋利"),
+    (6, &"逢"),
+    (6, &"奉献"),
+    (6, &"否决"),
+    (6, &"夫妇"),
+    (6, &"夫人"),
+    (6, &"敷衍"),
+    (6, &"服从"),
+    (6, &"服气"),
+    (6, &"俘虏"),
+    (6, &"符号"),
+    (6, &"幅度"),
+    (6, &"辐射"),
+    (6, &"福利"),
+    (6, &"福气"),
+    (6, &"抚摸"),
+    (6, &"抚养"),
+    (6, &"俯视"),
+    (6, &"辅助"),
+    (6, &"腐败"),
+    (6, &"腐烂"),
+    (6, &"腐蚀"),
+    (6, &"腐朽"),
+    (6, &"负担"),
+    (6, &"附和"),
+    (6, &"附件"),
+    (6, &"附属"),
+    (6, &"复活"),
+    (6, &"复兴"),
+    (6, &"副"),
+    (6, &"赋予"),
+    (6, &"富裕"),
+    (6, &"腹泻"),
+    (6, &"覆盖"),
+    (6, &"改良"),
+    (6, &"钙"),
+    (6, &"盖章"),
+    (6, &"干旱"),
+    (6, &"干扰"),
+    (6, &"干涉"),
+    (6, &"干预"),
+    (6, &"尴尬"),
+    (6, &"感慨"),
+    (6, &"感染"),
+    (6, &"干劲"),
+    (6, &"纲领"),
+    (6, &"岗位"),
+    (6, &"港口"),
+    (6, &"港湾"),
+    (6, &"杠杆"),
+    (6, &"高超"),
+    (6, &"高潮"),
+    (6, &"高峰"),
+    (6, &"高明"),
+    (6, &"高尚"),
+    (6, &"高涨"),
+    (6, &"稿件"),
+    (6, &"告辞"),
+    (6, &"告诫"),
+    (6, &"疙瘩"),
+    (6, &"鸽子"),
+    (6, &"搁"),
+    (6, &"割"),
+    (6, &"歌颂"),
+    (6, &"革命"),
+    (6, &"格局"),
+    (6, &"格式"),
+    (6, &"隔阂"),
+    (6, &"隔离"),
+    (6, &"个体"),
+    (6, &"各抒己见"),
+    (6, &"根深蒂固"),
+    (6, &"根源"),
+    (6, &"跟前"),
+    (6, &"跟随"),
+    (6, &"跟踪"),
+    (6, &"更新"),
+    (6, &"更正"),
+    (6, &"耕地"),
+    (6, &"工艺品"),
+    (6, &"公安局"),
+    (6, &"公道"),
+    (6, &"公告"),
+    (6, &"公关"),
+    (6, &"公民"),
+    (6, &"公然"),
+    (6, &"公认"),
+    (6, &"公式"),
+    (6, &"公务"),
+    (6, &"公正"),
+    (6, &"公证"),
+    (6, &"功劳"),
+    (6, &"功效"),
+    (6, &"攻击"),
+    (6, &"攻克"),
+    (6, &"供不应求"),
+    (6, &"供给"),
+    (6, &"宫殿"),
+    (6, &"恭敬"),
+    (6, &"巩固"),
+    (6, &"共和国"),
+    (6, &"共计"),
+    (6, &"共鸣"),
+    (6, &"勾结"),
+    (6, &"钩子"),
+    (6, &"构思"),
+    (6, &"孤独"),
+    (6, &"孤立"),
+    (6, &"姑且"),
+    (6, &"辜负"),
+    (6, &"古董"),
+    (6, &"古怪"),
+    (6, &"股东"),
+    (6, &"股份"),
+    (6, &"骨干"),
+    (6, &"鼓动"),
+    (6, &"固然"),
+    (6, &"固体"),
+    (6, &"固有"),
+    (6, &"固执"),
+    (6, &"故乡"),
+    (6, &"故障"),
+    (6, &"顾虑"),
+    (6, &"顾问"),
+    (6, &"雇佣"),
+    (6, &"拐杖"),
+    (6, &"关怀"),
+    (6, &"关照"),
+    (6, &"观光"),
+    (6, &"官方"),
+    (6, &"管辖"),
+    (6, &"贯彻"),
+    (6, &"惯例"),
+    (6, &"灌溉"),
+    (6, &"罐"),
+    (6, &"光彩"),
+    (6, &"光辉"),
+    (6, &"光芒"),
+    (6, &"光荣"),
+    (6, &"广阔"),
+    (6, &"归根到底"),
+    (6, &"归还"),
+    (6, &"规范"),
+    (6, &"规格"),
+    (6, &"规划"),
+    (6, &"规章"),
+    (6, &"轨道"),
+    (6, &"贵族"),
+    (6, &"跪"),
+    (6, &"棍棒"),
+    (6, &"国防"),
+    (6, &"国务院"),
+    (6, &"果断"),
+    (6, &"过度"),
+    (6, &"过渡"),
+    (6, &"过奖"),
+    (6, &"过滤"),
+    (6, &"过失"),
+    (6, &"过问"),
+    (6, &"过瘾"),
+    (6, &"过于"),
+    (6, &"嗨"),
+    (6, &"海拔"),
+    (6, &"海滨"),
+    (6, &"含糊"),
+    (6, &"含义"),
+    (6, &"寒暄"),
+    (6, &"罕见"),
+    (6, &"捍卫"),
+    (6, &"行列"),
+    (6, &"航空"),
+    (6, &"航天"),
+    (6, &"航行"),
+    (6, &"毫米"),
+    (6, &"毫无"),
+    (6, &"豪迈"),
+    (6, &"号召"),
+    (6, &"耗费"),
+    (6, &"呵"),
+    (6, &"合并"),
+    (6, &"合成"),
+    (6, &"合伙"),
+    (6, &"合算"),
+    (6, &"和蔼"),
+    (6, &"和解"),
+    (6, &"和睦"),
+    (6, &"和气"),
+    (6, &"和谐"),
+    (6, &"嘿"),
+    (6, &"痕迹"),
+    (6, &"狠心"),
+    (6, &"恨不得"),
+    (6, &"横"),
+    (6, &"哼"),
+    (6, &"轰动"),
+    (6, &"烘"),
+    (6, &"宏观"),
+    (6, &"宏伟"),
+    (6, &"洪水"),
+    (6, &"哄"),
+    (6, &"喉咙"),
+    (6, &"吼"),
+    (6, &"后代"),
+    (6, &"后顾之忧"),
+    (6, &"后勤"),
+    (6, &"候选"),
+    (6, &"呼唤"),
+    (6, &"呼啸"),
+    (6, &"呼吁"),
+    (6, &"忽略"),
+    (6, &"胡乱"),
+    (6, &"胡须"),
+    (6, &"湖泊"),
+    (6, &"花瓣"),
+    (6, &"花蕾"),
+    (6, &"华丽"),
+    (6, &"华侨"),
+    (6, &"化肥"),
+    (6, &"化石"),
+    (6, &"化验"),
+    (6, &"化妆"),
+    (6, &"划分"),
+    (6, &"画蛇添足"),
+    (6, &"话筒"),
+    (6, &"欢乐"),
+    (6, &"还原"),
+    (6, &"环节"),
+    (6, &"缓和"),
+    (6, &"患者"),
+    (6, &"荒凉"),
+    (6, &"荒谬"),
+    (6, &"荒唐"),
+    (6, &"皇帝"),
+    (6, &"皇后"),
+    (6, &"黄昏"),
+    (6, &"恍然大悟"),
+    (6, &"晃"),
+    (6, &"挥霍"),
+    (6, &"辉煌"),
+    (6, &"回报"),
+    (6, &"回避"),
+    (6, &"回顾"),
+    (6, &"回收"),
+    (6, &"悔恨"),
+    (6, &"毁灭"),
+    (6, &"汇报"),
+    (6, &"会晤"),
+    (6, &"贿赂"),
+    (6, &"昏迷"),
+    (6, &"荤"),
+    (6, &"浑身"),
+    (6, &"混合"),
+    (6, &"混乱"),
+    (6, &"混淆"),
+    (6, &"混浊"),
+    (6, &"活该"),
+    (6, &"活力"),
+    (6, &"火箭"),
+    (6, &"火焰"),
+    (6, &"火药"),
+    (6, &"货币"),
+    (6, &"讥笑"),
+    (6, &"饥饿"),
+    (6, &"机动"),
+    (6, &"机构"),
+    (6, &"机灵"),
+    (6, &"机密"),
+    (6, &"机械"),
+    (6, &"机遇"),
+    (6, &"机智"),
+    (6, &"基地"),
+    (6, &"基金"),
+    (6, &"基因"),
+    (6, &"激发"),
+    (6, &"激励"),
+    (6, &"激情"),
+    (6, &"及早"),
+    (6, &"吉祥"),
+    (6, &"级别"),
+    (6, &"极端"),
+    (6, &"极限"),
+    (6, &"即便"),
+    (6, &"即将"),
+    (6, &"急功近利"),
+    (6, &"急剧"),
+    (6, &"急切"),
+    (6, &"急于求成"),
+    (6, &"急躁"),
+    (6, &"疾病"),
+    (6, &"集团"),
+    (6, &"嫉妒"),
+    (6, &"籍贯"),
+    (6, &"给予"),
+    (6, &"计较"),
+    (6, &"记性"),
+    (6, &"记载"),
+    (6, &"纪要"),
+    (6, &"技巧"),
+    (6, &"忌讳"),
+    (6, &"季度"),
+    (6, &"季军"),
+    (6, &"迹象"),
+    (6, &"继承"),
+    (6, &"寄托"),
+    (6, &"寂静"),
+    (6, &"加工"),
+    (6, &"加剧"),
+    (6, &"夹杂"),
+    (6, &"佳肴"),
+    (6, &"家常"),
+    (6, &"家伙"),
+    (6, &"家属"),
+    (6, &"家喻户晓"),
+    (6, &"尖端"),
+    (6, &"尖锐"),
+    (6, &"坚定"),
+    (6, &"坚固"),
+    (6, &"坚韧"),
+    (6, &"坚实"),
+    (6, &"坚硬"),
+    (6, &"艰难"),
+    (6, &"监督"),
+    (6, &"监视"),
+    (6, &"监狱"),
+    (6, &"煎"),
+    (6, &"拣"),
+    (6, &"检讨"),
+    (6, &"检验"),
+    (6, &"剪彩"),
+    (6, &"简化"),
+    (6, &"简陋"),
+    (6, &"简体字"),
+    (6, &"简要"),
+    (6, &"见多识广"),
+    (6, &"见解"),
+    (6, &"见闻"),
+    (6, &"见义勇为"),
+    (6, &"间谍"),
+    (6, &"间隔"),
+    (6, &"间接"),
+    (6, &"剑"),
+    (6, &"健全"),
+    (6, &"舰艇"),
+    (6, &"践踏"),
+    (6, &"溅"),
+    (6, &"鉴别"),
+    (6, &"鉴定"),
+    (6, &"鉴于"),
+    (6, &"将近"),
+    (6, &"将就"),
+    (6, &"将军"),
+    (6, &"僵硬"),
+    (6, &"奖励"),
+    (6, &"奖赏"),
+    (6, &"桨"),
+    (6, &"降临"),
+    (6, &"交叉"),
+    (6, &"交代"),
+    (6, &"交涉"),
+    (6, &"交易"),
+    (6, &"娇气"),
+    (6, &"焦点"),
+    (6, &"焦急"),
+    (6, &"角落"),
+    (6, &"侥幸"),
+    (6, &"搅拌"),
+    (6, &"缴纳"),
+    (6, &"较量"),
+    (6, &"教养"),
+    (6, &"阶层"),
+    (6, &"皆"),
+    (6, &"接连"),
+    (6, &"揭露"),
+    (6, &"节制"),
+    (6, &"节奏"),
+    (6, &"杰出"),
+    (6, &"结晶"),
+    (6, &"结局"),
+    (6, &"结算"),
+    (6, &"截止"),
+    (6, &"截至"),
+    (6, &"竭尽全力"),
+    (6, &"解除"),
+    (6, &"解放"),
+    (6, &"解雇"),
+    (6, &"解剖"),
+    (6, &"解散"),
+    (6, &"解体"),
+    (6, &"戒备"),
+    (6, &"界限"),
+    (6, &"借鉴"),
+    (6, &"借助"),
+    (6, &"金融"),
+    (6, &"津津有味"),
+    (6, &"紧迫"),
+    (6, &"锦上添花"),
+    (6, &"进而"),
+    (6, &"进攻"),
+    (6, &"进化"),
+    (6, &"进展"),
+    (6, &"近来"),
+    (6, &"晋升"),
+    (6, &"浸泡"),
+    (6, &"茎"),
+    (6, &"经费"),
+    (6, &"经纬"),
+    (6, &"惊动"),
+    (6, &"惊奇"),
+    (6, &"惊讶"),
+    (6, &"兢兢业业"),
+    (6, &"精打细算"),
+    (6, &"精华"),
+    (6, &"精简"),
+    (6, &"精密"),
+    (6, &"精确"),
+    (6, &"精通"),
+    (6, &"精心"),
+    (6, &"精益求精"),
+    (6, &"精致"),
+    (6, &"井"),
+    (6, &"颈椎"),
+    (6, &"警告"),
+    (6, &"警惕"),
+    (6, &"竞赛"),
+    (6, &"竞选"),
+    (6, &"敬礼"),
+    (6, &"敬业"),
+    (6, &"境界"),
+    (6, &"镜头"),
+    (6, &"纠纷"),
+    (6, &"纠正"),
+    (6, &"酒精"),
+    (6, &"救济"),
+    (6, &"就近"),
+    (6, &"就业"),
+    (6, &"就职"),
+    (6, &"拘留"),
+    (6, &"拘束"),
+    (6, &"居民"),
+    (6, &"居住"),
+    (6, &"鞠躬"),
+    (6, &"局部"),
+    (6, &"局面"),
+    (6, &"局势"),
+    (6, &"局限"),
+    (6, &"咀嚼"),
+    (6, &"沮丧"),
+    (6, &"举动"),
+    (6, &"举世瞩目"),
+    (6, &"举足轻重"),
+    (6, &"剧本"),
+    (6, &"剧烈"),
+    (6, &"据悉"),
+    (6, &"聚精会神"),
+    (6, &"卷"),
+    (6, &"决策"),
+    (6, &"觉悟"),
+    (6, &"觉醒"),
+    (6, &"绝望"),
+    (6, &"倔强"),
+    (6, &"军队"),
+    (6, &"君子"),
+    (6, &"卡通"),
+    (6, &"开采"),
+    (6, &"开除"),
+    (6, &"开阔"),
+    (6, &"开朗"),
+    (6, &"开明"),
+    (6, &"开辟"),
+    (6, &"开拓"),
+    (6, &"开展"),
+    (6, &"开支"),
+    (6, &"刊登"),
+    (6, &"刊物"),
+    (6, &"勘探"),
+    (6, &"侃侃而谈"),
+    (6, &"砍伐"),
+    (6, &"看待"),
+    (6, &"慷慨"),
+    (6, &"扛"),
+    (6, &"抗议"),
+    (6, &"考察"),
+    (6, &"考古"),
+    (6, &"考核"),
+    (6, &"考验"),
+    (6, &"靠拢"),
+    (6, &"科目"),
+    (6, &"磕"),
+    (6, &"可观"),
+    (6, &"可口"),
+    (6, &"可恶"),
+    (6, &"可行"),
+    (6, &"渴望"),
+    (6, &"克制"),
+    (6, &"刻不容缓"),
+    (6, &"客户"),
+    (6, &"课题"),
+    (6, &"恳切"),
+    (6, &"啃"),
+    (6, &"坑"),
+    (6, &"空洞"),
+    (6, &"空前绝后"),
+    (6, &"空想"),
+    (6, &"空虚"),
+    (6, &"孔"),
+    (6, &"恐怖"),
+    (6, &"恐吓"),
+    (6, &"恐惧"),
+    (6, &"空白"),
+    (6, &"空隙"),
+    (6, &"口气"),
+    (6, &"口腔"),
+    (6, &"口头"),
+    (6, &"口音"),
+    (6, &"扣"),
+    (6, &"枯萎"),
+    (6, &"枯燥"),
+    (6, &"哭泣"),
+    (6, &"苦尽甘来"),
+    (6, &"苦涩"),
+    (6, &"挎"),
+    (6, &"跨"),
+    (6, &"快活"),
+    (6, &"宽敞"),
+    (6, &"宽容"),
+    (6, &"款待"),
+    (6, &"款式"),
+    (6, &"筐"),
+    (6, &"旷课"),
+    (6, &"况且"),
+    (6, &"矿产"),
+    (6, &"框架"),
+    (6, &"亏待"),
+    (6, &"亏损"),
+    (6, &"捆绑"),
+    (6, &"扩充"),
+    (6, &"扩散"),
+    (6, &"扩张"),
+    (6, &"喇叭"),
+    (6, &"蜡烛"),
+    (6, &"啦"),
+    (6, &"来历"),
+    (6, &"来源"),
+    (6, &"栏目"),
+    (6, &"懒惰"),
+    (6, &"狼狈"),
+    (6, &"狼吞虎咽"),
+    (6, &"捞"),
+    (6, &"牢固"),
+    (6, &"牢骚"),
+    (6, &"唠叨"),
+    (6, &"乐趣"),
+    (6, &"乐意"),
+    (6, &"雷达"),
+    (6, &"类似"),
+    (6, &"冷酷"),
+    (6, &"冷落"),
+    (6, &"冷却"),
+    (6, &"愣"),
+    (6, &"黎明"),
+    (6, &"礼节"),
+    (6, &"礼尚往来"),
+    (6, &"里程碑"),
+    (6, &"理睬"),
+    (6, &"理所当然"),
+    (6, &"理直气壮"),
+    (6, &"理智"),
+    (6, &"力求"),
+    (6, &"力所能及"),
+    (6, &"力争"),
+    (6, &"历代"),
+    (6, &"历来"),
+    (6, &"立场"),
+    (6, &"立方"),
+    (6, &"立交桥"),
+    (6, &"立体"),
+    (6, &"立足"),
+    (6, &"利害"),
+    (6, &"例外"),
+    (6, &"粒"),
+    (6, &"连年"),
+    (6, &"连锁"),
+    (6, &"连同"),
+    (6, &"联欢"),
+    (6, &"联络"),
+    (6, &"联盟"),
+    (6, &"联想"),
+    (6, &"廉洁"),
+    (6, &"良心"),
+    (6, &"谅解"),
+    (6, &"晾"),
+    (6, &"辽阔"),
+    (6, &"列举"),
+    (6, &"临床"),
+    (6, &"淋"),
+    (6, &"吝啬"),
+    (6, &"伶俐"),
+    (6, &"灵感"),
+    (6, &"灵魂"),
+    (6, &"灵敏"),
+    (6, &"凌晨"),
+    (6, &"零星"),
+    (6, &"领会"),
+    (6, &"领事馆"),
+    (6, &"领土"),
+    (6, &"领悟"),
+    (6, &"领先"),
+    (6, &"领袖"),
+    (6, &"溜"),
+    (6, &"留恋"),
+    (6, &"留念"),
+    (6, &"留神"),
+    (6, &"流浪"),
+    (6, &"流露"),
+    (6, &"流氓"),
+    (6, &"流通"),
+    (6, &"聋哑"),
+    (6, &"隆重"),
+    (6, &"垄断"),
+    (6, &"笼罩"),
+    (6, &"搂"),
+    (6, &"炉灶"),
+    (6, &"屡次"),
+    (6, &"履行"),
+    (6, &"掠夺"),
+    (6, &"轮船"),
+    (6, &"轮廓"),
+    (6, &"轮胎"),
+    (6, &"论坛"),
+    (6, &"论证"),
+    (6, &"啰唆"),
+    (6, &"络绎不绝"),
+    (6, &"落成"),
+    (6, &"落实"),
+    (6, &"麻痹"),
+    (6, &"麻木"),
+    (6, &"麻醉"),
+    (6, &"码头"),
+    (6, &"蚂蚁"),
+    (6, &"嘛"),
+    (6, &"埋伏"),
+    (6, &"埋没"),
+    (6, &"埋葬"),
+    (6, &"迈"),
+    (6, &"脉搏"),
+    (6, &"埋怨"),
+    (6, &"蔓延"),
+    (6, &"漫长"),
+    (6, &"漫画"),
+    (6, &"慢性"),
+    (6, &"忙碌"),
+    (6, &"盲目"),
+    (6, &"茫茫"),
+    (6, &"茫然"),
+    (6, &"茂盛"),
+    (6, &"冒充"),
+    (6, &"冒犯"),
+    (6, &"枚"),
+    (6, &"媒介"),
+    (6, &"美观"),
+    (6, &"美满"),
+    (6, &"美妙"),
+    (6, &"萌芽"),
+    (6, &"猛烈"),
+    (6, &"眯"),
+    (6, &"弥补"),
+    (6, &"弥漫"),
+    (6, &"迷惑"),
+    (6, &"迷人"),
+    (6, &"迷信"),
+    (6, &"谜语"),
+    (6, &"密度"),
+    (6, &"密封"),
+    (6, &"棉花"),
+    (6, &"免得"),
+    (6, &"免疫"),
+    (6, &"勉励"),
+    (6, &"勉强"),
+    (6, &"面貌"),
+    (6, &"面子"),
+    (6, &"描绘"),
+    (6, &"瞄准"),
+    (6, &"渺小"),
+    (6, &"藐视"),
+    (6, &"灭亡"),
+    (6, &"蔑视"),
+    (6, &"民间"),
+    (6, &"民主"),
+    (6, &"敏捷"),
+    (6, &"敏锐"),
+    (6, &"名次"),
+    (6, &"名额"),
+    (6, &"名副其实"),
+    (6, &"名誉"),
+    (6, &"明明"),
+    (6, &"明智"),
+    (6, &"命名"),
+    (6, &"摸索"),
+    (6, &"模范"),
+    (6, &"模式"),
+    (6, &"模型"),
+    (6, &"膜"),
+    (6, &"摩擦"),
+    (6, &"磨合"),
+    (6, &"魔鬼"),
+    (6, &"魔术"),
+    (6, &"抹杀"),
+    (6, &"莫名其妙"),
+    (6, &"墨水儿"),
+    (6, &"默默"),
+    (6, &"谋求"),
+    (6, &"模样"),
+    (6, &"母语"),
+    (6, &"目睹"),
+    (6, &"目光"),
+    (6, &"沐浴"),
+    (6, &"拿手"),
+    (6, &"纳闷儿"),
+    (6, &"耐用"),
+    (6, &"南辕北辙"),
+    (6, &"难得"),
+    (6, &"难堪"),
+    (6, &"难能可贵"),
+    (6, &"恼火"),
+    (6, &"内涵"),
+    (6, &"内幕"),
+    (6, &"内在"),
+    (6, &"能量"),
+    (6, &"拟定"),
+    (6, &"逆行"),
+    (6, &"年度"),
+    (6, &"捏"),
+    (6, &"凝固"),
+    (6, &"凝聚"),
+    (6, &"凝视"),
+    (6, &"拧"),
+    (6, &"宁肯"),
+    (6, &"宁愿"),
+    (6, &"扭转"),
+    (6, &"纽扣儿"),
+    (6, &"农历"),
+    (6, &"浓厚"),
+    (6, &"奴隶"),
+    (6, &"虐待"),
+    (6, &"挪"),
+    (6, &"哦"),
+    (6, &"殴打"),
+    (6, &"呕吐"),
+    (6, &"偶像"),
+    (6, &"趴"),
+    (6, &"排斥"),
+    (6, &"排除"),
+    (6, &"排放"),
+    (6, &"排练"),
+    (6, &"徘徊"),
+    (6, &"派别"),
+    (6, &"派遣"),
+    (6, &"攀登"),
+    (6, &"盘旋"),
+    (6, &"判决"),
+    (6, &"畔"),
+    (6, &"庞大"),
+    (6, &"抛弃"),
+    (6, &"泡沫"),
+    (6, &"培育"),
+    (6, &"配备"),
+    (6, &"配偶"),
+    (6, &"配套"),
+    (6, &"盆地"),
+    (6, &"烹饪"),
+    (6, &"捧"),
+    (6, &"批发"),
+    (6, &"批判"),
+    (6, &"劈"),
+    (6, &"皮革"),
+    (6, &"疲惫"),
+    (6, &"疲倦"),
+    (6, &"屁股"),
+    (6, &"譬如"),
+    (6, &"偏差"),
+    (6, &"偏见"),
+    (6, &"偏僻"),
+    (6, &"偏偏"),
+    (6, &"片断"),
+    (6, &"片刻"),
+    (6, &"漂浮"),
+    (6, &"飘扬"),
+    (6, &"撇"),
+    (6, &"拼搏"),
+    (6, &"拼命"),
+    (6, &"贫乏"),
+    (6, &"贫困"),
+    (6, &"频繁"),
+    (6, &"频率"),
+    (6, &"品尝"),
+    (6, &"品德"),
+    (6, &"品质"),
+    (6, &"品种"),
+    (6, &"平凡"),
+    (6, &"平面"),
+    (6, &"平坦"),
+    (6, &"平行"),
+    (6, &"平庸"),
+    (6, &"平原"),
+    (6, &"评估"),
+    (6, &"评论"),
+    (6, &"屏幕"),
+    (6, &"屏障"),
+    (6, &"坡"),
+    (6, &"泼"),
+    (6, &"颇"),
+    (6, &"迫不及待"),
+    (6, &"迫害"),
+    (6, &"破例"),
+    (6, &"魄力"),
+    (6, &"扑"),
+    (6, &"铺"),
+    (6, &"朴实"),
+    (6, &"朴素"),
+    (6, &"普及"),
+    (6, &"瀑布"),
+    (6, &"凄凉"),
+    (6, &"期望"),
+    (6, &"期限"),
+    (6, &"欺负"),
+    (6, &"欺骗"),
+    (6, &"齐全"),
+    (6, &"齐心协力"),
+    (6, &"奇妙"),
+    (6, &"歧视"),
+    (6, &"旗袍"),
+    (6, &"旗帜"),
+    (6, &"乞丐"),
+    (6, &"岂有此理"),
+    (6, &"企图"),
+    (6, &"启程"),
+    (6, &"启蒙"),
+    (6, &"启示"),
+    (6, &"启事"),
+    (6, &"起草"),
+    (6, &"起初"),
+    (6, &"起伏"),
+    (6, &"起哄"),
+    (6, &"起码"),
+    (6, &"起源"),
+    (6, &"气概"),
+    (6, &"气功"),
+    (6, &"气魄"),
+    (6, &"气色"),
+    (6, &"气势"),
+    (6, &"气味"),
+    (6, &"气象"),
+    (6, &"气压"),
+    (6, &"气质"),
+    (6, &"迄今为止"),
+    (6, &"器材"),
+    (6, &"器官"),
+    (6, &"掐"),
+    (6, &"洽谈"),
+    (6, &"恰当"),
+    (6, &"恰到好处"),
+    (6, &"恰巧"),
+    (6, &"千方百计"),
+    (6, &"迁就"),
+    (6, &"迁徙"),
+    (6, &"牵"),
+    (6, &"牵扯"),
+    (6, &"牵制"),
+    (6, &"谦逊"),
+    (6, &"签署"),
+    (6, &"前景"),
+    (6, &"前提"),
+    (6, &"潜力"),
+    (6, &"潜水"),
+    (6, &"潜移默化"),
+    (6, &"谴责"),
+    (6, &"强制"),
+    (6, &"抢劫"),
+    (6, &"抢救"),
+    (6, &"强迫"),
+    (6, &"桥梁"),
+    (6, &"窍门"),
+    (6, &"翘"),
+    (6, &"切实"),
+    (6, &"锲而不舍"),
+    (6, &"钦佩"),
+    (6, &"侵犯"),
+    (6, &"侵略"),
+    (6, &"亲密"),
+    (6, &"亲热"),
+    (6, &"勤俭"),
+    (6, &"勤劳"),
+    (6, &"倾听"),
+    (6, &"倾向"),
+    (6, &"倾斜"),
+    (6, &"清澈"),
+    (6, &"清晨"),
+    (6, &"清除"),
+    (6, &"清洁"),
+    (6, &"清理"),
+    (6, &"清晰"),
+    (6, &"清醒"),
+    (6, &"清真"),
+    (6, &"情报"),
+    (6, &"情节"),
+    (6, &"情理"),
+    (6, &"情形"),
+    (6, &"晴朗"),
+    (6, &"请柬"),
+    (6, &"请教"),
+    (6, &"请示"),
+    (6, &"请帖"),
+    (6, &"丘陵"),
+    (6, &"区分"),
+    (6, &"区域"),
+    (6, &"曲折"),
+    (6, &"驱逐"),
+    (6, &"屈服"),
+    (6, &"渠道"),
+    (6, &"曲子"),
+    (6, &"取缔"),
+    (6, &"趣味"),
+    (6, &"圈套"),
+    (6, &"权衡"),
+    (6, &"权威"),
+    (6, &"全局"),
+    (6, &"全力以赴"),
+    (6, &"拳头"),
+    (6, &"犬"),
+    (6, &"缺口"),
+    (6, &"缺席"),
+    (6, &"缺陷"),
+    (6, &"瘸"),
+    (6, &"确保"),
+    (6, &"确立"),
+    (6, &"确切"),
+    (6, &"确信"),
+    (6, &"群众"),
+    (6, &"染"),
+    (6, &"嚷"),
+    (6, &"让步"),
+    (6, &"饶恕"),
+    (6, &"扰乱"),
+    (6, &"惹祸"),
+    (6, &"热泪盈眶"),
+    (6, &"热门"),
+    (6, &"人道"),
+    (6, &"人格"),
+    (6, &"人工"),
+    (6, &"人家"),
+    (6, &"人间"),
+    (6, &"人士"),
+    (6, &"人为"),
+    (6, &"人性"),
+    (6, &"人质"),
+    (6, &"仁慈"),
+    (6, &"忍耐"),
+    (6, &"忍受"),
+    (6, &"认定"),
+    (6, &"认可"),
+    (6, &"任命"),
+    (6, &"任性"),
+    (6, &"任意"),
+    (6, &"任重道远"),
+    (6, &"仍旧"),
+    (6, &"日新月异"),
+    (6, &"日益"),
+    (6, &"荣幸"),
+    (6, &"荣誉"),
+    (6, &"容貌"),
+    (6, &"容纳"),
+    (6, &"容器"),
+    (6, &"容忍"),
+    (6, &"溶解"),
+    (6, &"融化"),
+    (6, &"融洽"),
+    (6, &"柔和"),
+    (6, &"揉"),
+    (6, &"儒家"),
+    (6, &"若干"),
+    (6, &"弱点"),
+    (6, &"撒谎"),
+    (6, &"散文"),
+    (6, &"散布"),
+    (6, &"散发"),
+    (6, &"丧失"),
+    (6, &"骚扰"),
+    (6, &"嫂子"),
+    (6, &"刹车"),
+    (6, &"啥"),
+    (6, &"筛选"),
+    (6, &"山脉"),
+    (6, &"闪烁"),
+    (6, &"擅长"),
+    (6, &"擅自"),
+    (6, &"伤脑筋"),
+    (6, &"商标"),
+    (6, &"上级"),
+    (6, &"上进"),
+    (6, &"上任"),
+    (6, &"上瘾"),
+    (6, &"上游"),
+    (6, &"尚且"),
+    (6, &"捎"),
+    (6, &"梢"),
+    (6, &"哨"),
+    (6, &"奢侈"),
+    (6, &"舌头"),
+    (6, &"设立"),
+    (6, &"设想"),
+    (6, &"设置"),
+    (6, &"社区"),
+    (6, &"涉及"),
+    (6, &"摄氏度"),
+    (6, &"申报"),
+    (6, &"呻吟"),
+    (6, &"绅士"),
+    (6, &"深奥"),
+    (6, &"深沉"),
+    (6, &"深情厚谊"),
+    (6, &"神经"),
+    (6, &"神奇"),
+    (6, &"神气"),
+    (6, &"神圣"),
+    (6, &"神态"),
+    (6, &"神仙"),
+    (6, &"审查"),
+    (6, &"审理"),
+    (6, &"审美"),
+    (6, &"审判"),
+    (6, &"渗透"),
+    (6, &"慎重"),
+    (6, &"生存"),
+    (6, &"生机"),
+    (6, &"生理"),
+    (6, &"生疏"),
+    (6, &"生态"),
+    (6, &"生物"),
+    (6, &"生肖"),
+    (6, &"生效"),
+    (6, &"生锈"),
+    (6, &"生育"),
+    (6, &"声明"),
+    (6, &"声势"),
+    (6, &"声誉"),
+    (6, &"牲畜"),
+    (6, &"省会"),
+    (6, &"胜负"),
+    (6, &"盛产"),
+    (6, &"盛开"),
+    (6, &"盛情"),
+    (6, &"盛行"),
+    (6, &"尸体"),
+    (6, &"失事"),
+    (6, &"失误"),
+    (6, &"失踪"),
+    (6, &"师范"),
+    (6, &"施加"),
+    (6, &"施展"),
+    (6, &"十足"),
+    (6, &"石油"),
+    (6, &"时常"),
+    (6, &"时而"),
+    (6, &"时光"),
+    (6, &"时机"),
+    (6, &"时事"),
+    (6, &"识别"),
+    (6, &"实惠"),
+    (6, &"实力"),
+    (6, &"实施"),
+    (6, &"实事求是"),
+    (6, &"实行"),
+    (6, &"实质"),
+    (6, &"拾"),
+    (6, &"使命"),
+    (6, &"示范"),
+    (6, &"示威"),
+    (6, &"示意"),
+    (6, &"世代"),
+    (6, &"势必"),
+    (6, &"势力"),
+    (6, &"事故"),
+    (6, &"事迹"),
+    (6, &"事件"),
+    (6, &"事态"),
+    (6, &"事务"),
+    (6, &"事项"),
+    (6, &"事业"),
+    (6, &"试图"),
+    (6, &"试验"),
+    (6, &"视力"),
+    (6, &"视频"),
+    (6, &"视线"),
+    (6, &"视野"),
+    (6, &"是非"),
+    (6, &"适宜"),
+    (6, &"逝世"),
+    (6, &"释放"),
+    (6, &"收藏"),
+    (6, &"收缩"),
+    (6, &"收益"),
+    (6, &"收音机"),
+    (6, &"手法"),
+    (6, &"手势"),
+    (6, &"手艺"),
+    (6, &"守护"),
+    (6, &"首饰"),
+    (6, &"首要"),
+    (6, &"受罪"),
+    (6, &"授予"),
+    (6, &"书法"),
+    (6, &"书籍"),
+    (6, &"书记"),
+    (6, &"书面"),
+    (6, &"舒畅"),
+    (6, &"疏忽"),
+    (6, &"疏远"),
+    (6, &"束"),
+    (6, &"束缚"),
+    (6, &"树立"),
+    (6, &"竖"),
+    (6, &"数额"),
+    (6, &"耍"),
+    (6, &"衰老"),
+    (6, &"衰退"),
+    (6, &"率领"),
+    (6, &"涮火锅"),
+    (6, &"双胞胎"),
+    (6, &"爽快"),
+    (6, &"水利"),
+    (6, &"水龙头"),
+    (6, &"水泥"),
+    (6, &"瞬间"),
+    (6, &"司法"),
+    (6, &"司令"),
+    (6, &"私自"),
+    (6, &"思念"),
+    (6, &"思索"),
+    (6, &"思维"),
+    (6, &"斯文"),
+    (6, &"死亡"),
+    (6, &"四肢"),
+    (6, &"寺庙"),
+    (6, &"饲养"),
+    (6, &"肆无忌惮"),
+    (6, &"耸"),
+    (6, &"艘"),
+    (6, &"苏醒"),
+    (6, &"俗话"),
+    (6, &"诉讼"),
+    (6, &"素食"),
+    (6, &"素质"),
+    (6, &"塑造"),
+    (6, &"算数"),
+    (6, &"随即"),
+    (6, &"随意"),
+    (6, &"岁月"),
+    (6, &"隧道"),
+    (6, &"损坏"),
+    (6, &"索取"),
+    (6, &"索性"),
+    (6, &"塌"),
+    (6, &"踏实"),
+    (6, &"塔"),
+    (6, &"台风"),
+    (6, &"太空"),
+    (6, &"泰斗"),
+    (6, &"贪婪"),
+    (6, &"贪污"),
+    (6, &"摊"),
+    (6, &"瘫痪"),
+    (6, &"弹性"),
+    (6, &"坦白"),
+    (6, &"叹气"),
+    (6, &"探测"),
+    (6, &"探索"),
+    (6, &"探讨"),
+    (6, &"探望"),
+    (6, &"倘若"),
+    (6, &"掏"),
+    (6, &"滔滔不绝"),
+    (6, &"陶瓷"),
+    (6, &"陶醉"),
+    (6, &"淘汰"),
+    (6, &"讨好"),
+    (6, &"特长"),
+    (6, &"特定"),
+    (6, &"特意"),
+    (6, &"提拔"),
+    (6, &"提炼"),
+    (6, &"提示"),
+    (6, &"提议"),
+    (6, &"题材"),
+    (6, &"体裁"),
+    (6, &"体积"),
+    (6, &"体谅"),
+    (6, &"体面"),
+    (6, &"体系"),
+    (6, &"天才"),
+    (6, &"天赋"),
+    (6, &"天伦之乐"),
+    (6, &"天然气"),
+    (6, &"天生"),
+    (6, &"天堂"),
+    (6, &"天文"),
+    (6, &"田径"),
+    (6, &"田野"),
+    (6, &"舔"),
+    (6, &"挑剔"),
+    (6, &"条款"),
+    (6, &"条理"),
+    (6, &"条约"),
+    (6, &"调和"),
+    (6, &"调剂"),
+    (6, &"调节"),
+    (6, &"调解"),
+    (6, &"调料"),
+    (6, &"挑拨"),
+    (6, &"挑衅"),
+    (6, &"跳跃"),
+    (6, &"亭子"),
+    (6, &"停泊"),
+    (6, &"停顿"),
+    (6, &"停滞"),
+    (6, &"挺拔"),
+    (6, &"通货膨胀"),
+    (6, &"通缉"),
+    (6, &"通俗"),
+    (6, &"通讯"),
+    (6, &"通用"),
+    (6, &"同胞"),
+    (6, &"同志"),
+    (6, &"铜"),
+    (6, &"童话"),
+    (6, &"统筹兼顾"),
+    (6, &"统计"),
+    (6, &"统统"),
+    (6, &"统治"),
+    (6, &"投机"),
+    (6, &"投票"),
+    (6, &"投诉"),
+    (6, &"投降"),
+    (6, &"投掷"),
+    (6, &"透露"),
+    (6, &"秃"),
+    (6, &"突破"),
+    (6, &"图案"),
+    (6, &"徒弟"),
+    (6, &"途径"),
+    (6, &"涂抹"),
+    (6, &"土壤"),
+    (6, &"团结"),
+    (6, &"团体"),
+    (6, &"团圆"),
+    (6, &"推测"),
+    (6, &"推翻"),
+    (6, &"推理"),
+    (6, &"推论"),
+    (6, &"推销"),
+    (6, &"吞吞吐吐"),
+    (6, &"托运"),
+    (6, &"拖延"),
+    (6, &"脱离"),
+    (6, &"妥当"),
+    (6, &"妥善"),
+    (6, &"妥协"),
+    (6, &"椭圆"),
+    (6, &"唾弃"),
+    (6, &"挖掘"),
+    (6, &"哇"),
+    (6, &"娃娃"),
+    (6, &"瓦解"),
+    (6, &"歪曲"),
+    (6, &"外表"),
+    (6, &"外行"),
+    (6, &"外界"),
+    (6, &"外向"),
+    (6, &"丸"),
+    (6, &"完备"),
+    (6, &"完毕"),
+    (6, &"玩弄"),
+    (6, &"玩意儿"),
+    (6, &"顽固"),
+    (6, &"顽强"),
+    (6, &"挽回"),
+    (6, &"挽救"),
+    (6, &"惋惜"),
+    (6, &"万分"),
+    (6, &"往常"),
+    (6, &"往事"),
+    (6, &"妄想"),
+    (6, &"危机"),
+    (6, &"威风"),
+    (6, &"威力"),
+    (6, &"威望"),
+    (6, &"威信"),
+    (6, &"微不足道"),
+    (6, &"微观"),
+    (6, &"为难"),
+    (6, &"为期"),
+    (6, &"违背"),
+    (6, &"唯独"),
+    (6, &"维持"),
+    (6, &"维护"),
+    (6, &"维生素"),
+    (6, &"伪造"),
+    (6, &"委托"),
+    (6, &"委员"),
+    (6, &"卫星"),
+    (6, &"未免"),
+    (6, &"畏惧"),
+    (6, &"喂（动词）"),
+    (6, &"蔚蓝"),
+    (6, &"慰问"),
+    (6, &"温带"),
+    (6, &"温和"),
+    (6, &"文凭"),
+    (6, &"文物"),
+    (6, &"文献"),
+    (6, &"文雅"),
+    (6, &"文艺"),
+    (6, &"问世"),
+    (6, &"窝"),
+    (6, &"乌黑"),
+    (6, &"污蔑"),
+    (6, &"诬陷"),
+    (6, &"无比"),
+    (6, &"无偿"),
+    (6, &"无耻"),
+    (6, &"无动于衷"),
+    (6, &"无非"),
+    (6, &"无辜"),
+    (6, &"无精打采"),
+    (6, &"无赖"),
+    (6, &"无理取闹"),
+    (6, &"无能为力"),
+    (6, &"无穷无尽"),
+    (6, &"无微不至"),
+    (6, &"无忧无虑"),
+    (6, &"无知"),
+    (6, &"武器"),
+    (6, &"武侠"),
+    (6, &"武装"),
+    (6, &"侮辱"),
+    (6, &"舞蹈"),
+    (6, &"务必"),
+    (6, &"物美价廉"),
+    (6, &"物业"),
+    (6, &"物资"),
+    (6, &"误差"),
+    (6, &"误解"),
+    (6, &"夕阳"),
+    (6, &"昔日"),
+    (6, &"牺牲"),
+    (6, &"溪"),
+    (6, &"熄灭"),
+    (6, &"膝盖"),
+    (6, &"习俗"),
+    (6, &"袭击"),
+    (6, &"媳妇"),
+    (6, &"喜闻乐见"),
+    (6, &"喜悦"),
+    (6, &"系列"),
+    (6, &"细胞"),
+    (6, &"细菌"),
+    (6, &"细致"),
+    (6, &"峡谷"),
+    (6, &"狭隘"),
+    (6, &"狭窄"),
+    (6, &"霞"),
+    (6, &"下属"),
+    (6, &"先进"),
+    (6, &"先前"),
+    (6, &"纤维"),
+    (6, &"掀起"),
+    (6, &"鲜明"),
+    (6, &"闲话"),
+    (6, &"贤惠"),
+    (6, &"弦"),
+    (6, &"衔接"),
+    (6, &"嫌"),
+    (6, &"嫌疑"),
+    (6, &"显著"),
+    (6, &"现场"),
+    (6, &"现成"),
+    (6, &"现状"),
+    (6, &"线索"),
+    (6, &"宪法"),
+    (6, &"陷害"),
+    (6, &"陷阱"),
+    (6, &"陷入"),
+    (6, &"馅儿"),
+    (6, &"乡镇"),
+    (6, &"相差"),
+    (6, &"相等"),
+    (6, &"相辅相成"),
+    (6, &"相应"),
+    (6, &"镶嵌"),
+    (6, &"响亮"),
+    (6, &"响应"),
+    (6, &"想方设法"),
+    (6, &"向导"),
+    (6, &"向来"),
+    (6, &"向往"),
+    (6, &"巷"),
+    (6, &"相声"),
+    (6, &"削"),
+    (6, &"消除"),
+    (6, &"消毒"),
+    (6, &"消防"),
+    (6, &"消耗"),
+    (6, &"消灭"),
+    (6, &"销毁"),
+    (6, &"潇洒"),
+    (6, &"小心翼翼"),
+    (6, &"肖像"),
+    (6, &"效益"),
+    (6, &"协会"),
+    (6, &"协商"),
+    (6, &"协调"),
+    (6, &"协议"),
+    (6, &"协助"),
+    (6, &"携带"),
+    (6, &"泄露"),
+    (6, &"泄气"),
+    (6, &"屑"),
+    (6, &"谢绝"),
+    (6, &"心得"),
+    (6, &"心甘情愿"),
+    (6, &"心灵"),
+    (6, &"心态"),
+    (6, &"心疼"),
+    (6, &"心血"),
+    (6, &"心眼儿"),
+    (6, &"辛勤"),
+    (6, &"欣慰"),
+    (6, &"欣欣向荣"),
+    (6, &"新陈代谢"),
+    (6, &"新郎"),
+    (6, &"新娘"),
+    (6, &"新颖"),
+    (6, &"薪水"),
+    (6, &"信赖"),
+    (6, &"信念"),
+    (6, &"信仰"),
+    (6, &"信誉"),
+    (6, &"兴隆"),
+    (6, &"兴旺"),
+    (6, &"腥"),
+    (6, &"刑事"),
+    (6, &"行政"),
+    (6, &"形态"),
+    (6, &"兴高采烈"),
+    (6, &"兴致勃勃"),
+    (6, &"性感"),
+    (6, &"性命"),
+    (6, &"性能"),
+    (6, &"凶恶"),
+    (6, &"凶手"),
+    (6, &"汹涌"),
+    (6, &"胸怀"),
+    (6, &"胸膛"),
+    (6, &"雄厚"),
+    (6, &"雄伟"),
+    (6, &"修复"),
+    (6, &"修建"),
+    (6, &"修养"),
+    (6, &"羞耻"),
+    (6, &"绣"),
+    (6, &"嗅觉"),
+    (6, &"须知"),
+    (6, &"虚假"),
+    (6, &"虚荣"),
+    (6, &"虚伪"),
+    (6, &"需求"),
+    (6, &"许可"),
+    (6, &"序言"),
+    (6, &"畜牧"),
+    (6, &"酗酒"),
+    (6, &"宣誓"),
+    (6, &"宣扬"),
+    (6, &"喧哗"),
+    (6, &"悬挂"),
+    (6, &"悬念"),
+    (6, &"悬殊"),
+    (6, &"悬崖峭壁"),
+    (6, &"旋律"),
+    (6, &"旋转"),
+    (6, &"选拔"),
+    (6, &"选举"),
+    (6, &"选手"),
+    (6, &"炫耀"),
+    (6, &"削弱"),
+    (6, &"学说"),
+    (6, &"学位"),
+    (6, &"雪上加霜"),
+    (6, &"血压"),
+    (6, &"熏陶"),
+    (6, &"寻觅"),
+    (6, &"巡逻"),
+    (6, &"循环"),
+    (6, &"循序渐进"),
+    (6, &"压迫"),
+    (6, &"压岁钱"),
+    (6, &"压缩"),
+    (6, &"压抑"),
+    (6, &"压榨"),
+    (6, &"压制"),
+    (6, &"鸦雀无声"),
+    (6, &"亚军"),
+    (6, &"烟花爆竹"),
+    (6, &"淹没"),
+    (6, &"延期"),
+    (6, &"延伸"),
+    (6, &"延续"),
+    (6, &"严寒"),
+    (6, &"严禁"),
+    (6, &"严峻"),
+    (6, &"严厉"),
+    (6, &"严密"),
+    (6, &"言论"),
+    (6, &"岩石"),
+    (6, &"炎热"),
+    (6, &"沿海"),
+    (6, &"掩盖"),
+    (6, &"掩护"),
+    (6, &"掩饰"),
+    (6, &"眼光"),
+    (6, &"眼色"),
+    (6, &"眼神"),
+    (6, &"演变"),
+    (6, &"演习"),
+    (6, &"演绎"),
+    (6, &"演奏"),
+    (6, &"厌恶"),
+    (6, &"验收"),
+    (6, &"验证"),
+    (6, &"氧气"),
+    (6, &"样品"),
+    (6, &"谣言"),
+    (6, &"摇摆"),
+    (6, &"摇滚"),
+    (6, &"遥控"),
+    (6, &"遥远"),
+    (6, &"要点"),
+    (6, &"要命"),
+    (6, &"要素"),
+    (6, &"耀眼"),
+    (6, &"野蛮"),
+    (6, &"野心"),
+    (6, &"液体"),
+    (6, &"一度"),
+    (6, &"一帆风顺"),
+    (6, &"一贯"),
+    (6, &"一举两得"),
+    (6, &"一流"),
+    (6, &"一目了然"),
+    (6, &"一如既往"),
+    (6, &"一丝不苟"),
+    (6, &"一向"),
+    (6, &"衣裳"),
+    (6, &"依旧"),
+    (6, &"依据"),
+    (6, &"依靠"),
+    (6, &"依赖"),
+    (6, &"依托"),
+    (6, &"仪器"),
+    (6, &"仪式"),
+    (6, &"遗产"),
+    (6, &"遗传"),
+    (6, &"遗留"),
+    (6, &"遗失"),
+    (6, &"疑惑"),
+    (6, &"以便"),
+    (6, &"以免"),
+    (6, &"以往"),
+    (6, &"以至"),
+    (6, &"以致"),
+    (6, &"亦"),
+    (6, &"异常"),
+    (6, &"意料"),
+    (6, &"意识"),
+    (6, &"意图"),
+    (6, &"意味着"),
+    (6, &"意向"),
+    (6, &"意志"),
+    (6, &"毅力"),
+    (6, &"毅然"),
+    (6, &"翼"),
+    (6, &"阴谋"),
+    (6, &"音响"),
+    (6, &"引导"),
+    (6, &"引擎"),
+    (6, &"引用"),
+    (6, &"饮食"),
+    (6, &"隐蔽"),
+    (6, &"隐患"),
+    (6, &"隐瞒"),
+    (6, &"隐私"),
+    (6, &"隐约"),
+    (6, &"英明"),
+    (6, &"英勇"),
+    (6, &"婴儿"),
+    (6, &"迎面"),
+    (6, &"盈利"),
+    (6, &"应酬"),
+    (6, &"应邀"),
+    (6, &"拥护"),
+    (6, &"拥有"),
+    (6, &"庸俗"),
+    (6, &"永恒"),
+    (6, &"勇于"),
+    (6, &"涌现"),
+    (6, &"踊跃"),
+    (6, &"用户"),
+    (6, &"优胜劣汰"),
+    (6, &"优先"),
+    (6, &"优异"),
+    (6, &"优越"),
+    (6, &"忧郁"),
+    (6, &"犹如"),
+    (6, &"油腻"),
+    (6, &"油漆"),
+    (6, &"有条不紊"),
+    (6, &"幼稚"),
+    (6, &"诱惑"),
+    (6, &"渔民"),
+    (6, &"愚蠢"),
+    (6, &"愚昧"),
+    (6, &"舆论"),
+    (6, &"与日俱增"),
+    (6, &"宇宙"),
+    (6, &"羽绒服"),
+    (6, &"玉"),
+    (6, &"预料"),
+    (6, &"预期"),
+    (6, &"预算"),
+    (6, &"预先"),
+    (6, &"预言"),
+    (6, &"预兆"),
+    (6, &"欲望"),
+    (6, &"寓言"),
+    (6, &"愈"),
+    (6, &"冤枉"),
+    (6, &"元首"),
+    (6, &"元素"),
+    (6, &"元宵节"),
+    (6, &"园林"),
+    (6, &"原告"),
+    (6, &"原理"),
+    (6, &"原始"),
+    (6, &"原先"),
+    (6, &"圆满"),
+    (6, &"缘故"),
+    (6, &"源泉"),
+    (6, &"约束"),
+    (6, &"乐谱"),
+    (6, &"岳母"),
+    (6, &"孕育"),
+    (6, &"运算"),
+    (6, &"运行"),
+    (6, &"酝酿"),
+    (6, &"蕴藏"),
+    (6, &"熨"),
+    (6, &"杂技"),
+    (6, &"杂交"),
+    (6, &"砸"),
+    (6, &"咋"),
+    (6, &"灾难"),
+    (6, &"栽培"),
+    (6, &"宰"),
+    (6, &"再接再厉"),
+    (6, &"在意"),
+    (6, &"攒"),
+    (6, &"暂且"),
+    (6, &"赞叹"),
+    (6, &"赞助"),
+    (6, &"遭受"),
+    (6, &"遭殃"),
+    (6, &"遭遇"),
+    (6, &"糟蹋"),
+    (6, &"造型"),
+    (6, &"噪音"),
+    (6, &"责怪"),
+    (6, &"贼"),
+    (6, &"增添"),
+    (6, &"赠送"),
+    (6, &"扎"),
+    (6, &"扎实"),
+    (6, &"渣"),
+    (6, &"眨"),
+    (6, &"诈骗"),
+    (6, &"摘要"),
+    (6, &"债券"),
+    (6, &"沾光"),
+    (6, &"瞻仰"),
+    (6, &"斩钉截铁"),
+    (6, &"展示"),
+    (6, &"展望"),
+    (6, &"展现"),
+    (6, &"崭新"),
+    (6, &"占据"),
+    (6, &"占领"),
+    (6, &"战斗"),
+    (6, &"战略"),
+    (6, &"战术"),
+    (6, &"战役"),
+    (6, &"章程"),
+    (6, &"帐篷"),
+    (6, &"障碍"),
+    (6, &"招标"),
+    (6, &"招收"),
+    (6, &"朝气蓬勃"),
+    (6, &"着迷"),
+    (6, &"沼泽"),
+    (6, &"照样"),
+    (6, &"照耀"),
+    (6, &"折腾"),
+    (6, &"遮挡"),
+    (6, &"折"),
+    (6, &"折磨"),
+    (6, &"侦探"),
+    (6, &"珍贵"),
+    (6, &"珍稀"),
+    (6, &"珍珠"),
+    (6, &"真理"),
+    (6, &"真相"),
+    (6, &"真挚"),
+    (6, &"斟酌"),
+    (6, &"枕头"),
+    (6, &"阵地"),
+    (6, &"阵容"),
+    (6, &"振奋"),
+    (6, &"振兴"),
+    (6, &"震撼"),
+    (6, &"震惊"),
+    (6, &"镇定"),
+    (6, &"镇静"),
+    (6, &"正月"),
+    (6, &"争端"),
+    (6, &"争夺"),
+    (6, &"争气"),
+    (6, &"争先恐后"),
+    (6, &"争议"),
+    (6, &"征服"),
+    (6, &"征收"),
+    (6, &"挣扎"),
+    (6, &"蒸发"),
+    (6, &"整顿"),
+    (6, &"正当"),
+    (6, &"正负"),
+    (6, &"正规"),
+    (6, &"正经"),
+    (6, &"正气"),
+    (6, &"正义"),
+    (6, &"正宗"),
+    (6, &"证实"),
+    (6, &"证书"),
+    (6, &"郑重"),
+    (6, &"政策"),
+    (6, &"政权"),
+    (6, &"症状"),
+    (6, &"之际"),
+    (6, &"支撑"),
+    (6, &"支出"),
+    (6, &"支流"),
+    (6, &"支配"),
+    (6, &"支援"),
+    (6, &"支柱"),
+    (6, &"枝"),
+    (6, &"知觉"),
+    (6, &"知足常乐"),
+    (6, &"脂肪"),
+    (6, &"执行"),
+    (6, &"执着"),
+    (6, &"直播"),
+    (6, &"直径"),
+    (6, &"侄子"),
+    (6, &"值班"),
+    (6, &"职能"),
+    (6, &"职位"),
+    (6, &"职务"),
+    (6, &"殖民地"),
+    (6, &"指标"),
+    (6, &"指定"),
+    (6, &"指甲"),
+    (6, &"指令"),
+    (6, &"指南针"),
+    (6, &"指示"),
+    (6, &"指望"),
+    (6, &"指责"),
+    (6, &"志气"),
+    (6, &"制裁"),
+    (6, &"制服"),
+    (6, &"制约"),
+    (6, &"制止"),
+    (6, &"治安"),
+    (6, &"治理"),
+    (6, &"致辞"),
+    (6, &"致力"),
+    (6, &"致使"),
+    (6, &"智力"),
+    (6, &"智能"),
+    (6, &"智商"),
+    (6, &"滞留"),
+    (6, &"中断"),
+    (6, &"中立"),
+    (6, &"中央"),
+    (6, &"忠诚"),
+    (6, &"忠实"),
+    (6, &"终点"),
+    (6, &"终究"),
+    (6, &"终身"),
+    (6, &"终止"),
+    (6, &"衷心"),
+    (6, &"肿瘤"),
+    (6, &"种子"),
+    (6, &"种族"),
+    (6, &"众所周知"),
+    (6, &"种植"),
+    (6, &"重心"),
+    (6, &"舟"),
+    (6, &"州"),
+    (6, &"周边"),
+    (6, &"周密"),
+    (6, &"周年"),
+    (6, &"周期"),
+    (6, &"周折"),
+    (6, &"周转"),
+    (6, &"粥"),
+    (6, &"昼夜"),
+    (6, &"皱纹"),
+    (6, &"株"),
+    (6, &"诸位"),
+    (6, &"逐年"),
+    (6, &"主办"),
+    (6, &"主导"),
+    (6, &"主管"),
+    (6, &"主流"),
+    (6, &"主权"),
+    (6, &"主义"),
+    (6, &"拄"),
+    (6, &"嘱咐"),
+    (6, &"助理"),
+    (6, &"助手"),
+    (6, &"住宅"),
+    (6, &"注射"),
+    (6, &"注视"),
+    (6, &"注释"),
+    (6, &"注重"),
+    (6, &"驻扎"),
+    (6, &"著作"),
+    (6, &"铸造"),
+    (6, &"拽"),
+    (6, &"专长"),
+    (6, &"专程"),
+    (6, &"专利"),
+    (6, &"专题"),
+    (6, &"砖"),
+    (6, &"转达"),
+    (6, &"转让"),
+    (6, &"转移"),
+    (6, &"转折"),
+    (6, &"传记"),
+    (6, &"庄稼"),
+    (6, &"庄严"),
+    (6, &"庄重"),
+    (6, &"装备"),
+    (6, &"装卸"),
+    (6, &"壮观"),
+    (6, &"壮丽"),
+    (6, &"壮烈"),
+    (6, &"幢"),
+    (6, &"追悼"),
+    (6, &"追究"),
+    (6, &"坠"),
+    (6, &"准则"),
+    (6, &"卓越"),
+    (6, &"着手"),
+    (6, &"着想"),
+    (6, &"着重"),
+    (6, &"姿态"),
+    (6, &"资本"),
+    (6, &"资产"),
+    (6, &"资深"),
+    (6, &"资助"),
+    (6, &"滋润"),
+    (6, &"滋味"),
+    (6, &"子弹"),
+    (6, &"自卑"),
+    (6, &"自发"),
+    (6, &"自力更生"),
+    (6, &"自满"),
+    (6, &"自主"),
+    (6, &"宗教"),
+    (6, &"宗旨"),
+    (6, &"棕色"),
+    (6, &"踪迹"),
+    (6, &"总而言之"),
+    (6, &"总和"),
+    (6, &"纵横"),
+    (6, &"走廊"),
+    (6, &"走漏"),
+    (6, &"走私"),
+    (6, &"揍"),
+    (6, &"租赁"),
+    (6, &"足以"),
+    (6, &"阻碍"),
+    (6, &"阻拦"),
+    (6, &"阻挠"),
+    (6, &"祖父"),
+    (6, &"祖国"),
+    (6, &"祖先"),
+    (6, &"钻研"),
+    (6, &"钻石"),
+    (6, &"嘴唇"),
+    (6, &"罪犯"),
+    (6, &"尊严"),
+    (6, &"遵循"),
+    (6, &"琢磨"),
+    (6, &"作弊"),
+    (6, &"作废"),
+    (6, &"作风"),
+    (6, &"作息"),
+    (6, &"座右铭"),
+    (6, &"做主"),
+];
@@ -0,0 +1,354 @@
+#![allow(dead_code)]
+// This file is automatically generated. DO NOT MAKE EDITS HERE!
+// To make changes, see bin/autogen-hsk.rs
+
+// Built-in Cantonese vocab in the binary dictionary format of dictionary.rs,
+// with 318 Jyutping search keys. The longest one has 15 characters.
+pub static DICTIONARY: &[u8] = include_bytes!("autogen_jyutping.dict");
+
+// Tuples are (normalized_jyutping, 词语) from early in vocab file code
+// generation precompute pipeline. These correspond closely to lines of
+// vocab .tsv files prior to any sorting or merging of duplicates.
+#[cfg(test)]
+pub static JYUTPING_CIYU_TEST_DATA: &[(&'static str, &'static str)] = &[
+    (&"ngo", &"我"),
+    (&"nei", &"你"),
+    (&"keoi", &"佢"),
+    (&"ngodei", &"我哋"),
+    (&"neidei", &"你哋"),
+    (&"keoidei", &"佢哋"),
+    (&"hai", &"係"),
+    (&"hai", &"喺"),
+    (&"m", &"唔"),
+    (&"mhai", &"唔係"),
+    (&"mou", &"冇"),
+    (&"jau", &"有"),
+    (&"jau", &"右"),
+    (&"ge", &"嘅"),
+    (&"zo", &"咗"),
+    (&"zo", &"左"),
+    (&"gan", &"緊"),
+    (&"gwo", &"過"),
+    (&"di", &"啲"),
+    (&"ni", &"呢"),
+    (&"go", &"嗰"),
+    (&"go", &"個"),
+    (&"gogo", &"哥哥"),
+    (&"matje", &"乜嘢"),
+    (&"mat", &"乜"),
+    (&"je", &"嘢"),
+    (&"je", &"夜"),
+    (&"dim", &"點"),
+    (&"dimgaai", &"點解"),
+    (&"dimjoeng", &"點樣"),
+    (&"bingo", &"邊個"),
+    (&"bindou", &"邊度"),
+    (&"bingaan", &"邊間"),
+    (&"bin", &"邊"),
+    (&"geido", &"幾多"),
+    (&"geisi", &"幾時"),
+    (&"geicin", &"幾錢"),
+    (&"gei", &"幾"),
+    (&"me", &"咩"),
+    (&"sik", &"食"),
+    (&"sik", &"識"),
+    (&"jam", &"飲"),
+    (&"sikfaan", &"食飯"),
+    (&"sikzofaan", &"食咗飯"),
+    (&"faan", &"飯"),
+    (&"faan", &"返"),
+    (&"mei", &"未"),
+    (&"neihou", &"你好"),
+    (&"zousan", &"早晨"),
+    (&"mgoi", &"唔該"),
+    (&"mgoisaai", &"唔該晒"),
+    (&"doze", &"多謝"),
+    (&"dozesaai", &"多謝晒"),
+    (&"saai", &"晒"),
+    (&"deoimzyu", &"對唔住"),
+    (&"mganjiu", &"唔緊要"),
+    (&"baaibaai", &"拜拜"),
+    (&"hou", &"好"),
+    (&"hou", &"毫"),
+    (&"leng", &"靚"),
+    (&"daai", &"大"),
+    (&"sai", &"細"),
+    (&"do", &"多"),
+    (&"siu", &"少"),
+    (&"jan", &"人"),
+    (&"ukkei", &"屋企"),
+    (&"hokhaau", &"學校"),
+    (&"faangung", &"返工"),
+    (&"faanhok", &"返學"),
+    (&"fonggung", &"放工"),
+    (&"gamjat", &"今日"),
+    (&"tingjat", &"聽日"),
+    (&"kamjat", &"琴日"),
+    (&"jigaa", &"而家"),
+    (&"tausin", &"頭先"),
+    (&"zihau", &"之後"),
+    (&"ganzyu", &"跟住"),
+    (&"hoenggong", &"香港"),
+    (&"gwongdung", &"廣東"),
+    (&"gwongdungwaa", &"廣東話"),
+    (&"gwongzau", &"廣州"),
+    (&"oumun", &"澳門"),
+    (&"zunggwok", &"中國"),
+    (&"jyutjyu", &"粵語"),
+    (&"poutungwaa", &"普通話"),
+    (&"jingman", &"英文"),
+    (&"zungman", &"中文"),
+    (&"gong", &"講"),
+    (&"gongje", &"講嘢"),
+    (&"zi", &"知"),
+    (&"zi", &"字"),
+    (&"zidou", &"知道"),
+    (&"mingbaak", &"明白"),
+    (&"tai", &"睇"),
+    (&"teng", &"聽"),
+    (&"haang", &"行"),
+    (&"zau", &"走"),
+    (&"zau", &"就"),
+    (&"lai", &"嚟"),
+    (&"heoi", &"去"),
+    (&"co", &"坐"),
+    (&"co", &"錯"),
+    (&"kei", &"企"),
+    (&"fangaau", &"瞓覺"),
+    (&"fan", &"瞓"),
+    (&"heisan", &"起身"),
+    (&"zou", &"做"),
+    (&"zouje", &"做嘢"),
+    (&"maai", &"買"),
+    (&"maai", &"賣"),
+    (&"bei", &"俾"),
+    (&"lo", &"攞"),
+    (&"wan", &"搵"),
+    (&"jung", &"用"),
+    (&"soeng", &"想"),
+    (&"zungji", &"鍾意"),
+    (&"oi", &"愛"),
+    (&"jiu", &"要"),
+    (&"hoji", &"可以"),
+    (&"jinggoi", &"應該"),
+    (&"wui", &"會"),
+    (&"cin", &"錢"),
+    (&"man", &"蚊"),
+    (&"man", &"問"),
+    (&"gwai", &"貴"),
+    (&"peng", &"平"),
+    (&"jat", &"一"),
+    (&"ji", &"二"),
+    (&"loeng", &"兩"),
+    (&"saam", &"三"),
+    (&"sei", &"四"),
+    (&"ng", &"五"),
+    (&"luk", &"六"),
+    (&"cat", &"七"),
+    (&"baat", &"八"),
+    (&"gau", &"九"),
+    (&"sap", &"十"),
+    (&"baak", &"百"),
+    (&"cin", &"千"),
+    (&"maan", &"萬"),
+    (&"zek", &"隻"),
+    (&"bun", &"本"),
+    (&"zoeng", &"張"),
+    (&"gin", &"件"),
+    (&"bou", &"部"),
+    (&"gaa", &"架"),
+    (&"gaan", &"間"),
+    (&"bui", &"杯"),
+    (&"wun", &"碗"),
+    (&"seoi", &"水"),
+    (&"caa", &"茶"),
+    (&"jamcaa", &"飲茶"),
+    (&"gaafe", &"咖啡"),
+    (&"naaicaa", &"奶茶"),
+    (&"dimsam", &"點心"),
+    (&"haagaau", &"蝦餃"),
+    (&"siumaai", &"燒賣"),
+    (&"caasiu", &"叉燒"),
+    (&"caasiubaau", &"叉燒包"),
+    (&"wantanmin", &"雲吞麵"),
+    (&"min", &"麵"),
+    (&"zuk", &"粥"),
+    (&"gai", &"雞"),
+    (&"zyu", &"豬"),
+    (&"ngau", &"牛"),
+    (&"jyu", &"魚"),
+    (&"coi", &"菜"),
+    (&"saanggwo", &"生果"),
+    (&"pinggwo", &"蘋果"),
+    (&"caang", &"橙"),
+    (&"pangjau", &"朋友"),
+    (&"lougung", &"老公"),
+    (&"loupo", &"老婆"),
+    (&"zai", &"仔"),
+    (&"neoi", &"女"),
+    (&"sailou", &"細路"),
+    (&"baabaa", &"爸爸"),
+    (&"maamaa", &"媽媽"),
+    (&"gaaze", &"家姐"),
+    (&"sailou", &"細佬"),
+    (&"saimui", &"細妹"),
+    (&"lousi", &"老師"),
+    (&"hoksaang", &"學生"),
+    (&"jisang", &"醫生"),
+    (&"sinsaang", &"先生"),
+    (&"siuze", &"小姐"),
+    (&"taaitaai", &"太太"),
+    (&"deitit", &"地鐵"),
+    (&"baasi", &"巴士"),
+    (&"diksi", &"的士"),
+    (&"dince", &"電車"),
+    (&"ce", &"車"),
+    (&"zaace", &"揸車"),
+    (&"daap", &"搭"),
+    (&"daap", &"答"),
+    (&"dinwaa", &"電話"),
+    (&"saugei", &"手機"),
+    (&"dinnou", &"電腦"),
+    (&"dinsi", &"電視"),
+    (&"taihei", &"睇戲"),
+    (&"tinhei", &"天氣"),
+    (&"jit", &"熱"),
+    (&"dung", &"凍"),
+    (&"lokjyu", &"落雨"),
+    (&"houcoi", &"好彩"),
+    (&"hoisam", &"開心"),
+    (&"mhoisam", &"唔開心"),
+    (&"gui", &"攰"),
+    (&"toungo", &"肚餓"),
+    (&"genghot", &"頸渴"),
+    (&"uk", &"屋"),
+    (&"gaai", &"街"),
+    (&"haanggaai", &"行街"),
+    (&"caanteng", &"餐廳"),
+    (&"caacaanteng", &"茶餐廳"),
+    (&"zaulau", &"酒樓"),
+    (&"jijyun", &"醫院"),
+    (&"nganhong", &"銀行"),
+    (&"gungsi", &"公司"),
+    (&"ciukapsicoeng", &"超級市場"),
+    (&"gaaisi", &"街市"),
+    (&"ciso", &"廁所"),
+    (&"gam", &"咁"),
+    (&"gam", &"噉"),
+    (&"houci", &"好似"),
+    (&"dou", &"都"),
+    (&"jik", &"亦"),
+    (&"tung", &"同"),
+    (&"tungmaai", &"同埋"),
+    (&"daanhai", &"但係"),
+    (&"janwai", &"因為"),
+    (&"soji", &"所以"),
+    (&"jyugwo", &"如果"),
+    (&"waakze", &"或者"),
+    (&"zanhai", &"真係"),
+    (&"ganghai", &"梗係"),
+    (&"jatcai", &"一齊"),
+    (&"jatdi", &"一啲"),
+    (&"siusiu", &"少少"),
+    (&"houdo", &"好多"),
+    (&"taai", &"太"),
+    (&"zeoi", &"最"),
+    (&"zung", &"仲"),
+    (&"sin", &"先"),
+    (&"zoi", &"再"),
+    (&"jiging", &"已經"),
+    (&"ngaam", &"啱"),
+    (&"ngaamngaam", &"啱啱"),
+    (&"moumantai", &"冇問題"),
+    (&"mantai", &"問題"),
+    (&"sigaan", &"時間"),
+    (&"zungtau", &"鐘頭"),
+    (&"fanzung", &"分鐘"),
+    (&"laibaai", &"禮拜"),
+    (&"singkei", &"星期"),
+    (&"jyut", &"月"),
+    (&"nin", &"年"),
+    (&"gamnin", &"今年"),
+    (&"gaunin", &"舊年"),
+    (&"ceotnin", &"出年"),
+    (&"ziuzou", &"朝早"),
+    (&"aanzau", &"晏晝"),
+    (&"jemaan", &"夜晚"),
+    (&"sanninfaailok", &"新年快樂"),
+    (&"gungheifaatcoi", &"恭喜發財"),
+    (&"saangjat", &"生日"),
+    (&"saangjatfaailok", &"生日快樂"),
+    (&"faailok", &"快樂"),
+    (&"gungzok", &"工作"),
+    (&"fonggaa", &"放假"),
+    (&"jausik", &"休息"),
+    (&"hok", &"學"),
+    (&"duksyu", &"讀書"),
+    (&"syu", &"書"),
+    (&"se", &"寫"),
+    (&"meng", &"名"),
+    (&"giu", &"叫"),
+    (&"waa", &"話"),
+    (&"gokdak", &"覺得"),
+    (&"nam", &"諗"),
+    (&"geidak", &"記得"),
+    (&"mgeidak", &"唔記得"),
+    (&"dang", &"等"),
+    (&"bongsau", &"幫手"),
+    (&"kinggai", &"傾偈"),
+    (&"waan", &"玩"),
+    (&"zoek", &"著"),
+    (&"saam", &"衫"),
+    (&"fu", &"褲"),
+    (&"haai", &"鞋"),
+    (&"lengzai", &"靚仔"),
+    (&"lengneoi", &"靚女"),
+    (&"gou", &"高"),
+    (&"ai", &"矮"),
+    (&"fei", &"肥"),
+    (&"sau", &"瘦"),
+    (&"san", &"新"),
+    (&"gau", &"舊"),
+    (&"faai", &"快"),
+    (&"maan", &"慢"),
+    (&"jyun", &"遠"),
+    (&"kan", &"近"),
+    (&"zou", &"早"),
+    (&"japmin", &"入面"),
+    (&"ceotmin", &"出面"),
+    (&"cinmin", &"前面"),
+    (&"haumin", &"後面"),
+    (&"fugan", &"附近"),
+    (&"nidou", &"呢度"),
+    (&"godou", &"嗰度"),
+    (&"nigo", &"呢個"),
+    (&"gogo", &"嗰個"),
+    (&"haimai", &"係咪"),
+    (&"dak", &"得"),
+    (&"mdak", &"唔得"),
+    (&"housik", &"好食"),
+    (&"houjam", &"好飲"),
+    (&"houtai", &"好睇"),
+    (&"houteng", &"好聽"),
+    (&"maafaan", &"麻煩"),
+    (&"maaidaan", &"埋單"),
+    (&"baatdaattung", &"八達通"),
+    (&"gaulung", &"九龍"),
+    (&"sangaai", &"新界"),
+    (&"wonggok", &"旺角"),
+    (&"zungwaan", &"中環"),
+    (&"zimsaazeoi", &"尖沙咀"),
+    (&"tunglowaan", &"銅鑼灣"),
+    (&"<", &"《"),
+    (&">", &"》"),
+    (&".", &"。"),
+    (&"`", &"·"),
+    (&"\\", &"、"),
+    (&",", &"，"),
+    (&"!", &"！"),
+    (&"?", &"？"),
+    (&":", &"："),
+    (&";", &"；"),
+    (&"(", &"（"),
+    (&")", &"）"),
+];
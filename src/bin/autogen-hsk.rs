@@ -0,0 +1,1272 @@
+// Dictionary compiler for the built-in vocab: HSK words with pinyin search
+// keys, and Cantonese words with Jyutping search keys. This reads the vocab
+// .tsv files, merges homophones, and writes the binary dictionary (see the
+// format notes in ../dictionary.rs) along with the Rust source that includes
+// it.
+//
+// Usage: cargo run --bin autogen-hsk -- [--check] [--vocab DIR] [--scheme NAME]
+//                                       [--dict FILE] [--rust FILE]
+//   --vocab DIR    read .tsv files from DIR (default: vocab)
+//   --scheme NAME  compile only the vocab of NAME (hsk or jyutping)
+//   --dict FILE    write binary dictionary to FILE
+//   --rust FILE    write Rust source that includes the dictionary to FILE
+//   --check        write nothing, but fail if output files are out of date
+// With neither --dict nor --rust, output goes to src/autogen_hsk.dict and
+// src/autogen_hsk.rs, and to src/autogen_jyutping.dict and
+// src/autogen_jyutping.rs. --dict and --rust without --scheme are for hsk.
+use ime_engine::syllable::{self, Scheme, INTERJECTIONS};
+use ime_engine::{dictionary, murmur3};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::{env, fs, process};
+
+// Word files with their HSK level, and whether they are -extra lists of words
+// that are not on the official list. Easy and common words come first, and
+// this order ranks prefix completions.
+const WORD_FILES: &[(&str, u8, bool)] = &[
+    ("hsk1.tsv", 1, false),
+    ("hsk1-extra.tsv", 1, true),
+    ("hsk2.tsv", 2, false),
+    ("hsk2-extra.tsv", 2, true),
+    ("hsk3.tsv", 3, false),
+    ("hsk3-extra.tsv", 3, true),
+    ("hsk4.tsv", 4, false),
+    ("hsk4-extra.tsv", 4, true),
+    ("hsk5.tsv", 5, false),
+    ("hsk5-extra.tsv", 5, true),
+    ("hsk6.tsv", 6, false),
+];
+const PUNCTUATION_FILE: &str = "pinyin-punctuation.tsv";
+const TRADITIONAL_FILE: &str = "traditional.tsv";
+// Official HSK 2012 word lists, for checking vocab coverage in Rust tests
+const OFFICIAL_FILE: &str = "official/hsk2012.gz";
+
+// Cantonese words are written in Traditional characters already, and have
+// no levels.
+const JYUTPING_WORD_FILES: &[(&str, u8, bool)] = &[("jyutping/words.tsv", 0, false)];
+
+// Vocab of one phonetic scheme, with paths of its files relative to the vocab
+// directory and its outputs relative to the crate.
+struct Files {
+    // Name for --scheme and for messages
+    name: &'static str,
+    scheme: Scheme,
+    word_files: &'static [(&'static str, u8, bool)],
+    traditional_file: Option<&'static str>,
+    official_file: Option<&'static str>,
+    dict_file: &'static str,
+    rust_file: &'static str,
+    // Name of the test data table in the Rust file
+    test_data: &'static str,
+}
+
+const HSK: Files = Files {
+    name: "hsk",
+    scheme: Scheme::Pinyin,
+    word_files: WORD_FILES,
+    traditional_file: Some(TRADITIONAL_FILE),
+    official_file: Some(OFFICIAL_FILE),
+    dict_file: "src/autogen_hsk.dict",
+    rust_file: "src/autogen_hsk.rs",
+    test_data: "PINYIN_CIYU_TEST_DATA",
+};
+
+const JYUTPING: Files = Files {
+    name: "jyutping",
+    scheme: Scheme::Jyutping,
+    word_files: JYUTPING_WORD_FILES,
+    traditional_file: None,
+    official_file: None,
+    dict_file: "src/autogen_jyutping.dict",
+    rust_file: "src/autogen_jyutping.rs",
+    test_data: "JYUTPING_CIYU_TEST_DATA",
+};
+
+const ALL_FILES: &[Files] = &[HSK, JYUTPING];
+
+// If compiling fails because of a hash collision, try another seed.
+const MURMUR3_SEED: u32 = 0;
+
+const SYLLABLE_SIZE_MAX: usize = 6;
+
+// One row of a .tsv file.
+struct Row {
+    ciyu: String,
+    pinyin: String,
+    // Optional word frequency weight (higher is more common, 0 if unknown)
+    weight: u32,
+    // Optional part of speech, like "v.", and English meaning
+    pos: String,
+    meaning: String,
+}
+
+// Return rows of tab separated fields, skipping comments and lines without a
+// tab. Most rows are like "词语\tpinyin". An optional number after the pinyin
+// is a frequency weight, like "和\thé\t9000", which puts more common
+// homophones first. Some rows add part of speech and meaning, like
+// "过\tguo\tv.\tto spend, to pass", which become glosses in the dictionary.
+// Fields after the meaning, like "[textbook spelling]" notes, are not used.
+fn read_tsv(path: &Path) -> Result<Vec<Row>, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut rows = Vec::new();
+    for line in text.lines() {
+        if line.starts_with('#') || !line.contains('\t') {
+            continue;
+        }
+        let mut fields = line.split('\t').peekable();
+        let ciyu = fields.next().unwrap_or("");
+        let pinyin = fields.next().unwrap_or("");
+        let weight = match fields.peek() {
+            Some(w) if !w.is_empty() && w.bytes().all(|b| b.is_ascii_digit()) => {
+                let weight = w
+                    .parse()
+                    .map_err(|_| format!("{}: weight too big: {}", path.display(), line))?;
+                fields.next();
+                weight
+            }
+            _ => 0,
+        };
+        let pos = fields.next().unwrap_or("");
+        let meaning = fields.next().unwrap_or("");
+        // Glosses in the dictionary use a space between part of speech and
+        // meaning
+        if pos.contains(' ') {
+            return Err(format!(
+                "{}: space in part of speech: {}",
+                path.display(),
+                line
+            ));
+        }
+        rows.push(Row {
+            ciyu: ciyu.to_string(),
+            pinyin: pinyin.to_string(),
+            weight,
+            pos: pos.to_string(),
+            meaning: meaning.trim().to_string(),
+        });
+    }
+    Ok(rows)
+}
+
+// Simplified to Traditional table for converting 词语.
+#[derive(Default)]
+struct Traditional {
+    // Usual Traditional form of each character
+    chars: HashMap<char, char>,
+    // (simplified, traditional, search key) phrases, longest first, for
+    // characters with more than one Traditional form. A phrase with a search
+    // key is only for 词语 with that key.
+    phrases: Vec<(String, String, Option<String>)>,
+}
+impl Traditional {
+    // Parse rows like "发\t發" for characters and "头发\t頭髮" for phrases,
+    // with an optional pinyin field that limits a row to one reading.
+    fn parse(text: &str) -> Result<Traditional, String> {
+        let mut table = Traditional::default();
+        for line in text.lines() {
+            if line.starts_with('#') || !line.contains('\t') {
+                continue;
+            }
+            let mut fields = line.split('\t');
+            let simplified = fields.next().unwrap_or("");
+            let traditional = fields.next().unwrap_or("");
+            let key = match fields.next() {
+                Some(pinyin) if !pinyin.is_empty() => Some(normalize(Scheme::Pinyin, pinyin)?),
+                _ => None,
+            };
+            // Conversion is character by character
+            let count = simplified.chars().count();
+            if count == 0 || count != traditional.chars().count() {
+                return Err(format!("{}: lengths differ: {}", TRADITIONAL_FILE, line));
+            }
+            let (s, t) = (simplified.chars().next(), traditional.chars().next());
+            match (s, t, count, &key) {
+                (Some(s), Some(t), 1, None) => {
+                    table.chars.insert(s, t);
+                }
+                _ => {
+                    let (s, t) = (simplified.to_string(), traditional.to_string());
+                    table.phrases.push((s, t, key));
+                }
+            }
+        }
+        table
+            .phrases
+            .sort_by_key(|(s, _, _)| std::cmp::Reverse(s.chars().count()));
+        Ok(table)
+    }
+
+    // Return Traditional form of 词语 with search key, taking the longest
+    // phrase at each character, or else the character's usual form.
+    fn convert(&self, ciyu: &str, key: &str) -> String {
+        let mut out = String::new();
+        let mut rest = ciyu;
+        while let Some(c) = rest.chars().next() {
+            let phrase = self.phrases.iter().find(|(s, _, phrase_key)| {
+                rest.starts_with(s.as_str()) && phrase_key.as_ref().is_none_or(|k| k == key)
+            });
+            match phrase {
+                Some((s, t, _)) => {
+                    out.push_str(t);
+                    rest = &rest[s.len()..];
+                }
+                None => {
+                    out.push(*self.chars.get(&c).unwrap_or(&c));
+                    rest = &rest[c.len_utf8()..];
+                }
+            }
+        }
+        out
+    }
+}
+
+// Return (HSK level, word) for words that are new at each level of the
+// official lists. The archive has one section per level, like
+// "--- hsk2-300.txt", and each level's list includes the levels below it.
+fn read_official(path: &Path) -> Result<Vec<(u8, String)>, String> {
+    let gz = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let bytes = gunzip(&gz).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let text = String::from_utf8(bytes).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let mut words = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    let mut level = None;
+    for line in text.lines().map(str::trim) {
+        if let Some(name) = line.strip_prefix("--- hsk") {
+            let digits: String = name.chars().take_while(char::is_ascii_digit).collect();
+            level = Some(
+                digits
+                    .parse()
+                    .map_err(|_| format!("bad section: {}", line))?,
+            );
+        } else if let (Some(level), false) = (level, line.is_empty()) {
+            if seen.insert(line.to_string()) {
+                words.push((level, line.to_string()));
+            }
+        }
+    }
+    Ok(words)
+}
+
+// Decompress a gzip file with a single member (RFC 1952).
+fn gunzip(gz: &[u8]) -> Result<Vec<u8>, String> {
+    const FEXTRA: u8 = 4;
+    const FNAME: u8 = 8;
+    const FCOMMENT: u8 = 16;
+    const FHCRC: u8 = 2;
+    if gz.len() < 18 || gz[..3] != [0x1f, 0x8b, 8] {
+        return Err("not a gzip file".to_string());
+    }
+    let flags = gz[3];
+    let mut pos = 10;
+    if flags & FEXTRA != 0 {
+        let len = gz.get(pos..pos + 2).ok_or("truncated header")?;
+        pos += 2 + usize::from(u16::from_le_bytes([len[0], len[1]]));
+    }
+    for flag in [FNAME, FCOMMENT] {
+        if flags & flag != 0 {
+            let len = gz[pos.min(gz.len())..].iter().position(|&b| b == 0);
+            pos += len.ok_or("truncated header")? + 1;
+        }
+    }
+    if flags & FHCRC != 0 {
+        pos += 2;
+    }
+    let deflated = gz.get(pos..gz.len() - 8).ok_or("truncated header")?;
+    inflate(deflated)
+}
+
+// Bit reader for deflate data, least significant bit first.
+struct Bits<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+impl Bits<'_> {
+    fn bit(&mut self) -> Result<u32, String> {
+        let byte = self
+            .data
+            .get(self.pos / 8)
+            .ok_or("unexpected end of data")?;
+        let bit = (byte >> (self.pos % 8)) & 1;
+        self.pos += 1;
+        Ok(u32::from(bit))
+    }
+
+    fn bits(&mut self, n: u32) -> Result<u32, String> {
+        let mut value = 0;
+        for i in 0..n {
+            value |= self.bit()? << i;
+        }
+        Ok(value)
+    }
+}
+
+// Canonical Huffman code as symbol counts per code length and symbols in
+// code order.
+struct Huffman {
+    counts: [u16; 16],
+    symbols: Vec<u16>,
+}
+impl Huffman {
+    fn new(lengths: &[u8]) -> Huffman {
+        let mut counts = [0u16; 16];
+        for &len in lengths {
+            counts[usize::from(len)] += 1;
+        }
+        counts[0] = 0;
+        let mut symbols = Vec::new();
+        for len in 1..16 {
+            for (symbol, _) in lengths.iter().enumerate().filter(|(_, &l)| l == len) {
+                symbols.push(symbol as u16);
+            }
+        }
+        Huffman { counts, symbols }
+    }
+
+    fn decode(&self, bits: &mut Bits) -> Result<usize, String> {
+        let (mut code, mut first, mut index) = (0, 0, 0);
+        for &count in self.counts[1..].iter() {
+            code |= bits.bit()? as i32;
+            let count = i32::from(count);
+            if code - count < first {
+                return Ok(usize::from(self.symbols[(index + code - first) as usize]));
+            }
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+        Err("bad Huffman code".to_string())
+    }
+}
+
+// Decompress raw deflate data (RFC 1951).
+fn inflate(data: &[u8]) -> Result<Vec<u8>, String> {
+    const LENGTH_BASE: [u16; 29] = [
+        3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115,
+        131, 163, 195, 227, 258,
+    ];
+    const LENGTH_EXTRA: [u8; 29] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+    ];
+    const DIST_BASE: [u16; 30] = [
+        1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+        2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+    ];
+    const DIST_EXTRA: [u8; 30] = [
+        0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12,
+        13, 13,
+    ];
+    // Order of code length code lengths in a dynamic block header
+    const CLEN_ORDER: [usize; 19] = [
+        16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+    ];
+    let mut out = Vec::new();
+    let mut bits = Bits { data, pos: 0 };
+    loop {
+        let last = bits.bit()? == 1;
+        let (lit, dist) = match bits.bits(2)? {
+            0 => {
+                // Stored block
+                let start = bits.pos.div_ceil(8);
+                let header = data.get(start..start + 4).ok_or("truncated stored block")?;
+                let len = usize::from(u16::from_le_bytes([header[0], header[1]]));
+                let block = data.get(start + 4..start + 4 + len);
+                out.extend_from_slice(block.ok_or("truncated stored block")?);
+                bits.pos = (start + 4 + len) * 8;
+                if last {
+                    return Ok(out);
+                }
+                continue;
+            }
+            1 => {
+                let mut lengths = [8u8; 288];
+                lengths[144..256].fill(9);
+                lengths[256..280].fill(7);
+                (Huffman::new(&lengths), Huffman::new(&[5; 30]))
+            }
+            2 => {
+                let hlit = bits.bits(5)? as usize + 257;
+                let hdist = bits.bits(5)? as usize + 1;
+                let hclen = bits.bits(4)? as usize + 4;
+                let mut clens = [0u8; 19];
+                for &i in CLEN_ORDER[..hclen].iter() {
+                    clens[i] = bits.bits(3)? as u8;
+                }
+                let clen = Huffman::new(&clens);
+                let mut lengths = Vec::new();
+                while lengths.len() < hlit + hdist {
+                    let (len, repeat) = match clen.decode(&mut bits)? {
+                        16 => (*lengths.last().ok_or("bad repeat")?, 3 + bits.bits(2)?),
+                        17 => (0, 3 + bits.bits(3)?),
+                        18 => (0, 11 + bits.bits(7)?),
+                        len => (len as u8, 1),
+                    };
+                    lengths.extend((0..repeat).map(|_| len));
+                }
+                (
+                    Huffman::new(&lengths[..hlit]),
+                    Huffman::new(&lengths[hlit..hlit + hdist]),
+                )
+            }
+            _ => return Err("bad block type".to_string()),
+        };
+        loop {
+            let symbol = lit.decode(&mut bits)?;
+            if symbol < 256 {
+                out.push(symbol as u8);
+                continue;
+            } else if symbol == 256 {
+                break;
+            }
+            let i = symbol - 257;
+            let extra = u32::from(*LENGTH_EXTRA.get(i).ok_or("bad length")?);
+            let len = usize::from(LENGTH_BASE[i]) + bits.bits(extra)? as usize;
+            let i = dist.decode(&mut bits)?;
+            let extra = u32::from(*DIST_EXTRA.get(i).ok_or("bad distance")?);
+            let distance = usize::from(DIST_BASE[i]) + bits.bits(extra)? as usize;
+            let start = out.len().checked_sub(distance).ok_or("bad distance")?;
+            for j in start..start + len {
+                out.push(out[j]);
+            }
+        }
+        if last {
+            return Ok(out);
+        }
+    }
+}
+
+// Normalize pinyin to a lowercase ASCII search key (remove tone marks, spaces,
+// apostrophes, and hyphens). Jyutping loses its tone numbers instead.
+fn normalize(scheme: Scheme, pinyin: &str) -> Result<String, String> {
+    let mut key = String::new();
+    for c in pinyin.chars().flat_map(char::to_lowercase) {
+        match c {
+            ' ' | '\'' | '-' => {}
+            '1'..='6' if scheme == Scheme::Jyutping => {}
+            c if c.is_ascii() => key.push(c),
+            c => match syllable::unmark(c) {
+                Some((letter, _)) => key.push(char::from(letter)),
+                None => return Err(format!("normalize({}): unexpected {:?}", pinyin, c)),
+            },
+        }
+    }
+    Ok(key)
+}
+
+// Return tone number of a vowel with a tone mark, or of a Jyutping tone
+// number.
+fn tone_of(scheme: Scheme, c: char) -> Option<u8> {
+    let c = c.to_lowercase().next()?;
+    match (scheme, syllable::unmark(c)) {
+        (Scheme::Pinyin, Some((_, tone))) if tone > 0 => Some(tone),
+        (Scheme::Jyutping, _) if ('1'..='6').contains(&c) => Some(c as u8 - b'0'),
+        _ => None,
+    }
+}
+
+// Split normalized pinyin into syllables, trying longer syllables first.
+// Return: syllables, or None if pinyin does not split.
+fn split_syllables(scheme: Scheme, pinyin: &str) -> Option<Vec<&str>> {
+    // Jyutping m and ng are syllables of their own, like 唔 m4
+    let interjection = |s| scheme == Scheme::Pinyin && INTERJECTIONS.contains(&s);
+    if pinyin.is_empty() {
+        return Some(Vec::new());
+    }
+    if interjection(pinyin) {
+        return Some(vec![pinyin]);
+    }
+    for n in (1..=pinyin.len().min(SYLLABLE_SIZE_MAX)).rev() {
+        let head = match pinyin.get(..n) {
+            Some(head) if scheme.has_syllable(head) && !interjection(head) => head,
+            _ => continue,
+        };
+        if let Some(mut rest) = split_syllables(scheme, &pinyin[n..]) {
+            rest.insert(0, head);
+            return Some(rest);
+        }
+    }
+    None
+}
+
+// Split pinyin of 词语 into one (syllable, tone) pair per hanzi, with tone 5
+// for neutral tone. Pinyin without any tone marks (much of hsk3 and up) gets
+// tone 0 for unknown. Spaces and punctuation in the pinyin are syllable
+// boundaries. An erhua "r" only counts when it has its own hanzi. In
+// Jyutping, a tone number ends its syllable, like "nei5hou2", and syllables
+// without one get tone 0.
+// Return: syllables, or None if syllables don't match hanzi.
+fn syllables_of(scheme: Scheme, ciyu: &str, pinyin: &str) -> Option<Vec<(String, u8)>> {
+    let tone_of = |c| tone_of(scheme, c);
+    let unmarked_tone = match pinyin.chars().any(|c| tone_of(c).is_some()) {
+        true if scheme == Scheme::Pinyin => 5,
+        _ => 0,
+    };
+    let mut syllables = Vec::new();
+    let chunks = pinyin.split(|c| " '-<>`".contains(c));
+    for chunk in chunks.flat_map(|chunk| match scheme {
+        Scheme::Pinyin => vec![chunk],
+        Scheme::Jyutping => chunk.split_inclusive(|c| tone_of(c).is_some()).collect(),
+    }) {
+        let key = normalize(scheme, chunk).ok()?;
+        if scheme == Scheme::Jyutping {
+            // Only the last syllable before a tone number gets its tone
+            let tone = chunk.chars().last().and_then(tone_of);
+            let split = split_syllables(scheme, &key)?;
+            let last = split.len().saturating_sub(1);
+            for (k, s) in split.into_iter().enumerate() {
+                let tone = if k == last { tone.unwrap_or(0) } else { 0 };
+                syllables.push((s.to_string(), tone));
+            }
+            continue;
+        }
+        // normalize() maps characters of chunk one to one
+        let tones: Vec<Option<u8>> = chunk.chars().map(tone_of).collect();
+        let mut pos = 0;
+        for s in split_syllables(scheme, &key)? {
+            let tone = tones[pos..pos + s.len()].iter().flatten().next();
+            syllables.push((s.to_string(), *tone.unwrap_or(&unmarked_tone)));
+            pos += s.len();
+        }
+    }
+    let hanzi_count = syllable::syllable_count(ciyu);
+    while syllables.len() > hanzi_count {
+        match syllables.iter().rposition(|(s, _)| s == "r") {
+            Some(r) => syllables.remove(r),
+            None => break,
+        };
+    }
+    match syllables.len() == hanzi_count && hanzi_count > 0 {
+        true => Some(syllables),
+        false => None,
+    }
+}
+
+// Make abbreviated search keys from initials of syllables. zh, ch, and sh
+// count as one initial, which may also be shortened to z, c, and s (果汁
+// guozhi ==> gzh, gz). So do Jyutping gw, kw, and ng (廣東 gwong2dung1 ==>
+// gwd, gd).
+fn abbreviate(scheme: Scheme, syllables: &[(String, u8)]) -> Vec<String> {
+    let mut abbrevs = vec![String::new()];
+    for (s, _) in syllables.iter() {
+        let initials = match (scheme, s.get(..2)) {
+            (Scheme::Pinyin, Some(zh @ ("zh" | "ch" | "sh"))) => vec![zh, &s[..1]],
+            (Scheme::Jyutping, Some(gw @ ("gw" | "kw" | "ng"))) if s.len() > 2 => {
+                vec![gw, &s[..1]]
+            }
+            _ => vec![&s[..1]],
+        };
+        abbrevs = abbrevs
+            .iter()
+            .flat_map(|a| initials.iter().map(move |i| format!("{}{}", a, i)))
+            .collect();
+    }
+    abbrevs
+}
+
+// Search key with its homophone 词语.
+struct Entry {
+    key: String,
+    ciyu: Vec<String>,
+    // Tones of each homophone, one string per reading
+    tones: Vec<Vec<String>>,
+    // Frequency weight of each homophone
+    weights: Vec<u32>,
+    // (HSK level, is from an -extra list) of each homophone. Words in
+    // several files get the lowest level.
+    levels: Vec<(u8, bool)>,
+    // (part of speech, meaning) senses of each homophone
+    glosses: Vec<Vec<(String, String)>>,
+    // Position in vocab file order for word file keys (None for punctuation)
+    rank: Option<usize>,
+}
+
+// Vocab merged from the .tsv files, in vocab file order.
+#[derive(Default)]
+struct Vocab {
+    scheme: Scheme,
+    entries: Vec<Entry>,
+    index_of: HashMap<String, usize>,
+    // Abbreviations with their (entry, homophone) candidates
+    abbrevs: Vec<(String, Vec<(usize, usize)>)>,
+    abbrev_index_of: HashMap<String, usize>,
+    // (search key, 词语) for each row, before merging, for Rust test data
+    test_data: Vec<(String, String)>,
+    // (HSK level, word) for words that are new at each level of the
+    // official lists, for Rust test data
+    official: Vec<(u8, String)>,
+    traditional: Traditional,
+    key_size_max: usize,
+}
+impl Vocab {
+    // Read word files and then punctuation file from vocab directory. Both
+    // schemes share the punctuation file.
+    fn read(dir: &Path, files: &Files) -> Result<Vocab, String> {
+        let mut vocab = Vocab {
+            scheme: files.scheme,
+            ..Vocab::default()
+        };
+        for &(file, level, extra) in files.word_files.iter() {
+            for row in read_tsv(&dir.join(file))? {
+                vocab.add_word(file, (level, extra), &row)?;
+            }
+        }
+        for row in read_tsv(&dir.join(PUNCTUATION_FILE))? {
+            vocab.add_punctuation(&row.ciyu, &row.pinyin);
+        }
+        vocab.sort_homophones();
+        if let Some(file) = files.official_file {
+            vocab.official = read_official(&dir.join(file))?;
+        }
+        if let Some(file) = files.traditional_file {
+            let path = dir.join(file);
+            let text =
+                fs::read_to_string(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+            vocab.traditional = Traditional::parse(&text)?;
+        }
+        Ok(vocab)
+    }
+
+    // Add 词语 from a word file, merging homophones of the same search key.
+    fn add_word(&mut self, file: &str, level: (u8, bool), row: &Row) -> Result<(), String> {
+        let (ciyu, pinyin) = (row.ciyu.as_str(), row.pinyin.as_str());
+        let key = normalize(self.scheme, pinyin)?;
+        let syllables = syllables_of(self.scheme, ciyu, pinyin);
+        let tones: String = match &syllables {
+            Some(syllables) => syllables
+                .iter()
+                .map(|(_, t)| char::from(b'0' + t))
+                .collect(),
+            None => String::new(),
+        };
+        self.test_data.push((key.clone(), ciyu.to_string()));
+        self.key_size_max = self.key_size_max.max(key.len());
+        let i = match self.index_of.get(&key) {
+            Some(&i) => i,
+            None => {
+                let i = self.entries.len();
+                self.index_of.insert(key.clone(), i);
+                self.entries.push(Entry {
+                    key,
+                    ciyu: Vec::new(),
+                    tones: Vec::new(),
+                    weights: Vec::new(),
+                    levels: Vec::new(),
+                    glosses: Vec::new(),
+                    rank: Some(i),
+                });
+                i
+            }
+        };
+        let gloss = (row.pos.clone(), row.meaning.clone());
+        let has_gloss = !(gloss.0.is_empty() && gloss.1.is_empty());
+        let entry = &mut self.entries[i];
+        let nth = match entry.ciyu.iter().position(|c| c == ciyu) {
+            Some(nth) => {
+                // Same 词语 and pinyin for a different part of speech, like
+                // 过 guò. Keep its tones if they are different, like 好 hǎo
+                // and 好 hào. Other repeats might be data entry mistakes.
+                if !entry.tones[nth].contains(&tones) {
+                    entry.tones[nth].push(tones);
+                }
+                entry.weights[nth] = entry.weights[nth].max(row.weight);
+                entry.levels[nth] = entry.levels[nth].min(level);
+                if has_gloss && !entry.glosses[nth].contains(&gloss) {
+                    entry.glosses[nth].push(gloss);
+                }
+                eprintln!(
+                    "Duplicate?: {:>14}:  {}:{:>10}   ==>    grep '^{}\\t' *.tsv",
+                    file, ciyu, pinyin, ciyu
+                );
+                nth
+            }
+            None => {
+                entry.ciyu.push(ciyu.to_string());
+                entry.tones.push(vec![tones]);
+                entry.weights.push(row.weight);
+                entry.levels.push(level);
+                entry.glosses.push(match has_gloss {
+                    true => vec![gloss],
+                    false => Vec::new(),
+                });
+                entry.ciyu.len() - 1
+            }
+        };
+        let abbrevs = syllables.as_deref().map(|s| abbreviate(self.scheme, s));
+        for abbrev in abbrevs.unwrap_or_default() {
+            let a = match self.abbrev_index_of.get(&abbrev) {
+                Some(&a) => a,
+                None => {
+                    self.abbrev_index_of
+                        .insert(abbrev.clone(), self.abbrevs.len());
+                    self.abbrevs.push((abbrev, Vec::new()));
+                    self.abbrevs.len() - 1
+                }
+            };
+            if !self.abbrevs[a].1.contains(&(i, nth)) {
+                self.abbrevs[a].1.push((i, nth));
+            }
+        }
+        Ok(())
+    }
+
+    // Add punctuation. Its pinyin is the search key as-is.
+    fn add_punctuation(&mut self, ciyu: &str, pinyin: &str) {
+        self.test_data.push((pinyin.to_string(), ciyu.to_string()));
+        self.index_of.insert(pinyin.to_string(), self.entries.len());
+        self.entries.push(Entry {
+            key: pinyin.to_string(),
+            ciyu: vec![ciyu.to_string()],
+            tones: vec![vec![String::new()]],
+            weights: vec![0],
+            levels: vec![(0, false)],
+            glosses: vec![Vec::new()],
+            rank: None,
+        });
+    }
+
+    // Put homophones in order of weight, keeping file order for equal
+    // weights, and update abbreviation candidates to match.
+    fn sort_homophones(&mut self) {
+        let mut new_nth = Vec::new();
+        for entry in self.entries.iter_mut() {
+            let mut order: Vec<usize> = (0..entry.ciyu.len()).collect();
+            order.sort_by_key(|&nth| std::cmp::Reverse(entry.weights[nth]));
+            entry.ciyu = order.iter().map(|&nth| entry.ciyu[nth].clone()).collect();
+            entry.tones = order.iter().map(|&nth| entry.tones[nth].clone()).collect();
+            entry.weights = order.iter().map(|&nth| entry.weights[nth]).collect();
+            entry.levels = order.iter().map(|&nth| entry.levels[nth]).collect();
+            entry.glosses = order
+                .iter()
+                .map(|&nth| entry.glosses[nth].clone())
+                .collect();
+            let mut inverse = vec![0; order.len()];
+            for (new, &old) in order.iter().enumerate() {
+                inverse[old] = new;
+            }
+            new_nth.push(inverse);
+        }
+        for (_, cands) in self.abbrevs.iter_mut() {
+            for (i, nth) in cands.iter_mut() {
+                *nth = new_nth[*i][*nth];
+            }
+        }
+    }
+}
+
+// Dictionary sections being written. TEXT collects all strings, and string
+// tables are lists of offsets into TEXT.
+struct Sections {
+    text: Vec<u8>,
+    list: Vec<Vec<u8>>,
+}
+impl Sections {
+    fn push_u32s(&mut self, values: impl Iterator<Item = usize>) {
+        let section = values.flat_map(|v| (v as u32).to_le_bytes()).collect();
+        self.list.push(section);
+    }
+
+    fn push_strings<'s>(&mut self, strings: impl Iterator<Item = &'s str>) {
+        let mut offsets = vec![self.text.len()];
+        for s in strings {
+            self.text.extend_from_slice(s.as_bytes());
+            offsets.push(self.text.len());
+        }
+        self.push_u32s(offsets.into_iter());
+    }
+}
+
+// Sort by murmur3 hash of key, checking for collisions.
+// Return: (hash, index) pairs in hash order
+fn hash_order<'k>(keys: impl Iterator<Item = &'k str>) -> Result<Vec<(u32, usize)>, String> {
+    let keys: Vec<&str> = keys.collect();
+    let mut order: Vec<(u32, usize)> = keys
+        .iter()
+        .enumerate()
+        .map(|(i, k)| (murmur3(k, MURMUR3_SEED), i))
+        .collect();
+    order.sort_unstable();
+    for pair in order.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            let (a, b) = (keys[pair[0].1], keys[pair[1].1]);
+            return Err(format!("murmur3 collision: {} {} (try another seed)", a, b));
+        }
+    }
+    Ok(order)
+}
+
+// Return vocab in the binary dictionary format.
+fn compile(vocab: &Vocab) -> Result<Vec<u8>, String> {
+    let order = hash_order(vocab.entries.iter().map(|e| e.key.as_str()))?;
+    let mut new_index = vec![0; order.len()];
+    for (n, &(_, i)) in order.iter().enumerate() {
+        new_index[i] = n;
+    }
+    let entries: Vec<&Entry> = order.iter().map(|&(_, i)| &vocab.entries[i]).collect();
+    let abbrev_order = hash_order(vocab.abbrevs.iter().map(|(a, _)| a.as_str()))?;
+    let abbrevs: Vec<_> = abbrev_order
+        .iter()
+        .map(|&(_, i)| &vocab.abbrevs[i])
+        .collect();
+    let mut prefix: Vec<(&str, usize, usize)> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(n, e)| e.rank.map(|rank| (e.key.as_str(), n, rank)))
+        .collect();
+    prefix.sort_unstable();
+
+    let mut sections = Sections {
+        text: Vec::new(),
+        list: Vec::new(),
+    };
+    sections.push_u32s(order.iter().map(|&(hash, _)| hash as usize));
+    sections.push_strings(entries.iter().map(|e| e.key.as_str()));
+    let ciyu: Vec<String> = entries.iter().map(|e| e.ciyu.join("\t")).collect();
+    sections.push_strings(ciyu.iter().map(String::as_str));
+    let tones: Vec<String> = entries
+        .iter()
+        .map(|e| {
+            e.tones
+                .iter()
+                .map(|t| t.join(","))
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect();
+    sections.push_strings(tones.iter().map(String::as_str));
+    sections.push_u32s(prefix.iter().flat_map(|&(_, n, rank)| [n, rank]));
+    sections.push_u32s(abbrev_order.iter().map(|&(hash, _)| hash as usize));
+    sections.push_strings(abbrevs.iter().map(|(a, _)| a.as_str()));
+    let mut refs = vec![0];
+    for (_, cands) in abbrevs.iter() {
+        refs.push(refs[refs.len() - 1] + cands.len());
+    }
+    sections.push_u32s(refs.into_iter());
+    let cands = abbrevs.iter().flat_map(|(_, cands)| cands.iter());
+    sections.push_u32s(cands.flat_map(|&(i, nth)| [new_index[i], nth]));
+    let weights = entries.iter().map(|e| e.weights[0] as usize);
+    sections.push_u32s(weights);
+    let levels: Vec<String> = entries
+        .iter()
+        .map(|e| {
+            let level = |&(level, extra): &(u8, bool)| match extra {
+                true => format!("{}+", level),
+                false => level.to_string(),
+            };
+            e.levels.iter().map(level).collect::<Vec<_>>().join("\t")
+        })
+        .collect();
+    sections.push_strings(levels.iter().map(String::as_str));
+    let glosses: Vec<String> = entries
+        .iter()
+        .map(|e| {
+            let senses = |senses: &Vec<(String, String)>| {
+                senses
+                    .iter()
+                    .map(|(pos, meaning)| format!("{} {}", pos, meaning))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            e.glosses.iter().map(senses).collect::<Vec<_>>().join("\t")
+        })
+        .collect();
+    sections.push_strings(glosses.iter().map(String::as_str));
+    // Reverse index of word file 词语, with the lowest level reading of a
+    // 词语 first, like 长 cháng (HSK2) before 长 zhǎng (HSK3)
+    let mut reverse: Vec<_> = entries
+        .iter()
+        .enumerate()
+        .filter_map(|(n, e)| e.rank.map(|rank| (n, e, rank)))
+        .flat_map(|(n, e, rank)| {
+            e.ciyu
+                .iter()
+                .enumerate()
+                .map(move |(nth, c)| (murmur3(c, MURMUR3_SEED), e.levels[nth], rank, n, nth))
+        })
+        .collect();
+    reverse.sort_unstable();
+    sections.push_u32s(
+        reverse
+            .iter()
+            .flat_map(|&(hash, _, _, n, nth)| [hash as usize, n, nth]),
+    );
+    // Traditional forms, leaving out the ones that are the same as 词语
+    let traditional: Vec<String> = entries
+        .iter()
+        .map(|e| {
+            let convert = |ciyu: &String| match vocab.traditional.convert(ciyu, &e.key) {
+                t if t == *ciyu => String::new(),
+                t => t,
+            };
+            e.ciyu.iter().map(convert).collect::<Vec<_>>().join("\t")
+        })
+        .collect();
+    sections.push_strings(traditional.iter().map(String::as_str));
+
+    // Header, then section table, then TEXT and the other sections in order
+    let mut all = vec![sections.text];
+    all.extend(sections.list);
+    let abbrev_size_max = vocab
+        .abbrevs
+        .iter()
+        .map(|(a, _)| a.len())
+        .max()
+        .unwrap_or(0);
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(dictionary::MAGIC);
+    bytes.extend_from_slice(&dictionary::VERSION.to_le_bytes());
+    let scheme = dictionary::SCHEMES.iter().position(|&s| s == vocab.scheme);
+    bytes.extend_from_slice(&(scheme.unwrap_or(0) as u16).to_le_bytes());
+    bytes.extend_from_slice(&MURMUR3_SEED.to_le_bytes());
+    bytes.extend_from_slice(&(vocab.key_size_max as u16).to_le_bytes());
+    bytes.extend_from_slice(&(abbrev_size_max as u16).to_le_bytes());
+    let mut offset = bytes.len() + 8 * all.len();
+    for section in all.iter() {
+        bytes.extend_from_slice(&(offset as u32).to_le_bytes());
+        bytes.extend_from_slice(&(section.len() as u32).to_le_bytes());
+        offset += section.len();
+    }
+    for section in all.iter() {
+        bytes.extend_from_slice(section);
+    }
+    dictionary::Dictionary::from_bytes(&bytes).map_err(|e| format!("bad dictionary: {:?}", e))?;
+    Ok(bytes)
+}
+
+// Return Rust source with the dictionary and test data. The dictionary file
+// must be in the same directory as the Rust file.
+fn rust_source(vocab: &Vocab, files: &Files, dict_file: &str) -> String {
+    let (vocab_name, key_name) = match vocab.scheme {
+        Scheme::Pinyin => ("HSK vocab", "pinyin"),
+        Scheme::Jyutping => ("Cantonese vocab", "Jyutping"),
+    };
+    let mut rs = String::new();
+    rs.push_str("#![allow(dead_code)]\n");
+    rs.push_str("// This file is automatically generated. DO NOT MAKE EDITS HERE!\n");
+    rs.push_str("// To make changes, see bin/autogen-hsk.rs\n\n");
+    rs.push_str(&format!(
+        "// Built-in {} in the binary dictionary format of dictionary.rs,\n",
+        vocab_name
+    ));
+    rs.push_str(&format!(
+        "// with {} {} search keys. The longest one has {} characters.\n",
+        vocab.entries.len(),
+        key_name,
+        vocab.key_size_max
+    ));
+    rs.push_str(&format!(
+        "pub static DICTIONARY: &[u8] = include_bytes!({:?});\n\n",
+        dict_file
+    ));
+    rs.push_str(&format!(
+        "// Tuples are (normalized_{}, 词语) from early in vocab file code\n",
+        key_name.to_lowercase()
+    ));
+    rs.push_str("// generation precompute pipeline. These correspond closely to lines of\n");
+    rs.push_str("// vocab .tsv files prior to any sorting or merging of duplicates.\n");
+    rs.push_str("#[cfg(test)]\n");
+    rs.push_str(&format!(
+        "pub static {}: &[(&'static str, &'static str)] = &[\n",
+        files.test_data
+    ));
+    for (key, ciyu) in vocab.test_data.iter() {
+        rs.push_str(&format!("    (&{:?}, &{:?}),\n", key, ciyu));
+    }
+    rs.push_str("];\n");
+    if files.official_file.is_none() {
+        return rs;
+    }
+    rs.push('\n');
+    rs.push_str("// Tuples are (HSK level, word) for words that are new at each level of the\n");
+    rs.push_str("// official HSK word lists in vocab/official, spelled as they are there.\n");
+    rs.push_str("#[cfg(test)]\n");
+    rs.push_str("pub static OFFICIAL_HSK_TEST_DATA: &[(usize, &'static str)] = &[\n");
+    for (level, word) in vocab.official.iter() {
+        rs.push_str(&format!("    ({}, &{:?}),\n", level, word));
+    }
+    rs.push_str("];\n");
+    rs
+}
+
+// Write file, or with check, fail if file does not already have contents.
+fn output(path: &Path, contents: &[u8], check: bool) -> Result<(), String> {
+    if check {
+        match fs::read(path) {
+            Ok(old) if old == contents => Ok(()),
+            _ => Err(format!("{} is out of date", path.display())),
+        }
+    } else {
+        fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+fn run() -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut vocab_dir = root.join("vocab");
+    let (mut dict_file, mut rust_file): (Option<PathBuf>, Option<PathBuf>) = (None, None);
+    let mut only: Option<&Files> = None;
+    let mut check = false;
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .map(PathBuf::from)
+                .ok_or(format!("{} needs a value", arg))
+        };
+        match arg.as_str() {
+            "--vocab" => vocab_dir = value()?,
+            "--scheme" => {
+                let name = value()?;
+                only = ALL_FILES.iter().find(|f| name.as_os_str() == f.name);
+                if only.is_none() {
+                    return Err(format!("unknown scheme: {}", name.display()));
+                }
+            }
+            "--dict" => dict_file = Some(value()?),
+            "--rust" => rust_file = Some(value()?),
+            "--check" => check = true,
+            _ => return Err(format!("unknown argument: {}", arg)),
+        }
+    }
+    let all = match (only, dict_file.is_none() && rust_file.is_none()) {
+        (Some(files), _) => std::slice::from_ref(files),
+        (None, true) => ALL_FILES,
+        (None, false) => std::slice::from_ref(&HSK),
+    };
+
+    for files in all.iter() {
+        let (dict_file, rust_file) = match (&dict_file, &rust_file) {
+            (None, None) => (
+                Some(root.join(files.dict_file)),
+                Some(root.join(files.rust_file)),
+            ),
+            (dict_file, rust_file) => (dict_file.clone(), rust_file.clone()),
+        };
+        let vocab = Vocab::read(&vocab_dir, files)?;
+        let bytes = compile(&vocab)?;
+        println!("Vocab: {}", files.name);
+        println!("Unique search keys: {}", vocab.entries.len());
+        println!("Abbreviated search keys: {}", vocab.abbrevs.len());
+        println!("Dictionary size: {} bytes", bytes.len());
+        if let Some(path) = dict_file.as_ref() {
+            output(path, &bytes, check)?;
+        }
+        if let Some(path) = rust_file.as_ref() {
+            let default_name = Path::new(files.dict_file).file_name();
+            let dict_name = dict_file
+                .as_ref()
+                .and_then(|d| d.file_name())
+                .or(default_name)
+                .and_then(|d| d.to_str())
+                .unwrap_or_default();
+            let rs = rust_source(&vocab, files, dict_name);
+            output(path, rs.as_bytes(), check)?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_removes_tone_marks_and_separators() {
+        assert_eq!(Ok("nver".to_string()), normalize(Scheme::Pinyin, "nǚ'ér"));
+        assert_eq!(Ok("xian".to_string()), normalize(Scheme::Pinyin, "Xī'ān"));
+        assert_eq!(
+            Ok("yiyang".to_string()),
+            normalize(Scheme::Pinyin, "yí-yàng")
+        );
+        assert_eq!(
+            Ok("bier`gaici".to_string()),
+            normalize(Scheme::Pinyin, "bié r`gǎicí")
+        );
+        assert!(normalize(Scheme::Pinyin, "ni好").is_err());
+    }
+
+    #[test]
+    fn syllables_have_one_tone_per_hanzi() {
+        let pairs = |ciyu, pinyin| {
+            syllables_of(Scheme::Pinyin, ciyu, pinyin).map(|s| {
+                let tones: String = s.iter().map(|(_, t)| char::from(b'0' + t)).collect();
+                (
+                    s.iter()
+                        .map(|(s, _)| s.as_str())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    tones,
+                )
+            })
+        };
+        assert_eq!(
+            Some(("ba ba".to_string(), "45".to_string())),
+            pairs("爸爸", "bàba")
+        );
+        assert_eq!(
+            Some(("xi an".to_string(), "11".to_string())),
+            pairs("西安", "Xī'ān")
+        );
+        // Unknown tones without any tone marks
+        assert_eq!(
+            Some(("guo zhi".to_string(), "00".to_string())),
+            pairs("果汁", "guozhi")
+        );
+        // Erhua r only counts when it has its own hanzi
+        assert_eq!(
+            Some(("na r".to_string(), "45".to_string())),
+            pairs("那儿", "nàr")
+        );
+        assert_eq!(
+            Some(("yi dian".to_string(), "43".to_string())),
+            pairs("一点", "yìdiǎnr")
+        );
+        assert_eq!(None, pairs("西安", "xian1"));
+    }
+
+    #[test]
+    fn abbreviations_allow_short_zh_ch_sh() {
+        let syllables = syllables_of(Scheme::Pinyin, "果汁", "guǒzhī").unwrap();
+        assert_eq!(vec!["gzh", "gz"], abbreviate(Scheme::Pinyin, &syllables));
+        let syllables = syllables_of(Scheme::Pinyin, "中国", "Zhōngguó").unwrap();
+        assert_eq!(vec!["zhg", "zg"], abbreviate(Scheme::Pinyin, &syllables));
+    }
+
+    #[test]
+    fn jyutping_tone_numbers_end_syllables() {
+        let jyutping = |ciyu, text| {
+            let syllables = syllables_of(Scheme::Jyutping, ciyu, text)?;
+            let tones: String = syllables
+                .iter()
+                .map(|(_, t)| char::from(b'0' + t))
+                .collect();
+            let s: Vec<&str> = syllables.iter().map(|(s, _)| s.as_str()).collect();
+            Some((s.join(" "), tones))
+        };
+        assert_eq!(
+            Ok("neihou".to_string()),
+            normalize(Scheme::Jyutping, "nei5 hou2")
+        );
+        assert_eq!(
+            Some(("nei hou".to_string(), "52".to_string())),
+            jyutping("你好", "nei5hou2")
+        );
+        assert_eq!(
+            Some(("m goi".to_string(), "41".to_string())),
+            jyutping("唔該", "m4 goi1")
+        );
+        // Syllables without a tone number have unknown tones
+        assert_eq!(
+            Some(("gwong dung".to_string(), "01".to_string())),
+            jyutping("廣東", "gwongdung1")
+        );
+        assert_eq!(None, jyutping("你好", "nei5"));
+        let syllables = syllables_of(Scheme::Jyutping, "廣東", "gwong2 dung1").unwrap();
+        assert_eq!(vec!["gwd", "gd"], abbreviate(Scheme::Jyutping, &syllables));
+    }
+
+    fn row(ciyu: &str, pinyin: &str, weight: u32) -> Row {
+        Row {
+            ciyu: ciyu.to_string(),
+            pinyin: pinyin.to_string(),
+            weight,
+            pos: String::new(),
+            meaning: String::new(),
+        }
+    }
+
+    #[test]
+    fn weights_put_common_homophones_first() {
+        let mut vocab = Vocab::default();
+        vocab
+            .add_word("t", (1, false), &row("喝", "hē", 0))
+            .unwrap();
+        vocab.add_word("t", (5, true), &row("河", "hé", 0)).unwrap();
+        vocab
+            .add_word("t", (1, false), &row("和", "hé", 981))
+            .unwrap();
+        vocab.sort_homophones();
+        let entry = &vocab.entries[0];
+        assert_eq!(vec!["和", "喝", "河"], entry.ciyu);
+        assert_eq!(vec![981, 0, 0], entry.weights);
+        assert_eq!(vec![(1, false), (1, false), (5, true)], entry.levels);
+        let tones: Vec<&str> = entry.tones.iter().map(|t| t[0].as_str()).collect();
+        assert_eq!(vec!["2", "1", "2"], tones);
+        // Abbreviation candidates follow their homophones
+        assert_eq!(vec![(0, 1), (0, 2), (0, 0)], vocab.abbrevs[0].1);
+    }
+
+    #[test]
+    fn glosses_are_kept_for_each_homophone() {
+        let gloss = |ciyu, pinyin, pos: &str, meaning: &str| Row {
+            pos: pos.to_string(),
+            meaning: meaning.to_string(),
+            ..row(ciyu, pinyin, 0)
+        };
+        let mut vocab = Vocab::default();
+        let part = gloss("过", "guò", "part.", "past action");
+        vocab.add_word("t", (2, false), &part).unwrap();
+        vocab
+            .add_word("t", (3, false), &row("国", "guó", 0))
+            .unwrap();
+        let verb = gloss("过", "guo", "v.", "to spend (time)");
+        vocab.add_word("t", (3, false), &verb).unwrap();
+        vocab.add_word("t", (3, false), &verb).unwrap();
+        let entry = &vocab.entries[0];
+        let senses = |nth: usize| -> Vec<(&str, &str)> {
+            let senses = entry.glosses[nth].iter();
+            senses.map(|(p, m)| (p.as_str(), m.as_str())).collect()
+        };
+        assert_eq!(
+            vec![("part.", "past action"), ("v.", "to spend (time)")],
+            senses(0)
+        );
+        assert!(senses(1).is_empty());
+    }
+
+    #[test]
+    fn traditional_phrases_override_characters() {
+        let table = "# test\n发\t發\n头\t頭\n系\t系\n头发\t頭髮\n系\t繫\tjì\n";
+        let table = Traditional::parse(table).unwrap();
+        assert_eq!("發展", table.convert("发展", "fazhan"));
+        assert_eq!("頭髮", table.convert("头发", "toufa"));
+        assert_eq!("頭頭髮", table.convert("头头发", "toutoufa"));
+        assert_eq!("繫", table.convert("系", "ji"));
+        assert_eq!("系", table.convert("系", "xi"));
+        assert!(Traditional::parse("头发\t頭").is_err());
+    }
+
+    #[test]
+    fn official_lists_have_new_words_for_each_level() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("vocab")
+            .join(OFFICIAL_FILE);
+        let words = read_official(&path).unwrap();
+        let count = |level| words.iter().filter(|(l, _)| *l == level).count();
+        let counts: Vec<usize> = (1..=6).map(count).collect();
+        assert_eq!(vec![150, 150, 300, 600, 1300, 2500], counts);
+        assert_eq!((1, "爱".to_string()), words[0]);
+    }
+
+    // The committed files must match what the compiler makes from the vocab
+    // .tsv files. To update them, run: cargo run --bin autogen-hsk
+    #[test]
+    fn built_in_dictionary_is_up_to_date() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("vocab");
+        let vocab = Vocab::read(&dir, &HSK).unwrap();
+        let bytes = compile(&vocab).unwrap();
+        assert!(bytes == include_bytes!("../autogen_hsk.dict"));
+        let rs = rust_source(&vocab, &HSK, "autogen_hsk.dict");
+        assert!(rs == include_str!("../autogen_hsk.rs"));
+        let vocab = Vocab::read(&dir, &JYUTPING).unwrap();
+        let bytes = compile(&vocab).unwrap();
+        assert!(bytes == include_bytes!("../autogen_jyutping.dict"));
+        let rs = rust_source(&vocab, &JYUTPING, "autogen_jyutping.dict");
+        assert!(rs == include_str!("../autogen_jyutping.rs"));
+    }
+}
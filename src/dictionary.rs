@@ -0,0 +1,973 @@
+// Binary dictionary format. A dictionary is a borrowed &[u8] that the engine
+// reads in place (zero-copy, no heap), so dictionaries can be compiled in with
+// include_bytes!() or loaded at runtime from a file or a network fetch. The
+// built-in HSK vocab (autogen_hsk.dict) and Cantonese vocab
+// (autogen_jyutping.dict) are instances of this format.
+//
+// All integers are little-endian. There is no alignment requirement.
+//
+// Header (136 bytes):
+//   0  magic "IMED"
+//   4  u16 format version (VERSION)
+//   6  u16 phonetic scheme of search keys (0 pinyin, 1 Jyutping)
+//   8  u32 murmur3 seed for hashes of search keys
+//   12 u16 characters in longest search key
+//   14 u16 characters in longest abbreviated search key
+//   16 section table: SECTION_COUNT x (u32 offset from start, u32 length)
+//
+// Sections (n search keys, m abbreviated search keys):
+//   TEXT         UTF-8 text of all strings
+//   KEY_HASH     n x u32 murmur3 hash of search keys, strictly increasing
+//   KEY_TEXT     n+1 x u32 offsets into TEXT: key i is TEXT[off[i]..off[i+1]]
+//   CIYU         n+1 x u32 offsets into TEXT: homophone 词语 of key i, joined
+//                by "\t"
+//   TONES        n+1 x u32 offsets into TEXT: tones of the homophones, one
+//                digit per syllable (5 neutral, 0 unknown, or 1 to 6 for
+//                Jyutping), joined by "\t",
+//                with alternate readings joined by ","
+//   PREFIX       (u32 key index, u32 rank) records of keys offered as prefix
+//                completions, sorted by key text. Lower rank comes first.
+//   ABBREV_HASH  m x u32 murmur3 hash of abbreviated keys, strictly increasing
+//   ABBREV_TEXT  m+1 x u32 offsets into TEXT for abbreviated keys
+//   ABBREV_REFS  m+1 x u32 offsets into ABBREV_CANDS records
+//   ABBREV_CANDS (u32 key index, u32 homophone index) records
+//   KEY_WEIGHT   n x u32 weight of the most common homophone of key i (higher
+//                is more common, 0 if unknown). Homophones in CIYU are
+//                already in order of weight.
+//   LEVELS       n+1 x u32 offsets into TEXT: HSK levels of the homophones,
+//                joined by "\t". A level is one digit (0 if unknown), with
+//                "+" for words from an -extra list.
+//   GLOSSES      n+1 x u32 offsets into TEXT: glosses of the homophones,
+//                joined by "\t". Each sense is a part of speech (may be
+//                empty), a space, and an English meaning, with senses of one
+//                homophone joined by "\n". Homophones without glosses are "".
+//   REVERSE      (u32 murmur3 hash of 词语, u32 key index, u32 homophone
+//                index) records for looking up readings of 词语, sorted by
+//                hash. Readings of one 词语 are in order of HSK level.
+//   TRADITIONAL  n+1 x u32 offsets into TEXT: Traditional forms of the
+//                homophones, joined by "\t". Homophones that are the same in
+//                both scripts are "".
+use crate::syllable::Scheme;
+use crate::user_dictionary::UserDictionary;
+use crate::{autogen_hsk, autogen_jyutping};
+use core::fmt;
+
+pub const MAGIC: &[u8; 4] = b"IMED";
+pub const VERSION: u16 = 7;
+
+// Phonetic schemes in the order of their numbers in the header
+pub const SCHEMES: [Scheme; 2] = [Scheme::Pinyin, Scheme::Jyutping];
+
+// Longest search key (in characters) that the engine can look up. Syllable
+// boundaries of a key are bits of a u64.
+pub const KEY_SIZE_MAX: usize = 63;
+
+const TEXT: usize = 0;
+const KEY_HASH: usize = 1;
+const KEY_TEXT: usize = 2;
+const CIYU: usize = 3;
+const TONES: usize = 4;
+const PREFIX: usize = 5;
+const ABBREV_HASH: usize = 6;
+const ABBREV_TEXT: usize = 7;
+const ABBREV_REFS: usize = 8;
+const ABBREV_CANDS: usize = 9;
+const KEY_WEIGHT: usize = 10;
+const LEVELS: usize = 11;
+const GLOSSES: usize = 12;
+const REVERSE: usize = 13;
+const TRADITIONAL: usize = 14;
+const SECTION_COUNT: usize = 15;
+const HEADER_SIZE: usize = 16 + 8 * SECTION_COUNT;
+
+// Reasons for rejecting dictionary bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum DictionaryError {
+    // Bytes do not start with MAGIC
+    Magic,
+    // Format version is not VERSION
+    Version(u16),
+    // Header, scheme, or section sizes are not valid
+    Layout,
+    // TEXT is not UTF-8, or string offsets are out of range or order
+    Text,
+    // Hash of a search key is not the hash of its text
+    Hash,
+    // Hashes or prefix keys are out of order
+    Sort,
+    // Record refers to a search key or homophone that does not exist
+    Index,
+}
+
+// Part of speech and English meaning of one sense of a 词语, like ("v.",
+// "to spend (time), to pass (time)") for 过. Part of speech may be empty.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gloss<'a> {
+    pub pos: &'a str,
+    pub meaning: &'a str,
+}
+impl<'a> Gloss<'a> {
+    // Return meaning up to its first comma or semicolon, like "to spend
+    // (time)", for showing next to choices.
+    pub fn short(&self) -> &'a str {
+        let end = self.meaning.find([',', ';']).unwrap_or(self.meaning.len());
+        self.meaning[..end].trim_end()
+    }
+}
+
+// Iterate over the senses of one homophone's glosses.
+pub(crate) fn senses(glosses: &str) -> impl Iterator<Item = Gloss<'_>> {
+    glosses
+        .split('\n')
+        .filter_map(|sense| sense.split_once(' '))
+        .map(|(pos, meaning)| Gloss { pos, meaning })
+}
+
+// Read little-endian integers (0 if out of range).
+fn u16_at(bytes: &[u8], pos: usize) -> u16 {
+    match bytes.get(pos..pos + 2) {
+        Some(b) => u16::from_le_bytes([b[0], b[1]]),
+        None => 0,
+    }
+}
+fn u32_at(bytes: &[u8], pos: usize) -> u32 {
+    match bytes.get(pos..pos + 4) {
+        Some(b) => u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        None => 0,
+    }
+}
+
+// Dictionary view of borrowed bytes. Accessors do not panic, even for bytes
+// that were not validated; out of range reads give empty results.
+#[derive(Copy, Clone)]
+pub struct Dictionary<'a> {
+    bytes: &'a [u8],
+    text: &'a str,
+    sections: [(usize, usize); SECTION_COUNT],
+    scheme: Scheme,
+    seed: u32,
+    key_size_max: usize,
+    abbrev_size_max: usize,
+    // User dictionary layered over this one
+    user: Option<&'a UserDictionary<'a>>,
+}
+impl Default for Dictionary<'_> {
+    fn default() -> Self {
+        Dictionary::hsk()
+    }
+}
+impl PartialEq for Dictionary<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        let same_user = match (self.user, other.user) {
+            (Some(a), Some(b)) => core::ptr::eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        same_user && (core::ptr::eq(self.bytes, other.bytes) || self.bytes == other.bytes)
+    }
+}
+impl Eq for Dictionary<'_> {}
+impl fmt::Debug for Dictionary<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Dictionary")
+            .field("bytes", &self.bytes.len())
+            .field("keys", &self.len())
+            .field("user", &self.user.map_or(0, |user| user.len()))
+            .finish()
+    }
+}
+impl<'a> Dictionary<'a> {
+    // Return the built-in HSK dictionary.
+    pub fn hsk() -> Dictionary<'static> {
+        // The built-in bytes are checked by tests, so only parse the header
+        Dictionary::parse(autogen_hsk::DICTIONARY).unwrap_or(Dictionary::empty())
+    }
+
+    // Return the built-in Cantonese dictionary with Jyutping search keys.
+    pub fn jyutping() -> Dictionary<'static> {
+        Dictionary::parse(autogen_jyutping::DICTIONARY).unwrap_or(Dictionary::empty())
+    }
+
+    // Return dictionary with no search keys.
+    pub fn empty() -> Dictionary<'static> {
+        Dictionary {
+            bytes: &[],
+            text: "",
+            sections: [(0, 0); SECTION_COUNT],
+            scheme: Scheme::Pinyin,
+            seed: 0,
+            key_size_max: 0,
+            abbrev_size_max: 0,
+            user: None,
+        }
+    }
+
+    // Check dictionary bytes, then use them in place.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Dictionary<'a>, DictionaryError> {
+        let dictionary = Dictionary::parse(bytes)?;
+        dictionary.validate()?;
+        Ok(dictionary)
+    }
+
+    // Read header, check that the sections are in bounds, and check that TEXT
+    // is UTF-8.
+    fn parse(bytes: &'a [u8]) -> Result<Dictionary<'a>, DictionaryError> {
+        if bytes.get(..4) != Some(&MAGIC[..]) {
+            return Err(DictionaryError::Magic);
+        }
+        if bytes.len() < HEADER_SIZE {
+            return Err(DictionaryError::Layout);
+        }
+        let version = u16_at(bytes, 4);
+        if version != VERSION {
+            return Err(DictionaryError::Version(version));
+        }
+        let scheme = match SCHEMES.get(usize::from(u16_at(bytes, 6))) {
+            Some(&scheme) => scheme,
+            None => return Err(DictionaryError::Layout),
+        };
+        let key_size_max = usize::from(u16_at(bytes, 12));
+        let abbrev_size_max = usize::from(u16_at(bytes, 14));
+        if key_size_max > KEY_SIZE_MAX || abbrev_size_max > KEY_SIZE_MAX {
+            return Err(DictionaryError::Layout);
+        }
+        let mut sections = [(0, 0); SECTION_COUNT];
+        for (i, section) in sections.iter_mut().enumerate() {
+            let offset = u32_at(bytes, 16 + 8 * i) as usize;
+            let len = u32_at(bytes, 20 + 8 * i) as usize;
+            match offset.checked_add(len) {
+                Some(end) if offset >= HEADER_SIZE && end <= bytes.len() => {}
+                _ => return Err(DictionaryError::Layout),
+            }
+            *section = (offset, len);
+        }
+        let (offset, len) = sections[TEXT];
+        let text = core::str::from_utf8(&bytes[offset..offset + len])
+            .map_err(|_| DictionaryError::Text)?;
+        Ok(Dictionary {
+            bytes,
+            text,
+            sections,
+            scheme,
+            seed: u32_at(bytes, 8),
+            key_size_max,
+            abbrev_size_max,
+            user: None,
+        })
+    }
+
+    // Check everything the engine relies on beyond the header.
+    fn validate(&self) -> Result<(), DictionaryError> {
+        let text = self.text;
+        // Sizes of sections must agree with their record counts
+        let n = self.len();
+        let m = self.abbrev_len();
+        let sizes = [
+            (KEY_HASH, 4, n),
+            (KEY_TEXT, 4, n + 1),
+            (CIYU, 4, n + 1),
+            (TONES, 4, n + 1),
+            (PREFIX, 8, self.count(PREFIX, 8)),
+            (ABBREV_TEXT, 4, m + 1),
+            (ABBREV_REFS, 4, m + 1),
+            (ABBREV_CANDS, 8, self.count(ABBREV_CANDS, 8)),
+            (KEY_WEIGHT, 4, n),
+            (LEVELS, 4, n + 1),
+            (GLOSSES, 4, n + 1),
+            (REVERSE, 12, self.count(REVERSE, 12)),
+            (TRADITIONAL, 4, n + 1),
+        ];
+        for &(section, size, count) in sizes.iter() {
+            if self.sections[section].1 != size * count {
+                return Err(DictionaryError::Layout);
+            }
+        }
+        let strings = [
+            KEY_TEXT,
+            CIYU,
+            TONES,
+            ABBREV_TEXT,
+            LEVELS,
+            GLOSSES,
+            TRADITIONAL,
+        ];
+        for &section in strings.iter() {
+            self.check_offsets(section, text.len(), |off| text.is_char_boundary(off))
+                .map_err(|_| DictionaryError::Text)?;
+        }
+        let cands_len = self.count(ABBREV_CANDS, 8);
+        self.check_offsets(ABBREV_REFS, cands_len, |_| true)
+            .map_err(|_| DictionaryError::Index)?;
+        // Search keys
+        self.check_keys(KEY_HASH, |i| self.key(i), self.key_size_max)?;
+        self.check_keys(ABBREV_HASH, |i| self.abbrev_key(i), self.abbrev_size_max)?;
+        for i in 0..n {
+            let homophones = self.ciyu(i).split('\t').count();
+            if homophones != self.tones(i).split('\t').count()
+                || homophones != self.levels(i).split('\t').count()
+                || homophones != self.glosses(i).split('\t').count()
+                || homophones != self.traditional(i).split('\t').count()
+            {
+                return Err(DictionaryError::Text);
+            }
+        }
+        // Prefix index
+        for i in 0..self.prefix_len() {
+            let (key, _) = self.prefix(i);
+            if key >= n {
+                return Err(DictionaryError::Index);
+            }
+            if i > 0 && self.key(self.prefix(i - 1).0) >= self.key(key) {
+                return Err(DictionaryError::Sort);
+            }
+        }
+        // Abbreviation candidates
+        for i in 0..cands_len {
+            let key = u32_at(self.section(ABBREV_CANDS), 8 * i) as usize;
+            let nth = u32_at(self.section(ABBREV_CANDS), 8 * i + 4) as usize;
+            if key >= n || nth >= self.ciyu(key).split('\t').count() {
+                return Err(DictionaryError::Index);
+            }
+        }
+        // Reverse index
+        for i in 0..self.count(REVERSE, 12) {
+            let (hash, key, nth) = self.reverse(i);
+            if key >= n || nth >= self.ciyu(key).split('\t').count() {
+                return Err(DictionaryError::Index);
+            }
+            if i > 0 && self.reverse(i - 1).0 > hash {
+                return Err(DictionaryError::Sort);
+            }
+        }
+        Ok(())
+    }
+
+    // Check that a table of offsets does not decrease and stays in range.
+    fn check_offsets(
+        &self,
+        section: usize,
+        limit: usize,
+        is_boundary: impl Fn(usize) -> bool,
+    ) -> Result<(), ()> {
+        let offsets = self.section(section);
+        let mut prev = 0;
+        for i in 0..offsets.len() / 4 {
+            let off = u32_at(offsets, 4 * i) as usize;
+            if off > limit || (i > 0 && off < prev) || !is_boundary(off) {
+                return Err(());
+            }
+            prev = off;
+        }
+        Ok(())
+    }
+
+    // Check that hashes match their keys and increase, and keys fit size_max.
+    fn check_keys(
+        &self,
+        hashes: usize,
+        key: impl Fn(usize) -> &'a str,
+        size_max: usize,
+    ) -> Result<(), DictionaryError> {
+        for i in 0..self.count(hashes, 4) {
+            let hash = u32_at(self.section(hashes), 4 * i);
+            let key = key(i);
+            if key.chars().count() > size_max {
+                return Err(DictionaryError::Layout);
+            }
+            if i > 0 && hash <= u32_at(self.section(hashes), 4 * (i - 1)) {
+                return Err(DictionaryError::Sort);
+            }
+            if hash != crate::murmur3(key, self.seed) {
+                return Err(DictionaryError::Hash);
+            }
+        }
+        Ok(())
+    }
+
+    // Number of pinyin search keys.
+    pub fn len(&self) -> usize {
+        self.count(KEY_HASH, 4)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Return this dictionary with a user dictionary layered over it. User
+    // entries come first in choices, and dictionary homophones with the same
+    // 词语 as a user entry are left out.
+    pub fn with_user(self, user: &'a UserDictionary<'a>) -> Dictionary<'a> {
+        Dictionary {
+            user: Some(user),
+            ..self
+        }
+    }
+
+    // Phonetic scheme of search keys, which sets the syllables and tone
+    // numbers that queries may use.
+    pub fn scheme(&self) -> Scheme {
+        self.scheme
+    }
+
+    // Characters in the longest search key, including user entries.
+    // Longer query windows never match.
+    pub fn key_size_max(&self) -> usize {
+        match self.user {
+            Some(user) => self.key_size_max.max(user.key_size_max()),
+            None => self.key_size_max,
+        }
+    }
+
+    // Iterate over user entries as (search key, 词语).
+    pub(crate) fn user_entries(&self) -> impl Iterator<Item = (&'a str, &'a str)> + 'a {
+        self.user.into_iter().flat_map(|user| user.iter())
+    }
+
+    // Iterate over user entries as (byte position, search key, 词语). The
+    // position is a quick way back to the entry with user_ciyu().
+    pub(crate) fn user_records(&self) -> impl Iterator<Item = (usize, &'a str, &'a str)> + 'a {
+        self.user.into_iter().flat_map(|user| user.records())
+    }
+
+    // Return 词语 of the user entry at byte position pos.
+    pub(crate) fn user_ciyu(&self, pos: usize) -> &'a str {
+        self.user.map_or("", |user| user.entry_at(pos).1)
+    }
+
+    fn section(&self, section: usize) -> &'a [u8] {
+        let (offset, len) = self.sections[section];
+        self.bytes.get(offset..offset + len).unwrap_or(&[])
+    }
+
+    fn count(&self, section: usize, record_size: usize) -> usize {
+        self.sections[section].1 / record_size
+    }
+
+    // Return string i of a table of offsets into TEXT.
+    fn string(&self, section: usize, i: usize) -> &'a str {
+        let offsets = self.section(section);
+        let start = u32_at(offsets, 4 * i) as usize;
+        let end = u32_at(offsets, 4 * i + 4) as usize;
+        self.text.get(start..end).unwrap_or("")
+    }
+
+    // Binary search for hash of key, then compare key text so that a murmur3
+    // collision with some other string does not count as a match.
+    fn find(&self, hashes: usize, keys: usize, key: &str) -> Option<usize> {
+        let hash = crate::murmur3(key, self.seed);
+        let table = self.section(hashes);
+        let (mut lo, mut hi) = (0, table.len() / 4);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let mid_hash = u32_at(table, 4 * mid);
+            if mid_hash < hash {
+                lo = mid + 1;
+            } else if mid_hash > hash {
+                hi = mid;
+            } else if self.string(keys, mid) == key {
+                return Some(mid);
+            } else {
+                return None;
+            }
+        }
+        None
+    }
+
+    // Return index of pinyin search key, or None for no match.
+    pub(crate) fn find_pinyin(&self, key: &str) -> Option<usize> {
+        self.find(KEY_HASH, KEY_TEXT, key)
+    }
+
+    // Return index of abbreviated search key, or None for no match.
+    pub(crate) fn find_abbrev(&self, key: &str) -> Option<usize> {
+        self.find(ABBREV_HASH, ABBREV_TEXT, key)
+    }
+
+    // Return text of pinyin search key i.
+    pub(crate) fn key(&self, i: usize) -> &'a str {
+        self.string(KEY_TEXT, i)
+    }
+
+    // Return homophone 词语 of search key i, joined by "\t".
+    pub(crate) fn ciyu(&self, i: usize) -> &'a str {
+        self.string(CIYU, i)
+    }
+
+    // Return tones of the homophones of search key i, joined by "\t".
+    pub(crate) fn tones(&self, i: usize) -> &'a str {
+        self.string(TONES, i)
+    }
+
+    // Return HSK levels of the homophones of search key i, joined by "\t".
+    pub(crate) fn levels(&self, i: usize) -> &'a str {
+        self.string(LEVELS, i)
+    }
+
+    // Return glosses of the homophones of search key i, joined by "\t".
+    pub(crate) fn glosses(&self, i: usize) -> &'a str {
+        self.string(GLOSSES, i)
+    }
+
+    // Return Traditional forms of the homophones of search key i, joined by
+    // "\t" ("" for homophones that are the same in both scripts).
+    pub(crate) fn traditional(&self, i: usize) -> &'a str {
+        self.string(TRADITIONAL, i)
+    }
+
+    // Iterate over glosses of 词语 from every search key that has it, like
+    // both 长 cháng "long" and 长 zhǎng "to grow". User entries have no
+    // glosses.
+    pub fn glosses_of<'b>(&self, ciyu: &'b str) -> impl Iterator<Item = Gloss<'a>> + 'b
+    where
+        'a: 'b,
+    {
+        let dictionary = *self;
+        let mut prev = "";
+        (0..self.len())
+            .filter_map(move |i| {
+                let nth = dictionary.ciyu(i).split('\t').position(|c| c == ciyu)?;
+                dictionary.glosses(i).split('\t').nth(nth)
+            })
+            // Skip repeats, like for 词语 with two pinyin spellings
+            .filter(move |&glosses| core::mem::replace(&mut prev, glosses) != glosses)
+            .flat_map(senses)
+    }
+
+    // Iterate over (search key index, homophone index) readings of 词语, in
+    // order of HSK level. User entries are not included.
+    pub(crate) fn readings_of<'b>(&self, ciyu: &'b str) -> impl Iterator<Item = (usize, usize)> + 'b
+    where
+        'a: 'b,
+    {
+        let dictionary = *self;
+        let hash = crate::murmur3(ciyu, self.seed);
+        let (mut lo, mut hi) = (0, self.count(REVERSE, 12));
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.reverse(mid).0 < hash {
+                true => lo = mid + 1,
+                false => hi = mid,
+            }
+        }
+        (lo..self.count(REVERSE, 12))
+            .map(move |i| dictionary.reverse(i))
+            .take_while(move |&(h, _, _)| h == hash)
+            // Compare text, since some other 词语 might have the same hash
+            .filter(move |&(_, key, nth)| dictionary.ciyu(key).split('\t').nth(nth) == Some(ciyu))
+            .map(|(_, key, nth)| (key, nth))
+    }
+
+    // Return (hash, search key index, homophone index) of reverse index
+    // record i.
+    fn reverse(&self, i: usize) -> (u32, usize, usize) {
+        let table = self.section(REVERSE);
+        (
+            u32_at(table, 12 * i),
+            u32_at(table, 12 * i + 4) as usize,
+            u32_at(table, 12 * i + 8) as usize,
+        )
+    }
+
+    // Return weight of search key i (higher is more common).
+    pub(crate) fn weight(&self, i: usize) -> u32 {
+        u32_at(self.section(KEY_WEIGHT), 4 * i)
+    }
+
+    pub(crate) fn abbrev_len(&self) -> usize {
+        self.count(ABBREV_HASH, 4)
+    }
+
+    // Return text of abbreviated search key i.
+    pub(crate) fn abbrev_key(&self, i: usize) -> &'a str {
+        self.string(ABBREV_TEXT, i)
+    }
+
+    pub(crate) fn prefix_len(&self) -> usize {
+        self.count(PREFIX, 8)
+    }
+
+    // Return (search key index, rank) of prefix index entry i.
+    pub(crate) fn prefix(&self, i: usize) -> (usize, usize) {
+        let table = self.section(PREFIX);
+        (
+            u32_at(table, 8 * i) as usize,
+            u32_at(table, 8 * i + 4) as usize,
+        )
+    }
+
+    // Return index of the first prefix index entry with key text >= prefix.
+    pub(crate) fn prefix_start(&self, prefix: &str) -> usize {
+        let (mut lo, mut hi) = (0, self.prefix_len());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            match self.key(self.prefix(mid).0) < prefix {
+                true => lo = mid + 1,
+                false => hi = mid,
+            }
+        }
+        lo
+    }
+
+    // Iterate over (search key index, homophone index) candidates for
+    // abbreviated search key i.
+    pub(crate) fn abbrev_cands(&self, i: usize) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let refs = self.section(ABBREV_REFS);
+        let start = u32_at(refs, 4 * i) as usize;
+        let end = u32_at(refs, 4 * i + 4) as usize;
+        let table = self.section(ABBREV_CANDS);
+        (start..end).map(move |j| {
+            (
+                u32_at(table, 8 * j) as usize,
+                u32_at(table, 8 * j + 4) as usize,
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Dictionary, DictionaryError, Gloss, HEADER_SIZE, KEY_HASH, PREFIX, TEXT};
+    use crate::options::{Options, Segmentation};
+    use crate::syllable::Scheme;
+    use crate::{autogen_hsk, autogen_jyutping, look_up_with, murmur3, BufWriter, Writer};
+
+    // Dictionary bytes being written, with sections in SECTION_COUNT order.
+    struct Out<'b> {
+        buf: &'b mut [u8],
+        len: usize,
+        section: usize,
+        start: usize,
+    }
+    impl Out<'_> {
+        fn put(&mut self, bytes: &[u8]) {
+            self.buf[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+            self.len += bytes.len();
+        }
+        fn put_u32(&mut self, n: usize) {
+            self.put(&(n as u32).to_le_bytes());
+        }
+        // Finish the current section and start the next one.
+        fn next_section(&mut self) {
+            let entry = 16 + 8 * self.section;
+            let (start, len) = (self.start as u32, (self.len - self.start) as u32);
+            self.buf[entry..entry + 4].copy_from_slice(&start.to_le_bytes());
+            self.buf[entry + 4..entry + 8].copy_from_slice(&len.to_le_bytes());
+            self.section += 1;
+            self.start = self.len;
+        }
+    }
+
+    // Dictionary entry: (key, 词语, tones, levels, glosses, weight)
+    type TestEntry<'e> = (&'e str, &'e str, &'e str, &'e str, &'e str, u32);
+
+    // Write dictionary for entries (no abbreviations) into buf. Entries get
+    // sorted by hash.
+    // Return: size of dictionary in bytes
+    fn build(entries: &mut [TestEntry], buf: &mut [u8]) -> usize {
+        entries.sort_unstable_by_key(|e| murmur3(e.0, 0));
+        let mut out = Out {
+            buf,
+            len: HEADER_SIZE,
+            section: 0,
+            start: HEADER_SIZE,
+        };
+        let longest = entries.iter().map(|e| e.0.len()).max().unwrap_or(0);
+        out.buf[..16].copy_from_slice(b"IMED\x07\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00\x00");
+        out.buf[12] = longest as u8;
+        // TEXT has all keys, then all 词语, tones, levels, glosses, and
+        // Traditional forms
+        for e in entries.iter() {
+            out.put(e.0.as_bytes());
+        }
+        for e in entries.iter() {
+            out.put(e.1.as_bytes());
+        }
+        for e in entries.iter() {
+            out.put(e.2.as_bytes());
+        }
+        let levels_start = out.len - HEADER_SIZE;
+        for e in entries.iter() {
+            out.put(e.3.as_bytes());
+        }
+        let glosses_start = out.len - HEADER_SIZE;
+        for e in entries.iter() {
+            out.put(e.4.as_bytes());
+        }
+        // Traditional forms are all "", the same as 词语
+        let traditional_start = out.len - HEADER_SIZE;
+        for e in entries.iter() {
+            for _ in e.1.matches('\t') {
+                out.put(b"\t");
+            }
+        }
+        out.next_section();
+        for e in entries.iter() {
+            out.put_u32(murmur3(e.0, 0) as usize);
+        }
+        out.next_section();
+        let mut off = 0;
+        for field in 0..3 {
+            out.put_u32(off);
+            for e in entries.iter() {
+                off += [e.0, e.1, e.2][field].len();
+                out.put_u32(off);
+            }
+            out.next_section();
+        }
+        // Prefix index ranks entries by key length
+        let mut order = [0usize; 8];
+        for (i, slot) in order.iter_mut().enumerate().take(entries.len()) {
+            *slot = i;
+        }
+        let order = &mut order[..entries.len()];
+        order.sort_unstable_by_key(|&i| entries[i].0);
+        for &i in order.iter() {
+            out.put_u32(i);
+            out.put_u32(entries[i].0.len());
+        }
+        out.next_section();
+        // No abbreviations
+        out.next_section();
+        out.put_u32(levels_start);
+        out.next_section();
+        out.put_u32(0);
+        out.next_section();
+        out.next_section();
+        for e in entries.iter() {
+            out.put_u32(e.5 as usize);
+        }
+        out.next_section();
+        for (field, start) in [(3, levels_start), (4, glosses_start)] {
+            let mut off = start;
+            out.put_u32(off);
+            for e in entries.iter() {
+                off += [e.3, e.4][field - 3].len();
+                out.put_u32(off);
+            }
+            out.next_section();
+        }
+        // Reverse index of every homophone, by hash of 词语
+        let mut reverse = [(0u32, 0usize, 0usize); 8];
+        let mut len = 0;
+        for (i, e) in entries.iter().enumerate() {
+            for (nth, ciyu) in e.1.split('\t').enumerate() {
+                reverse[len] = (murmur3(ciyu, 0), i, nth);
+                len += 1;
+            }
+        }
+        reverse[..len].sort_unstable();
+        for &(hash, i, nth) in reverse[..len].iter() {
+            out.put_u32(hash as usize);
+            out.put_u32(i);
+            out.put_u32(nth);
+        }
+        out.next_section();
+        let mut off = traditional_start;
+        out.put_u32(off);
+        for e in entries.iter() {
+            off += e.1.matches('\t').count();
+            out.put_u32(off);
+        }
+        out.next_section();
+        out.len
+    }
+
+    fn section_start(bytes: &[u8], section: usize) -> usize {
+        let entry = 16 + 8 * section;
+        u32::from_le_bytes([
+            bytes[entry],
+            bytes[entry + 1],
+            bytes[entry + 2],
+            bytes[entry + 3],
+        ]) as usize
+    }
+
+    const ENTRIES: [TestEntry; 3] = [
+        ("nihao", "你好", "33", "1", "int. hello", 0),
+        (
+            "ma",
+            "吗\t妈",
+            "5\t1",
+            "1\t2+",
+            "part. question marker\t",
+            0,
+        ),
+        ("shijie", "世界", "44", "3", "", 0),
+    ];
+
+    // This might fail some day as a consequence of vocab data entry. In case
+    // of failure due to hash collision, try changing the murmur3 seed in
+    // bin/autogen-hsk.rs.
+    #[test]
+    fn built_in_dictionary_is_valid() {
+        let dict = Dictionary::from_bytes(autogen_hsk::DICTIONARY);
+        assert_eq!(Ok(Dictionary::hsk()), dict);
+        assert!(dict.unwrap().len() > 2000);
+        let dict = Dictionary::from_bytes(autogen_jyutping::DICTIONARY);
+        assert_eq!(Ok(Dictionary::jyutping()), dict);
+        assert_eq!(Scheme::Jyutping, dict.unwrap().scheme());
+    }
+
+    #[test]
+    fn loaded_dictionary_replaces_built_in_vocab() {
+        let mut buf = [0u8; 512];
+        let mut entries = ENTRIES;
+        let len = build(&mut entries, &mut buf);
+        let options = Options {
+            dictionary: Dictionary::from_bytes(&buf[..len]).unwrap(),
+            completions: 5,
+            ..Options::default()
+        };
+        assert_eq!(3, options.dictionary.len());
+        assert_eq!(6, options.dictionary.key_size_max());
+        let mut sink = BufWriter::new();
+        look_up_with("nihao shijie ma2", &options, &mut sink);
+        assert_eq!("你好世界妈", sink.to_s());
+        // Words that are only in the built-in vocab do not match
+        let mut sink = BufWriter::new();
+        look_up_with("woxiang", &options, &mut sink);
+        assert_eq!("woxiang", sink.to_s());
+        // Completions come from the prefix index
+        let mut sink = BufWriter::new();
+        look_up_with("nihao sh", &options, &mut sink);
+        assert_eq!("你好 (1世界) ", sink.to_s());
+    }
+
+    #[test]
+    fn glosses_tell_readings_apart() {
+        let dict = Dictionary::hsk();
+        let long = Gloss {
+            pos: "adj.",
+            meaning: "long",
+        };
+        let grow = Gloss {
+            pos: "v.",
+            meaning: "to grow",
+        };
+        assert_eq!(2, dict.glosses_of("长").count());
+        assert!(dict.glosses_of("长").any(|g| g == long));
+        assert!(dict.glosses_of("长").any(|g| g == grow));
+        assert_eq!(None, dict.glosses_of("不是词语").next());
+        // Loaded dictionaries have their own glosses
+        let mut buf = [0u8; 512];
+        let mut entries = ENTRIES;
+        let len = build(&mut entries, &mut buf);
+        let dict = Dictionary::from_bytes(&buf[..len]).unwrap();
+        let hello = Gloss {
+            pos: "int.",
+            meaning: "hello",
+        };
+        assert_eq!(Some(hello), dict.glosses_of("你好").next());
+        assert_eq!(
+            "question marker",
+            dict.glosses_of("吗").next().unwrap().meaning
+        );
+        assert_eq!(None, dict.glosses_of("妈").next());
+    }
+
+    #[test]
+    fn short_gloss_is_first_meaning() {
+        let gloss = Gloss {
+            pos: "v.",
+            meaning: "to spend (time), to pass (time)",
+        };
+        assert_eq!("to spend (time)", gloss.short());
+        let gloss = Gloss {
+            pos: "",
+            meaning: "fully; thoroughly",
+        };
+        assert_eq!("fully", gloss.short());
+    }
+
+    #[test]
+    fn weights_break_segmentation_ties() {
+        let mut buf = [0u8; 512];
+        let mut entries = [
+            ("ab", "甲", "00", "0", "", 1),
+            ("c", "丙", "0", "0", "", 1),
+            ("a", "乙", "0", "0", "", 1),
+            ("bc", "丁", "00", "0", "", 9),
+        ];
+        let len = build(&mut entries, &mut buf);
+        let options = Options {
+            dictionary: Dictionary::from_bytes(&buf[..len]).unwrap(),
+            segmentation: Segmentation::Optimal,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        look_up_with("abc", &options, &mut sink);
+        assert_eq!("乙丁", sink.to_s());
+        // Without weights, the tie goes to the longer match at the start
+        for e in entries.iter_mut() {
+            e.5 = 0;
+        }
+        let mut unweighted = [0u8; 512];
+        let len = build(&mut entries, &mut unweighted);
+        let options = Options {
+            dictionary: Dictionary::from_bytes(&unweighted[..len]).unwrap(),
+            ..options
+        };
+        let mut sink = BufWriter::new();
+        look_up_with("abc", &options, &mut sink);
+        assert_eq!("甲丙", sink.to_s());
+    }
+
+    #[test]
+    fn bad_dictionary_bytes_are_rejected() {
+        let mut buf = [0u8; 512];
+        let mut entries = ENTRIES;
+        let len = build(&mut entries, &mut buf);
+        assert!(Dictionary::from_bytes(&buf[..len]).is_ok());
+        let check = |edit: &dyn Fn(&mut [u8]), err: DictionaryError| {
+            let mut bad = buf;
+            edit(&mut bad[..len]);
+            assert_eq!(Err(err), Dictionary::from_bytes(&bad[..len]));
+        };
+        check(&|b| b[0] = b'X', DictionaryError::Magic);
+        check(&|b| b[4] = 2, DictionaryError::Version(2));
+        check(&|b| b[6] = 2, DictionaryError::Layout);
+        check(&|b| b[12] = 3, DictionaryError::Layout);
+        check(&|b| b[12] = 99, DictionaryError::Layout);
+        // Section past the end of the bytes
+        check(&|b| b[23] = 0xff, DictionaryError::Layout);
+        // Key text that is not UTF-8, or does not match its hash
+        check(&|b| b[section_start(b, TEXT)] = 0xff, DictionaryError::Text);
+        check(&|b| b[section_start(b, TEXT)] = b'x', DictionaryError::Hash);
+        // Hashes out of order
+        check(
+            &|b| {
+                let i = section_start(b, KEY_HASH);
+                b.copy_within(i..i + 4, i + 4);
+            },
+            DictionaryError::Sort,
+        );
+        // Prefix index entry for a search key that does not exist
+        check(&|b| b[section_start(b, PREFIX)] = 9, DictionaryError::Index);
+        assert_eq!(
+            Err(DictionaryError::Layout),
+            Dictionary::from_bytes(&buf[..20])
+        );
+        assert_eq!(Err(DictionaryError::Magic), Dictionary::from_bytes(&[]));
+    }
+
+    #[test]
+    fn unvalidated_bytes_do_not_panic() {
+        let mut buf = [0u8; 512];
+        let mut entries = ENTRIES;
+        let len = build(&mut entries, &mut buf);
+        // Header and text are fine, but the tables are garbage
+        let tables = section_start(&buf, KEY_HASH);
+        for b in buf[tables..len].iter_mut() {
+            *b = 0xff;
+        }
+        let dict = Dictionary::parse(&buf[..len]).unwrap();
+        let options = Options {
+            dictionary: dict,
+            abbreviations: true,
+            completions: 5,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        look_up_with("nihao shijie ma", &options, &mut sink);
+        assert_eq!("nihao shijie ma", sink.to_s());
+    }
+}
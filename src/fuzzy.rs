@@ -0,0 +1,336 @@
+// Fuzzy pinyin for people whose dialects merge some sounds of Mandarin. A
+// profile is a set of pairs, and search tries each pinyin key with the
+// members of enabled pairs swapped (like "zongguo" for 中国 zhongguo).
+use crate::dictionary::KEY_SIZE_MAX;
+
+// Set of fuzzy pinyin pairs. Combine pairs with union(), like:
+//   Fuzzy::Z_ZH.union(Fuzzy::C_CH).union(Fuzzy::S_SH)
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Fuzzy(u8);
+impl Default for Fuzzy {
+    fn default() -> Self {
+        Fuzzy::NONE
+    }
+}
+impl Fuzzy {
+    pub const NONE: Fuzzy = Fuzzy(0);
+    // Initials
+    pub const Z_ZH: Fuzzy = Fuzzy(1 << 0);
+    pub const C_CH: Fuzzy = Fuzzy(1 << 1);
+    pub const S_SH: Fuzzy = Fuzzy(1 << 2);
+    pub const N_L: Fuzzy = Fuzzy(1 << 3);
+    // Finals
+    pub const AN_ANG: Fuzzy = Fuzzy(1 << 4);
+    pub const EN_ENG: Fuzzy = Fuzzy(1 << 5);
+    pub const IN_ING: Fuzzy = Fuzzy(1 << 6);
+    // Profiles
+    pub const RETROFLEX: Fuzzy = Fuzzy(0b000_0111);
+    pub const NASAL: Fuzzy = Fuzzy(0b111_0000);
+    pub const ALL: Fuzzy = Fuzzy(0b111_1111);
+
+    // Return set with the pairs of both self and other.
+    pub const fn union(self, other: Fuzzy) -> Fuzzy {
+        Fuzzy(self.0 | other.0)
+    }
+
+    // Are all the pairs of other in self?
+    pub const fn contains(self, other: Fuzzy) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+}
+
+// Most variants tried per key. Long keys with many fuzzy sounds have more
+// variants than this, but long keys rarely match anyway.
+const VARIANT_MAX: usize = 128;
+
+// Longest variant: swaps add at most one byte per byte of key
+const VARIANT_SIZE_MAX: usize = 2 * KEY_SIZE_MAX;
+
+// Call f with each variant of normalized pinyin key made by swapping the
+// sounds of fuzzy pairs. Key itself is not a variant. Bits of boundaries are
+// byte offsets of syllable boundaries in key, and marks are tones for bytes
+// of key (may be empty). f gets the boundaries and marks moved to match the
+// variant. Variants stop after VARIANT_MAX or when f returns false.
+pub(crate) fn for_each_variant(
+    key: &str,
+    boundaries: u64,
+    marks: &[u8],
+    fuzzy: Fuzzy,
+    f: &mut impl FnMut(&str, u64, &[u8]) -> bool,
+) {
+    if fuzzy.is_empty() || key.len() > KEY_SIZE_MAX {
+        return;
+    }
+    let mut expansion = Expansion {
+        key: key.as_bytes(),
+        boundaries,
+        marks,
+        fuzzy,
+        buf: [0; VARIANT_SIZE_MAX],
+        mark_buf: [0; VARIANT_SIZE_MAX],
+        budget: VARIANT_MAX,
+    };
+    expansion.expand(0, 0, 0, false, f);
+}
+
+struct Expansion<'a> {
+    key: &'a [u8],
+    boundaries: u64,
+    marks: &'a [u8],
+    fuzzy: Fuzzy,
+    buf: [u8; VARIANT_SIZE_MAX],
+    mark_buf: [u8; VARIANT_SIZE_MAX],
+    budget: usize,
+}
+impl<'a> Expansion<'a> {
+    // Write variants of key[i..] at buf[o..]. Bound holds the boundary bits
+    // for buf[..o], and changed tells if buf[..o] differs from key[..i].
+    // Return: false if there should be no more variants
+    fn expand(
+        &mut self,
+        i: usize,
+        o: usize,
+        bound: u64,
+        changed: bool,
+        f: &mut impl FnMut(&str, u64, &[u8]) -> bool,
+    ) -> bool {
+        if self.budget == 0 {
+            return false;
+        }
+        let bound = match self.boundaries & (1 << i) != 0 {
+            true => bound | 1u64.checked_shl(o as u32).unwrap_or(0),
+            false => bound,
+        };
+        if i == self.key.len() {
+            if !changed {
+                return true;
+            }
+            self.budget -= 1;
+            let marks = match self.marks.is_empty() {
+                true => &[][..],
+                false => &self.mark_buf[..o],
+            };
+            return match core::str::from_utf8(&self.buf[..o]) {
+                Ok(variant) => f(variant, bound, marks),
+                Err(_) => true,
+            };
+        }
+        // Swap the sound at key[i..], if there is one to swap
+        let mut swap = [0u8; 3];
+        for alt in 0..2 {
+            if let Some((eaten, written)) = self.swap_at(i, alt, &mut swap) {
+                if o + written <= VARIANT_SIZE_MAX {
+                    self.buf[o..o + written].copy_from_slice(&swap[..written]);
+                    // Tone of the swapped sound stays with its syllable
+                    let mark = self.mark_at(i..i + eaten);
+                    self.mark_buf[o..o + written].fill(0);
+                    self.mark_buf[o] = mark;
+                    if !self.expand(i + eaten, o + written, bound, true, f) {
+                        return false;
+                    }
+                }
+            }
+        }
+        // Keep key[i] unchanged
+        self.buf[o] = self.key[i];
+        self.mark_buf[o] = self.mark_at(i..i + 1);
+        self.expand(i + 1, o + 1, bound, changed, f)
+    }
+
+    // Return first tone mark in range of key (0 for none).
+    fn mark_at(&self, range: core::ops::Range<usize>) -> u8 {
+        let marks = self.marks.get(range).unwrap_or(&[]);
+        marks.iter().copied().find(|&t| t != 0).unwrap_or(0)
+    }
+
+    // Find a fuzzy sound at key[i..] and write swap number alt into out.
+    // There can be two swaps because "ng" might be a final, or it might be
+    // "n" followed by the next initial (like pinguo for 苹果 pingguo).
+    // Return: (bytes of key used, bytes of out written)
+    fn swap_at(&self, i: usize, alt: usize, out: &mut [u8; 3]) -> Option<(usize, usize)> {
+        let key = self.key;
+        let at = |n: usize| key.get(i + n).copied().unwrap_or(0);
+        let is_vowel = |b: u8| matches!(b, b'a' | b'e' | b'i' | b'o' | b'u' | b'v');
+        let pair = match at(0) {
+            b'z' => Fuzzy::Z_ZH,
+            b'c' => Fuzzy::C_CH,
+            b's' => Fuzzy::S_SH,
+            b'n' | b'l' => Fuzzy::N_L,
+            b'a' => Fuzzy::AN_ANG,
+            b'e' => Fuzzy::EN_ENG,
+            b'i' => Fuzzy::IN_ING,
+            _ => return None,
+        };
+        if !self.fuzzy.contains(pair) {
+            return None;
+        }
+        match at(0) {
+            vowel if alt == 1 && at(1) == b'n' && at(2) == b'g' => {
+                out.copy_from_slice(&[vowel, b'n', b'g']);
+                Some((2, 3))
+            }
+            _ if alt == 1 => None,
+            // z, c, and s are always initials, so they are safe to swap
+            b'z' | b'c' | b's' if at(1) == b'h' => {
+                out[0] = at(0);
+                Some((2, 1))
+            }
+            b'z' | b'c' | b's' => {
+                out[..2].copy_from_slice(&[at(0), b'h']);
+                Some((1, 2))
+            }
+            // l is always an initial, but n may be the end of a final
+            b'l' => {
+                out[0] = b'n';
+                Some((1, 1))
+            }
+            b'n' if is_vowel(at(1)) => {
+                out[0] = b'l';
+                Some((1, 1))
+            }
+            b'n' => None,
+            // Finals an, en, in swap with ang, eng, ing
+            vowel if at(1) == b'n' && at(2) == b'g' => {
+                out[..2].copy_from_slice(&[vowel, b'n']);
+                Some((3, 2))
+            }
+            vowel if at(1) == b'n' => {
+                out.copy_from_slice(&[vowel, b'n', b'g']);
+                Some((2, 3))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{for_each_variant, Fuzzy};
+
+    // Collect variants as a space separated string
+    fn variants(key: &str, fuzzy: Fuzzy) -> ([u8; 256], usize) {
+        let mut buf = [0u8; 256];
+        let mut len = 0;
+        for_each_variant(key, 0, &[], fuzzy, &mut |v, _, _| {
+            for b in v.bytes().chain(core::iter::once(b' ')) {
+                buf[len] = b;
+                len += 1;
+            }
+            true
+        });
+        (buf, len)
+    }
+
+    fn has_variant(key: &str, fuzzy: Fuzzy, variant: &str) -> bool {
+        let (buf, len) = variants(key, fuzzy);
+        let list = core::str::from_utf8(&buf[..len]).unwrap();
+        list.split(' ').any(|v| v == variant)
+    }
+
+    #[test]
+    fn profiles_combine_pairs() {
+        assert!(Fuzzy::NONE.is_empty());
+        assert!(Fuzzy::ALL.contains(Fuzzy::N_L));
+        assert!(!Fuzzy::RETROFLEX.contains(Fuzzy::N_L));
+        assert_eq!(
+            Fuzzy::RETROFLEX.union(Fuzzy::NASAL).union(Fuzzy::N_L),
+            Fuzzy::ALL
+        );
+        assert_eq!(Fuzzy::default(), Fuzzy::NONE);
+    }
+
+    #[test]
+    fn variants_swap_enabled_pairs() {
+        assert!(has_variant("zongguo", Fuzzy::Z_ZH, "zhongguo"));
+        assert!(has_variant("zhongguo", Fuzzy::Z_ZH, "zongguo"));
+        assert!(!has_variant("zongguo", Fuzzy::C_CH, "zhongguo"));
+        assert!(has_variant("cifan", Fuzzy::C_CH, "chifan"));
+        assert!(has_variant("sisi", Fuzzy::S_SH, "shishi"));
+        assert!(has_variant("lihao", Fuzzy::N_L, "nihao"));
+        assert!(has_variant("nihao", Fuzzy::N_L, "lihao"));
+        assert!(has_variant("henggao", Fuzzy::EN_ENG, "hengao"));
+        assert!(has_variant("pinguo", Fuzzy::IN_ING, "pingguo"));
+        assert!(has_variant("fanjian", Fuzzy::AN_ANG, "fangjiang"));
+        assert!(has_variant("zongguo", Fuzzy::ALL, "zhongguo"));
+        // The n of a final is not an initial
+        assert!(!has_variant("an", Fuzzy::N_L, "al"));
+        // Key itself is not a variant
+        assert!(!has_variant("nihao", Fuzzy::ALL, "nihao"));
+        assert_eq!(variants("nihao", Fuzzy::NONE).1, 0);
+    }
+
+    #[test]
+    fn variants_keep_syllable_boundaries() {
+        let mut found = false;
+        // "xi'an" has a boundary at byte 2
+        for_each_variant("xian", 1 << 2, &[], Fuzzy::AN_ANG, &mut |v, bound, _| {
+            assert_eq!("xiang", v);
+            assert_eq!(1 << 2, bound);
+            found = true;
+            true
+        });
+        assert!(found);
+        for_each_variant("sian", 1 << 1, &[], Fuzzy::S_SH, &mut |v, bound, _| {
+            assert_eq!("shian", v);
+            assert_eq!(1 << 2, bound);
+            true
+        });
+    }
+
+    #[test]
+    fn variants_keep_tone_marks() {
+        // xian1 ==> xiang1, with the tone moved from n to a
+        let mut found = false;
+        for_each_variant(
+            "xian",
+            0,
+            &[0, 0, 0, 1],
+            Fuzzy::AN_ANG,
+            &mut |v, _, marks| {
+                assert_eq!("xiang", v);
+                assert_eq!(&[0, 0, 1, 0, 0], marks);
+                found = true;
+                true
+            },
+        );
+        assert!(found);
+        // zhǒng ==> zǒng
+        for_each_variant(
+            "zhong",
+            0,
+            &[0, 0, 3, 0, 0],
+            Fuzzy::Z_ZH,
+            &mut |v, _, marks| {
+                assert_eq!("zong", v);
+                assert_eq!(&[0, 3, 0, 0], marks);
+                true
+            },
+        );
+    }
+
+    #[test]
+    fn variants_are_limited() {
+        let mut count = 0;
+        for_each_variant(
+            "zacasazacasazacasazaca",
+            0,
+            &[],
+            Fuzzy::ALL,
+            &mut |_, _, _| {
+                count += 1;
+                true
+            },
+        );
+        assert_eq!(super::VARIANT_MAX, count);
+        let mut count = 0;
+        for_each_variant("zacasa", 0, &[], Fuzzy::ALL, &mut |_, _, _| {
+            count += 1;
+            count < 3
+        });
+        assert_eq!(3, count);
+    }
+}
@@ -0,0 +1,286 @@
+// Hangul input with the 2-set (두벌식, Dubeolsik) keyboard. Keys of the query
+// are jamo, and composing them gives precomposed syllable blocks, like 한글
+// for "gksrmf". There is no dictionary search. Since the query keeps the
+// keys, removing the last key takes a block apart one jamo at a time (한,
+// 하, ㅎ).
+use crate::Writer;
+
+// Initial consonants in Unicode order of syllable blocks
+const INITIALS: &str = "ㄱㄲㄴㄷㄸㄹㅁㅂㅃㅅㅆㅇㅈㅉㅊㅋㅌㅍㅎ";
+
+// Vowels in Unicode order of syllable blocks
+const MEDIALS: &str = "ㅏㅐㅑㅒㅓㅔㅕㅖㅗㅘㅙㅚㅛㅜㅝㅞㅟㅠㅡㅢㅣ";
+
+// Final consonants in Unicode order of syllable blocks (after no final)
+const FINALS: &str = "ㄱㄲㄳㄴㄵㄶㄷㄹㄺㄻㄼㄽㄾㄿㅀㅁㅂㅄㅅㅆㅇㅈㅊㅋㅌㅍㅎ";
+
+// Vowels typed as two keys
+const COMPOUND_MEDIALS: [(char, char, char); 7] = [
+    ('ㅗ', 'ㅏ', 'ㅘ'),
+    ('ㅗ', 'ㅐ', 'ㅙ'),
+    ('ㅗ', 'ㅣ', 'ㅚ'),
+    ('ㅜ', 'ㅓ', 'ㅝ'),
+    ('ㅜ', 'ㅔ', 'ㅞ'),
+    ('ㅜ', 'ㅣ', 'ㅟ'),
+    ('ㅡ', 'ㅣ', 'ㅢ'),
+];
+
+// Final consonants typed as two keys
+const COMPOUND_FINALS: [(char, char, char); 11] = [
+    ('ㄱ', 'ㅅ', 'ㄳ'),
+    ('ㄴ', 'ㅈ', 'ㄵ'),
+    ('ㄴ', 'ㅎ', 'ㄶ'),
+    ('ㄹ', 'ㄱ', 'ㄺ'),
+    ('ㄹ', 'ㅁ', 'ㄻ'),
+    ('ㄹ', 'ㅂ', 'ㄼ'),
+    ('ㄹ', 'ㅅ', 'ㄽ'),
+    ('ㄹ', 'ㅌ', 'ㄾ'),
+    ('ㄹ', 'ㅍ', 'ㄿ'),
+    ('ㄹ', 'ㅎ', 'ㅀ'),
+    ('ㅂ', 'ㅅ', 'ㅄ'),
+];
+
+// 2-set layout of a US keyboard. Shift gives the double consonants and ㅒ,
+// ㅖ, and other shifted letters are the same as unshifted.
+const DUBEOLSIK: [(char, char); 31] = [
+    ('q', 'ㅂ'),
+    ('w', 'ㅈ'),
+    ('e', 'ㄷ'),
+    ('r', 'ㄱ'),
+    ('t', 'ㅅ'),
+    ('y', 'ㅛ'),
+    ('u', 'ㅕ'),
+    ('i', 'ㅑ'),
+    ('o', 'ㅐ'),
+    ('p', 'ㅔ'),
+    ('a', 'ㅁ'),
+    ('s', 'ㄴ'),
+    ('d', 'ㅇ'),
+    ('f', 'ㄹ'),
+    ('g', 'ㅎ'),
+    ('h', 'ㅗ'),
+    ('j', 'ㅓ'),
+    ('k', 'ㅏ'),
+    ('l', 'ㅣ'),
+    ('z', 'ㅋ'),
+    ('x', 'ㅌ'),
+    ('c', 'ㅊ'),
+    ('v', 'ㅍ'),
+    ('b', 'ㅠ'),
+    ('n', 'ㅜ'),
+    ('m', 'ㅡ'),
+    ('Q', 'ㅃ'),
+    ('W', 'ㅉ'),
+    ('E', 'ㄸ'),
+    ('R', 'ㄲ'),
+    ('T', 'ㅆ'),
+];
+
+// Return jamo for a key of the 2-set layout, like 'ㅎ' for 'g'. Jamo typed
+// directly are themselves.
+pub fn dubeolsik(key: char) -> Option<char> {
+    if is_consonant(key) || is_vowel(key) {
+        return Some(key);
+    }
+    let shifted = match key {
+        'O' => return Some('ㅒ'),
+        'P' => return Some('ㅖ'),
+        'Q' | 'W' | 'E' | 'R' | 'T' => key,
+        _ => key.to_ascii_lowercase(),
+    };
+    DUBEOLSIK
+        .iter()
+        .find(|(k, _)| *k == shifted)
+        .map(|&(_, j)| j)
+}
+
+fn is_consonant(c: char) -> bool {
+    INITIALS.contains(c) || FINALS.contains(c)
+}
+
+fn is_vowel(c: char) -> bool {
+    MEDIALS.contains(c)
+}
+
+fn combine(table: &[(char, char, char)], a: char, b: char) -> Option<char> {
+    table
+        .iter()
+        .find(|&&(x, y, _)| x == a && y == b)
+        .map(|&(_, _, ab)| ab)
+}
+
+// Return the two jamo of a compound final, like ('ㄹ', 'ㄱ') for 'ㄺ'.
+fn split_final(t: char) -> Option<(char, char)> {
+    COMPOUND_FINALS
+        .iter()
+        .find(|&&(_, _, ab)| ab == t)
+        .map(|&(a, b, _)| (a, b))
+}
+
+// Syllable block being composed. It may be a lone consonant or vowel.
+#[derive(Copy, Clone, Default)]
+struct Block {
+    initial: Option<char>,
+    medial: Option<char>,
+    fin: Option<char>,
+}
+impl Block {
+    // Add jamo to the block, or start a new block with it.
+    // Side-effect: write finished block to sink.
+    // Return: block being composed
+    fn add(self, jamo: char, sink: &mut impl Writer) -> Block {
+        let Block {
+            initial,
+            medial,
+            fin,
+        } = self;
+        if is_vowel(jamo) {
+            match (initial, medial, fin) {
+                // Vowel after an initial
+                (Some(_), None, _) => {
+                    return Block {
+                        medial: Some(jamo),
+                        ..self
+                    }
+                }
+                // Compound vowel
+                (_, Some(v), None) if combine(&COMPOUND_MEDIALS, v, jamo).is_some() => {
+                    let medial = combine(&COMPOUND_MEDIALS, v, jamo);
+                    return Block { medial, ..self };
+                }
+                // Final consonant moves to a new block with the vowel
+                (_, _, Some(t)) => {
+                    let (keep, moved) = match split_final(t) {
+                        Some((a, b)) => (Some(a), b),
+                        None => (None, t),
+                    };
+                    Block { fin: keep, ..self }.write(sink);
+                    return Block {
+                        initial: Some(moved),
+                        medial: Some(jamo),
+                        fin: None,
+                    };
+                }
+                _ => {}
+            }
+        } else {
+            match (initial, medial, fin) {
+                (Some(_), Some(_), None) if FINALS.contains(jamo) => {
+                    return Block {
+                        fin: Some(jamo),
+                        ..self
+                    }
+                }
+                (_, _, Some(t)) if combine(&COMPOUND_FINALS, t, jamo).is_some() => {
+                    let fin = combine(&COMPOUND_FINALS, t, jamo);
+                    return Block { fin, ..self };
+                }
+                _ => {}
+            }
+        }
+        // Anything else starts a new block
+        self.write(sink);
+        match is_vowel(jamo) {
+            true => Block {
+                medial: Some(jamo),
+                ..Block::default()
+            },
+            false => Block {
+                initial: Some(jamo),
+                ..Block::default()
+            },
+        }
+    }
+
+    // Side-effect: write block to sink as one syllable, or as a lone jamo.
+    fn write(&self, sink: &mut impl Writer) {
+        let position = |list: &str, c| list.chars().position(|x| x == c);
+        let c = match (self.initial, self.medial) {
+            (Some(l), Some(v)) => {
+                let l = position(INITIALS, l).unwrap_or(0) as u32;
+                let v = position(MEDIALS, v).unwrap_or(0) as u32;
+                let t = self
+                    .fin
+                    .and_then(|t| position(FINALS, t))
+                    .map_or(0, |t| t + 1) as u32;
+                char::from_u32(0xac00 + (l * 21 + v) * 28 + t)
+            }
+            (Some(l), None) => Some(l),
+            (None, Some(v)) => Some(v),
+            (None, None) => None,
+        };
+        if let Some(c) = c {
+            sink.write(c.encode_utf8(&mut [0; 4]));
+        }
+    }
+}
+
+// Compose 2-set keys (or jamo) of text into Hangul syllable blocks. A final
+// consonant moves to the next block when a vowel follows it (gksk is 하나,
+// not 한ㅏ), and so does the second half of a compound final (닭 + ㅏ is
+// 달가). Characters that are not keys pass through.
+// Side-effect: write composed text to sink.
+pub fn compose(text: &str, sink: &mut impl Writer) {
+    let mut block = Block::default();
+    for c in text.chars() {
+        let jamo = match dubeolsik(c) {
+            Some(jamo) => jamo,
+            None => {
+                block.write(sink);
+                block = Block::default();
+                sink.write(c.encode_utf8(&mut [0; 4]));
+                continue;
+            }
+        };
+        block = block.add(jamo, sink);
+    }
+    block.write(sink);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::compose;
+    use crate::{BufWriter, Writer};
+
+    fn composed(keys: &str) -> BufWriter {
+        let mut sink = BufWriter::new();
+        compose(keys, &mut sink);
+        sink
+    }
+
+    #[test]
+    fn keys_compose_syllable_blocks() {
+        assert_eq!("한글", composed("gksrmf").to_s());
+        assert_eq!("안녕하세요", composed("dkssudgktpdy").to_s());
+        assert_eq!("ㅎ", composed("g").to_s());
+        assert_eq!("ㅏ", composed("k").to_s());
+        // Jamo typed directly compose the same way
+        assert_eq!("한", composed("ㅎㅏㄴ").to_s());
+    }
+
+    #[test]
+    fn double_consonants_and_compound_vowels() {
+        assert_eq!("까치", composed("Rkcl").to_s());
+        assert_eq!("있어", composed("dlTdj").to_s());
+        assert_eq!("와", composed("dhk").to_s());
+        assert_eq!("원", composed("dnjs").to_s());
+        assert_eq!("의사", composed("dmltk").to_s());
+        // ㄸ can not be a final consonant
+        assert_eq!("아ㄸ", composed("dkE").to_s());
+    }
+
+    #[test]
+    fn finals_move_to_following_vowels() {
+        assert_eq!("하나", composed("gksk").to_s());
+        assert_eq!("닭", composed("ekfr").to_s());
+        assert_eq!("달가", composed("ekfrk").to_s());
+        assert_eq!("없어", composed("djqtdj").to_s());
+        assert_eq!("업서", composed("djqtj").to_s());
+    }
+
+    #[test]
+    fn other_characters_pass_through() {
+        assert_eq!("한 1글!", composed("gks 1rmf!").to_s());
+        assert_eq!("ㅎ😀ㅏ", composed("g😀k").to_s());
+    }
+}
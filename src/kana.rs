@@ -0,0 +1,334 @@
+// Japanese kana input. Hepburn or Kunrei romaji of the query gets converted
+// to hiragana, like ありがとう for "arigatou" (or "arigatou" with Kunrei
+// "si", "tu", "sya"). Romaji typed in capitals, or the Katakana input, give
+// katakana instead, like "PASOKON" for パソコン. There is no dictionary search.
+use crate::Writer;
+
+// Longest romaji in ROMAJI ("xtsu")
+const ROMAJI_SIZE_MAX: usize = 4;
+
+// Romaji with their hiragana, sorted for binary search. Small kana are x or
+// l with romaji, like "xya" for ゃ.
+static ROMAJI: &[(&str, &str)] = &[
+    ("a", "あ"),
+    ("ba", "ば"),
+    ("be", "べ"),
+    ("bi", "び"),
+    ("bo", "ぼ"),
+    ("bu", "ぶ"),
+    ("bya", "びゃ"),
+    ("byo", "びょ"),
+    ("byu", "びゅ"),
+    ("cha", "ちゃ"),
+    ("che", "ちぇ"),
+    ("chi", "ち"),
+    ("cho", "ちょ"),
+    ("chu", "ちゅ"),
+    ("cya", "ちゃ"),
+    ("cyo", "ちょ"),
+    ("cyu", "ちゅ"),
+    ("da", "だ"),
+    ("de", "で"),
+    ("dhi", "でぃ"),
+    ("di", "ぢ"),
+    ("do", "ど"),
+    ("du", "づ"),
+    ("dwu", "どぅ"),
+    ("dya", "ぢゃ"),
+    ("dyo", "ぢょ"),
+    ("dyu", "ぢゅ"),
+    ("e", "え"),
+    ("fa", "ふぁ"),
+    ("fe", "ふぇ"),
+    ("fi", "ふぃ"),
+    ("fo", "ふぉ"),
+    ("fu", "ふ"),
+    ("ga", "が"),
+    ("ge", "げ"),
+    ("gi", "ぎ"),
+    ("go", "ご"),
+    ("gu", "ぐ"),
+    ("gya", "ぎゃ"),
+    ("gyo", "ぎょ"),
+    ("gyu", "ぎゅ"),
+    ("ha", "は"),
+    ("he", "へ"),
+    ("hi", "ひ"),
+    ("ho", "ほ"),
+    ("hu", "ふ"),
+    ("hya", "ひゃ"),
+    ("hyo", "ひょ"),
+    ("hyu", "ひゅ"),
+    ("i", "い"),
+    ("ja", "じゃ"),
+    ("je", "じぇ"),
+    ("ji", "じ"),
+    ("jo", "じょ"),
+    ("ju", "じゅ"),
+    ("jya", "じゃ"),
+    ("jyo", "じょ"),
+    ("jyu", "じゅ"),
+    ("ka", "か"),
+    ("ke", "け"),
+    ("ki", "き"),
+    ("ko", "こ"),
+    ("ku", "く"),
+    ("kya", "きゃ"),
+    ("kyo", "きょ"),
+    ("kyu", "きゅ"),
+    ("la", "ぁ"),
+    ("le", "ぇ"),
+    ("li", "ぃ"),
+    ("lka", "ゕ"),
+    ("lke", "ゖ"),
+    ("lo", "ぉ"),
+    ("ltsu", "っ"),
+    ("ltu", "っ"),
+    ("lu", "ぅ"),
+    ("lwa", "ゎ"),
+    ("lya", "ゃ"),
+    ("lyo", "ょ"),
+    ("lyu", "ゅ"),
+    ("ma", "ま"),
+    ("me", "め"),
+    ("mi", "み"),
+    ("mo", "も"),
+    ("mu", "む"),
+    ("mya", "みゃ"),
+    ("myo", "みょ"),
+    ("myu", "みゅ"),
+    ("n'", "ん"),
+    ("na", "な"),
+    ("ne", "ね"),
+    ("ni", "に"),
+    ("nn", "ん"),
+    ("no", "の"),
+    ("nu", "ぬ"),
+    ("nya", "にゃ"),
+    ("nyo", "にょ"),
+    ("nyu", "にゅ"),
+    ("o", "お"),
+    ("pa", "ぱ"),
+    ("pe", "ぺ"),
+    ("pi", "ぴ"),
+    ("po", "ぽ"),
+    ("pu", "ぷ"),
+    ("pya", "ぴゃ"),
+    ("pyo", "ぴょ"),
+    ("pyu", "ぴゅ"),
+    ("ra", "ら"),
+    ("re", "れ"),
+    ("ri", "り"),
+    ("ro", "ろ"),
+    ("ru", "る"),
+    ("rya", "りゃ"),
+    ("ryo", "りょ"),
+    ("ryu", "りゅ"),
+    ("sa", "さ"),
+    ("se", "せ"),
+    ("sha", "しゃ"),
+    ("she", "しぇ"),
+    ("shi", "し"),
+    ("sho", "しょ"),
+    ("shu", "しゅ"),
+    ("si", "し"),
+    ("so", "そ"),
+    ("su", "す"),
+    ("sya", "しゃ"),
+    ("syo", "しょ"),
+    ("syu", "しゅ"),
+    ("ta", "た"),
+    ("te", "て"),
+    ("thi", "てぃ"),
+    ("ti", "ち"),
+    ("to", "と"),
+    ("tsa", "つぁ"),
+    ("tsu", "つ"),
+    ("tu", "つ"),
+    ("twu", "とぅ"),
+    ("tya", "ちゃ"),
+    ("tyo", "ちょ"),
+    ("tyu", "ちゅ"),
+    ("u", "う"),
+    ("va", "ゔぁ"),
+    ("ve", "ゔぇ"),
+    ("vi", "ゔぃ"),
+    ("vo", "ゔぉ"),
+    ("vu", "ゔ"),
+    ("wa", "わ"),
+    ("we", "うぇ"),
+    ("wi", "うぃ"),
+    ("wo", "を"),
+    ("xa", "ぁ"),
+    ("xe", "ぇ"),
+    ("xi", "ぃ"),
+    ("xka", "ゕ"),
+    ("xke", "ゖ"),
+    ("xo", "ぉ"),
+    ("xtsu", "っ"),
+    ("xtu", "っ"),
+    ("xu", "ぅ"),
+    ("xwa", "ゎ"),
+    ("xya", "ゃ"),
+    ("xyo", "ょ"),
+    ("xyu", "ゅ"),
+    ("ya", "や"),
+    ("ye", "いぇ"),
+    ("yo", "よ"),
+    ("yu", "ゆ"),
+    ("za", "ざ"),
+    ("ze", "ぜ"),
+    ("zi", "じ"),
+    ("zo", "ぞ"),
+    ("zu", "ず"),
+    ("zya", "じゃ"),
+    ("zyo", "じょ"),
+    ("zyu", "じゅ"),
+];
+
+// Return hiragana for romaji of a small kana or a syllable, like "か" for
+// "ka". Romaji must be lowercase.
+pub fn hiragana(romaji: &str) -> Option<&'static str> {
+    let i = ROMAJI.binary_search_by(|(r, _)| (*r).cmp(romaji)).ok()?;
+    Some(ROMAJI[i].1)
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c.to_ascii_lowercase(), 'a' | 'i' | 'u' | 'e' | 'o')
+}
+
+// Side-effect: write kana to sink, as katakana if katakana is true.
+fn write_kana(kana: &str, katakana: bool, sink: &mut impl Writer) {
+    for c in kana.chars() {
+        let c = match c {
+            // Katakana are 0x60 after hiragana
+            'ぁ'..='ゖ' if katakana => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+            _ => c,
+        };
+        sink.write(c.encode_utf8(&mut [0; 4]));
+    }
+}
+
+// Convert romaji of text to kana, as katakana if katakana is true or if the
+// romaji is in capitals. A doubled consonant is a small っ (kka is っか, and
+// tch is っち), and n before a consonant or at the end is ん, like nn and n'.
+// Long vowel "-" is ー, and "," "." are 、。. Characters that do not spell
+// kana pass through.
+// Side-effect: write converted text to sink.
+pub fn convert(text: &str, katakana: bool, sink: &mut impl Writer) {
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let mut next = rest.chars().skip(1);
+        let (n1, n2) = (next.next(), next.next());
+        let capital = c.is_ascii_uppercase();
+        // Longest romaji at the start of rest
+        let mut buf = [0u8; ROMAJI_SIZE_MAX];
+        let mut kana = None;
+        for len in (1..=ROMAJI_SIZE_MAX).rev() {
+            let romaji = match rest.get(..len) {
+                Some(r) if r.bytes().all(|b| b.is_ascii_alphabetic() || b == b'\'') => r,
+                _ => continue,
+            };
+            buf[..len].copy_from_slice(romaji.as_bytes());
+            buf[..len].make_ascii_lowercase();
+            let lower = core::str::from_utf8(&buf[..len]).unwrap_or("");
+            // The second n of "nna" starts な
+            if lower == "nn" && n2.is_some_and(|c| is_vowel(c) || c.eq_ignore_ascii_case(&'y')) {
+                continue;
+            }
+            if let Some(k) = hiragana(lower) {
+                let capitals = romaji.bytes().all(|b| !b.is_ascii_lowercase());
+                kana = Some((k, len, capitals));
+                break;
+            }
+        }
+        let lower = c.to_ascii_lowercase();
+        let same = |n: Option<char>| n.is_some_and(|n| n.eq_ignore_ascii_case(&c));
+        let (k, len, capitals) = match kana {
+            Some(found) => found,
+            None if c == '-' => ("ー", 1, false),
+            None if c == ',' => ("、", 1, false),
+            None if c == '.' => ("。", 1, false),
+            // Sokuon
+            None if c.is_ascii_alphabetic() && !is_vowel(c) && lower != 'n' && same(n1) => {
+                ("っ", 1, capital)
+            }
+            None if lower == 't' && n1.is_some_and(|n| n.eq_ignore_ascii_case(&'c')) => {
+                ("っ", 1, capital)
+            }
+            // n that does not start a syllable
+            None if lower == 'n'
+                && !n1.is_some_and(|n| is_vowel(n) || n.eq_ignore_ascii_case(&'y')) =>
+            {
+                ("ん", 1, capital)
+            }
+            None => {
+                sink.write(&rest[..c.len_utf8()]);
+                rest = &rest[c.len_utf8()..];
+                continue;
+            }
+        };
+        write_kana(k, katakana || capitals, sink);
+        rest = &rest[len..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert, hiragana, ROMAJI};
+    use crate::{BufWriter, Writer};
+
+    fn converted(romaji: &str, katakana: bool) -> BufWriter {
+        let mut sink = BufWriter::new();
+        convert(romaji, katakana, &mut sink);
+        sink
+    }
+
+    #[test]
+    fn romaji_table_is_sorted() {
+        assert!(ROMAJI.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(Some("しゃ"), hiragana("sha"));
+        assert_eq!(Some("しゃ"), hiragana("sya"));
+        assert_eq!(None, hiragana("q"));
+    }
+
+    #[test]
+    fn hepburn_and_kunrei_romaji() {
+        assert_eq!("ありがとう", converted("arigatou", false).to_s());
+        assert_eq!("しんぶん", converted("shinbun", false).to_s());
+        assert_eq!("しんぶん", converted("sinbun", false).to_s());
+        assert_eq!("ちゃつけ", converted("chatsuke", false).to_s());
+        assert_eq!("ちゃつけ", converted("tyatuke", false).to_s());
+        assert_eq!("ふじさん", converted("fujisan", false).to_s());
+        assert_eq!("ふじさん", converted("huzisan", false).to_s());
+    }
+
+    #[test]
+    fn n_sokuon_small_kana_and_long_vowels() {
+        assert_eq!("こんにちは", converted("konnnichiha", false).to_s());
+        assert_eq!("こんな", converted("konna", false).to_s());
+        assert_eq!("かんじ", converted("kanji", false).to_s());
+        assert_eq!("きんえん", converted("kin'en", false).to_s());
+        assert_eq!("ほん", converted("hon", false).to_s());
+        assert_eq!("きって", converted("kitte", false).to_s());
+        assert_eq!("まっちゃ", converted("matcha", false).to_s());
+        assert_eq!("ゃぁっ", converted("xyalaltsu", false).to_s());
+        assert_eq!("らーめん。", converted("ra-men.", false).to_s());
+    }
+
+    #[test]
+    fn katakana_by_capitals_or_option() {
+        assert_eq!(
+            "これはパソコンです",
+            converted("korehaPASOKONdesu", false).to_s()
+        );
+        assert_eq!("コーヒー", converted("ko-hi-", true).to_s());
+        assert_eq!("ヴァイオリン", converted("vaiorin", true).to_s());
+        assert_eq!("ベッド", converted("BEDDO", false).to_s());
+    }
+
+    #[test]
+    fn other_characters_pass_through() {
+        assert_eq!("q1 あ😀", converted("q1 a😀", false).to_s());
+    }
+}
@@ -0,0 +1,415 @@
+// Adaptive candidate order. Learning remembers which 词语 the user picks from
+// open choices, and later choices list the learned 词语 first, by frequency
+// with a decay for picks that are long ago. Learning is off unless the host
+// sets Options::learning, and its state never leaves the host's memory unless
+// the host exports it.
+//
+// Records are in Cells so that a Learning can be shared by reference from
+// Options (which is Copy) and still record picks during Session commits.
+use crate::dictionary::Dictionary;
+use crate::lex::Cand;
+use core::cell::Cell;
+use core::fmt;
+
+// Most 词语 that Learning remembers. When full, the record with the lowest
+// score gets replaced.
+pub const LEARNING_SIZE: usize = 256;
+// Picks of other 词语 it takes for the score of a pick to halve
+pub const HALF_LIFE: u32 = 64;
+
+// Export format (little-endian): magic, u16 version, u16 record count, u32
+// clock, then for each record: u32 murmur3 hash of 词语, u16 pick count, u32
+// clock value of last pick.
+pub const MAGIC: &[u8; 4] = b"IMEL";
+pub const VERSION: u16 = 1;
+const HEADER_SIZE: usize = 12;
+const RECORD_SIZE: usize = 10;
+pub const EXPORT_SIZE_MAX: usize = HEADER_SIZE + RECORD_SIZE * LEARNING_SIZE;
+
+// Problems with exported bytes.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LearningError {
+    // Bytes do not start with MAGIC
+    Magic,
+    // Format version is not VERSION
+    Version(u16),
+    // Record count does not match length of bytes
+    Layout,
+}
+
+// Picks of one 词语. Records with count == 0 are empty.
+#[derive(Copy, Clone, Default)]
+struct Record {
+    hash: u32,
+    count: u16,
+    last: u32,
+}
+
+pub struct Learning {
+    records: [Cell<Record>; LEARNING_SIZE],
+    // Counts picks, for the age of records
+    clock: Cell<u32>,
+}
+impl Default for Learning {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl PartialEq for Learning {
+    fn eq(&self, other: &Self) -> bool {
+        core::ptr::eq(self, other)
+    }
+}
+impl Eq for Learning {}
+impl fmt::Debug for Learning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Learning")
+            .field("records", &self.len())
+            .field("clock", &self.clock.get())
+            .finish()
+    }
+}
+impl Learning {
+    // Return Learning with nothing learned.
+    pub fn new() -> Learning {
+        Learning {
+            records: [(); LEARNING_SIZE].map(|_| Cell::new(Record::default())),
+            clock: Cell::new(0),
+        }
+    }
+
+    // Return Learning with state from export().
+    pub fn from_bytes(bytes: &[u8]) -> Result<Learning, LearningError> {
+        if bytes.get(..4) != Some(&MAGIC[..]) {
+            return Err(LearningError::Magic);
+        }
+        let version = u16_at(bytes, 4);
+        if version != VERSION {
+            return Err(LearningError::Version(version));
+        }
+        let n = usize::from(u16_at(bytes, 6));
+        if n > LEARNING_SIZE || bytes.len() != HEADER_SIZE + RECORD_SIZE * n {
+            return Err(LearningError::Layout);
+        }
+        let learning = Learning::new();
+        learning.clock.set(u32_at(bytes, 8));
+        for (i, cell) in learning.records[..n].iter().enumerate() {
+            let pos = HEADER_SIZE + RECORD_SIZE * i;
+            cell.set(Record {
+                hash: u32_at(bytes, pos),
+                count: u16_at(bytes, pos + 4),
+                last: u32_at(bytes, pos + 6),
+            });
+        }
+        Ok(learning)
+    }
+
+    // Write state into buf in the export format. A buf of EXPORT_SIZE_MAX
+    // bytes is always big enough.
+    // Return: number of bytes written, or None if buf is too small
+    pub fn export(&self, buf: &mut [u8]) -> Option<usize> {
+        let size = HEADER_SIZE + RECORD_SIZE * self.len();
+        let out = buf.get_mut(..size)?;
+        out[..4].copy_from_slice(MAGIC);
+        out[4..6].copy_from_slice(&VERSION.to_le_bytes());
+        out[6..8].copy_from_slice(&(self.len() as u16).to_le_bytes());
+        out[8..12].copy_from_slice(&self.clock.get().to_le_bytes());
+        let records = self.records.iter().map(Cell::get).filter(|r| r.count > 0);
+        for (i, r) in records.enumerate() {
+            let pos = HEADER_SIZE + RECORD_SIZE * i;
+            out[pos..pos + 4].copy_from_slice(&r.hash.to_le_bytes());
+            out[pos + 4..pos + 6].copy_from_slice(&r.count.to_le_bytes());
+            out[pos + 6..pos + 10].copy_from_slice(&r.last.to_le_bytes());
+        }
+        Some(size)
+    }
+
+    // Number of 词语 with recorded picks.
+    pub fn len(&self) -> usize {
+        self.records.iter().filter(|r| r.get().count > 0).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Forget everything.
+    pub fn clear(&self) {
+        for cell in self.records.iter() {
+            cell.set(Record::default());
+        }
+        self.clock.set(0);
+    }
+
+    // Record that the user picked 词语 from a choice.
+    pub fn record(&self, ciyu: &str) {
+        let hash = crate::murmur3(ciyu, 0);
+        let now = self.clock.get().wrapping_add(1);
+        self.clock.set(now);
+        let cell = match self.find(hash) {
+            Some(cell) => cell,
+            None => {
+                // Replace the empty or lowest scoring record
+                let lowest = self
+                    .records
+                    .iter()
+                    .min_by_key(|r| (self.score(&r.get()), r.get().last));
+                match lowest {
+                    Some(cell) => {
+                        cell.set(Record {
+                            hash,
+                            count: 0,
+                            last: now,
+                        });
+                        cell
+                    }
+                    None => return,
+                }
+            }
+        };
+        let r = cell.get();
+        cell.set(Record {
+            hash,
+            count: r.count.saturating_add(1),
+            last: now,
+        });
+    }
+
+    // Refresh the last pick time of a learned 词语 that the user accepted as
+    // the default, without counting it as a pick. 词语 that were never picked
+    // stay unlearned.
+    pub fn touch(&self, ciyu: &str) {
+        if let Some(cell) = self.find(crate::murmur3(ciyu, 0)) {
+            let r = cell.get();
+            cell.set(Record {
+                last: self.clock.get(),
+                ..r
+            });
+        }
+    }
+
+    // Move candidates with learned 词语 to the front, best score first. Other
+    // candidates keep their order after them.
+    pub(crate) fn reorder(&self, dictionary: &Dictionary, cands: &mut [Cand]) {
+        if self.is_empty() {
+            return;
+        }
+        let mut keyed = [(0, 0, 0, Cand { ciyu: 0, nth: 0 }); crate::lex::CAND_LIST_SIZE];
+        let n = crate::min(cands.len(), keyed.len());
+        for (i, cand) in cands[..n].iter().enumerate() {
+            let hash = crate::murmur3(cand.text(dictionary), 0);
+            let (score, last) = match self.find(hash) {
+                Some(r) => (self.score(&r.get()), r.get().last),
+                None => (0, 0),
+            };
+            // Higher score and more recent last pick come first
+            keyed[i] = (u32::MAX - score, u32::MAX - last, i, *cand);
+        }
+        keyed[..n].sort_unstable_by_key(|&(score, last, i, _)| (score, last, i));
+        for (cand, &(_, _, _, sorted)) in cands.iter_mut().zip(keyed[..n].iter()) {
+            *cand = sorted;
+        }
+    }
+
+    fn find(&self, hash: u32) -> Option<&Cell<Record>> {
+        self.records
+            .iter()
+            .find(|r| r.get().count > 0 && r.get().hash == hash)
+    }
+
+    // Score of record: pick count (in 1/256 units) halved for each HALF_LIFE
+    // picks since its last pick.
+    fn score(&self, r: &Record) -> u32 {
+        let age = self.clock.get().wrapping_sub(r.last);
+        let halvings = crate::min((age / HALF_LIFE) as usize, 31) as u32;
+        (u32::from(r.count) << 8) >> halvings
+    }
+}
+
+fn u16_at(bytes: &[u8], pos: usize) -> u16 {
+    match bytes.get(pos..pos + 2) {
+        Some(b) => u16::from_le_bytes([b[0], b[1]]),
+        None => 0,
+    }
+}
+fn u32_at(bytes: &[u8], pos: usize) -> u32 {
+    match bytes.get(pos..pos + 4) {
+        Some(b) => u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        None => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::Options;
+    use crate::session::{Key, Session};
+    use crate::{look_up_with, BufWriter, Writer};
+
+    fn look_up_learned<'a>(qry: &str, learning: &Learning, sink: &'a mut BufWriter) -> &'a str {
+        let options = Options {
+            learning: Some(learning),
+            ..Options::default()
+        };
+        sink.rewind();
+        look_up_with(qry, &options, sink);
+        sink.to_s()
+    }
+
+    fn commit(session: &mut Session, keys: &str) -> BufWriter {
+        let mut sink = BufWriter::new();
+        for c in keys.chars() {
+            session.key(Key::Char(c), &mut sink);
+        }
+        session.key(Key::Commit, &mut sink);
+        sink
+    }
+
+    #[test]
+    fn picked_ciyu_moves_to_front() {
+        let learning = Learning::new();
+        let sink = &mut BufWriter::new();
+        assert!(look_up_learned("xiang", &learning, sink).starts_with(" (1想 2向 3像"));
+        learning.record("像");
+        assert!(look_up_learned("xiang", &learning, sink).starts_with(" (1像 2想 3向"));
+        assert_eq!("像", look_up_learned("xiang ", &learning, sink));
+        // Single candidates and other choices are unchanged
+        assert_eq!("你", look_up_learned("ni", &learning, sink));
+        let mut plain = BufWriter::new();
+        look_up_with("he", &Options::default(), &mut plain);
+        assert_eq!(plain.to_s(), look_up_learned("he", &learning, sink));
+    }
+
+    #[test]
+    fn frequency_beats_recency_until_picks_get_old() {
+        let learning = Learning::new();
+        let sink = &mut BufWriter::new();
+        for _ in 0..3 {
+            learning.record("向");
+        }
+        learning.record("像");
+        assert!(look_up_learned("xiang", &learning, sink).starts_with(" (1向 2像 3想"));
+        // Two half lives later, one recent pick beats three old ones
+        for _ in 0..2 * HALF_LIFE {
+            learning.record("其他");
+        }
+        learning.record("像");
+        assert!(look_up_learned("xiang", &learning, sink).starts_with(" (1像 2向 3想"));
+    }
+
+    #[test]
+    fn session_commit_records_picks() {
+        let learning = Learning::new();
+        let options = Options {
+            learning: Some(&learning),
+            ..Options::default()
+        };
+        let mut session = Session::with_options(options);
+        assert_eq!("像", commit(&mut session, "xiang3").to_s());
+        assert_eq!(1, learning.len());
+        assert_eq!("像", commit(&mut session, "xiang").to_s());
+        // Preedit does not record anything
+        let mut sink = BufWriter::new();
+        session.key(Key::Char('x'), &mut sink);
+        session.render(&mut sink);
+        assert_eq!(1, learning.len());
+    }
+
+    #[test]
+    fn committing_defaults_leaves_scores_unchanged() {
+        let learning = Learning::new();
+        learning.record("向");
+        let options = Options {
+            learning: Some(&learning),
+            ..Options::default()
+        };
+        let mut before = [0u8; EXPORT_SIZE_MAX];
+        let size = learning.export(&mut before).unwrap();
+        let mut session = Session::with_options(options);
+        assert_eq!("向", commit(&mut session, "xiang").to_s());
+        assert_eq!("向", commit(&mut session, "xiang ").to_s());
+        assert_eq!("你向", commit(&mut session, "ni xiang").to_s());
+        let mut after = [0u8; EXPORT_SIZE_MAX];
+        assert_eq!(Some(size), learning.export(&mut after));
+        assert!(before[..size] == after[..size]);
+    }
+
+    #[test]
+    fn default_accepts_keep_learned_word_fresh() {
+        let learning = Learning::new();
+        for _ in 0..3 {
+            learning.record("像");
+        }
+        let options = Options {
+            learning: Some(&learning),
+            ..Options::default()
+        };
+        let mut session = Session::with_options(options);
+        for i in 0..4 * HALF_LIFE {
+            learning.record("其他");
+            if i % (HALF_LIFE / 2) == 0 {
+                assert_eq!("像", commit(&mut session, "xiang ").to_s());
+            }
+        }
+        // One recent pick of 向 does not beat the word that kept getting used
+        assert_eq!("向", commit(&mut session, "xiang3").to_s());
+        let sink = &mut BufWriter::new();
+        assert!(look_up_learned("xiang", &learning, sink).starts_with(" (1像 2向"));
+    }
+
+    #[test]
+    fn learning_is_off_without_option() {
+        let learning = Learning::new();
+        learning.record("像");
+        let mut session = Session::new();
+        assert_eq!("想", commit(&mut session, "xiang").to_s());
+        assert_eq!(1, learning.len());
+    }
+
+    #[test]
+    fn full_learning_replaces_lowest_score() {
+        let learning = Learning::new();
+        for _ in 0..3 {
+            learning.record("像");
+        }
+        let mut text = [0u8; 4];
+        for i in 0..LEARNING_SIZE as u32 {
+            // Hangul syllables, so none of them are candidates for xiang
+            let c = char::from_u32(0xac00 + i).unwrap();
+            learning.record(c.encode_utf8(&mut text));
+        }
+        assert_eq!(LEARNING_SIZE, learning.len());
+        let sink = &mut BufWriter::new();
+        assert!(look_up_learned("xiang", &learning, sink).starts_with(" (1像"));
+    }
+
+    #[test]
+    fn export_and_import_state() {
+        let learning = Learning::new();
+        learning.record("向");
+        learning.record("像");
+        let mut buf = [0u8; EXPORT_SIZE_MAX];
+        let size = learning.export(&mut buf).unwrap();
+        assert_eq!(HEADER_SIZE + 2 * RECORD_SIZE, size);
+        assert_eq!(None, learning.export(&mut buf[..size - 1]));
+        let imported = Learning::from_bytes(&buf[..size]).unwrap();
+        let sink = &mut BufWriter::new();
+        assert!(look_up_learned("xiang", &imported, sink).starts_with(" (1像 2向 3想"));
+        let mut again = [0u8; EXPORT_SIZE_MAX];
+        assert_eq!(Some(size), imported.export(&mut again));
+        assert!(buf[..size] == again[..size]);
+        // Bad bytes
+        assert_eq!(Err(LearningError::Magic), Learning::from_bytes(b"IMED"));
+        assert_eq!(
+            Err(LearningError::Layout),
+            Learning::from_bytes(&buf[..size - 1])
+        );
+        buf[4] = 2;
+        assert_eq!(
+            Err(LearningError::Version(2)),
+            Learning::from_bytes(&buf[..size])
+        );
+        imported.clear();
+        assert!(imported.is_empty());
+    }
+}
@@ -1,10 +1,26 @@
 #![no_std]
 
+pub mod annotate;
 pub mod constants;
+pub mod dictionary;
+pub mod fuzzy;
+pub mod hangul;
+pub mod kana;
+pub mod learning;
+pub mod options;
+pub mod segment;
+pub mod session;
+pub mod shuangpin;
+pub mod syllable;
+pub mod user_dictionary;
+pub mod zhuyin;
 
-// Static word list arrays generated by vocab precompute ruby script
-// CiyuIndex is type for phrases listed in autogen_hsk::CIYU array
+// Built-in dictionary bytes generated by `cargo run --bin autogen-hsk`
+// CiyuIndex is type for search keys of a dictionary::Dictionary
+#[allow(clippy::needless_borrow, clippy::redundant_static_lifetimes)]
 mod autogen_hsk;
+#[allow(clippy::needless_borrow, clippy::redundant_static_lifetimes)]
+mod autogen_jyutping;
 type CiyuIndex = usize;
 
 #[no_mangle]
@@ -27,7 +43,7 @@ struct Utf8Str<'a> {
 }
 impl<'a> Utf8Str<'a> {
     #[no_mangle]
-    pub fn new(str_slice: &'a str) -> Utf8Str {
+    pub fn new(str_slice: &'a str) -> Utf8Str<'a> {
         // Find start (inclusive lower bound) and end (exclusive upper bound) byte
         // index of each UTF-8 character in string slice
         let mut char_start_list: [usize; constants::BUF_SIZE] = [0; constants::BUF_SIZE];
@@ -46,8 +62,8 @@ impl<'a> Utf8Str<'a> {
         }
         Utf8Str {
             str_slice,
-            char_start_list: char_start_list,
-            char_end_list: char_end_list,
+            char_start_list,
+            char_end_list,
             char_count,
         }
     }
@@ -56,7 +72,7 @@ impl<'a> Utf8Str<'a> {
     // Using get(start..end) instead of [start..end] avoids possible panic.
     // This follows start..end range semantics (upper bound exclusive).
     #[no_mangle]
-    pub fn char_slice(&self, start: usize, end: usize) -> Option<&str> {
+    pub fn char_slice(&self, start: usize, end: usize) -> Option<&'a str> {
         // Subtle point: implicit test for end > 0
         if start < end && end <= constants::BUF_SIZE {
             let start_b = self.char_start_list[start];
@@ -73,33 +89,229 @@ impl<'a> Utf8Str<'a> {
 // TokenQueue is no_std, stack-only substitute for Vec<Token>. If TokenQueue
 // were Vec<Token>, it would require heap allocation and linking std.
 pub mod lex {
+    use crate::dictionary::Dictionary;
+    use crate::segment::{Candidates, Segments};
     const TOKEN_QUEUE_SIZE: usize = crate::constants::BUF_SIZE;
-    // Holds one Token.
+    // Candidates of all the choices in a TokenQueue share one pool
+    pub const CAND_POOL_SIZE: usize = 4 * crate::constants::BUF_SIZE;
+    // Most candidates that one choice can hold
+    pub const CAND_LIST_SIZE: usize = 64;
+    // Search key index of candidates that are user dictionary entries
+    pub const USER_CIYU: crate::CiyuIndex = usize::MAX;
+    // Holds one 词语 candidate: option nth (counting from 0) of the tab
+    // separated homophones for search key ciyu of a dictionary. For ciyu ==
+    // USER_CIYU, it is the user dictionary entry at byte position nth instead.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+    pub struct Cand {
+        pub ciyu: crate::CiyuIndex,
+        pub nth: usize,
+    }
+    impl Cand {
+        pub fn text<'d>(&self, dictionary: &Dictionary<'d>) -> &'d str {
+            if self.ciyu == USER_CIYU {
+                return dictionary.user_ciyu(self.nth);
+            }
+            dictionary
+                .ciyu(self.ciyu)
+                .split('\t')
+                .nth(self.nth)
+                .unwrap_or("")
+        }
+        // Text of candidate in Traditional characters with traditional, or
+        // else the same as text(). User entries are always as entered.
+        pub fn script_text<'d>(&self, dictionary: &Dictionary<'d>, traditional: bool) -> &'d str {
+            if !traditional || self.ciyu == USER_CIYU {
+                return self.text(dictionary);
+            }
+            match dictionary.traditional(self.ciyu).split('\t').nth(self.nth) {
+                Some(t) if !t.is_empty() => t,
+                _ => self.text(dictionary),
+            }
+        }
+        // Tone digits of candidate, one per syllable, with alternate
+        // readings joined by ',' (like "3,4" for 好). User entries have no
+        // tones.
+        pub fn tones<'d>(&self, dictionary: &Dictionary<'d>) -> &'d str {
+            if self.ciyu == USER_CIYU {
+                return "";
+            }
+            dictionary
+                .tones(self.ciyu)
+                .split('\t')
+                .nth(self.nth)
+                .unwrap_or("")
+        }
+        // HSK level of candidate (0 if unknown, like for user entries and
+        // punctuation), and whether it is from an -extra list.
+        pub fn level(&self, dictionary: &Dictionary) -> (usize, bool) {
+            if self.ciyu == USER_CIYU {
+                return (0, false);
+            }
+            let level = dictionary
+                .levels(self.ciyu)
+                .split('\t')
+                .nth(self.nth)
+                .unwrap_or("");
+            let digit = level.bytes().next().filter(u8::is_ascii_digit);
+            (
+                digit.map_or(0, |d| usize::from(d - b'0')),
+                level.ends_with('+'),
+            )
+        }
+        // Part of speech and meaning of each sense of candidate. User
+        // entries have no glosses.
+        pub fn glosses<'d>(
+            &self,
+            dictionary: &Dictionary<'d>,
+        ) -> impl Iterator<Item = crate::dictionary::Gloss<'d>> {
+            let glosses = match self.ciyu {
+                USER_CIYU => "",
+                ciyu => dictionary
+                    .glosses(ciyu)
+                    .split('\t')
+                    .nth(self.nth)
+                    .unwrap_or(""),
+            };
+            crate::dictionary::senses(glosses)
+        }
+    }
+    // Holds list of candidates for one match (append only). This is for
+    // collecting candidates before pushing them to a TokenQueue.
+    pub struct CandList {
+        list: [Cand; CAND_LIST_SIZE],
+        count: usize,
+    }
+    impl Default for CandList {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+    impl CandList {
+        pub fn new() -> CandList {
+            CandList {
+                list: [Cand { ciyu: 0, nth: 0 }; CAND_LIST_SIZE],
+                count: 0,
+            }
+        }
+        // Add candidate to list (duplicates are ignored).
+        pub fn push(&mut self, cand: Cand) -> bool {
+            if self.as_slice().contains(&cand) {
+                true
+            } else if self.count < CAND_LIST_SIZE {
+                self.list[self.count] = cand;
+                self.count += 1;
+                true
+            } else {
+                // Error: List is full
+                false
+            }
+        }
+        // Add all homophones for search key ciyu_i that pass filter, except
+        // for 词语 that are already in the list as user entries.
+        pub fn push_ciyu(
+            &mut self,
+            dictionary: &Dictionary,
+            ciyu_i: crate::CiyuIndex,
+            filter: impl Fn(&Cand) -> bool,
+        ) {
+            for nth in 0..dictionary.ciyu(ciyu_i).split('\t').count() {
+                let cand = Cand { ciyu: ciyu_i, nth };
+                if filter(&cand) && !self.has_user_text(dictionary, cand.text(dictionary)) {
+                    self.push(cand);
+                }
+            }
+        }
+        // Add user entries for search key that pass filter.
+        pub fn push_user(
+            &mut self,
+            dictionary: &Dictionary,
+            key: &str,
+            filter: impl Fn(&Cand) -> bool,
+        ) {
+            for (pos, user_key, _) in dictionary.user_records() {
+                let cand = Cand {
+                    ciyu: USER_CIYU,
+                    nth: pos,
+                };
+                if user_key == key && filter(&cand) {
+                    self.push(cand);
+                }
+            }
+        }
+        // Is 词语 text already in the list as a user entry?
+        fn has_user_text(&self, dictionary: &Dictionary, text: &str) -> bool {
+            self.as_slice()
+                .iter()
+                .any(|c| c.ciyu == USER_CIYU && c.text(dictionary) == text)
+        }
+        pub fn clear(&mut self) {
+            self.count = 0;
+        }
+        pub fn len(&self) -> usize {
+            self.count
+        }
+        pub fn is_empty(&self) -> bool {
+            self.count == 0
+        }
+        pub fn as_slice(&self) -> &[Cand] {
+            &self.list[..self.count]
+        }
+        pub fn as_mut_slice(&mut self) -> &mut [Cand] {
+            &mut self.list[..self.count]
+        }
+        // Keep only the candidates that pass filter, in the same order.
+        pub fn retain(&mut self, filter: impl Fn(&Cand) -> bool) {
+            let mut kept = 0;
+            for i in 0..self.count {
+                if filter(&self.list[i]) {
+                    self.list[kept] = self.list[i];
+                    kept += 1;
+                }
+            }
+            self.count = kept;
+        }
+    }
+    // Holds one Token. CiOpenChoice holds (start, count) of its candidates
+    // in the candidate pool of the TokenQueue.
     #[derive(Copy, Clone)]
     pub enum Token {
-        CiOne(crate::CiyuIndex),
-        CiOpenChoice(crate::CiyuIndex),
+        CiOne(Cand),
+        CiOpenChoice(usize, usize),
         MaybeChoice(char),
         Other(char),
         Skip,
     }
-    // Holds queue of Tokens (append only)
+    // Holds queue of Tokens (append only, except for truncate). Each token
+    // remembers the start..end character range of query text it came from.
+    #[derive(Copy, Clone)]
     pub struct TokenQueue {
         pub queue: [Token; TOKEN_QUEUE_SIZE],
+        pub span: [(usize, usize); TOKEN_QUEUE_SIZE],
         pub count: usize,
+        pub cands: [Cand; CAND_POOL_SIZE],
+        pub cand_count: usize,
+    }
+    impl Default for TokenQueue {
+        fn default() -> Self {
+            Self::new()
+        }
     }
     impl TokenQueue {
         // Initialize queue.
         pub fn new() -> TokenQueue {
             TokenQueue {
                 queue: [Token::Skip; TOKEN_QUEUE_SIZE],
+                span: [(0, 0); TOKEN_QUEUE_SIZE],
                 count: 0,
+                cands: [Cand { ciyu: 0, nth: 0 }; CAND_POOL_SIZE],
+                cand_count: 0,
             }
         }
-        // Add Token to queue.
-        pub fn push(&mut self, tk: Token) -> bool {
+        // Add Token for query character range start..end to queue.
+        pub fn push(&mut self, tk: Token, start: usize, end: usize) -> bool {
             if self.count < TOKEN_QUEUE_SIZE {
                 self.queue[self.count] = tk;
+                self.span[self.count] = (start, end);
                 self.count += 1;
                 true
             } else {
@@ -107,27 +319,80 @@ pub mod lex {
                 false
             }
         }
-        // Iterate through tokens, resolve choices, render as strings.
-        // Side-effect: render strings into buffer provided by Writer.
+        // Add token for a 词语 match of query characters start..end. One
+        // candidate gives CiOne, more than one gives CiOpenChoice.
+        pub fn push_cands(&mut self, cands: &[Cand], start: usize, end: usize) -> bool {
+            match cands.len() {
+                0 => false,
+                1 => self.push(Token::CiOne(cands[0]), start, end),
+                _ => self.push_open_choice(cands, start, end),
+            }
+        }
+        // Add CiOpenChoice token for candidates, even if there is only one.
+        pub fn push_open_choice(&mut self, cands: &[Cand], start: usize, end: usize) -> bool {
+            let n = cands.len();
+            let pool_start = self.cand_count;
+            if n == 0 || pool_start + n > CAND_POOL_SIZE || self.count >= TOKEN_QUEUE_SIZE {
+                // Error: Pool is full
+                return false;
+            }
+            self.cands[pool_start..pool_start + n].copy_from_slice(cands);
+            self.cand_count += n;
+            self.push(Token::CiOpenChoice(pool_start, n), start, end)
+        }
+        // Return candidates of a CiOpenChoice token.
+        pub fn choice_cands(&self, pool_start: usize, n: usize) -> &[Cand] {
+            self.cands.get(pool_start..pool_start + n).unwrap_or(&[])
+        }
+        // Drop tokens from the end of the queue so that n tokens remain.
+        pub fn truncate(&mut self, n: usize) {
+            if n < self.count {
+                self.count = n;
+                self.cand_count = 0;
+                for tk in self.queue[..n].iter().rev() {
+                    if let Token::CiOpenChoice(pool_start, count) = *tk {
+                        self.cand_count = pool_start + count;
+                        break;
+                    }
+                }
+            }
+        }
+        // Iterate through tokens, resolve choices, collect results as segments.
+        // Side-effect: push segments into out. Query is needed for slicing
+        // pinyin keys and pass-through text from the token spans.
         // Possible surprising behavior:
         // - Value of CiOpenChoice depends on lookahead for MaybeChoice
         // - MaybeChoice gets consumed (skipped) if used to resolve choice
-        pub fn render_and_write(&mut self, sink: &mut impl super::Writer) {
+        // - With commit=true, open choices that are not resolved by lookahead
+        //   get their default option instead of staying open
+        pub(crate) fn resolve<'a>(
+            &'a self,
+            query: &crate::Utf8Str<'a>,
+            options: &crate::options::Options<'a>,
+            commit: bool,
+            out: &mut Segments<'a>,
+        ) {
+            let page_size = options.choice_page_size();
+            out.use_dictionary(&options.dictionary, options.traditional);
+            // Lookahead marks consumed tokens as Skip, so work from a copy
+            let mut queue = self.queue;
             let mut current = 0;
-            let mut utf8_buf = [0u8; 4];
             while current < self.count {
-                match self.queue[current] {
+                let (start, end) = self.span[current];
+                match queue[current] {
                     // CiOne: This is an clear pinyin match for just one 词语
-                    Token::CiOne(ciyu_i) => {
-                        sink.write(&crate::autogen_hsk::CIYU[ciyu_i]);
+                    Token::CiOne(cand) => {
+                        out.push_committed(
+                            cand.script_text(&options.dictionary, options.traditional),
+                        );
                         // Look ahead for adjacent space that might be intended
                         // to prevent this ciyu from getting matched as part
                         // of the pinyin for another longer ciyu
                         if current + 1 < self.count {
-                            if let Token::MaybeChoice(tk) = self.queue[current + 1] {
+                            if let Token::MaybeChoice(tk) = queue[current + 1] {
                                 // Consume the space
                                 if tk == ' ' {
-                                    self.queue[current + 1] = Token::Skip;
+                                    queue[current + 1] = Token::Skip;
                                 }
                             }
                         }
@@ -136,36 +401,84 @@ pub mod lex {
                     // CiOpenChoice: This is an ambiguous pinyin match for
                     // a set of homphone 词语 that require further input to
                     // resolve the choice between them
-                    Token::CiOpenChoice(ciyu_i) => {
-                        let ciyu = &crate::autogen_hsk::CIYU[ciyu_i];
-                        // Look ahead for a possible MaybeChoice token to
-                        // resolve the open choice
-                        let mut choice_resolved = false;
-                        for i in current..self.count {
-                            if let Token::MaybeChoice(tk) = self.queue[i] {
-                                match crate::expand_choice_and_write(ciyu, tk, sink) {
-                                    crate::ExpandChoiceResult::WasChoice => {
-                                        self.queue[i] = Token::Skip;
-                                        choice_resolved = true;
-                                        break;
-                                    }
-                                    crate::ExpandChoiceResult::WasNotChoice => {}
+                    Token::CiOpenChoice(pool_start, n) => {
+                        let candidates = Candidates::new(
+                            self.choice_cands(pool_start, n),
+                            &options.dictionary,
+                            options.traditional,
+                        );
+                        // Look ahead for possible MaybeChoice tokens to page
+                        // through the candidates or resolve the open choice.
+                        // Page keys only belong to choices with more than
+                        // one page, otherwise they stay available for later
+                        // choices or pass through.
+                        let page_count = candidates.page_count(page_size);
+                        let mut page = 0;
+                        let mut pick = None;
+                        let mut numbered = false;
+                        for tk in queue[current..self.count].iter_mut() {
+                            if let Token::MaybeChoice(c) = *tk {
+                                if page_count > 1 && c == options.page_next {
+                                    page = crate::min(page + 1, page_count - 1);
+                                    *tk = Token::Skip;
+                                    continue;
+                                }
+                                if page_count > 1 && c == options.page_prev {
+                                    page = page.saturating_sub(1);
+                                    *tk = Token::Skip;
+                                    continue;
+                                }
+                                pick = candidates.pick(c, page, page_size);
+                                if pick.is_some() {
+                                    numbered = c != ' ';
+                                    *tk = Token::Skip;
+                                    break;
                                 }
                             }
                         }
-                        if !choice_resolved {
-                            // TODO: use enum variant instead of '0' to indicate no MaybeChoice found
-                            let _ = crate::expand_choice_and_write(ciyu, '0', sink);
+                        // Spacebar pick and commit both use the first
+                        // candidate of the current page
+                        let default = page * page_size;
+                        if commit && pick.is_none() {
+                            pick = Some(default);
                         }
+                        match pick.and_then(|i| candidates.as_cands().get(i)) {
+                            Some(cand) => {
+                                // Only committed picks are final, since preedit
+                                // gets resolved again after every key. Number
+                                // picks count, and accepted defaults keep an
+                                // already learned 词语 fresh. Learning is the
+                                // same for both scripts.
+                                if let (true, Some(learning)) = (commit, options.learning) {
+                                    let text = cand.text(&options.dictionary);
+                                    match numbered {
+                                        true => learning.record(text),
+                                        false => learning.touch(text),
+                                    }
+                                }
+                                let traditional = options.traditional;
+                                out.push_committed(
+                                    cand.script_text(&options.dictionary, traditional),
+                                )
+                            }
+                            None => out.push_choice(
+                                query.char_slice(start, end).unwrap_or(""),
+                                self.choice_cands(pool_start, n),
+                                default,
+                                page,
+                                page_size,
+                                (start, end),
+                            ),
+                        };
                     }
 
                     // MaybeChoice: This is for spaces or numbers that should
                     // be passed through unchanged because they were not
                     // consumed by the lookahead from a CiOne or CiOpenChoice
-                    Token::MaybeChoice(tk) => sink.write(tk.encode_utf8(&mut utf8_buf)),
-
                     // Other: This is for stuff like "UPPER CASE" or emoji
-                    Token::Other(tk) => sink.write(tk.encode_utf8(&mut utf8_buf)),
+                    Token::MaybeChoice(_) | Token::Other(_) => {
+                        out.push_pass_through(query, start, end);
+                    }
 
                     // Skip: This marks spaces and numbers consumed by the
                     // lookahead for CiOne or CiOpenChoice, and it fills empty
@@ -174,7 +487,7 @@ pub mod lex {
                 }
                 current += 1;
             } // end while
-        } // end render_and_write()
+        } // end resolve()
     } // end impl TokenQueue
 } // end lex
 
@@ -195,7 +508,7 @@ pub fn murmur3(key: &str, seed: u32) -> u32 {
         h = h.wrapping_mul(5);
         h = h.wrapping_add(0xe6546b64);
     }
-    h ^= key.bytes().count() as u32;
+    h ^= key.len() as u32;
     // Finalize with avalanche
     h ^= h >> 16;
     h = h.wrapping_mul(0x85ebca6b);
@@ -204,163 +517,663 @@ pub fn murmur3(key: &str, seed: u32) -> u32 {
     h ^ (h >> 16)
 }
 
+// Find 词语 matches for exactly the start..end character range of query
+// buffer. Apostrophes in the range are explicit syllable boundaries: they are
+// left out of the pinyin key, and only 词语 whose syllables can line up with
+// the boundaries count as matches (so "xi'an" matches 西安, but not 先). The
+// range must not start or end with an apostrophe. With the tone_input option,
+// tone numbers after syllables (xiang3) and tone marks (xiǎng) are left out
+// of the key too, and they filter candidates by tone. With the fuzzy option,
+// 词语 for fuzzy variants of the pinyin (like "zongguo" for 中国) come after
+// the exact matches. With the abbreviations option, 词语 for the range as
+// initials (like "gz" for 果汁) come last.
+// Side-effect: Clear cands, then push candidates for the match into cands.
+// Return: true if there was at least one candidate
+fn exact_match(
+    query: &Utf8Str,
+    start: usize,
+    end: usize,
+    options: &options::Options,
+    cands: &mut lex::CandList,
+) -> bool {
+    cands.clear();
+    let query_slice = match query.char_slice(start, end) {
+        Some(s) => s,
+        None => return false,
+    };
+    if query_slice.starts_with('\'') || query_slice.ends_with('\'') {
+        return false;
+    }
+    // Build normalized pinyin key, remembering the byte offsets of syllable
+    // boundaries, and the tone for bytes of the key in marks
+    let mut buf = [0u8; 4 * dictionary::KEY_SIZE_MAX];
+    let mut marks = [0u8; 4 * dictionary::KEY_SIZE_MAX];
+    let char_at = |i| query.char_slice(i, i + 1).and_then(|s| s.chars().next());
+    let key = match options.input {
+        options::Input::Pinyin => pinyin_key(query_slice, options, &mut buf, &mut marks),
+        options::Input::Zhuyin => {
+            let prev = start.checked_sub(1).and_then(char_at);
+            zhuyin::to_key(query_slice, prev, char_at(end), &mut buf, &mut marks)
+        }
+        options::Input::Shuangpin(layout) => {
+            // Syllables are pairs of keys from the start of each run of keys,
+            // so a match must start an even number of keys into its run
+            let run = (0..start).rev().map(char_at);
+            let keys_before = run
+                .take_while(|c| c.is_some_and(|c| layout.is_key(c)))
+                .count();
+            match keys_before % 2 {
+                0 => shuangpin::to_key(&layout, query_slice, &mut buf, &mut marks),
+                _ => None,
+            }
+        }
+        options::Input::Hangul | options::Input::Hiragana | options::Input::Katakana => None,
+    };
+    let (len, boundaries) = match key {
+        Some(key) => key,
+        None => return false,
+    };
+    // Removing and replacing whole characters of valid UTF-8 leaves valid UTF-8
+    let key_str = match core::str::from_utf8(&buf[..len]) {
+        Ok(s) => s,
+        Err(_) => return false,
+    };
+    let marks = &marks[..len];
+    let marked = marks.iter().any(|&t| t != 0);
+    let dict = &options.dictionary;
+    // User entries go first. They have no tones, so they fit any tone marks.
+    cands.push_user(dict, key_str, |cand| {
+        (boundaries == 0 && !marked) || fits_cand(dict, key_str, boundaries, marks, cand)
+    });
+    if let Some(ciyu_i) = dict.find_pinyin(key_str) {
+        if marked {
+            // Candidates known to have the right tones go first
+            cands.push_ciyu(dict, ciyu_i, |cand| {
+                !cand.tones(dict).contains('0') && fits_cand(dict, key_str, boundaries, marks, cand)
+            });
+        }
+        cands.push_ciyu(dict, ciyu_i, |cand| {
+            (boundaries == 0 && !marked) || fits_cand(dict, key_str, boundaries, marks, cand)
+        });
+    }
+    if !options.fuzzy.is_empty() {
+        fuzzy::for_each_variant(
+            key_str,
+            boundaries,
+            marks,
+            options.fuzzy,
+            &mut |variant, bound, variant_marks| {
+                cands.push_user(dict, variant, |cand| {
+                    fits_cand(dict, variant, bound, variant_marks, cand)
+                });
+                if let Some(ciyu_i) = dict.find_pinyin(variant) {
+                    cands.push_ciyu(dict, ciyu_i, |cand| {
+                        fits_cand(dict, variant, bound, variant_marks, cand)
+                    });
+                }
+                true
+            },
+        );
+    }
+    if options.abbreviations && options.input == options::Input::Pinyin {
+        if let Some(abbrev_i) = dict.find_abbrev(key_str) {
+            for (ciyu, nth) in dict.abbrev_cands(abbrev_i) {
+                cands.push(lex::Cand { ciyu, nth });
+            }
+        }
+    }
+    if options.input != options::Input::Pinyin {
+        // Zhuyin and shuangpin give every syllable boundary, so 西安 must
+        // not match one syllable xian
+        let count = boundaries.count_ones() as usize;
+        cands.retain(|cand| {
+            cand.ciyu == lex::USER_CIYU || syllable::syllable_count(cand.text(dict)) == count
+        });
+    }
+    if options.level_max > 0 {
+        cands.retain(|cand| options.allows_level(cand.level(dict).0));
+    }
+    if let Some(learning) = options.learning {
+        learning.reorder(dict, cands.as_mut_slice());
+    }
+    !cands.is_empty()
+}
+
+// Convert pinyin text of query to a normalized key, without apostrophes and
+// tones.
+// Side-effect: write key to buf, and the tone for bytes of the key in marks.
+// Return: (length of key, bitmask of syllable boundary offsets), or None if
+// text does not fit in buf or has a misplaced tone number
+fn pinyin_key(
+    text: &str,
+    options: &options::Options,
+    buf: &mut [u8],
+    marks: &mut [u8],
+) -> Option<(usize, u64)> {
+    let mut len = 0;
+    let mut boundaries: u64 = 0;
+    let mut after_letter = false;
+    for c in text.chars() {
+        if c == '\'' || (options.tone_input && is_tone_number(c, options)) {
+            if c != '\'' {
+                // Tone number must follow a letter of the syllable it marks
+                if !after_letter {
+                    return None;
+                }
+                marks[len - 1] = c as u8 - b'0';
+            }
+            boundaries |= 1u64.checked_shl(len as u32).unwrap_or(0);
+            after_letter = false;
+            continue;
+        }
+        let mut utf8 = [0u8; 4];
+        let (bytes, tone) = match syllable::unmark(c) {
+            Some((letter, tone)) if options.tone_input => {
+                utf8[0] = letter;
+                (&utf8[..1], tone)
+            }
+            _ => (c.encode_utf8(&mut utf8).as_bytes(), 0),
+        };
+        if len + bytes.len() > buf.len() {
+            return None;
+        }
+        buf[len..len + bytes.len()].copy_from_slice(bytes);
+        marks[len] = tone;
+        len += bytes.len();
+        after_letter = bytes.len() == 1 && bytes[0].is_ascii_lowercase();
+    }
+    Some((len, boundaries))
+}
+
+// Can candidate be split into syllables that line up with the boundaries and
+// tone marks of key? Candidates with unknown tones fit any tone marks.
+fn fits_cand(
+    dict: &dictionary::Dictionary,
+    key: &str,
+    boundaries: u64,
+    marks: &[u8],
+    cand: &lex::Cand,
+) -> bool {
+    let count = syllable::syllable_count(cand.text(dict));
+    let scheme = dict.scheme();
+    if marks.iter().all(|&t| t == 0) {
+        return syllable::fits(scheme, key, boundaries, count);
+    }
+    cand.tones(dict)
+        .split(',')
+        .any(|tones| match tones.len() == count {
+            true => syllable::fits_tones(scheme, key, boundaries, marks, tones.as_bytes()),
+            false => syllable::fits(scheme, key, boundaries, count),
+        })
+}
+
+// Is c a tone number of the dictionary's scheme (1 to 5 for pinyin, 1 to 6
+// for Jyutping)?
+fn is_tone_number(c: char, options: &options::Options) -> bool {
+    let max = options.dictionary.scheme().tone_max();
+    ('1'..=char::from(b'0' + max)).contains(&c)
+}
+
+// Find end of the match window starting at start. Apostrophes and tone
+// numbers (or Zhuyin tone keys) do not count toward the window size limit of
+// the longest phrase in the pinyin array.
+// Return: end boundary character of window
+fn window_end(query: &Utf8Str, start: usize, end: usize, options: &options::Options) -> usize {
+    let end = min(query.char_count, end);
+    let mut size = 0;
+    let mut i = start;
+    while i < end && size < options.dictionary.key_size_max() {
+        if counts_toward_window(query, i, options) {
+            size += 1;
+        }
+        i += 1;
+    }
+    i
+}
+
+// Return: false if character i of query is an apostrophe, tone number, or
+// Zhuyin tone key that window_end skips over
+pub(crate) fn counts_toward_window(query: &Utf8Str, i: usize, options: &options::Options) -> bool {
+    match query.char_slice(i, i + 1).and_then(|s| s.chars().next()) {
+        Some('\'') => false,
+        Some(c) if options.tone_input && is_tone_number(c, options) => false,
+        Some(c) if options.input == options::Input::Zhuyin && zhuyin::is_tone(c) => false,
+        _ => true,
+    }
+}
+
 // Find longest 词语 match in start..end character window of query buffer.
-// Side-effect: None.
-// Return: (index in 词语 array for match, end boundary character in query for match)
-fn longest_match(query: &Utf8Str, start: usize, mut end: usize) -> Option<(CiyuIndex, usize)> {
+// Side-effect: Push candidates for the match into cands.
+// Return: end boundary character in query for match
+fn longest_match(
+    query: &Utf8Str,
+    start: usize,
+    mut end: usize,
+    options: &options::Options,
+    cands: &mut lex::CandList,
+) -> Option<usize> {
     end = min(query.char_count, end);
     // Subtle point: implicit test for end > 0
     while end > start {
-        if let Some(query_slice) = query.char_slice(start, end) {
-            let key = murmur3(&query_slice, autogen_hsk::MURMUR3_SEED);
-            if let Ok(ciyu) = autogen_hsk::PINYIN.binary_search(&key) {
-                return Some((ciyu, end));
-            }
+        if exact_match(query, start, end, options, cands) {
+            return Some(end);
         }
         // Must not allow end==0 here. For usize, (0 - 1) will panic.
         end -= 1;
     }
-    return None;
+    None
 }
 
-// Render 词语 multi-matches as resolved choice or prompt for choice.
-// Side-effect: render strings into buffer provided by Writer.
-// Return: Was the maybe_choice token used to resolve a choice?
-enum ExpandChoiceResult {
-    WasChoice,
-    WasNotChoice,
-}
-fn expand_choice_and_write(
-    ciyu: &str,
-    maybe_choice: char,
+// Search for 词语 matches in substrings of query.
+// Side-effect: Push tokens into queue.
+fn search(
+    query: &Utf8Str,
+    queue: &mut lex::TokenQueue,
+    start: usize,
+    end: usize,
+    options: &options::Options,
     sink: &mut impl Writer,
-) -> ExpandChoiceResult {
-    let n = ciyu.split("\t").count();
-    if n == 1 {
-        // If this ever happens, there's a bug. Log and recover.
-        sink.trace(901);
-        sink.write(ciyu);
-        return ExpandChoiceResult::WasNotChoice;
-    }
-    // Try to pick a choice (return immediately if number out of range)
-    let pick = match maybe_choice {
-        ' ' => 1, // Spacebar picks default option (label=1)
-        '1' => 1,
-        '2' => 2,
-        '3' => 3,
-        '4' => 4,
-        '5' => 5,
-        '6' => 6,
-        '7' => 7,
-        '8' => 8,
-        '9' => 9, // TODO: Fix. This only works for <=9 choices
-        _ => 0,
-    };
-    if pick > 0 {
-        for (i, choice) in ciyu.split("\t").enumerate() {
-            if i + 1 == pick {
-                sink.write(choice);
-                return ExpandChoiceResult::WasChoice;
-            }
-        }
-        // Out of range for possible choice, so return without sink.write() to
-        // prevent duplicate choice prompting
-        return ExpandChoiceResult::WasNotChoice;
-    }
-    // Show all choices
-    sink.write(&" (");
-    for (i, choice) in ciyu.split("\t").enumerate() {
-        sink.write(match i {
-            0 => &"1",
-            1 => &"2",
-            2 => &"3",
-            3 => &"4",
-            4 => &"5",
-            5 => &"6",
-            6 => &"7",
-            7 => &"8",
-            _ => &"9",
-        });
-        sink.write(choice);
-        if i + 1 < n {
-            sink.write(&" ");
+) {
+    // Hangul and kana have no 词语 to search for
+    if !options.input.searches() {
+        return;
+    }
+    let first_token = queue.count;
+    match options.segmentation {
+        options::Segmentation::Greedy => search_greedy(query, queue, start, end, options, sink),
+        options::Segmentation::Optimal => search_optimal(query, queue, start, end, options, sink),
+    }
+    complete_trailing(query, queue, first_token, end, options);
+}
+
+// Replace tokens at the end of the query with a choice of completions when
+// they end with an unmatched letter, like "xiangj" for 香蕉 xiangjiao. The
+// completions come from the longest run of trailing tokens that has any.
+// Spaces, digits, and page keys after the run stay after the choice, so they
+// can pick a completion ("xiangj " for 香蕉).
+// Side-effect: Truncate queue and push open choice for completions.
+fn complete_trailing(
+    query: &Utf8Str,
+    queue: &mut lex::TokenQueue,
+    first_token: usize,
+    end: usize,
+    options: &options::Options,
+) {
+    if options.completions == 0 || queue.count <= first_token || end != query.char_count {
+        return;
+    }
+    // Unfinished pinyin is only a key prefix for pinyin input
+    if options.input != options::Input::Pinyin {
+        return;
+    }
+    // Find last token of the run, before any trailing choice keys
+    let mut last = queue.count - 1;
+    while last > first_token {
+        match queue.queue[last] {
+            lex::Token::MaybeChoice(_) => last -= 1,
+            _ => break,
+        }
+    }
+    match queue.queue[last] {
+        lex::Token::Other(c) if c.is_ascii_lowercase() => {}
+        _ => return,
+    }
+    let run_end = queue.span[last].1;
+    // Find first token of the run of trailing tokens that are all letters
+    let mut first = last;
+    while first > first_token {
+        let (start, end) = queue.span[first - 1];
+        if !is_pinyin_letters(query, start, end) {
+            break;
+        }
+        first -= 1;
+    }
+    let mut cands = lex::CandList::new();
+    for t in first..=last {
+        let start = queue.span[t].0;
+        if prefix_match(query, start, run_end, options, &mut cands) {
+            queue.truncate(t);
+            if !queue.push_open_choice(cands.as_slice(), start, run_end) {
+                push_pass_through(query, queue, start, run_end);
+            }
+            // Put back the choice keys, which are one character each
+            for i in run_end..end {
+                if let Some(c) = query.char_slice(i, i + 1).and_then(|s| s.chars().next()) {
+                    let _ = queue.push(lex::Token::MaybeChoice(c), i, i + 1);
+                }
+            }
+            return;
         }
     }
-    sink.write(&") ");
-    return ExpandChoiceResult::WasNotChoice;
 }
 
-// Search for 词语 matches in substrings of query.
+// Are query characters start..end all pinyin letters or apostrophes?
+fn is_pinyin_letters(query: &Utf8Str, start: usize, end: usize) -> bool {
+    match query.char_slice(start, end) {
+        Some(s) => s.bytes().all(|b| b.is_ascii_lowercase() || b == b'\''),
+        None => false,
+    }
+}
+
+// Find 词语 whose pinyin starts with the query characters start..end, up to
+// options.completions of them, in vocab file order (easy words first).
+// Side-effect: Clear cands, then push completions into cands.
+// Return: true if there was at least one completion
+fn prefix_match(
+    query: &Utf8Str,
+    start: usize,
+    end: usize,
+    options: &options::Options,
+    cands: &mut lex::CandList,
+) -> bool {
+    cands.clear();
+    if !is_pinyin_letters(query, start, end) {
+        return false;
+    }
+    // Strip apostrophes
+    let mut buf = [0u8; constants::BUF_SIZE];
+    let mut len = 0;
+    for b in query.char_slice(start, end).unwrap_or("").bytes() {
+        if b != b'\'' && len < buf.len() {
+            buf[len] = b;
+            len += 1;
+        }
+    }
+    let prefix = core::str::from_utf8(&buf[..len]).unwrap_or("");
+    if prefix.is_empty() {
+        return false;
+    }
+    // Keep the best ranked keys as (rank, search key index), sorted by rank
+    let dict = &options.dictionary;
+    let cap = min(options.completions, lex::CAND_LIST_SIZE);
+    let mut best = [(0, 0); lex::CAND_LIST_SIZE];
+    let mut best_count = 0;
+    for entry in dict.prefix_start(prefix)..dict.prefix_len() {
+        let (ciyu, rank) = dict.prefix(entry);
+        if !dict.key(ciyu).starts_with(prefix) {
+            break;
+        }
+        let i = best[..best_count].partition_point(|&(r, _)| r < rank);
+        if i < cap {
+            best_count = min(best_count + 1, cap);
+            best.copy_within(i..best_count - 1, i + 1);
+            best[i] = (rank, ciyu);
+        }
+    }
+    for &(_, ciyu) in best[..best_count].iter() {
+        let homophones = dict.ciyu(ciyu).split('\t').count();
+        for nth in 0..homophones {
+            let cand = lex::Cand { ciyu, nth };
+            if cands.len() < cap && options.allows_level(cand.level(dict).0) {
+                cands.push(cand);
+            }
+        }
+    }
+    if let Some(learning) = options.learning {
+        learning.reorder(dict, cands.as_mut_slice());
+    }
+    !cands.is_empty()
+}
+
+// Search left to right, taking the longest match at each position.
 // Side-effect: Push tokens into queue.
-fn search(
+fn search_greedy(
     query: &Utf8Str,
     queue: &mut lex::TokenQueue,
     mut start: usize,
     end: usize,
+    options: &options::Options,
     sink: &mut impl Writer,
 ) {
+    let mut cands = lex::CandList::new();
     while start < end {
+        if is_page_key_at(query, start, options) {
+            push_char(query, queue, start, options, sink);
+            start += 1;
+            continue;
+        }
         // Limit window size to length of longest phrase in pinyin array
-        let window_end = min(start + autogen_hsk::PINYIN_SIZE_MAX, end);
-        if let Some((ciyu_i, match_end)) = longest_match(query, start, window_end) {
+        let window_end = window_end(query, start, end, options);
+        if let Some(match_end) = longest_match(query, start, window_end, options, &mut cands) {
             // Got Match: push match, continue search in remainder of query
-            if autogen_hsk::CIYU[ciyu_i].contains("\t") {
-                queue.push(lex::Token::CiOpenChoice(ciyu_i));
-            } else {
-                queue.push(lex::Token::CiOne(ciyu_i));
-            }
+            push_match(query, queue, &cands, start, match_end);
             start = match_end;
         } else {
             // No match... push one character, continue search in remainder of query
-            if let Some(s) = query.char_slice(start, start + 1) {
-                // TODO: Better solution than silently ignoring possible full queue
-                let _ = match s {
-                    // Space and digit characters may be intended to resolve a
-                    // choice of homophone 词语 from an earlier CiOpenChoice
-                    // token. Spaces may separate the pinyin from a CiOne token
-                    // so the pinyin does not get consumed as the prefix to a
-                    // longer 词语. Spaces and digits may also be intended to
-                    // pass through as ASCII.
-                    " " => queue.push(lex::Token::MaybeChoice(' ')),
-                    "1" => queue.push(lex::Token::MaybeChoice('1')),
-                    "2" => queue.push(lex::Token::MaybeChoice('2')),
-                    "3" => queue.push(lex::Token::MaybeChoice('3')),
-                    "4" => queue.push(lex::Token::MaybeChoice('4')),
-                    "5" => queue.push(lex::Token::MaybeChoice('5')),
-                    "6" => queue.push(lex::Token::MaybeChoice('6')),
-                    "7" => queue.push(lex::Token::MaybeChoice('7')),
-                    "8" => queue.push(lex::Token::MaybeChoice('8')),
-                    "9" => queue.push(lex::Token::MaybeChoice('9')),
-                    _ => {
-                        if let Some(c) = s.chars().nth(0) {
-                            // This covers stuff like "UPPER CASE" and emoji
-                            queue.push(lex::Token::Other(c))
-                        } else {
-                            // Reaching this branch is a bug. For nth(0) to
-                            // return None, s would have to be "" when
-                            // s.chars() gets called. The `if let Some(s)` and
-                            // `while start < end` above should not allow that
-                            // to happen.
-                            sink.trace(902);
-                            false
-                        }
-                    }
-                };
-            }
+            push_char(query, queue, start, options, sink);
             start += 1;
         }
     }
 }
 
+// Cost of segmenting part of the query. Tuples compare lexicographically, so
+// fewer unmatched characters always wins, then fewer segments, then more
+// total word frequency weight of the matches.
+type PathCost = (usize, usize, core::cmp::Reverse<u64>);
+
+// Search for the best overall segmentation of query with dynamic programming.
+// Each position considers every dictionary match in the window that starts
+// there, and the path with the lowest PathCost wins. Ties go to the longer
+// match at the earlier position, which is what greedy search would pick.
+// Side-effect: Push tokens into queue.
+fn search_optimal(
+    query: &Utf8Str,
+    queue: &mut lex::TokenQueue,
+    start: usize,
+    end: usize,
+    options: &options::Options,
+    sink: &mut impl Writer,
+) {
+    let end = min(end, constants::BUF_SIZE);
+    if start >= end {
+        return;
+    }
+    // cost[i] is the cost of the best path for query[i..end], and step[i] is
+    // the first step of that path as (end of step, is step a match).
+    let zero = (0, 0, core::cmp::Reverse(0));
+    let mut cost: [PathCost; constants::BUF_SIZE + 1] = [zero; constants::BUF_SIZE + 1];
+    let mut step: [(usize, bool); constants::BUF_SIZE + 1] = [(0, false); constants::BUF_SIZE + 1];
+    let mut cands = lex::CandList::new();
+    for i in (start..end).rev() {
+        // Unmatched character is always possible
+        let (unmatched, segments, weight) = cost[i + 1];
+        cost[i] = (unmatched + 1, segments + 1, weight);
+        step[i] = (i + 1, false);
+        if is_page_key_at(query, i, options) {
+            continue;
+        }
+        let mut j = window_end(query, i, end, options);
+        while j > i {
+            if exact_match(query, i, j, options, &mut cands) {
+                let (unmatched, segments, core::cmp::Reverse(weight)) = cost[j];
+                let weight = weight + u64::from(match_weight(&options.dictionary, &cands));
+                let c = (unmatched, segments + 1, core::cmp::Reverse(weight));
+                if c < cost[i] {
+                    cost[i] = c;
+                    step[i] = (j, true);
+                }
+            }
+            j -= 1;
+        }
+    }
+    // Follow the best path from start and push its tokens
+    let mut i = start;
+    while i < end {
+        let (next, matched) = step[i];
+        if matched && exact_match(query, i, next, options, &mut cands) {
+            push_match(query, queue, &cands, i, next);
+        } else {
+            push_char(query, queue, i, options, sink);
+        }
+        i = next;
+    }
+}
+
+// Return weight of the most common candidate of a match (user entries have no
+// weight).
+fn match_weight(dict: &dictionary::Dictionary, cands: &lex::CandList) -> u32 {
+    cands
+        .as_slice()
+        .iter()
+        .filter(|c| c.ciyu != lex::USER_CIYU)
+        .map(|c| dict.weight(c.ciyu))
+        .max()
+        .unwrap_or(0)
+}
+
+// Is the character at position i one of the page keys? Page keys may be
+// intended to page through choices from an earlier CiOpenChoice token, so
+// they win over matching as punctuation.
+fn is_page_key_at(query: &Utf8Str, i: usize, options: &options::Options) -> bool {
+    match query.char_slice(i, i + 1).and_then(|s| s.chars().next()) {
+        Some(c) => options.is_page_key(c),
+        None => false,
+    }
+}
+
+// Push token for 词语 match of query characters start..end.
+// Side-effect: Push token into queue, or pass-through tokens for the matched
+// characters if the candidate pool is full.
+fn push_match(
+    query: &Utf8Str,
+    queue: &mut lex::TokenQueue,
+    cands: &lex::CandList,
+    start: usize,
+    end: usize,
+) {
+    if !queue.push_cands(cands.as_slice(), start, end) {
+        push_pass_through(query, queue, start, end);
+    }
+}
+
+// Push an Other token for each of query characters start..end, so that text
+// which could not be stored as a match still shows up in the output.
+// Side-effect: Push tokens into queue.
+fn push_pass_through(query: &Utf8Str, queue: &mut lex::TokenQueue, start: usize, end: usize) {
+    for i in start..end {
+        if let Some(c) = query.char_slice(i, i + 1).and_then(|s| s.chars().next()) {
+            // TODO: Better solution than silently ignoring possible full queue
+            let _ = queue.push(lex::Token::Other(c), i, i + 1);
+        }
+    }
+}
+
+// Does a 词语 match end right at character start of query?
+fn follows_match(queue: &lex::TokenQueue, start: usize) -> bool {
+    match queue.count.checked_sub(1) {
+        Some(last) => {
+            let ends_here = queue.span[last].1 == start;
+            let is_match = matches!(
+                queue.queue[last],
+                lex::Token::CiOne(_) | lex::Token::CiOpenChoice(..)
+            );
+            ends_here && is_match
+        }
+        None => false,
+    }
+}
+
+// Push token for one query character that was not part of a 词语 match.
+// Side-effect: Push token into queue.
+fn push_char(
+    query: &Utf8Str,
+    queue: &mut lex::TokenQueue,
+    start: usize,
+    options: &options::Options,
+    sink: &mut impl Writer,
+) {
+    if let Some(s) = query.char_slice(start, start + 1) {
+        // TODO: Better solution than silently ignoring possible full queue
+        let _ = match s.chars().next() {
+            // Space and digit characters may be intended to resolve a choice
+            // of homophone 词语 from an earlier CiOpenChoice token. Spaces may
+            // separate the pinyin from a CiOne token so the pinyin does not
+            // get consumed as the prefix to a longer 词语. Spaces and digits
+            // may also be intended to pass through as ASCII. Page keys may be
+            // intended to page through choices.
+            Some(c) if c == ' ' || ('1'..='9').contains(&c) => {
+                queue.push(lex::Token::MaybeChoice(c), start, start + 1)
+            }
+            Some(c) if options.is_page_key(c) => {
+                queue.push(lex::Token::MaybeChoice(c), start, start + 1)
+            }
+            // Apostrophe right after a match is a syllable separator, so it
+            // gets consumed
+            Some('\'') if follows_match(queue, start) => {
+                queue.push(lex::Token::Skip, start, start + 1)
+            }
+            // This covers stuff like "UPPER CASE" and emoji
+            Some(c) => queue.push(lex::Token::Other(c), start, start + 1),
+            None => {
+                // Reaching this branch is a bug. For next() to return None, s
+                // would have to be "" when s.chars() gets called. The
+                // `if let Some(s)` and callers' range checks should not allow
+                // that to happen.
+                sink.trace(902);
+                false
+            }
+        };
+    }
+}
+
 // Look up 词语 for search query (pinyin keys are ASCII, but inbox is UTF-8).
 // Side-effect: renders utf8 result string into buffer provided by Writer.
 pub fn look_up(query_bytes: &str, sink: &mut impl Writer) {
+    look_up_with(query_bytes, &options::Options::default(), sink);
+}
+
+// Look up 词语 for search query, same as look_up(), but with options.
+// Side-effect: renders utf8 result string into buffer provided by Writer.
+pub fn look_up_with(query_bytes: &str, options: &options::Options, sink: &mut impl Writer) {
+    if !options.input.searches() {
+        return convert_keys(query_bytes, &options.input, sink);
+    }
     let query = Utf8Str::new(query_bytes);
     let mut queue = lex::TokenQueue::new();
     let start = 0;
     let end = query.char_count;
-    search(&query, &mut queue, start, end, sink);
-    queue.render_and_write(sink);
+    search(&query, &mut queue, start, end, options, sink);
+    let mut segments = segment::Segments::new();
+    queue.resolve(&query, options, false, &mut segments);
+    segments.render_and_write(options, sink);
+}
+
+// Convert keys of query for inputs without dictionary search, like 한글 for
+// "gksrmf" with Hangul input.
+// Side-effect: renders utf8 result string into buffer provided by Writer.
+fn convert_keys(query_bytes: &str, input: &options::Input, sink: &mut impl Writer) {
+    match input {
+        options::Input::Hangul => hangul::compose(query_bytes, sink),
+        options::Input::Hiragana => kana::convert(query_bytes, false, sink),
+        options::Input::Katakana => kana::convert(query_bytes, true, sink),
+        _ => {}
+    }
+}
+
+// Look up 词语 for search query, same as look_up_with(), but without text
+// rendering.
+// Side-effect: search query into queue, then push structured results into
+// out. Pinyin keys and pass-through text in the segments are slices of the
+// query string, 词语 are slices of the dictionary, and candidates of choices
+// are slices of the candidate pool of queue.
+pub fn look_up_segments<'a>(
+    query_bytes: &'a str,
+    options: &options::Options<'a>,
+    queue: &'a mut lex::TokenQueue,
+    out: &mut segment::Segments<'a>,
+) {
+    let query = Utf8Str::new(query_bytes);
+    queue.truncate(0);
+    search(
+        &query,
+        queue,
+        0,
+        query.char_count,
+        options,
+        &mut NullWriter {},
+    );
+    let queue: &'a lex::TokenQueue = queue;
+    queue.resolve(&query, options, false, out);
 }
 
 // Writer decouples query response formatting from stream IO implementation details.
@@ -370,11 +1183,27 @@ pub trait Writer {
     fn to_s(&self) -> &str;
 }
 
+// NullWriter is a Writer that discards everything. This is for functions that
+// return structured results and have nowhere to send traces.
+struct NullWriter {}
+impl Writer for NullWriter {
+    fn write(&mut self, _: &str) {}
+    fn trace(&mut self, _: i32) {}
+    fn to_s(&self) -> &str {
+        ""
+    }
+}
+
 // BufWriter is a Writer for string slices backed by stack allocated [u8].
 pub struct BufWriter {
     buf: [u8; constants::BUF_SIZE],
     buf_pos: usize,
 }
+impl Default for BufWriter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 impl BufWriter {
     // Return empty buffer ready for use.
     pub fn new() -> BufWriter {
@@ -405,10 +1234,8 @@ impl Writer for BufWriter {
 
     // Return string slice of buffer contents.
     fn to_s(&self) -> &str {
-        match core::str::from_utf8(&self.buf[0..self.buf_pos]) {
-            Ok(s) => &s,
-            Err(_) => &"", // TODO: handle mal-formed utf8 strings better
-        }
+        // TODO: handle mal-formed utf8 strings better
+        core::str::from_utf8(&self.buf[0..self.buf_pos]).unwrap_or_default()
     }
 }
 
@@ -416,25 +1243,37 @@ impl Writer for BufWriter {
 // This is for calling as a library function from rust.
 // Returns: string slice of results backed by sink.
 pub fn query<'a>(qry: &str, sink: &'a mut impl Writer) -> &'a str {
-    look_up(&qry, sink);
+    look_up(qry, sink);
     sink.to_s()
 }
 
 #[cfg(test)]
 mod tests {
     use super::autogen_hsk;
+    use super::autogen_jyutping;
     use super::constants;
+    use super::dictionary::Dictionary;
+    use super::fuzzy::Fuzzy;
+    use super::options::{Input, Options, Segmentation};
     use super::query;
+    use super::shuangpin::{MICROSOFT, XIAOHE, ZIRANMA};
     use super::BufWriter;
+    use super::Writer;
+
+    fn query_with<'a>(qry: &str, options: &Options, sink: &'a mut BufWriter) -> &'a str {
+        sink.rewind();
+        super::look_up_with(qry, options, sink);
+        sink.to_s()
+    }
 
     #[test]
     fn min_query() {
-        assert_eq!("", query(&"", &mut BufWriter::new()));
+        assert_eq!("", query("", &mut BufWriter::new()));
     }
 
     #[test]
     fn max_query() {
-        let buf_max = ['A' as u8; constants::BUF_SIZE];
+        let buf_max = [b'A'; constants::BUF_SIZE];
         let qry_max = core::str::from_utf8(&buf_max).unwrap();
         // This should be passed through unchanged as ASCII
         assert_eq!(qry_max, query(qry_max, &mut BufWriter::new()));
@@ -442,23 +1281,55 @@ mod tests {
 
     #[test]
     fn max_query_plus_1_truncate() {
-        let buf_max = ['A' as u8; constants::BUF_SIZE];
+        let buf_max = [b'A'; constants::BUF_SIZE];
         let qry_max = core::str::from_utf8(&buf_max).unwrap();
-        let buf_1_too_big = ['A' as u8; constants::BUF_SIZE + 1];
+        let buf_1_too_big = [b'A'; constants::BUF_SIZE + 1];
         let qry_1_too_big = core::str::from_utf8(&buf_1_too_big).unwrap();
         // This should truncate the query
         assert_eq!(qry_max, query(qry_1_too_big, &mut BufWriter::new()));
     }
 
+    #[test]
+    fn full_candidate_pool_passes_matches_through() {
+        let abbreviations = Options {
+            abbreviations: true,
+            ..Options::default()
+        };
+        // Each "s" is a choice with many candidates, so the pool fills up
+        let mut buf = [0u8; constants::BUF_SIZE];
+        for pair in buf.chunks_mut(2) {
+            pair.copy_from_slice(b"sX");
+        }
+        let qry = core::str::from_utf8(&buf).unwrap();
+        let mut queue = super::lex::TokenQueue::new();
+        let mut segments = super::segment::Segments::new();
+        super::look_up_segments(qry, &abbreviations, &mut queue, &mut segments);
+        // Every character is still there, as a choice key or as pass-through
+        let mut choices = 0;
+        let mut size = 0;
+        for segment in segments.iter() {
+            match segment {
+                super::segment::Segment::Choice(choice) => {
+                    choices += 1;
+                    size += choice.key.len();
+                }
+                super::segment::Segment::PassThrough(text) => size += text.len(),
+                super::segment::Segment::Committed(text) => panic!("{:?}", text),
+            }
+        }
+        assert!(choices < constants::BUF_SIZE / 2);
+        assert_eq!(constants::BUF_SIZE, size);
+    }
+
     #[test]
     fn choice_xiang1() {
-        assert_eq!("想", query(&"xiang1", &mut BufWriter::new()));
+        assert_eq!("想", query("xiang1", &mut BufWriter::new()));
     }
 
     #[test]
     fn zhang3chang2() {
-        assert!(query(&"zhang", &mut BufWriter::new()).contains("长"));
-        assert!(query(&"chang", &mut BufWriter::new()).contains("长"));
+        assert!(query("zhang", &mut BufWriter::new()).contains("长"));
+        assert!(query("chang", &mut BufWriter::new()).contains("长"));
     }
 
     #[test]
@@ -469,48 +1340,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn query_all_jyutping_search_keys_verify_ciyu() {
+        let jyutping = Options {
+            dictionary: Dictionary::jyutping(),
+            ..Options::default()
+        };
+        let test_data = &autogen_jyutping::JYUTPING_CIYU_TEST_DATA;
+        for (normalized_jyutping, ciyu) in test_data.iter() {
+            let mut sink = BufWriter::new();
+            assert!(query_with(normalized_jyutping, &jyutping, &mut sink).contains(ciyu));
+        }
+    }
+
+    // Official list words may have a part of speech note, like "长（形容词）",
+    // or be a pattern, like "虽然……但是……", which needs each of its words.
+    #[test]
+    fn official_hsk_word_lists_are_covered() {
+        let test_data = &autogen_hsk::PINYIN_CIYU_TEST_DATA;
+        for (level, word) in autogen_hsk::OFFICIAL_HSK_TEST_DATA.iter() {
+            let word = word.split('（').next().unwrap_or(word);
+            for part in word.split('…').filter(|p| !p.is_empty()) {
+                let covered = test_data.iter().any(|(_, ciyu)| *ciyu == part);
+                assert!(covered, "HSK{} word is not in vocab: {}", level, part);
+            }
+        }
+    }
+
     #[test]
     fn choosing_ciyu_with_numbers_and_spaces() {
-        assert!(query(&"xiang", &mut BufWriter::new()).contains("(1想"));
-        assert!(query(&"xiang", &mut BufWriter::new()).contains("2向"));
-        assert_eq!(query(&"xiang ", &mut BufWriter::new()), "想");
-        assert!(query(&" xiang", &mut BufWriter::new()).starts_with(" "));
-        assert!(query(&" xiang", &mut BufWriter::new()).contains("(1想"));
-        assert_eq!(query(&"xiang1", &mut BufWriter::new()), "想");
-        assert_eq!(query(&"xiang2", &mut BufWriter::new()), "向");
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("(1想"));
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("2向"));
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xianghe", &mut BufWriter::new()).contains("2和"));
-        assert!(query(&"xiang he", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xiang he", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xiang1he", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xiang1he", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xianghe1", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xianghe1", &mut BufWriter::new()).contains("(1喝"));
-        assert!(query(&"xianghe ", &mut BufWriter::new()).starts_with("想"));
-        assert!(query(&"xianghe ", &mut BufWriter::new()).contains("(1喝"));
-        assert_eq!(query(&"xianghe 1", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xianghe11", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xiang he1", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xiang he ", &mut BufWriter::new()), "想喝");
-        assert_eq!(query(&"xianghe 2", &mut BufWriter::new()), "想和");
+        assert!(query("xiang", &mut BufWriter::new()).contains("(1想"));
+        assert!(query("xiang", &mut BufWriter::new()).contains("2向"));
+        assert_eq!(query("xiang ", &mut BufWriter::new()), "想");
+        assert!(query(" xiang", &mut BufWriter::new()).starts_with(" "));
+        assert!(query(" xiang", &mut BufWriter::new()).contains("(1想"));
+        assert_eq!(query("xiang1", &mut BufWriter::new()), "想");
+        assert_eq!(query("xiang2", &mut BufWriter::new()), "向");
+        assert!(query("xianghe", &mut BufWriter::new()).contains("(1想"));
+        assert!(query("xianghe", &mut BufWriter::new()).contains("2向"));
+        assert!(query("xianghe", &mut BufWriter::new()).contains("(1喝"));
+        assert!(query("xianghe", &mut BufWriter::new()).contains("2和"));
+        assert!(query("xiang he", &mut BufWriter::new()).starts_with("想"));
+        assert!(query("xiang he", &mut BufWriter::new()).contains("(1喝"));
+        assert!(query("xiang1he", &mut BufWriter::new()).starts_with("想"));
+        assert!(query("xiang1he", &mut BufWriter::new()).contains("(1喝"));
+        assert!(query("xianghe1", &mut BufWriter::new()).starts_with("想"));
+        assert!(query("xianghe1", &mut BufWriter::new()).contains("(1喝"));
+        assert!(query("xianghe ", &mut BufWriter::new()).starts_with("想"));
+        assert!(query("xianghe ", &mut BufWriter::new()).contains("(1喝"));
+        assert_eq!(query("xianghe 1", &mut BufWriter::new()), "想喝");
+        assert_eq!(query("xianghe11", &mut BufWriter::new()), "想喝");
+        assert_eq!(query("xiang he1", &mut BufWriter::new()), "想喝");
+        assert_eq!(query("xiang he ", &mut BufWriter::new()), "想喝");
+        assert_eq!(query("xianghe 2", &mut BufWriter::new()), "想和");
+    }
+
+    #[test]
+    fn weights_put_common_homophones_first() {
+        assert_eq!(query("shi ", &mut BufWriter::new()), "是");
+        assert_eq!(query("ke ", &mut BufWriter::new()), "可");
+        assert!(query("wei", &mut BufWriter::new()).starts_with(" (1为 2喂"));
     }
 
     #[test]
     fn query_chars_not_matched_should_pass_through() {
-        assert_eq!(query(&"🐇✨", &mut BufWriter::new()), "🐇✨");
-        assert_eq!(query(&"baiSEde🐇✨11", &mut BufWriter::new()), "白SE的🐇✨");
+        assert_eq!(query("🐇✨", &mut BufWriter::new()), "🐇✨");
+        assert_eq!(query("baiSEde🐇✨11", &mut BufWriter::new()), "白SE的🐇✨");
         assert_eq!(
-            query(&"RABBIT SPARKLES 11", &mut BufWriter::new()),
+            query("RABBIT SPARKLES 11", &mut BufWriter::new()),
             "RABBIT SPARKLES 11"
         );
-        assert_eq!(query(&"XIANGHE", &mut BufWriter::new()), "XIANGHE");
+        assert_eq!(query("XIANGHE", &mut BufWriter::new()), "XIANGHE");
     }
 
     #[test]
     fn matching_buffer_sizes() {
-        let utf8s = super::Utf8Str::new(&"slice");
+        let utf8s = super::Utf8Str::new("slice");
         let u_len_s = utf8s.char_start_list.len();
         let u_len_e = utf8s.char_end_list.len();
         let tq = super::lex::TokenQueue::new();
@@ -525,33 +1430,600 @@ mod tests {
 
     #[test]
     fn space_disambiguating_pinyin_prefix_is_consumed() {
-        assert_eq!("昆虫", query(&"kunchong", &mut BufWriter::new()));
-        assert_eq!("困冲", query(&"kun chong", &mut BufWriter::new()));
-        assert_eq!("困冲", query(&"kun chong ", &mut BufWriter::new()));
+        assert_eq!("昆虫", query("kunchong", &mut BufWriter::new()));
+        assert_eq!("困冲", query("kun chong", &mut BufWriter::new()));
+        assert_eq!("困冲", query("kun chong ", &mut BufWriter::new()));
+        assert_eq!(
+            "我想喝果汁",
+            query("wo xiang he guozhi", &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn apostrophe_marks_syllable_boundary() {
+        let optimal = Options {
+            segmentation: Segmentation::Optimal,
+            ..Options::default()
+        };
+        assert_eq!("西安", query("xi'an", &mut BufWriter::new()));
+        assert_eq!("西安", query_with("xi'an", &optimal, &mut BufWriter::new()));
+        assert!(!query("xi'an", &mut BufWriter::new()).contains('先'));
+        assert!(query("xian", &mut BufWriter::new()).contains('先'));
+        assert!(query("xian", &mut BufWriter::new()).contains("西安"));
+        // Apostrophe after a match is consumed as a separator
+        assert_eq!("大概", query("da'gai", &mut BufWriter::new()));
+        assert_eq!("昆虫", query("kun'chong", &mut BufWriter::new()));
+        assert_eq!("困", query("kun'", &mut BufWriter::new()));
+        // Apostrophe with no match before it passes through
+        assert_eq!("'", query("'", &mut BufWriter::new()));
+        assert_eq!("X'", query("X'", &mut BufWriter::new()));
+    }
+
+    #[test]
+    fn optimal_segmentation_avoids_unmatched_leftovers() {
+        let optimal = Options {
+            segmentation: Segmentation::Optimal,
+            ..Options::default()
+        };
+        // Greedy takes 东西 and leaves "n" unmatched
+        assert_eq!("东西n", query("dongxin", &mut BufWriter::new()));
+        assert!(query_with("dongxin", &optimal, &mut BufWriter::new()).contains("(1新 2信)"));
+        // Greedy takes 放弃 and 熬, then splits up 克力
+        assert!(query("fangqiaokeli", &mut BufWriter::new()).starts_with("放弃熬"));
+        assert!(query_with("fangqiaokeli", &optimal, &mut BufWriter::new()).ends_with("巧克力"));
+        assert!(query("yueduibi", &mut BufWriter::new()).starts_with("阅读i"));
+        assert_eq!(
+            "约对比",
+            query_with("yueduibi3", &optimal, &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn optimal_segmentation_agrees_with_greedy_on_easy_queries() {
+        let optimal = Options {
+            segmentation: Segmentation::Optimal,
+            ..Options::default()
+        };
+        for q in [
+            "woxiang he guozhi",
+            "kunchong",
+            "kun chong",
+            "xianghe 2",
+            "baiSEde🐇✨11",
+        ] {
+            assert_eq!(
+                query(q, &mut BufWriter::new()),
+                query_with(q, &optimal, &mut BufWriter::new())
+            );
+        }
+    }
+
+    #[test]
+    fn abbreviations_match_syllable_initials() {
+        let abbreviations = Options {
+            abbreviations: true,
+            ..Options::default()
+        };
+        // Off by default
+        assert_eq!("gzh", query("gzh", &mut BufWriter::new()));
+        assert!(query_with("gzh", &abbreviations, &mut BufWriter::new()).contains("(1果汁"));
+        // zh, ch, and sh may be shortened to z, c, and s
+        assert!(query_with("gz", &abbreviations, &mut BufWriter::new()).contains("果汁"));
+        assert!(query_with("zhg", &abbreviations, &mut BufWriter::new()).contains("(1中国"));
+        assert!(query_with("zg", &abbreviations, &mut BufWriter::new()).contains("中国"));
+        // Apostrophes are fine as separators between initials
+        assert!(query_with("g'zh", &abbreviations, &mut BufWriter::new()).contains("(1果汁"));
+        // Choice keys work the same as for full pinyin
         assert_eq!(
             "我想喝果汁",
-            query(&"wo xiang he guozhi", &mut BufWriter::new())
+            query_with("wo xiang he gzh1", &abbreviations, &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn abbreviations_rank_after_full_pinyin() {
+        let abbreviations = Options {
+            abbreviations: true,
+            ..Options::default()
+        };
+        // Full pinyin matches keep their place at the front of the list
+        let mut full_sink = BufWriter::new();
+        let full = query("da", &mut full_sink);
+        let mut sink = BufWriter::new();
+        let abbreviated = query_with("da", &abbreviations, &mut sink);
+        assert!(full.ends_with(") "));
+        assert!(abbreviated.starts_with(&full[..full.len() - 2]));
+        assert!(abbreviated.len() > full.len());
+        assert_eq!(
+            "我们",
+            query_with("women", &abbreviations, &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn fuzzy_pinyin_matches_confused_sounds() {
+        let fuzzy = |fuzzy| Options {
+            fuzzy,
+            ..Options::default()
+        };
+        assert!(!query("zongguo", &mut BufWriter::new()).contains("中国"));
+        assert_eq!(
+            "中国",
+            query_with("zongguo", &fuzzy(Fuzzy::Z_ZH), &mut BufWriter::new())
+        );
+        assert_ne!(
+            "中国",
+            query_with("zongguo", &fuzzy(Fuzzy::NASAL), &mut BufWriter::new())
+        );
+        assert!(
+            query_with("sisi", &fuzzy(Fuzzy::S_SH), &mut BufWriter::new()).starts_with(" (1事实")
+        );
+        assert_eq!(
+            "西安",
+            query_with("xi'an", &fuzzy(Fuzzy::ALL), &mut BufWriter::new())
+        );
+        let mut sink = BufWriter::new();
+        let zhang = query_with("zang", &fuzzy(Fuzzy::ALL), &mut sink);
+        assert!(zhang.contains("张"));
+        assert!(zhang.contains("脏"));
+    }
+
+    #[test]
+    fn fuzzy_pinyin_ranks_exact_matches_first() {
+        let fuzzy = |fuzzy| Options {
+            fuzzy,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        let zhang = query_with("zhang", &fuzzy(Fuzzy::Z_ZH), &mut sink);
+        assert!(zhang.starts_with(" (1张 2长 3涨"));
+        assert!(zhang.contains("脏"));
+        assert_eq!(
+            "我想喝果汁",
+            query_with(
+                "wo xiang he guozhi",
+                &fuzzy(Fuzzy::ALL),
+                &mut BufWriter::new()
+            )
+        );
+    }
+
+    #[test]
+    fn tone_numbers_narrow_choices() {
+        let tones = Options {
+            tone_input: true,
+            ..Options::default()
+        };
+        // Digits pick choices unless tone input is on
+        assert_eq!("向", query("xiang2", &mut BufWriter::new()));
+        assert!(query_with("xiang4", &tones, &mut BufWriter::new()).starts_with(" (1项 2象 "));
+        assert!(!query_with("xiang4", &tones, &mut BufWriter::new()).contains('想'));
+        assert!(query_with("xiang3", &tones, &mut BufWriter::new()).starts_with(" (1想 "));
+        assert!(!query_with("xiang3", &tones, &mut BufWriter::new()).contains('项'));
+        // Digit after a tone number picks a choice
+        assert_eq!("象", query_with("xiang42", &tones, &mut BufWriter::new()));
+        assert_eq!("号", query_with("hao4", &tones, &mut BufWriter::new()));
+        assert_eq!("好", query_with("hao3", &tones, &mut BufWriter::new()));
+        // Neutral tone is 5
+        assert_eq!("爸爸", query_with("ba4ba5", &tones, &mut BufWriter::new()));
+        assert_eq!("西安", query_with("xi1an1", &tones, &mut BufWriter::new()));
+        assert_eq!(
+            "我想喝果汁",
+            query_with("wo3 xiang3 he1 guozhi", &tones, &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn tone_marks_narrow_choices() {
+        let tones = Options {
+            tone_input: true,
+            ..Options::default()
+        };
+        assert!(!query("xiǎng", &mut BufWriter::new()).contains('想'));
+        assert!(query_with("xiǎng", &tones, &mut BufWriter::new()).starts_with(" (1想 "));
+        assert!(query_with("xiàng", &tones, &mut BufWriter::new()).starts_with(" (1项 2象 "));
+        assert_eq!("爸爸", query_with("bàba", &tones, &mut BufWriter::new()));
+        assert_eq!("号", query_with("hào", &tones, &mut BufWriter::new()));
+        assert_eq!("女儿", query_with("nǚ'ér", &tones, &mut BufWriter::new()));
+    }
+
+    #[test]
+    fn zhuyin_input_matches_pinyin_keys() {
+        let zhuyin = Options {
+            input: Input::Zhuyin,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        assert_eq!("你好", query_with("ㄋㄧˇㄏㄠˇ", &zhuyin, &mut sink));
+        assert_eq!("中国", query_with("ㄓㄨㄥ ㄍㄨㄛˊ", &zhuyin, &mut sink));
+        // Digits after tone keys pick choices
+        let qry = "ㄨㄛˇ1ㄒㄧㄤˇ1ㄏㄜ 1ㄍㄨㄛˇㄓ ";
+        assert_eq!("我想喝果汁", query_with(qry, &zhuyin, &mut sink));
+        // Tone keys narrow down choices, and space is first tone
+        assert!(query_with("ㄒㄧㄤˋ", &zhuyin, &mut BufWriter::new()).starts_with(" (1项 2象 "));
+        assert!(!query_with("ㄒㄧㄤˋ", &zhuyin, &mut BufWriter::new()).contains('想'));
+        assert!(query_with("ㄏㄜ ", &zhuyin, &mut BufWriter::new()).starts_with(" (1喝 "));
+        assert_eq!("象", query_with("ㄒㄧㄤˋ2", &zhuyin, &mut BufWriter::new()));
+        assert_eq!(
+            "爸爸",
+            query_with("ㄅㄚˋㄅㄚ˙", &zhuyin, &mut BufWriter::new())
+        );
+        // ü is u after j, q, x, but v after n, l
+        assert!(query_with("ㄐㄩˋ", &zhuyin, &mut BufWriter::new()).contains('句'));
+        assert_eq!("绿", query_with("ㄌㄩˋ", &zhuyin, &mut BufWriter::new()));
+        // Pinyin does not match in Zhuyin mode
+        assert_eq!("ni", query_with("ni", &zhuyin, &mut BufWriter::new()));
+    }
+
+    #[test]
+    fn zhuyin_input_does_not_split_syllables() {
+        let zhuyin = Options {
+            input: Input::Zhuyin,
+            ..Options::default()
+        };
+        // ㄒㄧ and ㄤ would be xi + ang
+        assert!(!query_with("ㄒㄧㄤˇ", &zhuyin, &mut BufWriter::new()).contains('西'));
+        // Invalid syllable passes through whole
+        assert_eq!(
+            "ㄅㄧㄤˇ",
+            query_with("ㄅㄧㄤˇ", &zhuyin, &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn shuangpin_pairs_match_pinyin_keys() {
+        let layout = |layout| Options {
+            input: Input::Shuangpin(layout),
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        let mut pinyin_sink = BufWriter::new();
+        let pinyin = query("woxiangheguozhi", &mut pinyin_sink);
+        assert_eq!(
+            pinyin,
+            query_with("woxdhegovi", &layout(ZIRANMA), &mut sink)
+        );
+        assert_eq!(pinyin, query_with("woxlhegovi", &layout(XIAOHE), &mut sink));
+        assert_eq!("我想", query_with("wo1xd1", &layout(MICROSOFT), &mut sink));
+        assert_eq!("电影", query_with("dmy;", &layout(MICROSOFT), &mut sink));
+        assert_eq!("电影", query_with("dmyy", &layout(ZIRANMA), &mut sink));
+    }
+
+    #[test]
+    fn shuangpin_matches_start_on_syllable_boundaries() {
+        let layout = |layout| Options {
+            input: Input::Shuangpin(layout),
+            ..Options::default()
+        };
+        // xian could be 西安 in full pinyin, but "xm" is one syllable
+        let mut sink = BufWriter::new();
+        assert!(!query_with("xm", &layout(ZIRANMA), &mut sink).contains('西'));
+        // Keys pair up from the start of a run, so a space is needed to
+        // leave a key out
+        assert!(query_with("xdmyy", &layout(ZIRANMA), &mut sink).starts_with(" (1想 "));
+        assert_eq!("x 电影", query_with("x dmyy", &layout(ZIRANMA), &mut sink));
+    }
+
+    #[test]
+    fn hangul_input_composes_without_search() {
+        let hangul = Options {
+            input: Input::Hangul,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        assert_eq!(
+            "안녕하세요 1",
+            query_with("dkssudgktpdy 1", &hangul, &mut sink)
+        );
+    }
+
+    #[test]
+    fn kana_input_converts_without_search() {
+        let hiragana = Options {
+            input: Input::Hiragana,
+            ..Options::default()
+        };
+        let katakana = Options {
+            input: Input::Katakana,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        assert_eq!("にほんご", query_with("nihongo", &hiragana, &mut sink));
+        assert_eq!("ニホンゴ", query_with("nihongo", &katakana, &mut sink));
+    }
+
+    #[test]
+    fn jyutping_dictionary_matches_cantonese_words() {
+        let jyutping = Options {
+            dictionary: Dictionary::jyutping(),
+            tone_input: true,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        assert_eq!("你好", query_with("neihou", &jyutping, &mut sink));
+        assert_eq!("食咗飯未", query_with("sikzofaanmei", &jyutping, &mut sink));
+        assert_eq!("唔該", query_with("m4goi1", &jyutping, &mut sink));
+        // Weights put 係 before 喺, and tone numbers 1 to 6 pick readings
+        assert!(query_with("hai", &jyutping, &mut BufWriter::new()).starts_with(" (1係 2喺"));
+        assert_eq!("喺", query_with("hai2", &jyutping, &mut BufWriter::new()));
+        assert_eq!("食", query_with("sik6", &jyutping, &mut BufWriter::new()));
+        assert_eq!("識", query_with("sik1", &jyutping, &mut BufWriter::new()));
+        // Mandarin words are not in the Cantonese vocab
+        assert_eq!(
+            "xiexie",
+            query_with("xiexie", &jyutping, &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn jyutping_words_have_known_tones() {
+        let dict = Dictionary::jyutping();
+        assert_eq!(super::syllable::Scheme::Jyutping, dict.scheme());
+        for (ciyu, tones) in (0..dict.len()).map(|i| (dict.ciyu(i), dict.tones(i))) {
+            for (text, reading) in ciyu.split('\t').zip(tones.split('\t')) {
+                // Punctuation has no syllables
+                let count = super::syllable::syllable_count(text);
+                assert_eq!(count, reading.len(), "{} {}", text, reading);
+                assert!(reading.bytes().all(|t| (b'1'..=b'6').contains(&t)));
+            }
+        }
+    }
+
+    #[test]
+    fn candidate_tones_match_candidate_syllables() {
+        let dict = Dictionary::hsk();
+        for (ciyu, tones) in (0..dict.len()).map(|i| (dict.ciyu(i), dict.tones(i))) {
+            assert_eq!(ciyu.split('\t').count(), tones.split('\t').count());
+            for (text, tones) in ciyu.split('\t').zip(tones.split('\t')) {
+                for reading in tones.split(',').filter(|t| !t.is_empty()) {
+                    let count = super::syllable::syllable_count(text);
+                    assert_eq!(count, reading.len(), "{} {}", text, reading);
+                    assert!(reading.bytes().all(|t| (b'0'..=b'5').contains(&t)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn level_max_filters_candidates() {
+        let level = |level_max| Options {
+            level_max,
+            completions: 3,
+            ..Options::default()
+        };
+        let sink = &mut BufWriter::new();
+        assert_eq!(
+            " (1想 2向 3像 4香 5响 6项 7象 8巷) ",
+            query_with("xiang", &level(0), sink)
+        );
+        assert_eq!(
+            " (1想 2向 3像 4香 5响 6项 7象) ",
+            query_with("xiang", &level(5), sink)
+        );
+        assert_eq!(" (1想 2向 3像) ", query_with("xiang", &level(3), sink));
+        assert_eq!("想", query_with("xiang", &level(2), sink));
+        // Words from -extra lists count as their list's level
+        assert_eq!(" (1喝 2和) ", query_with("he", &level(2), sink));
+        assert_eq!(" (1喝 2和 3河) ", query_with("he", &level(3), sink));
+        // Completions and punctuation follow the same rules
+        assert_eq!(" (1先生 2现在 3想) ", query_with("xian", &level(1), sink));
+        assert_eq!("想。", query_with("xiang.", &level(2), sink));
+        // 词语 above the level do not match at all
+        assert_eq!("相信", query_with("xiangxin", &level(3), sink));
+        assert!(query_with("xiangxin", &level(2), sink).starts_with("想"));
+    }
+
+    #[test]
+    fn candidate_levels_come_from_vocab_files() {
+        let dict = Dictionary::hsk();
+        let mut queue = super::lex::TokenQueue::new();
+        let mut segments = super::segment::Segments::new();
+        super::look_up_segments("he", &Options::default(), &mut queue, &mut segments);
+        match segments.get(0) {
+            Some(super::segment::Segment::Choice(choice)) => {
+                assert_eq!(Some((1, false)), choice.candidates.level(0));
+                assert_eq!(Some((3, true)), choice.candidates.level(2));
+            }
+            _ => panic!("he should be a choice"),
+        }
+        for i in 0..dict.len() {
+            let punctuation = dict.tones(i).is_empty();
+            for nth in 0..dict.ciyu(i).split('\t').count() {
+                let (level, _) = super::lex::Cand { ciyu: i, nth }.level(&dict);
+                assert_eq!(punctuation, level == 0, "{}", dict.key(i));
+                assert!(level <= 6);
+            }
+        }
+    }
+
+    #[test]
+    fn traditional_output_follows_phrases() {
+        let traditional = Options {
+            traditional: true,
+            ..Options::default()
+        };
+        let sink = &mut BufWriter::new();
+        // 发 is 發 except for hair, 复 is 復 or 複, and 面 is 麵 for noodles
+        assert_eq!(
+            "頭髮發展理髮",
+            query_with("toufa fazhan lifa", &traditional, sink)
+        );
+        assert_eq!(
+            "複習恢復重複",
+            query_with("fuxi huifu chongfu", &traditional, sink)
+        );
+        assert_eq!("麵包面子", query_with("mianbao mianzi", &traditional, sink));
+        assert_eq!(
+            "乾淨能幹",
+            query_with("ganjing nenggan", &traditional, sink)
         );
+        // 系 is 繫 for jì (to tie), but stays 系 for xì (department)
+        assert!(query_with("ji", &traditional, sink).contains("繫"));
+        assert!(query_with("xi", &traditional, sink).contains("3系"));
+        assert_eq!("關係聯繫", query_with("guanxi lianxi2", &traditional, sink));
+        // Simplified is still the default
+        assert_eq!("头发", query("toufa", &mut BufWriter::new()));
     }
 
-    // This might fail some day as consequence of vocab data entry. As long as
-    // this test continues to pass, using single digit choice picking protocol
-    // is okay. Fail means time for fancier algorithm to resolve choices.
     #[test]
-    fn longest_choice_has_nine_or_less_options() {
-        assert!(autogen_hsk::CIYU_CHOICE_MAX <= 9);
+    fn traditional_forms_have_one_character_per_hanzi() {
+        let dict = Dictionary::hsk();
+        let mut converted = 0;
+        for i in 0..dict.len() {
+            for nth in 0..dict.ciyu(i).split('\t').count() {
+                let cand = super::lex::Cand { ciyu: i, nth };
+                let (simplified, traditional) = (cand.text(&dict), cand.script_text(&dict, true));
+                assert_eq!(simplified.chars().count(), traditional.chars().count());
+                if simplified != traditional {
+                    converted += 1;
+                }
+            }
+        }
+        assert!(converted > 2000);
     }
 
-    // This might fail some day as a consequence of vocab data entry. In case
-    // of failure due to hash collision, try changing the murmur3 seed in
-    // vocab/autogen_hsk.rb.
     #[test]
-    fn pinyin_murmur3_hashes_are_sorted_with_no_collisions() {
-        let mut prev = autogen_hsk::PINYIN[0];
-        for i in 1..autogen_hsk::PINYIN.len() {
-            let curr = autogen_hsk::PINYIN[i];
-            assert!(curr > prev);
-            prev = curr;
+    fn completion_of_unfinished_pinyin() {
+        let completions = Options {
+            completions: 5,
+            ..Options::default()
+        };
+        assert_eq!("想j", query("xiang j", &mut BufWriter::new()));
+        assert!(query_with("xiangj", &completions, &mut BufWriter::new()).contains("(1香蕉 "));
+        assert!(
+            query_with("wo xiangj", &completions, &mut BufWriter::new()).starts_with("我 (1香蕉 ")
+        );
+        // Choice keys after the unfinished pinyin pick completions
+        assert_eq!(
+            "香蕉",
+            query_with("xiangj ", &completions, &mut BufWriter::new())
+        );
+        assert_eq!(
+            "我香蕉",
+            query_with("wo xiangj1", &completions, &mut BufWriter::new())
+        );
+        // Finished pinyin is left alone
+        assert_eq!(
+            query("xiang", &mut BufWriter::new()),
+            query_with("xiang", &completions, &mut BufWriter::new())
+        );
+        assert_eq!(
+            "我想几",
+            query_with("wo xiang j ", &completions, &mut BufWriter::new())
+        );
+    }
+
+    #[test]
+    fn completions_are_capped() {
+        for cap in [1, 3, 9, 20] {
+            let options = Options {
+                completions: cap,
+                ..Default::default()
+            };
+            let mut queue = super::lex::TokenQueue::new();
+            let mut out = super::segment::Segments::new();
+            super::look_up_segments("zh", &options, &mut queue, &mut out);
+            match out.get(0) {
+                Some(super::segment::Segment::Choice(choice)) => {
+                    assert_eq!(cap, choice.candidates.len());
+                }
+                _ => panic!("zh should have completions"),
+            }
+        }
+    }
+
+    #[test]
+    fn abbreviation_candidates_have_one_initial_per_hanzi() {
+        let dict = Dictionary::hsk();
+        for i in 0..dict.abbrev_len() {
+            let initials = dict.abbrev_key(i).len();
+            assert!(dict.abbrev_cands(i).next().is_some());
+            for (ciyu, nth) in dict.abbrev_cands(i) {
+                let cand = super::lex::Cand { ciyu, nth };
+                let hanzi = super::syllable::syllable_count(cand.text(&dict));
+                assert!(hanzi > 0 && hanzi <= initials, "{}", i);
+            }
+        }
+    }
+
+    #[test]
+    fn murmur3_collisions_do_not_match() {
+        let abbreviations = Options {
+            abbreviations: true,
+            ..Options::default()
+        };
+        let dict = Dictionary::hsk();
+        let seed = 0;
+        // Each pair is some string with the same hash as a search key
+        for &(junk, key) in [("hhie", "yiran"), ("lwty", "zimu"), ("cvuse", "suowei")].iter() {
+            assert_eq!(super::murmur3(junk, seed), super::murmur3(key, seed));
+            assert!(dict.find_pinyin(key).is_some());
+            assert!(dict.find_pinyin(junk).is_none());
+        }
+        assert!(!query("hhie", &mut BufWriter::new()).contains("依然"));
+        assert!(query("yiran", &mut BufWriter::new()).starts_with(" (1依然"));
+        for &(junk, key) in [("kjirf", "sdy"), ("oqohx", "fnms")].iter() {
+            assert_eq!(super::murmur3(junk, seed), super::murmur3(key, seed));
+            assert!(dict.find_abbrev(junk).is_none());
+            let abbrev_i = dict.find_abbrev(key).unwrap();
+            let (ciyu, nth) = dict.abbrev_cands(abbrev_i).next().unwrap();
+            let text = super::lex::Cand { ciyu, nth }.text(&dict);
+            assert!(query_with(key, &abbreviations, &mut BufWriter::new()).contains(text));
+            assert!(!query_with(junk, &abbreviations, &mut BufWriter::new()).contains(text));
+        }
+    }
+
+    #[test]
+    fn random_ascii_matches_only_search_keys() {
+        let dict = Dictionary::hsk();
+        let options = Options {
+            abbreviations: true,
+            ..Default::default()
+        };
+        let mut rng: u32 = 12345;
+        let mut next = |n: u32| {
+            rng = rng.wrapping_mul(1103515245).wrapping_add(12345);
+            (rng >> 16) % n
+        };
+        let mut bytes = [0u8; 12];
+        let mut key = [0u8; 12];
+        for _ in 0..2000 {
+            // Mostly lowercase letters, with some other printable ASCII
+            let len = 1 + next(bytes.len() as u32) as usize;
+            for b in bytes[..len].iter_mut() {
+                *b = match next(4) {
+                    0 => b' ' + next(95) as u8,
+                    _ => b'a' + next(26) as u8,
+                };
+            }
+            let text = core::str::from_utf8(&bytes[..len]).unwrap();
+            let utf8_str = super::Utf8Str::new(text);
+            for start in 0..len {
+                for end in start + 1..=len {
+                    let mut cands = super::lex::CandList::new();
+                    if !super::exact_match(&utf8_str, start, end, &options, &mut cands) {
+                        continue;
+                    }
+                    // Every match must come from a search key equal to the
+                    // text, so compare the text of the key that a lookup finds
+                    // instead of trusting its hash
+                    let mut n = 0;
+                    for &b in bytes[start..end].iter().filter(|&&b| b != b'\'') {
+                        key[n] = b;
+                        n += 1;
+                    }
+                    let key = core::str::from_utf8(&key[..n]).unwrap();
+                    assert!(
+                        dict.find_pinyin(key).is_some_and(|i| dict.key(i) == key)
+                            || dict
+                                .find_abbrev(key)
+                                .is_some_and(|i| dict.abbrev_key(i) == key),
+                        "{:?} in {:?}",
+                        key,
+                        text
+                    );
+                }
+            }
         }
     }
 }
@@ -1,13 +1,12 @@
 // ime_engine CLI demo
-use ime_engine;
 
 // Minimal example of using ime_engine as library with std and CLI
 fn main() {
     let queries = &[
-        &"woxiangheguozhi",
-        &"woxiang heguozhi",
-        &"woxiang he guozhi",
-        &"woxianheguozhi11",
+        &"wo xiangheguozhi",
+        &"wo xiang heguozhi",
+        &"wo xiang he guozhi",
+        &"wo xianheguozhi11",
     ];
     // Make a stack allocated string buffer with the Writer trait
     // that ime_engine::query() expects
@@ -0,0 +1,140 @@
+use crate::dictionary::Dictionary;
+use crate::fuzzy::Fuzzy;
+use crate::learning::Learning;
+use crate::shuangpin::Shuangpin;
+
+// How search splits the query into 词语 matches.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Segmentation {
+    // Take the longest match at each position, left to right. Spaces in the
+    // query may be needed to disambiguate pinyin prefixes.
+    Greedy,
+    // Pick the best overall path through all matches in the query (fewest
+    // unmatched characters, then fewest segments).
+    Optimal,
+}
+
+// How query text spells syllables.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Input<'a> {
+    // ASCII Hanyu Pinyin, like "woxiang", or Jyutping, like "ngoseung", with
+    // a Jyutping dictionary
+    Pinyin,
+    // Zhuyin (Bopomofo) symbols with tone keys, like "ㄨㄛˇㄒㄧㄤˇ", where
+    // space after a syllable is first tone. Session maps the keys of a
+    // Dachen keyboard to Zhuyin (see zhuyin::dachen()).
+    Zhuyin,
+    // Shuangpin (double pinyin) with a layout like shuangpin::ZIRANMA, where
+    // every syllable is two keys, like "woxd" for 我想
+    Shuangpin(Shuangpin<'a>),
+    // Korean with 2-set (Dubeolsik) keys, like "gksrmf" for 한글. Keys get
+    // composed into syllable blocks without dictionary search (see
+    // hangul::compose()), so there are no choices, and structured results
+    // from look_up_segments() are empty.
+    Hangul,
+    // Japanese kana from Hepburn or Kunrei romaji, like "arigatou" for
+    // ありがとう, with romaji in capitals for katakana ("PASOKON" for パソコン).
+    // Like Hangul, this has no dictionary search (see kana::convert()).
+    Hiragana,
+    // Same as Hiragana, but all kana are katakana.
+    Katakana,
+}
+impl Input<'_> {
+    // Does input search the dictionary for 词语? Other inputs convert keys to
+    // text on their own.
+    pub(crate) fn searches(&self) -> bool {
+        !matches!(self, Input::Hangul | Input::Hiragana | Input::Katakana)
+    }
+}
+
+// Options for query look up. Use struct update syntax to change some options
+// and keep defaults for the rest, like:
+//   Options { page_size: 5, ..Options::default() }
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Options<'a> {
+    // Number of homophone choices shown per page. Choice keys are single
+    // digits, so the usable range is 1..=9.
+    pub page_size: usize,
+    // Keys for moving to the next or previous page of an open choice. These
+    // take priority over pinyin punctuation, so '.' and ',' work as page keys
+    // for people who do not need them as punctuation.
+    pub page_next: char,
+    pub page_prev: char,
+    pub segmentation: Segmentation,
+    pub input: Input<'a>,
+    // Also match 词语 by the initials of their syllables, like "wxhgz" for
+    // 我想喝果汁. Full pinyin matches are listed before abbreviation matches.
+    // Abbreviations are for pinyin input only.
+    pub abbreviations: bool,
+    // Fuzzy pinyin pairs to try, like Fuzzy::Z_ZH for "zongguo" to match
+    // 中国. Exact matches are listed before fuzzy matches.
+    pub fuzzy: Fuzzy,
+    // Tone input mode: a digit 1-5 right after a pinyin letter is a tone
+    // number (xiang3), and vowels may have tone marks (xiǎng). Tones narrow
+    // down the choices. Digits after anything else still pick choices. With
+    // a Jyutping dictionary, tone numbers are 1-6 (soeng2).
+    pub tone_input: bool,
+    // Most completions to offer for unfinished pinyin at the end of the
+    // query, like 香蕉 for "xiangj" (0 turns completion off). Completions are
+    // for pinyin input only.
+    pub completions: usize,
+    // Highest HSK level of 词语 to offer, for learners who have not reached
+    // the higher levels yet (0 allows all levels). Words from -extra lists
+    // count as their list's level. 词语 with no level, like punctuation and
+    // user dictionary entries, are always allowed.
+    pub level_max: usize,
+    // Show a short English gloss after choices that have one, like
+    // " (1长[long] 2常[often]) ", so learners can tell homophones apart.
+    pub glosses: bool,
+    // Show choices and committed 词语 in Traditional characters, like 頭髮 for
+    // "toufa". Search keys stay the same, and user dictionary entries are
+    // shown as entered.
+    pub traditional: bool,
+    // Vocab to look up 词语 in (built-in HSK vocab by default, or Cantonese
+    // vocab with Dictionary::jyutping()). Other dictionaries can be loaded
+    // with Dictionary::from_bytes(), and custom
+    // 词语 can be layered over any dictionary with Dictionary::with_user().
+    pub dictionary: Dictionary<'a>,
+    // Adaptive candidate order: 词语 picked in committed choices move to the
+    // front of later choices. None (the default) turns learning off.
+    pub learning: Option<&'a Learning>,
+}
+impl Default for Options<'_> {
+    fn default() -> Self {
+        Options {
+            page_size: 9,
+            page_next: '=',
+            page_prev: '-',
+            segmentation: Segmentation::Greedy,
+            input: Input::Pinyin,
+            abbreviations: false,
+            fuzzy: Fuzzy::NONE,
+            tone_input: false,
+            completions: 0,
+            level_max: 0,
+            glosses: false,
+            traditional: false,
+            dictionary: Dictionary::hsk(),
+            learning: None,
+        }
+    }
+}
+impl Options<'_> {
+    // Return page size clamped to range that single digit labels can pick.
+    pub(crate) fn choice_page_size(&self) -> usize {
+        match self.page_size {
+            0 => 1,
+            n if n > 9 => 9,
+            n => n,
+        }
+    }
+
+    // Is HSK level within level_max?
+    pub(crate) fn allows_level(&self, level: usize) -> bool {
+        self.level_max == 0 || level <= self.level_max
+    }
+
+    pub(crate) fn is_page_key(&self, c: char) -> bool {
+        c == self.page_next || c == self.page_prev
+    }
+}
@@ -0,0 +1,519 @@
+// Structured query results. Resolving a lex::TokenQueue gives a list of
+// segments, so front ends can draw a candidate window without re-parsing the
+// "(1想 2向)" text protocol. Text rendering is one formatter on top of this.
+use crate::constants::BUF_SIZE;
+use crate::dictionary::{Dictionary, Gloss};
+use crate::lex::Cand;
+use crate::options::Options;
+use crate::{Utf8Str, Writer};
+
+const SEGMENTS_SIZE: usize = BUF_SIZE;
+
+// Holds one piece of query result.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Segment<'a> {
+    // 词语 from a match that needs no further input (one match or resolved choice)
+    Committed(&'a str),
+    // Query text that was passed through unchanged (e.g. "UPPER CASE" or emoji)
+    PassThrough(&'a str),
+    // Homophone 词语 choice that is still waiting for a choice key
+    Choice(Choice<'a>),
+}
+
+// Open choice between homophone 词语 for one pinyin key.
+// Candidates are shown in pages of page_size, and page is the current page
+// (first page is 0). Choice keys pick relative to the current page.
+// start..end is the character range of the pinyin key in the query.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Choice<'a> {
+    pub key: &'a str,
+    pub candidates: Candidates<'a>,
+    pub default: usize,
+    pub page: usize,
+    pub page_size: usize,
+    pub start: usize,
+    pub end: usize,
+}
+impl<'a> Choice<'a> {
+    pub fn page_count(&self) -> usize {
+        self.candidates.page_count(self.page_size)
+    }
+
+    // Iterate over candidates on the current page.
+    pub fn page_candidates(&self) -> impl Iterator<Item = &'a str> + 'a {
+        self.candidates
+            .iter()
+            .skip(self.page * self.page_size)
+            .take(self.page_size)
+    }
+}
+
+// List of homophone 词语 candidates.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Candidates<'a> {
+    cands: &'a [Cand],
+    dictionary: &'a Dictionary<'a>,
+    // Candidate text is in Traditional characters
+    traditional: bool,
+}
+impl<'a> Candidates<'a> {
+    pub(crate) fn new(
+        cands: &'a [Cand],
+        dictionary: &'a Dictionary<'a>,
+        traditional: bool,
+    ) -> Candidates<'a> {
+        Candidates {
+            cands,
+            dictionary,
+            traditional,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cands.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cands.is_empty()
+    }
+
+    // Return candidate at index i (first candidate is index 0).
+    pub fn get(&self, i: usize) -> Option<&'a str> {
+        let traditional = self.traditional;
+        self.cands
+            .get(i)
+            .map(|c| c.script_text(self.dictionary, traditional))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + 'a {
+        let (dictionary, traditional) = (self.dictionary, self.traditional);
+        self.cands
+            .iter()
+            .map(move |c| c.script_text(dictionary, traditional))
+    }
+
+    // Return (HSK level, is from an -extra list) of candidate at index i.
+    // Level 0 means unknown.
+    pub fn level(&self, i: usize) -> Option<(usize, bool)> {
+        self.cands.get(i).map(|c| c.level(self.dictionary))
+    }
+
+    // Iterate over part of speech and meaning of each sense of candidate at
+    // index i, like ("adj.", "long") for 长 in a choice for "chang".
+    pub fn glosses(&self, i: usize) -> impl Iterator<Item = Gloss<'a>> + 'a {
+        let dictionary = self.dictionary;
+        let cand = self.cands.get(i).copied();
+        cand.into_iter().flat_map(move |c| c.glosses(dictionary))
+    }
+
+    // Return candidates as references to dictionary entries.
+    pub fn as_cands(&self) -> &'a [Cand] {
+        self.cands
+    }
+
+    // Number of pages needed to show all candidates.
+    pub fn page_count(&self, page_size: usize) -> usize {
+        match page_size {
+            0 => 0,
+            _ => self.len().div_ceil(page_size),
+        }
+    }
+
+    // Try to pick a choice with a space or digit key. Digits are labels for
+    // candidates on the current page, so they only go up to page_size.
+    // Return: index of picked candidate, or None if key is not a choice key
+    // or number is out of range for this page
+    pub(crate) fn pick(&self, maybe_choice: char, page: usize, page_size: usize) -> Option<usize> {
+        let label = match maybe_choice {
+            ' ' => 1, // Spacebar picks default option (label=1)
+            '1'..='9' => maybe_choice as usize - '0' as usize,
+            _ => return None,
+        };
+        let i = page * page_size + label - 1;
+        match label <= page_size && i < self.len() {
+            true => Some(i),
+            false => None,
+        }
+    }
+}
+
+// Segment as stored in Segments. Candidates of choices borrow the candidate
+// pool of the TokenQueue the list was resolved from, so a lookup needs only
+// one pool.
+#[derive(Copy, Clone)]
+enum Stored<'a> {
+    Committed(&'a str),
+    PassThrough(&'a str),
+    Choice {
+        key: &'a str,
+        cands: &'a [Cand],
+        default: usize,
+        page: usize,
+        page_size: usize,
+        span: (usize, usize),
+    },
+}
+
+// Holds list of Segments (append only). This is a no_std, stack-only
+// substitute for Vec<Segment>, like lex::TokenQueue.
+pub struct Segments<'a> {
+    list: [Stored<'a>; SEGMENTS_SIZE],
+    count: usize,
+    // Dictionary that the candidates refer to
+    dictionary: Dictionary<'a>,
+    // Candidate text is in Traditional characters
+    traditional: bool,
+    // Character range of the most recent PassThrough segment, for merging
+    // adjacent pass-through characters into one segment
+    pass_start: usize,
+    pass_end: usize,
+}
+impl Default for Segments<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<'a> Segments<'a> {
+    // Return empty list ready for use.
+    pub fn new() -> Segments<'a> {
+        Segments {
+            list: [Stored::Committed(""); SEGMENTS_SIZE],
+            count: 0,
+            dictionary: Dictionary::empty(),
+            traditional: false,
+            pass_start: 0,
+            pass_end: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    // Truncate list back to 0 segments.
+    pub fn clear(&mut self) {
+        self.count = 0;
+    }
+
+    pub fn get(&self, i: usize) -> Option<Segment<'_>> {
+        if i >= self.count {
+            return None;
+        }
+        Some(match self.list[i] {
+            Stored::Committed(s) => Segment::Committed(s),
+            Stored::PassThrough(s) => Segment::PassThrough(s),
+            Stored::Choice {
+                key,
+                cands,
+                default,
+                page,
+                page_size,
+                span,
+            } => Segment::Choice(Choice {
+                key,
+                candidates: Candidates::new(cands, &self.dictionary, self.traditional),
+                default,
+                page,
+                page_size,
+                start: span.0,
+                end: span.1,
+            }),
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Segment<'_>> {
+        (0..self.count).filter_map(move |i| self.get(i))
+    }
+
+    fn push(&mut self, seg: Stored<'a>) -> bool {
+        if self.count < SEGMENTS_SIZE {
+            self.list[self.count] = seg;
+            self.count += 1;
+            true
+        } else {
+            // Error: List is full
+            false
+        }
+    }
+
+    // Add 词语 that needs no further input.
+    pub(crate) fn push_committed(&mut self, ciyu: &'a str) -> bool {
+        self.push(Stored::Committed(ciyu))
+    }
+
+    // Set dictionary and script for the candidates of choices. All choices in
+    // the list share them.
+    pub(crate) fn use_dictionary(&mut self, dictionary: &Dictionary<'a>, traditional: bool) {
+        self.dictionary = *dictionary;
+        self.traditional = traditional;
+    }
+
+    // Add open choice with its candidates from a TokenQueue pool.
+    pub(crate) fn push_choice(
+        &mut self,
+        key: &'a str,
+        cands: &'a [Cand],
+        default: usize,
+        page: usize,
+        page_size: usize,
+        span: (usize, usize),
+    ) -> bool {
+        self.push(Stored::Choice {
+            key,
+            cands,
+            default,
+            page,
+            page_size,
+            span,
+        })
+    }
+
+    // Add query characters start..end as pass-through text. Characters that
+    // directly follow the previous PassThrough segment get merged into it.
+    pub(crate) fn push_pass_through(&mut self, query: &Utf8Str<'a>, start: usize, end: usize) {
+        if self.count > 0 && self.pass_end == start {
+            if let Stored::PassThrough(_) = self.list[self.count - 1] {
+                if let Some(s) = query.char_slice(self.pass_start, end) {
+                    self.list[self.count - 1] = Stored::PassThrough(s);
+                    self.pass_end = end;
+                    return;
+                }
+            }
+        }
+        if let Some(s) = query.char_slice(start, end) {
+            if self.push(Stored::PassThrough(s)) {
+                self.pass_start = start;
+                self.pass_end = end;
+            }
+        }
+    }
+
+    // Render segments as text, with open choices shown as " (1想 2向) ".
+    // Side-effect: render strings into buffer provided by Writer.
+    pub fn render_and_write(&self, options: &Options, sink: &mut impl Writer) {
+        for seg in self.iter() {
+            match seg {
+                Segment::Committed(s) => sink.write(s),
+                Segment::PassThrough(s) => sink.write(s),
+                Segment::Choice(choice) => write_choice_prompt(&choice, options, sink),
+            }
+        }
+    }
+}
+
+// Render open choice as prompt with numbered options for the current page.
+// When there are other pages, the page keys are shown at the ends of the
+// prompt, like " (- 1香 2响 3项 =) ". With the glosses option, choices with a
+// gloss are followed by it in brackets, like "1长[long]".
+// Side-effect: render strings into buffer provided by Writer.
+fn write_choice_prompt(choice: &Choice, options: &Options, sink: &mut impl Writer) {
+    let mut utf8_buf = [0u8; 4];
+    sink.write(" (");
+    if choice.page > 0 {
+        sink.write(options.page_prev.encode_utf8(&mut utf8_buf));
+        sink.write(" ");
+    }
+    for (i, ciyu) in choice.page_candidates().enumerate() {
+        if i > 0 {
+            sink.write(" ");
+        }
+        sink.write(match i {
+            0 => "1",
+            1 => "2",
+            2 => "3",
+            3 => "4",
+            4 => "5",
+            5 => "6",
+            6 => "7",
+            7 => "8",
+            _ => "9",
+        });
+        sink.write(ciyu);
+        if options.glosses {
+            let index = choice.page * choice.page_size + i;
+            let mut shorts = choice.candidates.glosses(index).map(|g| g.short());
+            if let Some(short) = shorts.find(|short| !short.is_empty()) {
+                sink.write("[");
+                sink.write(short);
+                sink.write("]");
+            }
+        }
+    }
+    if choice.page + 1 < choice.page_count() {
+        sink.write(" ");
+        sink.write(options.page_next.encode_utf8(&mut utf8_buf));
+    }
+    sink.write(") ");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Segment, Segments};
+    use crate::lex::TokenQueue;
+    use crate::options::Options;
+    use crate::{autogen_hsk, look_up_segments, look_up_with, BufWriter, Writer};
+
+    #[test]
+    fn open_choice_has_key_candidates_and_range() {
+        let mut queue = TokenQueue::new();
+        let mut out = Segments::new();
+        look_up_segments("nixiang", &Options::default(), &mut queue, &mut out);
+        assert_eq!(out.len(), 2);
+        assert_eq!(out.get(0), Some(Segment::Committed("你")));
+        match out.get(1) {
+            Some(Segment::Choice(choice)) => {
+                assert_eq!(choice.key, "xiang");
+                assert_eq!((choice.start, choice.end), (2, 7));
+                assert_eq!(choice.default, 0);
+                assert_eq!(choice.page, 0);
+                assert_eq!(choice.page_count(), 1);
+                assert_eq!(choice.candidates.get(0), Some("想"));
+                assert_eq!(choice.candidates.get(1), Some("向"));
+                assert_eq!(choice.candidates.iter().count(), choice.candidates.len());
+            }
+            _ => panic!("expected open choice"),
+        }
+    }
+
+    #[test]
+    fn candidates_have_glosses() {
+        let mut queue = TokenQueue::new();
+        let mut out = Segments::new();
+        look_up_segments("zhang", &Options::default(), &mut queue, &mut out);
+        match out.get(0) {
+            Some(Segment::Choice(choice)) => {
+                let i = choice.candidates.iter().position(|c| c == "长").unwrap();
+                let gloss = choice.candidates.glosses(i).next().unwrap();
+                assert_eq!(("v.", "to grow"), (gloss.pos, gloss.meaning));
+                assert_eq!(None, choice.candidates.glosses(99).next());
+            }
+            _ => panic!("expected open choice"),
+        }
+        let glosses = Options {
+            glosses: true,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        look_up_with("chang", &glosses, &mut sink);
+        assert_eq!(" (1长[long] 2尝 3场) ", sink.to_s());
+        let mut sink = BufWriter::new();
+        look_up_with("chang", &Options::default(), &mut sink);
+        assert_eq!(" (1长 2尝 3场) ", sink.to_s());
+    }
+
+    #[test]
+    fn resolved_choices_are_committed() {
+        let mut queue = TokenQueue::new();
+        let mut out = Segments::new();
+        look_up_segments("xiang he2", &Options::default(), &mut queue, &mut out);
+        assert!(out
+            .iter()
+            .eq([Segment::Committed("想"), Segment::Committed("和")]));
+    }
+
+    #[test]
+    fn adjacent_pass_through_characters_are_merged() {
+        let mut queue = TokenQueue::new();
+        let mut out = Segments::new();
+        look_up_segments("baiSEde🐇✨11", &Options::default(), &mut queue, &mut out);
+        assert!(out.iter().eq([
+            Segment::Committed("白"),
+            Segment::PassThrough("SE"),
+            Segment::Committed("的"),
+            Segment::PassThrough("🐇✨")
+        ]));
+        let mut queue = TokenQueue::new();
+        let mut out = Segments::new();
+        let query = "RABBIT SPARKLES 11";
+        look_up_segments(query, &Options::default(), &mut queue, &mut out);
+        assert!(out.iter().eq([Segment::PassThrough("RABBIT SPARKLES 11")]));
+    }
+
+    #[test]
+    fn empty_query_has_no_segments() {
+        let mut queue = TokenQueue::new();
+        let mut out = Segments::new();
+        look_up_segments("", &Options::default(), &mut queue, &mut out);
+        assert!(out.is_empty());
+    }
+
+    fn paged(query: &str, page_size: usize) -> BufWriter {
+        let options = Options {
+            page_size,
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        look_up_with(query, &options, &mut sink);
+        sink
+    }
+
+    #[test]
+    fn paging_through_choices() {
+        assert_eq!(paged("xiang", 3).to_s(), " (1想 2向 3像 =) ");
+        assert_eq!(paged("xiang=", 3).to_s(), " (- 1香 2响 3项 =) ");
+        assert_eq!(paged("xiang==", 3).to_s(), " (- 1象 2巷) ");
+        assert_eq!(paged("xiang===", 3).to_s(), " (- 1象 2巷) ");
+        assert_eq!(paged("xiang==-", 3).to_s(), " (- 1香 2响 3项 =) ");
+        assert_eq!(paged("xiang=2", 3).to_s(), "响");
+        assert_eq!(paged("xiang==1", 3).to_s(), "象");
+        assert_eq!(paged("xiang== ", 3).to_s(), "象");
+        assert_eq!(paged("xiang=-2", 3).to_s(), "向");
+        // Label 4 is out of range for page size 3
+        assert_eq!(paged("xiang4", 3).to_s(), " (1想 2向 3像 =) 4");
+    }
+
+    #[test]
+    fn page_keys_pass_through_without_multi_page_choice() {
+        assert_eq!(paged("xiang=1", 9).to_s(), "想=");
+        assert_eq!(paged("ni-hao ", 9).to_s(), "你-好");
+    }
+
+    #[test]
+    fn page_keys_can_replace_punctuation() {
+        let options = Options {
+            page_size: 3,
+            page_next: '.',
+            page_prev: ',',
+            ..Options::default()
+        };
+        let mut sink = BufWriter::new();
+        look_up_with("xiang.2", &options, &mut sink);
+        assert_eq!(sink.to_s(), "响");
+        sink.rewind();
+        look_up_with("xiang.,2", &options, &mut sink);
+        assert_eq!(sink.to_s(), "向");
+    }
+
+    // Every candidate of every homophone set must be reachable with paging,
+    // no matter how many homophones the vocab data has.
+    #[test]
+    fn all_choices_reachable_with_paging() {
+        let options = Options {
+            page_size: 2,
+            ..Options::default()
+        };
+        for (pinyin, ciyu) in autogen_hsk::PINYIN_CIYU_TEST_DATA.iter() {
+            let mut queue = TokenQueue::new();
+            let mut out = Segments::new();
+            look_up_segments(pinyin, &options, &mut queue, &mut out);
+            if let (1, Some(Segment::Choice(choice))) = (out.len(), out.get(0)) {
+                let i = choice.candidates.iter().position(|c| c == *ciyu).unwrap();
+                let mut qry = [0u8; 64];
+                let mut n = 0;
+                for b in pinyin.bytes().chain(core::iter::repeat_n(b'=', i / 2)) {
+                    qry[n] = b;
+                    n += 1;
+                }
+                qry[n] = b'1' + (i % 2) as u8;
+                let qry = core::str::from_utf8(&qry[..n + 1]).unwrap();
+                let mut sink = BufWriter::new();
+                look_up_with(qry, &options, &mut sink);
+                assert_eq!(sink.to_s(), *ciyu);
+            }
+        }
+    }
+}
@@ -0,0 +1,624 @@
+// Stateful composition session for front ends that send one key event at a
+// time. Session keeps the query buffer and its lex::TokenQueue between calls,
+// so each keystroke only re-searches the part of the query that the edit could
+// have changed, instead of re-segmenting the whole buffer from scratch.
+use crate::constants::BUF_SIZE;
+use crate::options::{Input, Options, Segmentation};
+use crate::segment::Segments;
+use crate::{convert_keys, counts_toward_window, lex, search, zhuyin, Utf8Str, Writer};
+
+// Key events understood by Session::key().
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Key {
+    // Typed character, mapped by the keyboard layout of the input mode (like
+    // Dachen keys to Zhuyin symbols for Input::Zhuyin)
+    Char(char),
+    // Character that goes into the query as is. This is for choice and page
+    // keys that the keyboard layout would map to something else, like digits
+    // for Input::Zhuyin.
+    Literal(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    Commit,
+    Cancel,
+}
+
+// Session holds query text as UTF-8 bytes in a stack allocated buffer. The
+// cursor is a character position (not bytes!) in the range 0..=char_count.
+pub struct Session<'a> {
+    buf: [u8; BUF_SIZE],
+    buf_len: usize,
+    cursor: usize,
+    queue: lex::TokenQueue,
+    options: Options<'a>,
+}
+impl Default for Session<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<'a> Session<'a> {
+    // Return empty session ready for use.
+    pub fn new() -> Session<'a> {
+        Session::with_options(Options::default())
+    }
+
+    // Return empty session that uses options for look up.
+    pub fn with_options(options: Options<'a>) -> Session<'a> {
+        Session {
+            buf: [0; BUF_SIZE],
+            buf_len: 0,
+            cursor: 0,
+            queue: lex::TokenQueue::new(),
+            options,
+        }
+    }
+
+    pub fn options(&self) -> &Options<'a> {
+        &self.options
+    }
+
+    // Change options. This re-searches the whole query.
+    pub fn set_options(&mut self, options: Options<'a>, sink: &mut impl Writer) {
+        self.options = options;
+        self.queue.truncate(0);
+        self.rescan(0, sink);
+    }
+
+    // Apply one key event.
+    // Side-effect: Commit renders the converted text into buffer provided by
+    // Writer, then clears the session. Other keys do not write to sink.
+    // Return: Did the key change the session state?
+    pub fn key(&mut self, key: Key, sink: &mut impl Writer) -> bool {
+        match key {
+            Key::Char(c) => {
+                let c = match self.options.input {
+                    Input::Zhuyin => zhuyin::dachen(c).unwrap_or(c),
+                    _ => c,
+                };
+                self.key(Key::Literal(c), sink)
+            }
+            Key::Literal(c) => {
+                let pos = self.cursor;
+                if self.insert(pos, c) {
+                    self.cursor += 1;
+                    self.rescan(pos, sink);
+                    true
+                } else {
+                    false
+                }
+            }
+            Key::Backspace => {
+                if self.cursor > 0 && self.remove(self.cursor - 1) {
+                    self.cursor -= 1;
+                    self.rescan(self.cursor, sink);
+                    true
+                } else {
+                    false
+                }
+            }
+            Key::Delete => {
+                let pos = self.cursor;
+                if self.remove(pos) {
+                    self.rescan(pos, sink);
+                    true
+                } else {
+                    false
+                }
+            }
+            Key::Left => self.move_cursor(self.cursor.saturating_sub(1)),
+            Key::Right => self.move_cursor(self.cursor + 1),
+            Key::Home => self.move_cursor(0),
+            Key::End => self.move_cursor(self.char_count()),
+            Key::Commit => {
+                if self.buf_len == 0 {
+                    return false;
+                }
+                if !self.options.input.searches() {
+                    convert_keys(self.text(), &self.options.input, sink);
+                } else {
+                    let query = Utf8Str::new(self.text());
+                    let mut segments = Segments::new();
+                    self.queue
+                        .resolve(&query, &self.options, true, &mut segments);
+                    segments.render_and_write(&self.options, sink);
+                }
+                self.clear();
+                true
+            }
+            Key::Cancel => {
+                let changed = self.buf_len > 0;
+                self.clear();
+                changed
+            }
+        }
+    }
+
+    // Render preedit text (with choice prompts) for the current query.
+    // Side-effect: render utf8 string into buffer provided by Writer.
+    pub fn render(&self, sink: &mut impl Writer) {
+        if !self.options.input.searches() {
+            return convert_keys(self.text(), &self.options.input, sink);
+        }
+        let mut segments = Segments::new();
+        self.segments(&mut segments);
+        segments.render_and_write(&self.options, sink);
+    }
+
+    // Resolve preedit for the current query as structured results.
+    // Side-effect: push segments into out.
+    pub fn segments<'s>(&'s self, out: &mut Segments<'s>) {
+        let query = Utf8Str::new(self.text());
+        self.queue.resolve(&query, &self.options, false, out);
+    }
+
+    // Return raw query text typed so far.
+    pub fn text(&self) -> &str {
+        as_str(&self.buf, self.buf_len)
+    }
+
+    // Return cursor position in characters.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf_len == 0
+    }
+
+    // Discard query text and tokens.
+    fn clear(&mut self) {
+        self.buf_len = 0;
+        self.cursor = 0;
+        self.queue.truncate(0);
+    }
+
+    fn char_count(&self) -> usize {
+        self.text().chars().count()
+    }
+
+    // Convert character position to byte offset in buf.
+    fn byte_offset(&self, pos: usize) -> usize {
+        match self.text().char_indices().nth(pos) {
+            Some((b, _)) => b,
+            None => self.buf_len,
+        }
+    }
+
+    fn move_cursor(&mut self, pos: usize) -> bool {
+        let pos = crate::min(pos, self.char_count());
+        let changed = pos != self.cursor;
+        self.cursor = pos;
+        changed
+    }
+
+    // Insert character at character position pos (fails if buffer is full).
+    fn insert(&mut self, pos: usize, c: char) -> bool {
+        let mut utf8_buf = [0u8; 4];
+        let bytes = c.encode_utf8(&mut utf8_buf).as_bytes();
+        let n = bytes.len();
+        if self.buf_len + n > BUF_SIZE {
+            return false;
+        }
+        let at = self.byte_offset(pos);
+        self.buf.copy_within(at..self.buf_len, at + n);
+        self.buf[at..at + n].copy_from_slice(bytes);
+        self.buf_len += n;
+        true
+    }
+
+    // Remove character at character position pos (fails if out of range).
+    fn remove(&mut self, pos: usize) -> bool {
+        if pos >= self.char_count() {
+            return false;
+        }
+        let at = self.byte_offset(pos);
+        let next = self.byte_offset(pos + 1);
+        self.buf.copy_within(next..self.buf_len, at);
+        self.buf_len -= next - at;
+        true
+    }
+
+    // Re-search the region of the query affected by an edit at character
+    // position edit_pos. Tokens before the edit that cannot be affected by it
+    // are kept, and everything after them gets a new search.
+    fn rescan(&mut self, edit_pos: usize, sink: &mut impl Writer) {
+        let keep = match self.options.segmentation {
+            Segmentation::Greedy => self.unaffected_greedy(edit_pos),
+            Segmentation::Optimal => self.unaffected_optimal(edit_pos),
+        };
+        let keep = crate::min(keep, self.completion_start());
+        let start = match keep {
+            0 => 0,
+            _ => self.queue.span[keep - 1].1,
+        };
+        self.queue.truncate(keep);
+        let query = Utf8Str::new(as_str(&self.buf, self.buf_len));
+        let end = query.char_count;
+        search(&query, &mut self.queue, start, end, &self.options, sink);
+    }
+
+    // Greedy search goes from left to right, and a match starting at
+    // character s can only depend on the window of N key characters after s,
+    // where N is the key_size_max of the dictionary, plus the one character
+    // after the window (Zhuyin looks ahead for a tone key). Apostrophes and
+    // tone keys inside the window do not count, so walk back from the edit
+    // counting only key characters. Tokens starting at or before the Nth one
+    // are still valid.
+    // Return: number of tokens to keep
+    fn unaffected_greedy(&self, edit_pos: usize) -> usize {
+        let query = Utf8Str::new(as_str(&self.buf, self.buf_len));
+        let mut size = 0;
+        let mut bound = edit_pos.saturating_sub(1);
+        while bound > 0 && size < self.options.dictionary.key_size_max() {
+            bound -= 1;
+            if counts_toward_window(&query, bound, &self.options) {
+                size += 1;
+            }
+        }
+        if size < self.options.dictionary.key_size_max() {
+            return 0;
+        }
+        let mut keep = 0;
+        while keep < self.queue.count && self.queue.span[keep].0 <= bound {
+            keep += 1;
+        }
+        keep
+    }
+
+    // Completions only go at the end of the query, so any edit can change
+    // the trailing tokens that a completion choice replaced. The choice is
+    // the last token that is not a MaybeChoice.
+    // Return: number of tokens before the possible completion choice
+    fn completion_start(&self) -> usize {
+        if self.options.completions == 0 {
+            return self.queue.count;
+        }
+        let mut i = self.queue.count;
+        while i > 0 {
+            i -= 1;
+            if let lex::Token::MaybeChoice(_) = self.queue.queue[i] {
+                continue;
+            }
+            return i;
+        }
+        0
+    }
+
+    // Optimal search picks the best path for the whole query, so an edit can
+    // change how earlier characters get segmented. But, no pinyin key contains
+    // spaces, digits, or page keys (MaybeChoice tokens), so paths on either
+    // side of those characters are independent. Tokens up to the last
+    // MaybeChoice before the edit are still valid. For Zhuyin, space is the
+    // first tone key and can end up inside a key, so it is not a barrier.
+    // Return: number of tokens to keep
+    fn unaffected_optimal(&self, edit_pos: usize) -> usize {
+        let mut keep = 0;
+        for i in 0..self.queue.count {
+            if self.queue.span[i].1 > edit_pos {
+                break;
+            }
+            match self.queue.queue[i] {
+                lex::Token::MaybeChoice(' ') if self.options.input == Input::Zhuyin => {}
+                lex::Token::MaybeChoice(_) => keep = i + 1,
+                _ => {}
+            }
+        }
+        keep
+    }
+}
+
+// Decode buffer bytes as &str. Session only inserts whole UTF-8 characters, so
+// the error case should not happen.
+fn as_str(buf: &[u8], len: usize) -> &str {
+    core::str::from_utf8(&buf[..len]).unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Key, Session};
+    use crate::fuzzy::Fuzzy;
+    use crate::options::{Input, Options, Segmentation};
+    use crate::{look_up_with, query, BufWriter, Writer};
+
+    fn type_str(s: &mut Session, text: &str) {
+        for c in text.chars() {
+            s.key(Key::Char(c), &mut BufWriter::new());
+        }
+    }
+
+    fn preedit(s: &Session) -> BufWriter {
+        let mut sink = BufWriter::new();
+        s.render(&mut sink);
+        sink
+    }
+
+    #[test]
+    fn typing_matches_whole_query_look_up() {
+        let mut s = Session::new();
+        let text = "woxiang he guozhi1";
+        for (i, c) in text.char_indices() {
+            assert!(s.key(Key::Char(c), &mut BufWriter::new()));
+            let expected = &text[..i + c.len_utf8()];
+            assert_eq!(s.text(), expected);
+            assert_eq!(preedit(&s).to_s(), query(expected, &mut BufWriter::new()));
+        }
+    }
+
+    #[test]
+    fn editing_in_middle_of_query() {
+        let mut s = Session::new();
+        let mut sink = BufWriter::new();
+        type_str(&mut s, "woxianheguozhi");
+        assert_eq!(s.cursor(), 14);
+        for _ in 0..8 {
+            s.key(Key::Left, &mut sink);
+        }
+        s.key(Key::Char('g'), &mut sink);
+        assert_eq!(s.text(), "woxiangheguozhi");
+        s.key(Key::Right, &mut sink);
+        s.key(Key::Char(' '), &mut sink);
+        assert_eq!(s.text(), "woxiangh eguozhi");
+        s.key(Key::Backspace, &mut sink);
+        s.key(Key::Right, &mut sink);
+        s.key(Key::Char(' '), &mut sink);
+        assert_eq!(s.text(), "woxianghe guozhi");
+        s.key(Key::Home, &mut sink);
+        s.key(Key::Delete, &mut sink);
+        s.key(Key::Delete, &mut sink);
+        assert_eq!(s.text(), "xianghe guozhi");
+        assert_eq!(
+            preedit(&s).to_s(),
+            query("xianghe guozhi", &mut BufWriter::new())
+        );
+    }
+
+    // Keys for random edits: pinyin letters with choice, tone, page, and
+    // pass-through keys, and Dachen keys for ㄗ ㄕ ㄧ ㄅ ㄒ ㄨ ㄏ ㄜ with tone
+    // keys (space is first tone) and a page key
+    const PINYIN_KEYS: &str = "xiangheuoz 13'''ˇˊ=✨";
+    const ZHUYIN_KEYS: &str = "ygu1vjck 43 =";
+
+    #[test]
+    fn random_edits_match_whole_query_look_up() {
+        random_edits(Options::default(), PINYIN_KEYS);
+        random_edits(
+            Options {
+                segmentation: Segmentation::Optimal,
+                ..Options::default()
+            },
+            PINYIN_KEYS,
+        );
+        random_edits(
+            Options {
+                completions: 5,
+                ..Options::default()
+            },
+            PINYIN_KEYS,
+        );
+        random_edits(
+            Options {
+                segmentation: Segmentation::Optimal,
+                completions: 5,
+                ..Options::default()
+            },
+            PINYIN_KEYS,
+        );
+        random_edits(
+            Options {
+                tone_input: true,
+                ..Options::default()
+            },
+            PINYIN_KEYS,
+        );
+        random_edits(
+            Options {
+                input: Input::Zhuyin,
+                ..Options::default()
+            },
+            ZHUYIN_KEYS,
+        );
+        random_edits(
+            Options {
+                input: Input::Zhuyin,
+                segmentation: Segmentation::Optimal,
+                ..Options::default()
+            },
+            ZHUYIN_KEYS,
+        );
+    }
+
+    #[test]
+    fn edits_redo_trailing_completion() {
+        let options = Options {
+            segmentation: Segmentation::Optimal,
+            completions: 5,
+            ..Options::default()
+        };
+        for text in ["'b =a", "oxˇdb2n", "xiangj s"] {
+            let mut s = Session::with_options(options);
+            type_str(&mut s, text);
+            let mut expected = BufWriter::new();
+            look_up_with(s.text(), &options, &mut expected);
+            assert_eq!(preedit(&s).to_s(), expected.to_s());
+        }
+    }
+
+    #[test]
+    fn zhuyin_space_is_not_a_segment_barrier() {
+        let options = Options {
+            input: Input::Zhuyin,
+            segmentation: Segmentation::Optimal,
+            ..Options::default()
+        };
+        // Dachen keys for ㄗ ㄕˋ (姿势) and ㄗㄗ ㄕˋ
+        for keys in ["y g4", "yy g4"] {
+            let mut s = Session::with_options(options);
+            type_str(&mut s, keys);
+            let mut expected = BufWriter::new();
+            look_up_with(s.text(), &options, &mut expected);
+            assert_eq!(preedit(&s).to_s(), expected.to_s());
+            assert!(preedit(&s).to_s().ends_with("姿势"));
+        }
+    }
+
+    #[test]
+    fn apostrophes_do_not_stretch_reused_window() {
+        let mut s = Session::new();
+        type_str(&mut s, "xi''''''''''''''''''''''''''''''an");
+        let mut expected = BufWriter::new();
+        look_up_with(s.text(), &Options::default(), &mut expected);
+        assert_eq!(preedit(&s).to_s(), expected.to_s());
+        assert_eq!(preedit(&s).to_s(), "西安");
+    }
+
+    // Type a random mix of chars and editing keys, checking the preedit
+    // against a lookup of the whole text after each key.
+    fn random_edits(options: Options, chars: &str) {
+        let edits = [
+            Key::Backspace,
+            Key::Delete,
+            Key::Left,
+            Key::Right,
+            Key::Home,
+            Key::End,
+        ];
+        let n = chars.chars().count();
+        let mut s = Session::with_options(options);
+        let mut rng: u32 = 12345;
+        for _ in 0..1000 {
+            rng = rng.wrapping_mul(1103515245).wrapping_add(12345);
+            let i = (rng >> 16) as usize % (n + edits.len());
+            let k = match chars.chars().nth(i) {
+                Some(c) => Key::Char(c),
+                None => edits[i - n],
+            };
+            s.key(k, &mut BufWriter::new());
+            let mut expected = BufWriter::new();
+            look_up_with(s.text(), &options, &mut expected);
+            assert_eq!(preedit(&s).to_s(), expected.to_s());
+        }
+    }
+
+    #[test]
+    fn commit_writes_default_choices_and_clears() {
+        let mut s = Session::new();
+        let mut sink = BufWriter::new();
+        type_str(&mut s, "wo xiang he2guozhi");
+        assert!(s.key(Key::Commit, &mut sink));
+        assert_eq!(sink.to_s(), "我想和果汁");
+        assert!(s.is_empty());
+        assert_eq!(s.cursor(), 0);
+        assert!(!s.key(Key::Commit, &mut sink));
+    }
+
+    #[test]
+    fn traditional_option_changes_preedit_and_commit() {
+        let options = Options {
+            traditional: true,
+            ..Options::default()
+        };
+        let mut s = Session::with_options(options);
+        let mut sink = BufWriter::new();
+        type_str(&mut s, "toufa hou");
+        assert_eq!(preedit(&s).to_s(), "頭髮 (1後 2厚 3吼) ");
+        assert!(s.key(Key::Commit, &mut sink));
+        assert_eq!(sink.to_s(), "頭髮後");
+    }
+
+    #[test]
+    fn zhuyin_input_maps_dachen_keys() {
+        let options = Options {
+            input: Input::Zhuyin,
+            ..Options::default()
+        };
+        let mut s = Session::with_options(options);
+        let mut sink = BufWriter::new();
+        // ㄨㄛˇㄒㄧㄤˇ, then a literal digit to pick 我
+        type_str(&mut s, "ji3vu;3");
+        assert_eq!(s.text(), "ㄨㄛˇㄒㄧㄤˇ");
+        assert!(preedit(&s).to_s().starts_with(" (1我 2窝)  (1想 "));
+        s.key(Key::Literal('1'), &mut sink);
+        // ㄏㄜ with space for first tone, then Unicode Zhuyin typed directly
+        type_str(&mut s, "ck ㄍㄨㄛˇㄓ ");
+        assert!(s.key(Key::Commit, &mut sink));
+        assert_eq!(sink.to_s(), "我想喝果汁");
+    }
+
+    #[test]
+    fn hangul_backspace_takes_blocks_apart() {
+        let options = Options {
+            input: Input::Hangul,
+            ..Options::default()
+        };
+        let mut s = Session::with_options(options);
+        let mut sink = BufWriter::new();
+        type_str(&mut s, "gksrmf");
+        assert_eq!(preedit(&s).to_s(), "한글");
+        for expected in ["한그", "한ㄱ", "한", "하", "ㅎ", ""].iter() {
+            s.key(Key::Backspace, &mut sink);
+            assert_eq!(preedit(&s).to_s(), *expected);
+        }
+        type_str(&mut s, "dhk");
+        s.key(Key::Backspace, &mut sink);
+        assert_eq!(preedit(&s).to_s(), "오");
+        type_str(&mut s, "k");
+        assert!(s.key(Key::Commit, &mut sink));
+        assert_eq!(sink.to_s(), "와");
+    }
+
+    #[test]
+    fn cancel_discards_query() {
+        let mut s = Session::new();
+        let mut sink = BufWriter::new();
+        type_str(&mut s, "xiang");
+        assert!(s.key(Key::Cancel, &mut sink));
+        assert_eq!(sink.to_s(), "");
+        assert_eq!(s.text(), "");
+        assert_eq!(preedit(&s).to_s(), "");
+    }
+
+    #[test]
+    fn cursor_stays_in_range() {
+        let mut s = Session::new();
+        let mut sink = BufWriter::new();
+        assert!(!s.key(Key::Left, &mut sink));
+        assert!(!s.key(Key::Backspace, &mut sink));
+        type_str(&mut s, "🐇a");
+        assert!(!s.key(Key::Right, &mut sink));
+        assert!(!s.key(Key::Delete, &mut sink));
+        assert!(s.key(Key::Left, &mut sink));
+        assert!(s.key(Key::Backspace, &mut sink));
+        assert_eq!(s.text(), "a");
+    }
+
+    #[test]
+    fn fuzzy_profile_can_change_mid_session() {
+        let mut s = Session::new();
+        type_str(&mut s, "zongguo");
+        assert!(!preedit(&s).to_s().contains("中国"));
+        let options = Options {
+            fuzzy: Fuzzy::RETROFLEX,
+            ..Options::default()
+        };
+        s.set_options(options, &mut BufWriter::new());
+        assert_eq!(preedit(&s).to_s(), "中国");
+        s.set_options(Options::default(), &mut BufWriter::new());
+        assert!(!preedit(&s).to_s().contains("中国"));
+    }
+
+    #[test]
+    fn full_buffer_refuses_insert() {
+        let mut s = Session::new();
+        let mut sink = BufWriter::new();
+        for _ in 0..crate::constants::BUF_SIZE {
+            assert!(s.key(Key::Char('A'), &mut sink));
+        }
+        assert!(!s.key(Key::Char('A'), &mut sink));
+        assert!(!s.key(Key::Char('🐇'), &mut sink));
+    }
+}
@@ -0,0 +1,341 @@
+// Shuangpin (双拼, double pinyin) input. Every syllable is typed as exactly two
+// keys: an initial key, then a final key. A layout table gives the keys of
+// the initials zh, ch, sh and of the finals. Search decodes each pair of keys
+// to a full pinyin syllable, and the fixed syllable boundaries limit which
+// parts of the query can match.
+use crate::syllable;
+
+// Keyboard layout of a shuangpin scheme. Custom layouts can use the same
+// shape as the built-in ones, like:
+//   Shuangpin { initials: &MY_INITIALS, finals: &MY_FINALS, zero_initial: None }
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Shuangpin<'a> {
+    // Keys of initials that are not typed as their own letter, like ('v', "zh")
+    pub initials: &'a [(char, &'a str)],
+    // Keys of finals. A key may have two finals, like ('d', "iang") and ('d',
+    // "uang"), and the one that makes a syllable with the initial wins (the
+    // first one if both do). Finals are normalized with v for ü.
+    pub finals: &'a [(char, &'a str)],
+    // Key that starts syllables without an initial, like 'o' for "oa" (a),
+    // or None to start them with their first letter, like "aa" (a), "ai"
+    // (ai), and "ah" (ang)
+    pub zero_initial: Option<char>,
+}
+
+const ZH_CH_SH: [(char, &str); 3] = [('v', "zh"), ('i', "ch"), ('u', "sh")];
+
+// 自然码
+pub static ZIRANMA: Shuangpin<'static> = Shuangpin {
+    initials: &ZH_CH_SH,
+    finals: &[
+        ('a', "a"),
+        ('b', "ou"),
+        ('c', "iao"),
+        ('d', "iang"),
+        ('d', "uang"),
+        ('e', "e"),
+        ('f', "en"),
+        ('g', "eng"),
+        ('h', "ang"),
+        ('i', "i"),
+        ('j', "an"),
+        ('k', "ao"),
+        ('l', "ai"),
+        ('m', "ian"),
+        ('n', "in"),
+        ('o', "uo"),
+        ('o', "o"),
+        ('p', "un"),
+        ('p', "vn"),
+        ('q', "iu"),
+        ('r', "uan"),
+        ('r', "van"),
+        ('s', "ong"),
+        ('s', "iong"),
+        ('t', "ve"),
+        ('t', "ue"),
+        ('u', "u"),
+        ('v', "v"),
+        ('v', "ui"),
+        ('w', "ia"),
+        ('w', "ua"),
+        ('x', "ie"),
+        ('y', "ing"),
+        ('y', "uai"),
+        ('z', "ei"),
+    ],
+    zero_initial: None,
+};
+
+// 微软双拼
+pub static MICROSOFT: Shuangpin<'static> = Shuangpin {
+    initials: &ZH_CH_SH,
+    finals: &[
+        ('a', "a"),
+        ('b', "ou"),
+        ('c', "iao"),
+        ('d', "iang"),
+        ('d', "uang"),
+        ('e', "e"),
+        ('f', "en"),
+        ('g', "eng"),
+        ('h', "ang"),
+        ('i', "i"),
+        ('j', "an"),
+        ('k', "ao"),
+        ('l', "ai"),
+        ('m', "ian"),
+        ('n', "in"),
+        ('o', "uo"),
+        ('o', "o"),
+        ('p', "un"),
+        ('q', "iu"),
+        ('r', "uan"),
+        ('r', "van"),
+        ('r', "er"),
+        ('s', "ong"),
+        ('s', "iong"),
+        ('t', "ue"),
+        ('u', "u"),
+        ('v', "ve"),
+        ('v', "ui"),
+        ('w', "ia"),
+        ('w', "ua"),
+        ('x', "ie"),
+        ('y', "v"),
+        ('y', "uai"),
+        ('z', "ei"),
+        (';', "ing"),
+    ],
+    zero_initial: Some('o'),
+};
+
+// 小鹤双拼
+pub static XIAOHE: Shuangpin<'static> = Shuangpin {
+    initials: &ZH_CH_SH,
+    finals: &[
+        ('a', "a"),
+        ('b', "in"),
+        ('c', "ao"),
+        ('d', "ai"),
+        ('e', "e"),
+        ('f', "en"),
+        ('g', "eng"),
+        ('h', "ang"),
+        ('i', "i"),
+        ('j', "an"),
+        ('k', "ing"),
+        ('k', "uai"),
+        ('l', "iang"),
+        ('l', "uang"),
+        ('m', "ian"),
+        ('n', "iao"),
+        ('o', "uo"),
+        ('o', "o"),
+        ('p', "ie"),
+        ('q', "iu"),
+        ('r', "uan"),
+        ('r', "van"),
+        ('s', "ong"),
+        ('s', "iong"),
+        ('t', "ve"),
+        ('t', "ue"),
+        ('u', "u"),
+        ('v', "v"),
+        ('v', "ui"),
+        ('w', "ei"),
+        ('x', "ia"),
+        ('x', "ua"),
+        ('y', "un"),
+        ('y', "vn"),
+        ('z', "ou"),
+    ],
+    zero_initial: None,
+};
+
+// Letters that are their own initial key
+const INITIAL_LETTERS: &str = "bpmfdtnlgkhjqxrzcsyw";
+
+impl Shuangpin<'_> {
+    // Is c a key of the layout?
+    pub fn is_key(&self, c: char) -> bool {
+        c.is_ascii_lowercase() || self.finals.iter().any(|&(k, _)| k == c)
+    }
+
+    // Decode a pair of keys to a normalized pinyin syllable, like "xiang" for
+    // "xd" in Ziranma.
+    // Side-effect: write syllable to out.
+    // Return: length of syllable, or None if the keys are not a syllable or
+    // out is too small
+    pub fn decode(&self, initial: char, fin: char, out: &mut [u8]) -> Option<usize> {
+        let mut utf8 = [0u8; 4];
+        let initial: &str = match self.initials.iter().find(|&&(k, _)| k == initial) {
+            Some(&(_, s)) => s,
+            None if INITIAL_LETTERS.contains(initial) => initial.encode_utf8(&mut utf8),
+            None => return self.decode_zero_initial(initial, fin, out),
+        };
+        self.spell_syllable(initial, fin, out)
+    }
+
+    // Spell initial with the first final of key fin that makes a syllable.
+    // Side-effect: write syllable to out.
+    // Return: length of syllable, or None if no final makes a syllable
+    fn spell_syllable(&self, initial: &str, fin: char, out: &mut [u8]) -> Option<usize> {
+        for &(_, f) in self.finals.iter().filter(|&&(k, _)| k == fin) {
+            match spell(initial, f, out) {
+                Some(n) if is_syllable(&out[..n]) => return Some(n),
+                _ => {}
+            }
+        }
+        None
+    }
+
+    // Same as decode(), for syllables without an initial.
+    fn decode_zero_initial(&self, initial: char, fin: char, out: &mut [u8]) -> Option<usize> {
+        match self.zero_initial {
+            Some(zero) if zero == initial => self.spell_syllable("", fin, out),
+            Some(_) => None,
+            None => {
+                // One letter syllables are typed twice (aa), two letter
+                // syllables are typed as is (ai), and longer ones are their
+                // first letter and their final (ah for ang)
+                if !matches!(initial, 'a' | 'e' | 'o') {
+                    return None;
+                }
+                let (mut a, mut b) = ([0u8; 4], [0u8; 4]);
+                let (a, b) = (initial.encode_utf8(&mut a), fin.encode_utf8(&mut b));
+                if a == b {
+                    return spell("", a, out);
+                }
+                match spell(a, b, out) {
+                    Some(n) if is_syllable(&out[..n]) => return Some(n),
+                    _ => {}
+                }
+                let mut finals = self.finals.iter().filter(|&&(k, _)| k == fin);
+                let &(_, f) = finals.find(|&&(_, f)| f.starts_with(initial))?;
+                spell("", f, out).filter(|&n| is_syllable(&out[..n]))
+            }
+        }
+    }
+}
+
+// Write initial and final of a syllable to out, with ü as u after j, q, x, y.
+// Return: length of syllable, or None if out is too small
+fn spell(initial: &str, fin: &str, out: &mut [u8]) -> Option<usize> {
+    let n = initial.len() + fin.len();
+    let out = out.get_mut(..n)?;
+    out[..initial.len()].copy_from_slice(initial.as_bytes());
+    let jqxy = matches!(initial, "j" | "q" | "x" | "y");
+    for (o, &b) in out[initial.len()..].iter_mut().zip(fin.as_bytes()) {
+        *o = if b == b'v' && jqxy { b'u' } else { b };
+    }
+    Some(n)
+}
+
+fn is_syllable(s: &[u8]) -> bool {
+    core::str::from_utf8(s).is_ok_and(syllable::is_syllable)
+}
+
+// Convert shuangpin keys to a normalized pinyin key, like "xiang" for "xd"
+// in Ziranma. Text must start at the start of a syllable.
+// Side-effect: write key to buf, and zero tones for the key in marks.
+// Return: (length of key, bitmask of syllable end offsets), or None if text
+// is not whole pairs of keys that are syllables, or does not fit in buf
+pub(crate) fn to_key(
+    layout: &Shuangpin,
+    text: &str,
+    buf: &mut [u8],
+    marks: &mut [u8],
+) -> Option<(usize, u64)> {
+    let mut len = 0;
+    let mut boundaries: u64 = 0;
+    let mut keys = text.chars();
+    while let Some(initial) = keys.next() {
+        let fin = keys.next()?;
+        if !layout.is_key(initial) || !layout.is_key(fin) {
+            return None;
+        }
+        len += layout.decode(initial, fin, buf.get_mut(len..)?)?;
+        boundaries |= 1u64.checked_shl(len as u32).unwrap_or(0);
+    }
+    marks.get_mut(..len)?.fill(0);
+    Some((len, boundaries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_key, Shuangpin, MICROSOFT, XIAOHE, ZIRANMA};
+    use crate::syllable::{INTERJECTIONS, SYLLABLES};
+
+    fn decoded<'a>(layout: &Shuangpin, keys: &str, buf: &'a mut [u8; 64]) -> Option<&'a str> {
+        let (len, _) = to_key(layout, keys, &mut buf[..], &mut [0; 64])?;
+        core::str::from_utf8(&buf[..len]).ok()
+    }
+
+    #[test]
+    fn pairs_decode_to_syllables() {
+        let cases: [(&Shuangpin, &str, &str); 3] = [
+            (&ZIRANMA, "woxdhegovi", "woxiangheguozhi"),
+            (&MICROSOFT, "woxdhegovi", "woxiangheguozhi"),
+            (&XIAOHE, "woxlhegovi", "woxiangheguozhi"),
+        ];
+        for (layout, keys, pinyin) in cases.iter() {
+            let mut buf = [0u8; 64];
+            assert_eq!(Some(*pinyin), decoded(layout, keys, &mut buf), "{}", keys);
+        }
+    }
+
+    #[test]
+    fn syllables_without_initials() {
+        let mut buf = [0u8; 64];
+        assert_eq!(Some("a"), decoded(&ZIRANMA, "aa", &mut buf));
+        assert_eq!(Some("ai"), decoded(&ZIRANMA, "ai", &mut buf));
+        assert_eq!(Some("ang"), decoded(&ZIRANMA, "ah", &mut buf));
+        assert_eq!(Some("er"), decoded(&XIAOHE, "er", &mut buf));
+        assert_eq!(Some("eng"), decoded(&XIAOHE, "eg", &mut buf));
+        assert_eq!(Some("a"), decoded(&MICROSOFT, "oa", &mut buf));
+        assert_eq!(Some("ang"), decoded(&MICROSOFT, "oh", &mut buf));
+        assert_eq!(Some("er"), decoded(&MICROSOFT, "or", &mut buf));
+        assert_eq!(None, decoded(&MICROSOFT, "ah", &mut buf));
+    }
+
+    #[test]
+    fn shared_keys_pick_the_final_that_makes_a_syllable() {
+        let mut buf = [0u8; 64];
+        assert_eq!(Some("jianguang"), decoded(&ZIRANMA, "jmgd", &mut buf));
+        assert_eq!(Some("lvxue"), decoded(&ZIRANMA, "lvxt", &mut buf));
+        assert_eq!(Some("lvejuan"), decoded(&ZIRANMA, "ltjr", &mut buf));
+        assert_eq!(Some("duiying"), decoded(&MICROSOFT, "dvy;", &mut buf));
+        assert_eq!(Some("kuaile"), decoded(&XIAOHE, "kkle", &mut buf));
+        // Odd number of keys, and a pair that is not a syllable
+        assert_eq!(None, decoded(&ZIRANMA, "xdh", &mut buf));
+        assert_eq!(None, decoded(&ZIRANMA, "bd", &mut buf));
+    }
+
+    // Every syllable should be typable as some pair of keys of each layout.
+    #[test]
+    fn layouts_cover_all_syllables() {
+        let keys = "abcdefghijklmnopqrstuvwxyz;";
+        for layout in [&ZIRANMA, &MICROSOFT, &XIAOHE].iter() {
+            for syllable in SYLLABLES.iter() {
+                // Erhua r joins a syllable, lue and nue are spelled lve and
+                // nve, and lo (咯) gives way to luo
+                let skip = ["r", "lue", "nue", "lo"];
+                if INTERJECTIONS.contains(syllable) || skip.contains(syllable) {
+                    continue;
+                }
+                let found = keys.chars().any(|a| {
+                    keys.chars().any(|b| {
+                        let mut buf = [0u8; 64];
+                        let mut pair = [0u8; 8];
+                        let len = a.encode_utf8(&mut pair).len();
+                        let len = len + b.encode_utf8(&mut pair[len..]).len();
+                        let pair = core::str::from_utf8(&pair[..len]).unwrap_or("");
+                        decoded(layout, pair, &mut buf) == Some(*syllable)
+                    })
+                });
+                assert!(found, "{}", syllable);
+            }
+        }
+    }
+}
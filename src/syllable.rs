@@ -0,0 +1,395 @@
+// Syllable inventories for splitting normalized search keys into syllables:
+// Mandarin pinyin, and Cantonese Jyutping for dictionaries in that scheme.
+// Queries may use ' as an explicit syllable boundary, like "xi'an" for 西安,
+// and the boundaries limit which 词语 can match a search key.
+use crate::dictionary::KEY_SIZE_MAX;
+use crate::min;
+
+// Longest syllable in the inventories ("zhuang", "gwaang")
+const SYLLABLE_SIZE_MAX: usize = 6;
+
+// Normalized syllables (ASCII, with v for ü), sorted for binary search.
+// Includes interjections like "hm" and "ng", and "r" for erhua (儿化).
+pub static SYLLABLES: &[&str] = &[
+    "a", "ai", "an", "ang", "ao", "ba", "bai", "ban", "bang", "bao", "bei", "ben", "beng", "bi",
+    "bian", "biao", "bie", "bin", "bing", "bo", "bu", "ca", "cai", "can", "cang", "cao", "ce",
+    "cen", "ceng", "cha", "chai", "chan", "chang", "chao", "che", "chen", "cheng", "chi", "chong",
+    "chou", "chu", "chua", "chuai", "chuan", "chuang", "chui", "chun", "chuo", "ci", "cong", "cou",
+    "cu", "cuan", "cui", "cun", "cuo", "da", "dai", "dan", "dang", "dao", "de", "dei", "den",
+    "deng", "di", "dia", "dian", "diao", "die", "ding", "diu", "dong", "dou", "du", "duan", "dui",
+    "dun", "duo", "e", "ei", "en", "eng", "er", "fa", "fan", "fang", "fei", "fen", "feng", "fo",
+    "fou", "fu", "ga", "gai", "gan", "gang", "gao", "ge", "gei", "gen", "geng", "gong", "gou",
+    "gu", "gua", "guai", "guan", "guang", "gui", "gun", "guo", "ha", "hai", "han", "hang", "hao",
+    "he", "hei", "hen", "heng", "hm", "hng", "hong", "hou", "hu", "hua", "huai", "huan", "huang",
+    "hui", "hun", "huo", "ji", "jia", "jian", "jiang", "jiao", "jie", "jin", "jing", "jiong",
+    "jiu", "ju", "juan", "jue", "jun", "ka", "kai", "kan", "kang", "kao", "ke", "kei", "ken",
+    "keng", "kong", "kou", "ku", "kua", "kuai", "kuan", "kuang", "kui", "kun", "kuo", "la", "lai",
+    "lan", "lang", "lao", "le", "lei", "leng", "li", "lia", "lian", "liang", "liao", "lie", "lin",
+    "ling", "liu", "lo", "long", "lou", "lu", "luan", "lue", "lun", "luo", "lv", "lve", "m", "ma",
+    "mai", "man", "mang", "mao", "me", "mei", "men", "meng", "mi", "mian", "miao", "mie", "min",
+    "ming", "miu", "mo", "mou", "mu", "n", "na", "nai", "nan", "nang", "nao", "ne", "nei", "nen",
+    "neng", "ng", "ni", "nian", "niang", "niao", "nie", "nin", "ning", "niu", "nong", "nou", "nu",
+    "nuan", "nue", "nuo", "nv", "nve", "o", "ou", "pa", "pai", "pan", "pang", "pao", "pei", "pen",
+    "peng", "pi", "pian", "piao", "pie", "pin", "ping", "po", "pou", "pu", "qi", "qia", "qian",
+    "qiang", "qiao", "qie", "qin", "qing", "qiong", "qiu", "qu", "quan", "que", "qun", "r", "ran",
+    "rang", "rao", "re", "ren", "reng", "ri", "rong", "rou", "ru", "rua", "ruan", "rui", "run",
+    "ruo", "sa", "sai", "san", "sang", "sao", "se", "sen", "seng", "sha", "shai", "shan", "shang",
+    "shao", "she", "shei", "shen", "sheng", "shi", "shou", "shu", "shua", "shuai", "shuan",
+    "shuang", "shui", "shun", "shuo", "si", "song", "sou", "su", "suan", "sui", "sun", "suo", "ta",
+    "tai", "tan", "tang", "tao", "te", "tei", "teng", "ti", "tian", "tiao", "tie", "ting", "tong",
+    "tou", "tu", "tuan", "tui", "tun", "tuo", "wa", "wai", "wan", "wang", "wei", "wen", "weng",
+    "wo", "wu", "xi", "xia", "xian", "xiang", "xiao", "xie", "xin", "xing", "xiong", "xiu", "xu",
+    "xuan", "xue", "xun", "ya", "yan", "yang", "yao", "ye", "yi", "yin", "ying", "yo", "yong",
+    "you", "yu", "yuan", "yue", "yun", "za", "zai", "zan", "zang", "zao", "ze", "zei", "zen",
+    "zeng", "zha", "zhai", "zhan", "zhang", "zhao", "zhe", "zhei", "zhen", "zheng", "zhi", "zhong",
+    "zhou", "zhu", "zhua", "zhuai", "zhuan", "zhuang", "zhui", "zhun", "zhuo", "zi", "zong", "zou",
+    "zu", "zuan", "zui", "zun", "zuo",
+];
+
+// Interjections are left out of syllable splitting because they would split
+// "nan" into "n" + "an". They still count as a whole key or chunk of pinyin.
+pub static INTERJECTIONS: &[&str] = &["hm", "hng", "m", "n", "ng"];
+
+// Is s one normalized pinyin syllable?
+pub fn is_syllable(s: &str) -> bool {
+    SYLLABLES.binary_search(&s).is_ok()
+}
+
+// Jyutping initials, with "" for syllables that start with a vowel
+pub static JYUTPING_INITIALS: &[&str] = &[
+    "", "b", "p", "m", "f", "d", "t", "n", "l", "g", "k", "ng", "h", "gw", "kw", "w", "z", "c",
+    "s", "j",
+];
+
+// Jyutping finals. The syllabic nasals m (唔) and ng (五) have no initial.
+pub static JYUTPING_FINALS: &[&str] = &[
+    "aa", "aai", "aau", "aam", "aan", "aang", "aap", "aat", "aak", "a", "ai", "au", "am", "an",
+    "ang", "ap", "at", "ak", "e", "ei", "eu", "em", "en", "eng", "ep", "et", "ek", "i", "iu", "im",
+    "in", "ing", "ip", "it", "ik", "o", "oi", "ou", "on", "ong", "ot", "ok", "oe", "oeng", "oek",
+    "eo", "eoi", "eon", "eot", "u", "ui", "un", "ung", "ut", "uk", "yu", "yun", "yut",
+];
+
+// Is s one Jyutping syllable (without its tone number)? Any initial may go
+// with any final, which allows a few syllables that Cantonese does not use.
+pub fn is_jyutping_syllable(s: &str) -> bool {
+    s == "m"
+        || s == "ng"
+        || JYUTPING_INITIALS.iter().any(|initial| {
+            s.strip_prefix(initial)
+                .is_some_and(|fin| JYUTPING_FINALS.contains(&fin))
+        })
+}
+
+// Phonetic scheme of the search keys of a dictionary.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Scheme {
+    // Hanyu Pinyin for Mandarin, with tones 1 to 4 and 5 for neutral tone
+    #[default]
+    Pinyin,
+    // Jyutping for Cantonese, with tones 1 to 6
+    Jyutping,
+}
+impl Scheme {
+    // Is s one normalized syllable of this scheme?
+    pub fn has_syllable(self, s: &str) -> bool {
+        match self {
+            Scheme::Pinyin => is_syllable(s),
+            Scheme::Jyutping => is_jyutping_syllable(s),
+        }
+    }
+
+    // Return highest tone number, like 6 for Jyutping.
+    pub fn tone_max(self) -> u8 {
+        match self {
+            Scheme::Pinyin => 5,
+            Scheme::Jyutping => 6,
+        }
+    }
+}
+
+// Count the syllables of a 词语 as its number of hanzi.
+pub fn syllable_count(ciyu: &str) -> usize {
+    ciyu.chars().filter(|c| is_hanzi(*c)).count()
+}
+
+fn is_hanzi(c: char) -> bool {
+    matches!(c, '\u{3400}'..='\u{9fff}' | '\u{f900}'..='\u{faff}' | '\u{20000}'..='\u{2ffff}')
+}
+
+// Can normalized key be split into count syllables of scheme, with a split
+// at every byte offset whose bit is set in boundaries? Characters that are
+// not lowercase letters (like the ` in "bier`gaici") are skipped over. An
+// erhua "r" may count as its own syllable (那儿 nar) or not (模特 moter).
+pub(crate) fn fits(scheme: Scheme, key: &str, boundaries: u64, count: usize) -> bool {
+    // Unknown tone for every syllable
+    let tones = &[b'0'; KEY_SIZE_MAX];
+    count <= KEY_SIZE_MAX && fits_tones(scheme, key, boundaries, &[], &tones[..count])
+}
+
+// Same as fits(), but with one tone digit per syllable in tones (like b"35",
+// with '5' for neutral tone and '0' for unknown), and the tone the query gave
+// for some bytes of key in marks (0 for none). Tones of marked syllables
+// must agree with tones.
+pub(crate) fn fits_tones(
+    scheme: Scheme,
+    key: &str,
+    boundaries: u64,
+    marks: &[u8],
+    tones: &[u8],
+) -> bool {
+    let key = key.as_bytes();
+    let n = key.len();
+    let count = tones.len();
+    if n > KEY_SIZE_MAX || count > KEY_SIZE_MAX {
+        return false;
+    }
+    // agree[t] has bit k set if syllable k may have tone t
+    let mut agree = [0u64; 7];
+    for (k, &tone) in tones.iter().enumerate() {
+        for (t, bits) in agree.iter_mut().enumerate() {
+            if tone == b'0' || usize::from(tone.wrapping_sub(b'0')) == t {
+                *bits |= 1 << k;
+            }
+        }
+    }
+    // reach[p] has bit k set if key[..p] splits into k syllables
+    let mut reach = [0u64; KEY_SIZE_MAX + 1];
+    reach[0] = 1;
+    for p in 0..n {
+        if reach[p] == 0 {
+            continue;
+        }
+        if !key[p].is_ascii_lowercase() {
+            // Skip separator, but only where a split is allowed anyway
+            reach[p + 1] |= reach[p];
+            continue;
+        }
+        for q in p + 1..=min(p + SYLLABLE_SIZE_MAX, n) {
+            // A syllable must not contain a required boundary
+            if boundaries & mask_between(p, q) != 0 {
+                break;
+            }
+            let s = match core::str::from_utf8(&key[p..q]) {
+                Ok(s) => s,
+                Err(_) => break,
+            };
+            if scheme.has_syllable(s) {
+                let mut allowed = reach[p];
+                for &t in marks.get(p..q).unwrap_or(&[]) {
+                    if t != 0 {
+                        allowed &= agree[usize::from(t).min(6)];
+                    }
+                }
+                reach[q] |= allowed << 1;
+                if s == "r" {
+                    reach[q] |= reach[p];
+                }
+            }
+        }
+    }
+    reach[n] & (1 << count) != 0
+}
+
+// Split normalized key into count syllables of scheme, trying longer
+// syllables first like the vocab compiler does. Characters that are not
+// lowercase letters are skipped over. An erhua "r" joins the syllable before
+// it unless there is no other way to split the key (那儿 nar, but 比尔 bier).
+// Return: bits for the byte offsets where syllables end, or None
+pub(crate) fn split(scheme: Scheme, key: &str, count: usize) -> Option<u64> {
+    if key.len() > KEY_SIZE_MAX {
+        return None;
+    }
+    let key = key.as_bytes();
+    let split = |erhua| split_from(scheme, key, 0, count, 0, erhua);
+    split(false).or_else(|| split(true))
+}
+
+fn split_from(
+    scheme: Scheme,
+    key: &[u8],
+    p: usize,
+    count: usize,
+    ends: u64,
+    erhua: bool,
+) -> Option<u64> {
+    let n = key.len();
+    if p == n {
+        return (count == 0).then_some(ends);
+    }
+    if !key[p].is_ascii_lowercase() {
+        return split_from(scheme, key, p + 1, count, ends, erhua);
+    }
+    for q in (p + 1..=min(p + SYLLABLE_SIZE_MAX, n)).rev() {
+        let s = match core::str::from_utf8(&key[p..q]) {
+            Ok(s) => s,
+            Err(_) => continue,
+        };
+        // Jyutping m and ng are syllables of their own, like 唔 m4
+        let whole = p == 0 && q == n;
+        let allowed = match (scheme, s) {
+            (Scheme::Jyutping, _) => true,
+            (_, "r") => erhua,
+            _ => whole || !INTERJECTIONS.contains(&s),
+        };
+        if count > 0 && allowed && scheme.has_syllable(s) {
+            let ends = ends | 1 << q;
+            if let Some(ends) = split_from(scheme, key, q, count - 1, ends, erhua) {
+                return Some(ends);
+            }
+        }
+    }
+    if scheme == Scheme::Pinyin && key[p] == b'r' && ends & 1 << p != 0 {
+        let ends = (ends & !(1 << p)) | 1 << (p + 1);
+        return split_from(scheme, key, p + 1, count, ends, erhua);
+    }
+    None
+}
+
+// Split pinyin vowel with tone mark (or ü) into its normalized ASCII letter
+// and tone (0 for none).
+pub fn unmark(c: char) -> Option<(u8, u8)> {
+    let (vowel, tone) = match c {
+        'ā' => (b'a', 1),
+        'á' => (b'a', 2),
+        'ǎ' => (b'a', 3),
+        'à' => (b'a', 4),
+        'ē' => (b'e', 1),
+        'é' => (b'e', 2),
+        'ě' => (b'e', 3),
+        'è' => (b'e', 4),
+        'ī' => (b'i', 1),
+        'í' => (b'i', 2),
+        'ǐ' => (b'i', 3),
+        'ì' => (b'i', 4),
+        'ō' => (b'o', 1),
+        'ó' => (b'o', 2),
+        'ǒ' => (b'o', 3),
+        'ò' => (b'o', 4),
+        'ū' => (b'u', 1),
+        'ú' => (b'u', 2),
+        'ǔ' => (b'u', 3),
+        'ù' => (b'u', 4),
+        'ǖ' => (b'v', 1),
+        'ǘ' => (b'v', 2),
+        'ǚ' => (b'v', 3),
+        'ǜ' => (b'v', 4),
+        'ü' => (b'v', 0),
+        'ń' => (b'n', 2),
+        'ň' => (b'n', 3),
+        'ǹ' => (b'n', 4),
+        _ => return None,
+    };
+    Some((vowel, tone))
+}
+
+// Bits for offsets strictly between p and q.
+fn mask_between(p: usize, q: usize) -> u64 {
+    match q > p + 1 {
+        true => ((1u64 << q) - 1) & !((1u64 << (p + 1)) - 1),
+        false => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        fits, fits_tones, is_jyutping_syllable, is_syllable, split, syllable_count, unmark,
+        Scheme::{Jyutping, Pinyin},
+        SYLLABLES,
+    };
+    use crate::autogen_hsk;
+
+    #[test]
+    fn syllables_are_sorted() {
+        for i in 1..SYLLABLES.len() {
+            assert!(SYLLABLES[i - 1] < SYLLABLES[i]);
+        }
+    }
+
+    #[test]
+    fn inventory_lookup() {
+        assert!(is_syllable("xian"));
+        assert!(is_syllable("zhuang"));
+        assert!(is_syllable("lve"));
+        assert!(!is_syllable("xhi"));
+        assert!(!is_syllable("zhuangg"));
+    }
+
+    #[test]
+    fn boundaries_limit_splits() {
+        assert!(fits(Pinyin, "xian", 0, 1));
+        assert!(fits(Pinyin, "xian", 0, 2));
+        assert!(!fits(Pinyin, "xian", 1 << 2, 1));
+        assert!(fits(Pinyin, "xian", 1 << 2, 2));
+        assert!(!fits(Pinyin, "xian", 1 << 1, 2));
+        assert!(fits(Pinyin, "nar", 0, 2));
+        assert!(fits(Pinyin, "moter", 0, 2));
+    }
+
+    #[test]
+    fn marks_must_agree_with_tones() {
+        // xiǎng, with the mark on a
+        let marks = [0, 0, 3, 0, 0];
+        assert!(fits_tones(Pinyin, "xiang", 0, &marks, b"3"));
+        assert!(fits_tones(Pinyin, "xiang", 0, &marks, b"0"));
+        assert!(!fits_tones(Pinyin, "xiang", 0, &marks, b"4"));
+        // xi'an with a tone number after each syllable (xi1an1)
+        let marks = [0, 1, 0, 1];
+        assert!(fits_tones(Pinyin, "xian", 1 << 2, &marks, b"11"));
+        assert!(!fits_tones(Pinyin, "xian", 1 << 2, &marks, b"14"));
+        assert!(fits_tones(Pinyin, "xian", 0, &[], b"14"));
+        assert_eq!(Some((b'a', 3)), unmark('ǎ'));
+        assert_eq!(Some((b'v', 0)), unmark('ü'));
+        assert_eq!(None, unmark('a'));
+    }
+
+    #[test]
+    fn split_prefers_longer_syllables() {
+        assert_eq!(Some(1 << 4), split(Pinyin, "xian", 1));
+        assert_eq!(Some(1 << 2 | 1 << 4), split(Pinyin, "xian", 2));
+        assert_eq!(Some(1 << 3 | 1 << 7), split(Pinyin, "rengong", 2));
+        assert_eq!(None, split(Pinyin, "xian", 3));
+        // Interjections only as the whole key
+        assert_eq!(Some(1 << 3 | 1 << 5), split(Pinyin, "nanan", 2));
+        assert_eq!(Some(1 << 2), split(Pinyin, "ng", 1));
+        // Erhua joined to the syllable before it, or on its own
+        assert_eq!(Some(1 << 2 | 1 << 3), split(Pinyin, "nar", 2));
+        assert_eq!(Some(1 << 3), split(Pinyin, "nar", 1));
+        assert_eq!(
+            Some(1 << 2 | 1 << 4 | 1 << 8 | 1 << 10),
+            split(Pinyin, "bier`gaici", 4)
+        );
+    }
+
+    #[test]
+    fn jyutping_syllables_split_with_six_tones() {
+        assert!(is_jyutping_syllable("gwaang"));
+        assert!(is_jyutping_syllable("jyut"));
+        assert!(is_jyutping_syllable("m"));
+        assert!(!is_jyutping_syllable("zhong"));
+        assert!(!is_syllable("jyut"));
+        // 你好 nei5hou2
+        assert_eq!(Some(1 << 3 | 1 << 6), split(Jyutping, "neihou", 2));
+        // 唔該 m4goi1, where pinyin would only allow m as the whole key
+        assert_eq!(Some(1 << 1 | 1 << 4), split(Jyutping, "mgoi", 2));
+        assert_eq!(None, split(Pinyin, "mgoi", 2));
+        // 食飯 sik6faan6, with tone 6 marked on the first syllable
+        let marks = [0, 0, 6, 0, 0, 0, 0];
+        assert!(fits_tones(Jyutping, "sikfaan", 0, &marks, b"66"));
+        assert!(!fits_tones(Jyutping, "sikfaan", 0, &marks, b"56"));
+        assert!(!fits(Pinyin, "sikfaan", 0, 2));
+    }
+
+    // Every vocab key should split into one syllable per hanzi, except for
+    // book titles and such with punctuation in the key.
+    #[test]
+    fn all_vocab_keys_split_into_syllables() {
+        for (pinyin, ciyu) in autogen_hsk::PINYIN_CIYU_TEST_DATA.iter() {
+            let n = syllable_count(ciyu);
+            if n > 0 && pinyin.bytes().all(|b| b.is_ascii_lowercase() || b == b'`') {
+                assert!(fits(Pinyin, pinyin, 0, n), "{} {}", pinyin, ciyu);
+                assert!(split(Pinyin, pinyin, n).is_some(), "{} {}", pinyin, ciyu);
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+// User dictionary of custom 词语, like product names, names of people, and
+// jargon that is not in the HSK lists. Entries live in a caller-provided
+// buffer (no heap), and a dictionary::Dictionary can be layered over them with
+// Dictionary::with_user(). User entries come before the dictionary's own
+// homophones in choices.
+//
+// Buffer layout: a run of records, each [key length u8][词语 length u8]
+// followed by the key and 词语 bytes. Bytes past the last record are unused.
+// To save a user dictionary, store as_bytes(). To load it again, copy the
+// bytes to the start of a buffer and use from_bytes().
+use crate::dictionary::KEY_SIZE_MAX;
+use crate::syllable;
+
+// Most bytes of 词语 in one entry
+pub const CIYU_SIZE_MAX: usize = 255;
+const RECORD_HEADER_SIZE: usize = 2;
+
+// Problems that keep an entry from being added.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UserDictionaryError {
+    // Pinyin is empty, too long, or has characters that are not pinyin
+    Key,
+    // 词语 is empty, too long, or has a tab
+    Ciyu,
+    // Buffer has no room for the entry
+    Full,
+    // Bytes for from_bytes() are not a run of valid records
+    Layout,
+}
+
+pub struct UserDictionary<'a> {
+    buf: &'a mut [u8],
+    used: usize,
+}
+impl<'a> UserDictionary<'a> {
+    // Return empty user dictionary that keeps its entries in buf.
+    pub fn new(buf: &'a mut [u8]) -> UserDictionary<'a> {
+        UserDictionary { buf, used: 0 }
+    }
+
+    // Return user dictionary for the first len bytes of buf, which hold
+    // records saved from as_bytes(). Later bytes of buf are room for more
+    // entries.
+    pub fn from_bytes(
+        buf: &'a mut [u8],
+        len: usize,
+    ) -> Result<UserDictionary<'a>, UserDictionaryError> {
+        let used = buf.get(..len).ok_or(UserDictionaryError::Layout)?;
+        let mut pos = 0;
+        while pos < len {
+            let key_len = usize::from(used[pos]);
+            let ciyu_len = *used.get(pos + 1).ok_or(UserDictionaryError::Layout)?;
+            let key_start = pos + RECORD_HEADER_SIZE;
+            let ciyu_start = key_start + key_len;
+            let end = ciyu_start + usize::from(ciyu_len);
+            let key = used.get(key_start..ciyu_start);
+            let ciyu = used.get(ciyu_start..end).map(core::str::from_utf8);
+            match (key, ciyu) {
+                (Some(key), Some(Ok(ciyu)))
+                    if !key.is_empty()
+                        && key.len() <= KEY_SIZE_MAX
+                        && key.iter().all(u8::is_ascii_lowercase)
+                        && !ciyu.is_empty()
+                        && !ciyu.contains('\t') => {}
+                _ => return Err(UserDictionaryError::Layout),
+            }
+            pos = end;
+        }
+        Ok(UserDictionary { buf, used: len })
+    }
+
+    // Bytes of all entries, for saving the user dictionary.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.used]
+    }
+
+    // Add 词语 with pinyin search key, like ("Xī'ān", "西安"). Tone marks,
+    // spaces, apostrophes, and hyphens are left out of the key, so queries
+    // match the same way as for vocab in the dictionary. Adding an entry
+    // that is already there does nothing.
+    pub fn add(&mut self, pinyin: &str, ciyu: &str) -> Result<(), UserDictionaryError> {
+        let mut key_buf = [0u8; KEY_SIZE_MAX];
+        let key = normalize(pinyin, &mut key_buf).ok_or(UserDictionaryError::Key)?;
+        if ciyu.is_empty() || ciyu.len() > CIYU_SIZE_MAX || ciyu.contains('\t') {
+            return Err(UserDictionaryError::Ciyu);
+        }
+        if self.position(key, ciyu).is_some() {
+            return Ok(());
+        }
+        let size = RECORD_HEADER_SIZE + key.len() + ciyu.len();
+        let record = match self.buf.get_mut(self.used..self.used + size) {
+            Some(record) => record,
+            None => return Err(UserDictionaryError::Full),
+        };
+        record[0] = key.len() as u8;
+        record[1] = ciyu.len() as u8;
+        record[2..2 + key.len()].copy_from_slice(key.as_bytes());
+        record[2 + key.len()..].copy_from_slice(ciyu.as_bytes());
+        self.used += size;
+        Ok(())
+    }
+
+    // Remove 词语 with pinyin search key.
+    // Return: true if the entry was there
+    pub fn remove(&mut self, pinyin: &str, ciyu: &str) -> bool {
+        let mut key_buf = [0u8; KEY_SIZE_MAX];
+        let key = match normalize(pinyin, &mut key_buf) {
+            Some(key) => key,
+            None => return false,
+        };
+        match self.position(key, ciyu) {
+            Some(pos) => {
+                let size = RECORD_HEADER_SIZE + key.len() + ciyu.len();
+                self.buf.copy_within(pos + size..self.used, pos);
+                self.used -= size;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Remove all entries.
+    pub fn clear(&mut self) {
+        self.used = 0;
+    }
+
+    // Number of entries.
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.used == 0
+    }
+
+    // Iterate over entries as (search key, 词语), oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.records().map(|(_, key, ciyu)| (key, ciyu))
+    }
+
+    // Return entry of the record at byte position pos as (search key, 词语),
+    // or ("", "") if no record starts there.
+    pub(crate) fn entry_at(&self, pos: usize) -> (&str, &str) {
+        self.records_from(pos)
+            .next()
+            .map_or(("", ""), |(_, key, ciyu)| (key, ciyu))
+    }
+
+    // Characters in the longest search key.
+    pub(crate) fn key_size_max(&self) -> usize {
+        self.iter().map(|(key, _)| key.len()).max().unwrap_or(0)
+    }
+
+    // Iterate over records as (byte position, search key, 词语).
+    pub(crate) fn records(&self) -> impl Iterator<Item = (usize, &str, &str)> {
+        self.records_from(0)
+    }
+
+    // Iterate over records starting with the one at byte position pos.
+    fn records_from(&self, pos: usize) -> impl Iterator<Item = (usize, &str, &str)> {
+        let used = &self.buf[..self.used];
+        let mut pos = pos;
+        core::iter::from_fn(move || {
+            let key_len = usize::from(*used.get(pos)?);
+            let ciyu_len = usize::from(*used.get(pos + 1)?);
+            let key_start = pos + RECORD_HEADER_SIZE;
+            let ciyu_start = key_start + key_len;
+            let end = ciyu_start + ciyu_len;
+            // Records are written by add() or checked by from_bytes(), so
+            // they are valid UTF-8
+            let key = core::str::from_utf8(used.get(key_start..ciyu_start)?).ok()?;
+            let ciyu = core::str::from_utf8(used.get(ciyu_start..end)?).ok()?;
+            let record = (pos, key, ciyu);
+            pos = end;
+            Some(record)
+        })
+    }
+
+    // Return byte position of the record for key and 词语.
+    fn position(&self, key: &str, ciyu: &str) -> Option<usize> {
+        self.records()
+            .find(|&(_, k, c)| k == key && c == ciyu)
+            .map(|(pos, _, _)| pos)
+    }
+}
+
+// Normalize pinyin into a lowercase ASCII search key in buf.
+// Return: key, or None if pinyin is not a valid search key
+fn normalize<'b>(pinyin: &str, buf: &'b mut [u8; KEY_SIZE_MAX]) -> Option<&'b str> {
+    let mut len = 0;
+    for c in pinyin.chars().flat_map(char::to_lowercase) {
+        let letter = match c {
+            ' ' | '\'' | '-' => continue,
+            'a'..='z' => c as u8,
+            c => syllable::unmark(c)?.0,
+        };
+        *buf.get_mut(len)? = letter;
+        len += 1;
+    }
+    match len {
+        0 => None,
+        _ => core::str::from_utf8(&buf[..len]).ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dictionary::Dictionary;
+    use crate::options::Options;
+    use crate::{look_up_with, BufWriter, Writer};
+
+    fn look_up_user<'a>(qry: &str, user: &UserDictionary, sink: &'a mut BufWriter) -> &'a str {
+        let options = Options {
+            dictionary: Dictionary::hsk().with_user(user),
+            ..Options::default()
+        };
+        sink.rewind();
+        look_up_with(qry, &options, sink);
+        sink.to_s()
+    }
+
+    #[test]
+    fn add_and_remove_entries() {
+        let mut buf = [0u8; 64];
+        let mut user = UserDictionary::new(&mut buf);
+        assert!(user.is_empty());
+        assert_eq!(Ok(()), user.add("Xī'ān", "西安"));
+        assert_eq!(Ok(()), user.add("gong si", "公司"));
+        assert_eq!(Ok(()), user.add("xian", "西安"));
+        assert_eq!(2, user.len());
+        assert_eq!(Some(("xian", "西安")), user.iter().next());
+        assert!(user.remove("xian", "西安"));
+        assert!(!user.remove("xian", "西安"));
+        assert_eq!(1, user.len());
+        assert_eq!(("gongsi", "公司"), user.entry_at(0));
+        user.clear();
+        assert!(user.is_empty());
+    }
+
+    #[test]
+    fn saved_bytes_load_again() {
+        let mut buf = [0u8; 64];
+        let mut user = UserDictionary::new(&mut buf);
+        user.add("Xī'ān", "西安").unwrap();
+        user.add("gongsi", "公司").unwrap();
+        let mut saved = [0u8; 64];
+        let len = user.as_bytes().len();
+        saved[..len].copy_from_slice(user.as_bytes());
+        let mut loaded = UserDictionary::from_bytes(&mut saved, len).unwrap();
+        assert!(loaded.iter().eq(user.iter()));
+        assert_eq!(Ok(()), loaded.add("an", "安"));
+        assert_eq!(3, loaded.len());
+        let sink = &mut BufWriter::new();
+        assert_eq!("西安", look_up_user("xian ", &loaded, sink));
+        // Bad bytes
+        let mut empty = [0u8; 4];
+        assert!(UserDictionary::from_bytes(&mut empty, 0)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            Err(UserDictionaryError::Layout),
+            UserDictionary::from_bytes(&mut empty, 5).map(|u| u.len())
+        );
+        let mut zero_key = [0u8, 1, b'x'];
+        assert_eq!(
+            Err(UserDictionaryError::Layout),
+            UserDictionary::from_bytes(&mut zero_key, 3).map(|u| u.len())
+        );
+        let mut short = [2u8, 3, b'a', b'n', 0xe5, 0xae];
+        assert_eq!(
+            Err(UserDictionaryError::Layout),
+            UserDictionary::from_bytes(&mut short, 6).map(|u| u.len())
+        );
+        let mut upper = *b"\x02\x01ANx";
+        assert_eq!(
+            Err(UserDictionaryError::Layout),
+            UserDictionary::from_bytes(&mut upper, 5).map(|u| u.len())
+        );
+    }
+
+    #[test]
+    fn bad_entries_are_rejected() {
+        let mut buf = [0u8; 16];
+        let mut user = UserDictionary::new(&mut buf);
+        assert_eq!(Err(UserDictionaryError::Key), user.add("", "公司"));
+        assert_eq!(Err(UserDictionaryError::Key), user.add("gong4si1", "公司"));
+        let long = core::str::from_utf8(&[b'a'; KEY_SIZE_MAX + 1]).unwrap();
+        assert_eq!(Err(UserDictionaryError::Key), user.add(long, "公司"));
+        assert_eq!(Err(UserDictionaryError::Ciyu), user.add("gongsi", ""));
+        assert_eq!(Err(UserDictionaryError::Ciyu), user.add("gongsi", "公\t司"));
+        assert_eq!(Ok(()), user.add("gongsi", "公司"));
+        assert_eq!(Err(UserDictionaryError::Full), user.add("gongsi", "工司"));
+        assert_eq!(1, user.len());
+    }
+
+    #[test]
+    fn user_entries_match_queries() {
+        let mut buf = [0u8; 256];
+        let mut user = UserDictionary::new(&mut buf);
+        user.add("Āndélǔ", "安德鲁").unwrap();
+        user.add("kuaisuanpan", "快算盘").unwrap();
+        let sink = &mut BufWriter::new();
+        assert_eq!("安德鲁", look_up_user("andelu", &user, sink));
+        assert_eq!(
+            "我 (1的 2得 3地) 快算盘",
+            look_up_user("wo dekuaisuanpan", &user, sink)
+        );
+        assert_eq!("我想", look_up_user("wo xiang ", &user, sink));
+    }
+
+    #[test]
+    fn user_entries_come_first_in_homophone_choices() {
+        let mut buf = [0u8; 256];
+        let mut user = UserDictionary::new(&mut buf);
+        let sink = &mut BufWriter::new();
+        let choices = " (1想 2向 3像 4香 5响 6项 7象 8巷) ";
+        assert_eq!(choices, look_up_user("xiang", &user, sink));
+        user.add("xiang", "湘").unwrap();
+        user.add("xiang", "像").unwrap();
+        let choices = " (1湘 2像 3想 4向 5香 6响 7项 8象 9巷) ";
+        assert_eq!(choices, look_up_user("xiang", &user, sink));
+        assert_eq!("湘", look_up_user("xiang ", &user, sink));
+        assert_eq!("像", look_up_user("xiang2", &user, sink));
+    }
+}
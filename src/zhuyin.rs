@@ -0,0 +1,348 @@
+// Zhuyin (Bopomofo) input. A Zhuyin syllable is spelled with up to one
+// initial, one medial, and one final symbol, in that order, and ends with a
+// tone key. Space is the tone key for first tone, like on Taiwanese Dachen
+// keyboards. Search converts Zhuyin syllables to the same pinyin keys that
+// pinyin input uses, and the tone keys narrow down the choices.
+
+// Initials, with their pinyin spelling
+const INITIALS: [(char, &str); 21] = [
+    ('ㄅ', "b"),
+    ('ㄆ', "p"),
+    ('ㄇ', "m"),
+    ('ㄈ', "f"),
+    ('ㄉ', "d"),
+    ('ㄊ', "t"),
+    ('ㄋ', "n"),
+    ('ㄌ', "l"),
+    ('ㄍ', "g"),
+    ('ㄎ', "k"),
+    ('ㄏ', "h"),
+    ('ㄐ', "j"),
+    ('ㄑ', "q"),
+    ('ㄒ', "x"),
+    ('ㄓ', "zh"),
+    ('ㄔ', "ch"),
+    ('ㄕ', "sh"),
+    ('ㄖ', "r"),
+    ('ㄗ', "z"),
+    ('ㄘ', "c"),
+    ('ㄙ', "s"),
+];
+
+const MEDIALS: [char; 3] = ['ㄧ', 'ㄨ', 'ㄩ'];
+
+// Finals, with their pinyin spelling when there is no medial
+const FINALS: [(char, &str); 13] = [
+    ('ㄚ', "a"),
+    ('ㄛ', "o"),
+    ('ㄜ', "e"),
+    ('ㄝ', "e"),
+    ('ㄞ', "ai"),
+    ('ㄟ', "ei"),
+    ('ㄠ', "ao"),
+    ('ㄡ', "ou"),
+    ('ㄢ', "an"),
+    ('ㄣ', "en"),
+    ('ㄤ', "ang"),
+    ('ㄥ', "eng"),
+    ('ㄦ', "er"),
+];
+
+// Tone keys, with their tone numbers (5 for neutral tone)
+const TONES: [(char, u8); 6] = [(' ', 1), ('ˉ', 1), ('ˊ', 2), ('ˇ', 3), ('ˋ', 4), ('˙', 5)];
+
+// Standard Dachen (大千) layout of a US keyboard
+const DACHEN: [(char, char); 41] = [
+    ('1', 'ㄅ'),
+    ('q', 'ㄆ'),
+    ('a', 'ㄇ'),
+    ('z', 'ㄈ'),
+    ('2', 'ㄉ'),
+    ('w', 'ㄊ'),
+    ('s', 'ㄋ'),
+    ('x', 'ㄌ'),
+    ('e', 'ㄍ'),
+    ('d', 'ㄎ'),
+    ('c', 'ㄏ'),
+    ('r', 'ㄐ'),
+    ('f', 'ㄑ'),
+    ('v', 'ㄒ'),
+    ('5', 'ㄓ'),
+    ('t', 'ㄔ'),
+    ('g', 'ㄕ'),
+    ('b', 'ㄖ'),
+    ('y', 'ㄗ'),
+    ('h', 'ㄘ'),
+    ('n', 'ㄙ'),
+    ('u', 'ㄧ'),
+    ('j', 'ㄨ'),
+    ('m', 'ㄩ'),
+    ('8', 'ㄚ'),
+    ('i', 'ㄛ'),
+    ('k', 'ㄜ'),
+    (',', 'ㄝ'),
+    ('9', 'ㄞ'),
+    ('o', 'ㄟ'),
+    ('l', 'ㄠ'),
+    ('.', 'ㄡ'),
+    ('0', 'ㄢ'),
+    ('p', 'ㄣ'),
+    (';', 'ㄤ'),
+    ('/', 'ㄥ'),
+    ('-', 'ㄦ'),
+    ('6', 'ˊ'),
+    ('3', 'ˇ'),
+    ('4', 'ˋ'),
+    ('7', '˙'),
+];
+
+// Return Zhuyin symbol or tone mark for a key of the Dachen layout, like 'ㄅ'
+// for '1'. Space stays space, since it is the first tone key.
+pub fn dachen(key: char) -> Option<char> {
+    DACHEN.iter().find(|(k, _)| *k == key).map(|&(_, z)| z)
+}
+
+// Return position of Zhuyin symbol c in a syllable (0 initial, 1 medial,
+// 2 final), or None if c is not a Zhuyin symbol.
+fn class(c: char) -> Option<u8> {
+    if INITIALS.iter().any(|(z, _)| *z == c) {
+        Some(0)
+    } else if MEDIALS.contains(&c) {
+        Some(1)
+    } else if FINALS.iter().any(|(z, _)| *z == c) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn tone(c: char) -> Option<u8> {
+    TONES.iter().find(|(k, _)| *k == c).map(|&(_, t)| t)
+}
+
+// Is c a tone key? Tone keys do not count toward the size of a search window.
+pub(crate) fn is_tone(c: char) -> bool {
+    tone(c).is_some()
+}
+
+// Return pinyin spelling of a medial and final, first for after an initial,
+// then for a syllable without an initial (v is ü, like in search keys).
+fn rime(medial: Option<char>, fin: Option<char>) -> Option<(&'static str, &'static str)> {
+    let spelling = match (medial, fin) {
+        (None, Some(f)) => {
+            let s = FINALS.iter().find(|(z, _)| *z == f)?.1;
+            (s, s)
+        }
+        (Some('ㄧ'), None) => ("i", "yi"),
+        (Some('ㄧ'), Some('ㄚ')) => ("ia", "ya"),
+        (Some('ㄧ'), Some('ㄛ')) => ("io", "yo"),
+        (Some('ㄧ'), Some('ㄝ')) => ("ie", "ye"),
+        (Some('ㄧ'), Some('ㄞ')) => ("iai", "yai"),
+        (Some('ㄧ'), Some('ㄠ')) => ("iao", "yao"),
+        (Some('ㄧ'), Some('ㄡ')) => ("iu", "you"),
+        (Some('ㄧ'), Some('ㄢ')) => ("ian", "yan"),
+        (Some('ㄧ'), Some('ㄣ')) => ("in", "yin"),
+        (Some('ㄧ'), Some('ㄤ')) => ("iang", "yang"),
+        (Some('ㄧ'), Some('ㄥ')) => ("ing", "ying"),
+        (Some('ㄨ'), None) => ("u", "wu"),
+        (Some('ㄨ'), Some('ㄚ')) => ("ua", "wa"),
+        (Some('ㄨ'), Some('ㄛ')) => ("uo", "wo"),
+        (Some('ㄨ'), Some('ㄞ')) => ("uai", "wai"),
+        (Some('ㄨ'), Some('ㄟ')) => ("ui", "wei"),
+        (Some('ㄨ'), Some('ㄢ')) => ("uan", "wan"),
+        (Some('ㄨ'), Some('ㄣ')) => ("un", "wen"),
+        (Some('ㄨ'), Some('ㄤ')) => ("uang", "wang"),
+        (Some('ㄨ'), Some('ㄥ')) => ("ong", "weng"),
+        (Some('ㄩ'), None) => ("v", "yu"),
+        (Some('ㄩ'), Some('ㄝ')) => ("ve", "yue"),
+        (Some('ㄩ'), Some('ㄢ')) => ("van", "yuan"),
+        (Some('ㄩ'), Some('ㄣ')) => ("vn", "yun"),
+        (Some('ㄩ'), Some('ㄥ')) => ("iong", "yong"),
+        _ => return None,
+    };
+    Some(spelling)
+}
+
+// Zhuyin symbols of one syllable, by class
+#[derive(Copy, Clone, Default)]
+struct Syllable {
+    symbols: [Option<char>; 3],
+    // Class of the last symbol, or None for an empty syllable
+    last: Option<u8>,
+}
+impl Syllable {
+    // Can c go after the symbols so far?
+    fn continues(&self, class: u8) -> bool {
+        self.last.is_none_or(|last| class > last)
+    }
+
+    // Side-effect: write pinyin spelling to out.
+    // Return: number of bytes written, or None if this is not a syllable or
+    // out is too small
+    fn write_pinyin(&self, out: &mut [u8]) -> Option<usize> {
+        let [initial, medial, fin] = self.symbols;
+        let (initial, rime) = match initial {
+            // Buzzing vowel of zhi, chi, shi, ri, zi, ci, si
+            Some(i @ 'ㄓ'..='ㄙ') if medial.is_none() && fin.is_none() => (Some(i), "i"),
+            Some(i) => (Some(i), rime(medial, fin)?.0),
+            None => (None, rime(medial, fin)?.1),
+        };
+        let mut len = 0;
+        if let Some(i) = initial {
+            let s = INITIALS.iter().find(|(z, _)| *z == i)?.1;
+            out.get_mut(..s.len())?.copy_from_slice(s.as_bytes());
+            len = s.len();
+        }
+        // ü is written u after j, q, x
+        let jqx = matches!(initial, Some('ㄐ' | 'ㄑ' | 'ㄒ'));
+        for &b in rime.as_bytes() {
+            *out.get_mut(len)? = if b == b'v' && jqx { b'u' } else { b };
+            len += 1;
+        }
+        Some(len)
+    }
+}
+
+// Convert Zhuyin text to a normalized pinyin key, like "wo" for ㄨㄛˇ. Next
+// is the character after text in the query, which must not belong to the
+// last syllable of text, and prev is the character before text, which must
+// not leave text starting in the middle of a syllable.
+// Side-effect: write key to buf, and the tone of each syllable (0 if not
+// given) at the offset of its last byte in marks.
+// Return: (length of key, bitmask of syllable end offsets), or None if text
+// is not whole Zhuyin syllables or does not fit in buf
+pub(crate) fn to_key(
+    text: &str,
+    prev: Option<char>,
+    next: Option<char>,
+    buf: &mut [u8],
+    marks: &mut [u8],
+) -> Option<(usize, u64)> {
+    let first = text.chars().next()?;
+    if let (Some(p), Some(c)) = (prev.and_then(class), class(first)) {
+        if c > p {
+            return None;
+        }
+    }
+    let mut len = 0;
+    let mut boundaries: u64 = 0;
+    let mut syllable = Syllable::default();
+    let mut end_syllable = |syllable: &mut Syllable, tone: u8| -> Option<()> {
+        let n = syllable.write_pinyin(buf.get_mut(len..)?)?;
+        marks.get_mut(len..len + n)?.fill(0);
+        len += n;
+        marks[len - 1] = tone;
+        boundaries |= 1u64.checked_shl(len as u32).unwrap_or(0);
+        *syllable = Syllable::default();
+        Some(())
+    };
+    for c in text.chars() {
+        if let Some(k) = class(c) {
+            if !syllable.continues(k) {
+                // Syllable without a tone key
+                end_syllable(&mut syllable, 0)?;
+            }
+            syllable.symbols[usize::from(k)] = Some(c);
+            syllable.last = Some(k);
+        } else {
+            // Tone key must end a syllable
+            let tone = tone(c)?;
+            syllable.last?;
+            end_syllable(&mut syllable, tone)?;
+        }
+    }
+    if let Some(last) = syllable.last {
+        match next {
+            Some(c) if is_tone(c) => return None,
+            Some(c) if class(c).is_some_and(|k| k > last) => return None,
+            _ => end_syllable(&mut syllable, 0)?,
+        }
+    }
+    Some((len, boundaries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dachen, to_key};
+
+    // Return pinyin key and tone marks (as digits) for Zhuyin text.
+    fn key<'a>(text: &str, buf: &'a mut [u8; 32], tones: &mut [u8; 32]) -> Option<&'a str> {
+        let mut marks = [0u8; 32];
+        let (len, _) = to_key(text, None, None, &mut buf[..], &mut marks)?;
+        for (t, &m) in tones.iter_mut().zip(marks[..len].iter()) {
+            *t = if m == 0 { b'.' } else { b'0' + m };
+        }
+        core::str::from_utf8(&buf[..len]).ok()
+    }
+
+    #[test]
+    fn syllables_convert_to_pinyin() {
+        let cases = [
+            ("ㄨㄛˇ", "wo", ".3"),
+            ("ㄒㄧㄤˇ", "xiang", "....3"),
+            ("ㄏㄜ ", "he", ".1"),
+            ("ㄓ ", "zhi", "..1"),
+            ("ㄙˋ", "si", ".4"),
+            ("ㄐㄩㄝˊ", "jue", "..2"),
+            ("ㄌㄩˋ", "lv", ".4"),
+            ("ㄩㄢˊ", "yuan", "...2"),
+            ("ㄑㄩㄥˊ", "qiong", "....2"),
+            ("ㄧㄡˇ", "you", "..3"),
+            ("ㄌㄧㄡˊ", "liu", "..2"),
+            ("ㄉㄨㄟˋ", "dui", "..4"),
+            ("ㄉㄨㄥ ", "dong", "...1"),
+            ("ㄨㄥ ", "weng", "...1"),
+            ("ㄦˊ", "er", ".2"),
+            ("ㄇㄜ˙", "me", ".5"),
+        ];
+        for (zhuyin, pinyin, tones) in cases.iter() {
+            let (mut buf, mut marks) = ([0u8; 32], [0u8; 32]);
+            assert_eq!(
+                Some(*pinyin),
+                key(zhuyin, &mut buf, &mut marks),
+                "{}",
+                zhuyin
+            );
+            assert_eq!(tones.as_bytes(), &marks[..tones.len()], "{}", zhuyin);
+        }
+    }
+
+    #[test]
+    fn syllables_end_at_tone_keys_or_new_initials() {
+        let (mut buf, mut marks) = ([0u8; 32], [0u8; 32]);
+        assert_eq!(Some("nihao"), key("ㄋㄧˇㄏㄠˇ", &mut buf, &mut marks));
+        assert_eq!(Some("nihao"), key("ㄋㄧㄏㄠ", &mut buf, &mut marks));
+        assert_eq!(Some("xian"), key("ㄒㄧㄢ", &mut buf, &mut marks));
+        // Tone key without a syllable, and a symbol out of order
+        assert_eq!(None, key("ˇㄋㄧ", &mut buf, &mut marks));
+        assert_eq!(None, key("ㄋㄧ  ", &mut buf, &mut marks));
+        assert_eq!(None, key("ㄅㄉ", &mut buf, &mut marks));
+        assert_eq!(None, key("ni", &mut buf, &mut marks));
+    }
+
+    #[test]
+    fn text_must_not_split_a_syllable() {
+        let mut buf = [0u8; 32];
+        let mut marks = [0u8; 32];
+        let mut to = |text, prev, next| to_key(text, prev, next, &mut buf, &mut marks);
+        assert_eq!(Some((2, 1 << 2)), to("ㄒㄧ", None, Some('ㄏ')));
+        // The rest of ㄒㄧㄤ, or its tone key
+        assert_eq!(None, to("ㄒㄧ", None, Some('ㄤ')));
+        assert_eq!(None, to("ㄒㄧ", None, Some('ˇ')));
+        assert_eq!(None, to("ㄒㄧ", None, Some(' ')));
+        assert_eq!(None, to("ㄧㄤ", Some('ㄒ'), None));
+        assert_eq!(Some((4, 1 << 4)), to("ㄧㄤ", Some('ˇ'), None));
+    }
+
+    #[test]
+    fn dachen_layout() {
+        let keys = "su3cl3";
+        let mut zhuyin = [' '; 6];
+        for (z, k) in zhuyin.iter_mut().zip(keys.chars()) {
+            *z = dachen(k).unwrap();
+        }
+        assert_eq!(['ㄋ', 'ㄧ', 'ˇ', 'ㄏ', 'ㄠ', 'ˇ'], zhuyin);
+        assert_eq!(None, dachen(' '));
+        assert_eq!(None, dachen('A'));
+    }
+}
@@ -73,3 +73,4 @@ compare_tsv_to_official("hsk2.tsv", new_words[:hsk2])
 compare_tsv_to_official("hsk3.tsv", new_words[:hsk3])
 compare_tsv_to_official("hsk4.tsv", new_words[:hsk4])
 compare_tsv_to_official("hsk5.tsv", new_words[:hsk5])
+compare_tsv_to_official("hsk6.tsv", new_words[:hsk6])
@@ -34,7 +34,7 @@ function showHelp() {
                     '<li> For choices like (1喝 2和 3河), pick with numbers or space </li>\n' +
                     '<li> Send with return or enter. </li> </ul>\n' +
                     '<p> Example: <br>\n' +
-                    '&nbsp; "woxiang he guozhi", plus return, makes "<span lang="zh-CN">我想喝果汁</span>" </p>\n' +
+                    '&nbsp; "wo xiang he guozhi", plus return, makes "<span lang="zh-CN">我想喝果汁</span>" </p>\n' +
                     '<p> Slash Commands: <span>/</span>help <span>/</span>about <span>/</span>clear </p>');
 }
 
@@ -104,7 +104,7 @@ function wasmDemo() {
     // The "<span>/</span>help..." stuff causes VoiceOver to say "slash help" instead of just "help".
     chatLogSafeHTML("<p>This terminal has a built in Simplified Chinese IME. " +
                     "You can type pinyin phrases, other text, or <span>/</span>commands.<br> " +
-                    "Try <strong>wo xiang he guozhi</strong> or <strong>woxiangheguozhi11</strong> " +
+                    "Try <strong>wo xiang he guozhi</strong> or <strong>wo xiangheguozhi11</strong> " +
                     "(...one one) <br> " +
                     "Try <strong><span>/</span>help</strong> or <strong><span>/</span>about</strong>.</p>");
     enableChatMode();